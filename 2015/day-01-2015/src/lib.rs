@@ -0,0 +1,67 @@
+// https://adventofcode.com/2015/day/1
+
+// imports
+
+use solver::Solver;
+
+// code
+
+pub fn part_1(input_string: &str) -> i32 {
+    let final_floor =
+        input_string.chars().fold(
+            0,
+            |current_floor: i32, instruction: char| match instruction {
+                '(' => {
+                    return current_floor + 1;
+                }
+                ')' => {
+                    return current_floor - 1;
+                }
+                _ => {
+                    unreachable!();
+                }
+            },
+        );
+
+    return final_floor;
+}
+
+// the position of the first instruction that causes Santa to enter the basement
+pub fn part_2(input_string: &str) -> Option<usize> {
+    let mut current_floor = 0;
+    let mut pos_of_instruction = 0;
+    for instruction in input_string.chars() {
+        pos_of_instruction += 1;
+
+        match instruction {
+            '(' => {
+                current_floor += 1;
+            }
+            ')' => {
+                current_floor -= 1;
+            }
+            _ => {
+                unreachable!();
+            }
+        }
+
+        if current_floor < 0 {
+            return Some(pos_of_instruction);
+        }
+    }
+
+    return None;
+}
+
+pub const SOLVER: Solver = Solver {
+    year: 2015,
+    day: 1,
+    input: include_str!("input.txt"),
+    part_1: |input_string| Ok(part_1(input_string).to_string()),
+    part_2: Some(|input_string| {
+        return part_2(input_string)
+            .map(|position| position.to_string())
+            .ok_or_else(|| "Santa never enters the basement".to_string());
+    }),
+    examples: &[],
+};
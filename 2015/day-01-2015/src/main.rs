@@ -1,52 +1,18 @@
 // https://adventofcode.com/2015/day/1
 
-// code
+use day_01_2015::{part_1, part_2};
+use solver::load_input_from_args;
 
 fn main() {
-    let input_string = include_str!("input.txt");
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let input_string = load_input_from_args(&args).unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        std::process::exit(1);
+    });
 
-    // part 1
+    println!("Part 1: {}", part_1(&input_string));
 
-    let final_floor =
-        input_string.chars().fold(
-            0,
-            |current_floor: i32, instruction: char| match instruction {
-                '(' => {
-                    return current_floor + 1;
-                }
-                ')' => {
-                    return current_floor - 1;
-                }
-                _ => {
-                    unreachable!();
-                }
-            },
-        );
-
-    println!("Part 1: {}", final_floor);
-
-    // part 2
-
-    let mut current_floor = 0;
-    let mut pos_of_instruction = 0;
-    for instruction in input_string.chars() {
-        pos_of_instruction += 1;
-
-        match instruction {
-            '(' => {
-                current_floor += 1;
-            }
-            ')' => {
-                current_floor -= 1;
-            }
-            _ => {
-                unreachable!();
-            }
-        }
-
-        if current_floor < 0 {
-            println!("Part 2: {}", pos_of_instruction);
-            break;
-        }
+    if let Some(pos_of_instruction) = part_2(&input_string) {
+        println!("Part 2: {}", pos_of_instruction);
     }
 }
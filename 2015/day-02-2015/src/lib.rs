@@ -0,0 +1,143 @@
+// https://adventofcode.com/2015/day/2
+
+// imports
+
+use solver::{Example, Solver};
+
+// code
+
+#[derive(Debug)]
+struct Dimensions {
+    length: u32,
+    width: u32,
+    height: u32,
+}
+
+impl Dimensions {
+    fn get_slack(&self) -> u32 {
+        let axis_1 = self.length * self.width;
+        let axis_2 = self.width * self.height;
+        let axis_3 = self.length * self.height;
+
+        let slacks = vec![axis_1, axis_2, axis_3];
+
+        slacks.into_iter().min_by(|x, y| x.cmp(y)).unwrap()
+    }
+
+    fn get_area(&self) -> u32 {
+        let axis_1 = 2 * self.length * self.width;
+        let axis_2 = 2 * self.width * self.height;
+        let axis_3 = 2 * self.length * self.height;
+
+        axis_1 + axis_2 + axis_3 + self.get_slack()
+    }
+
+    fn required_amount_of_ribbon(&self) -> u32 {
+        let axis_1 = 2 * self.length + 2 * self.width;
+        let axis_2 = 2 * self.width + 2 * self.height;
+        let axis_3 = 2 * self.length + 2 * self.height;
+
+        let possible_wraps = vec![axis_1, axis_2, axis_3];
+
+        let minimum_wrap = possible_wraps.into_iter().min_by(|x, y| x.cmp(y)).unwrap();
+
+        let bow_tie = self.length * self.width * self.height;
+
+        minimum_wrap + bow_tie
+    }
+}
+
+fn parse_input(input_string: &str) -> Vec<Dimensions> {
+    let mut output: Vec<Dimensions> = vec![];
+
+    for input in input_string.trim().lines() {
+        let input = input.trim();
+
+        let coords: Vec<u32> = input
+            .split('x')
+            .map(|x| x.trim())
+            .map(|x| x.parse::<u32>().unwrap())
+            .collect();
+
+        let result = Dimensions {
+            length: coords[0],
+            width: coords[1],
+            height: coords[2],
+        };
+
+        output.push(result);
+    }
+
+    output
+}
+
+pub fn part_1(input_string: &str) -> u32 {
+    let dimensions = parse_input(input_string);
+
+    let sum: u32 = dimensions
+        .iter()
+        .fold(0, |acc, item| -> u32 { acc + item.get_area() });
+
+    sum
+}
+
+pub fn part_2(input_string: &str) -> u32 {
+    let dimensions = parse_input(input_string);
+
+    let sum: u32 = dimensions.iter().fold(0, |acc, item| -> u32 {
+        acc + item.required_amount_of_ribbon()
+    });
+
+    sum
+}
+
+pub const SOLVER: Solver = Solver {
+    year: 2015,
+    day: 2,
+    input: include_str!("input.txt"),
+    part_1: |input_string| Ok(part_1(input_string).to_string()),
+    part_2: Some(|input_string| Ok(part_2(input_string).to_string())),
+    examples: &[
+        Example {
+            input: include_str!("../examples/1.txt"),
+            part_1: Some("58"),
+            part_2: Some("34"),
+        },
+        Example {
+            input: include_str!("../examples/2.txt"),
+            part_1: Some("43"),
+            part_2: Some("14"),
+        },
+    ],
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(part_1(include_str!("input.txt")), 1586300);
+
+        let input_string = include_str!("../examples/1.txt");
+
+        assert_eq!(part_1(input_string), 58);
+
+        let input_string = include_str!("../examples/2.txt");
+
+        assert_eq!(part_1(input_string), 43);
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(part_2(include_str!("input.txt")), 3737498);
+
+        let input_string = include_str!("../examples/1.txt");
+
+        assert_eq!(part_2(input_string), 34);
+
+        let input_string = include_str!("../examples/2.txt");
+
+        assert_eq!(part_2(input_string), 14);
+    }
+}
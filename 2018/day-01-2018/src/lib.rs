@@ -0,0 +1,183 @@
+// https://adventofcode.com/2018/day/1
+
+// stdlib imports
+
+use solver::{Example, Solver};
+use std::collections::HashSet;
+
+// helpers
+
+fn parse_changes(input_string: &str) -> Vec<i32> {
+    return input_string
+        .split_whitespace()
+        .map(|input| input.parse().unwrap())
+        .collect();
+}
+
+// Part 1
+
+pub fn part_1(input_string: &str) -> i32 {
+    // according to the puzzle, the starting frequency is 0
+    return parse_changes(input_string).into_iter().sum();
+}
+
+// Part 2
+
+// whether a frequency is ever reached twice, without cycling through the changes.
+//
+// after k cycles, the frequency reached by the first i changes is partial_sums[i] + k * drift,
+// where drift is the sum of every change.
+// if there's no drift, the frequencies repeat on the second cycle at the latest.
+// otherwise, two frequencies can only meet if their partial sums leave the same remainder when divided by the drift,
+// as the one that's behind catches up to the other one drift by drift.
+fn has_repeat(partial_sums: &[i32], drift: i32) -> bool {
+    if drift == 0 {
+        return true;
+    }
+
+    let mut remainders: HashSet<i32> = HashSet::new();
+
+    for partial_sum in partial_sums {
+        if !remainders.insert(partial_sum.rem_euclid(drift)) {
+            return true;
+        }
+    }
+
+    return false;
+}
+
+// the first frequency reached twice, cycling through the changes as many times as it takes.
+// there's none if every frequency is only ever reached once.
+pub fn part_2(input_string: &str) -> Option<i32> {
+    let changes = parse_changes(input_string);
+
+    if changes.is_empty() {
+        return None;
+    }
+
+    // the frequencies reached within the first cycle, starting with the starting frequency of 0
+    let partial_sums: Vec<i32> = std::iter::once(0)
+        .chain(changes.iter().scan(0, |frequency, change| {
+            *frequency += change;
+            return Some(*frequency);
+        }))
+        .take(changes.len())
+        .collect();
+    let drift: i32 = changes.iter().sum();
+
+    if !has_repeat(&partial_sums, drift) {
+        return None;
+    }
+
+    let mut seen_frequencies: HashSet<i32> = HashSet::new();
+    let mut current_frequency = 0;
+    seen_frequencies.insert(current_frequency);
+
+    // a large drift compared to the changes can take many cycles to reach a repeat
+    for change in changes.iter().cycle() {
+        current_frequency += change;
+
+        if !seen_frequencies.insert(current_frequency) {
+            return Some(current_frequency);
+        }
+    }
+
+    unreachable!();
+}
+
+pub const SOLVER: Solver = Solver {
+    year: 2018,
+    day: 1,
+    input: include_str!("input.txt"),
+    part_1: |input_string| Ok(part_1(input_string).to_string()),
+    part_2: Some(|input_string| {
+        return part_2(input_string)
+            .map(|frequency| frequency.to_string())
+            .ok_or_else(|| "No frequency is ever seen twice".to_string());
+    }),
+    examples: &[
+        Example {
+            input: include_str!("../examples/1.txt"),
+            part_1: Some("3"),
+            part_2: Some("2"),
+        },
+        Example {
+            input: include_str!("../examples/2.txt"),
+            part_1: Some("3"),
+            part_2: None,
+        },
+        Example {
+            input: include_str!("../examples/3.txt"),
+            part_1: Some("0"),
+            part_2: None,
+        },
+        Example {
+            input: include_str!("../examples/4.txt"),
+            part_1: Some("-6"),
+            part_2: None,
+        },
+        Example {
+            input: include_str!("../examples/5.txt"),
+            part_1: None,
+            part_2: Some("0"),
+        },
+        Example {
+            input: include_str!("../examples/6.txt"),
+            part_1: None,
+            part_2: Some("10"),
+        },
+        Example {
+            input: include_str!("../examples/7.txt"),
+            part_1: None,
+            part_2: Some("5"),
+        },
+        Example {
+            input: include_str!("../examples/8.txt"),
+            part_1: None,
+            part_2: Some("14"),
+        },
+    ],
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(part_1(include_str!("../examples/1.txt")), 3);
+        assert_eq!(part_1(include_str!("../examples/2.txt")), 3);
+        assert_eq!(part_1(include_str!("../examples/3.txt")), 0);
+        assert_eq!(part_1(include_str!("../examples/4.txt")), -6);
+
+        assert_eq!(part_1(include_str!("input.txt")), 513);
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(part_2(include_str!("../examples/1.txt")), Some(2));
+        assert_eq!(part_2(include_str!("../examples/5.txt")), Some(0));
+        assert_eq!(part_2(include_str!("../examples/6.txt")), Some(10));
+        assert_eq!(part_2(include_str!("../examples/7.txt")), Some(5));
+        assert_eq!(part_2(include_str!("../examples/8.txt")), Some(14));
+
+        // the changes only ever drift the frequency away
+        assert_eq!(part_2(include_str!("../examples/2.txt")), None);
+        assert_eq!(part_2("+1\n+2"), None);
+        assert_eq!(part_2(""), None);
+
+        // a large drift takes many cycles to come back around
+        assert_eq!(part_2("+10000\n-9999"), Some(10000));
+
+        assert_eq!(part_2(include_str!("input.txt")), Some(287));
+    }
+
+    #[test]
+    fn test_has_repeat() {
+        assert!(has_repeat(&[0, 1], 0));
+        assert!(has_repeat(&[0, 10000], 1));
+        assert!(!has_repeat(&[0, 1, 2], 3));
+        assert!(!has_repeat(&[0, -1, -2], -3));
+        assert!(has_repeat(&[0, 4], -2));
+    }
+}
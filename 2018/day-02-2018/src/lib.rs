@@ -0,0 +1,244 @@
+// https://adventofcode.com/2018/day/2
+
+// stdlib imports
+
+use solver::{Example, Solver};
+use std::collections::HashMap;
+
+// types
+
+#[derive(Debug)]
+enum Parsed {
+    // input string only contain letter(s) that appear exactly two times
+    HasTwo,
+    // input string only contain letter(s) that appear exactly three times
+    HasThree,
+    // input string only contain letter(s) that either appear exactly two times,
+    // or appear exactly thre times
+    HasBoth,
+    // input string does not satisfy the above conditions
+    None,
+}
+
+// helpers
+
+fn parse_input(input: &str) -> Parsed {
+    let letter_counter = input.chars().fold(
+        HashMap::new(), // accumulator
+        |mut letter_counter: HashMap<char, i32>, letter| {
+            // count the number of occurrences of the letters within the given input
+
+            match &letter_counter.get(&letter) {
+                None => {
+                    letter_counter.insert(letter, 1);
+                }
+                Some(&num_of_occurrences) => {
+                    letter_counter.insert(letter, num_of_occurrences + 1);
+                }
+            }
+
+            return letter_counter;
+        },
+    );
+
+    // from letter_counter, determine if input contains letters that either
+    // - occur exactly two times
+    // - occur exactly three times
+    // - or both
+
+    let result = letter_counter.into_iter().fold(
+        Parsed::None, // accumulator
+        |current_state, (_letter, num_of_occurrences)| {
+            let has_two = num_of_occurrences == 2;
+            let has_three = num_of_occurrences == 3;
+
+            if !has_two && !has_three {
+                return current_state;
+            }
+
+            match current_state {
+                Parsed::None => {
+                    if has_two {
+                        return Parsed::HasTwo;
+                    }
+
+                    return Parsed::HasThree;
+                }
+                Parsed::HasTwo => {
+                    if has_two {
+                        return Parsed::HasTwo;
+                    }
+
+                    return Parsed::HasBoth;
+                }
+                Parsed::HasThree => {
+                    if has_three {
+                        return Parsed::HasThree;
+                    }
+
+                    return Parsed::HasBoth;
+                }
+                Parsed::HasBoth => {
+                    return Parsed::HasBoth;
+                }
+            }
+        },
+    );
+
+    return result;
+}
+
+// the number of IDs with a letter that appears exactly two times, and with a letter that appears exactly three times
+pub fn part_1(inputs: &[&str]) -> (i32, i32) {
+    let (num_of_two, num_of_three) = inputs.iter().map(|input| parse_input(input)).fold(
+        (
+            0, // number of times inputs containing 2 letters that occur at least once
+            0, // number of times inputs containing 3 letters that occur at least once
+        ),
+        |accumulator, parsed_state: Parsed| {
+            let (num_of_two, num_of_three) = accumulator;
+
+            match parsed_state {
+                Parsed::None => {
+                    return (num_of_two, num_of_three);
+                }
+                Parsed::HasBoth => {
+                    return (num_of_two + 1, num_of_three + 1);
+                }
+                Parsed::HasTwo => {
+                    return (num_of_two + 1, num_of_three);
+                }
+                Parsed::HasThree => {
+                    return (num_of_two, num_of_three + 1);
+                }
+            }
+        },
+    );
+
+    return (num_of_two, num_of_three);
+}
+
+pub fn common_letters(this: &str, other: &str) -> String {
+    let result: String = this
+        .chars()
+        .zip(other.chars())
+        .filter(|&(this_char, other_char): &(char, char)| -> bool {
+            return this_char == other_char;
+        })
+        .map(|(this_char, _other_char): (char, char)| this_char)
+        .collect();
+
+    return result;
+}
+
+// the two IDs that differ by exactly one character at the same position.
+//
+// rather than comparing every pair of IDs, every ID is keyed by its letters without the character at a position,
+// one position at a time. two IDs differ only at that position exactly if they have the same key.
+pub fn part_2<'a>(inputs: &[&'a str]) -> Option<(&'a str, &'a str)> {
+    let max_len = inputs
+        .iter()
+        .map(|input| input.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    for position in 0..max_len {
+        let mut seen: HashMap<String, &str> = HashMap::new();
+
+        for input in inputs.iter() {
+            let letters: Vec<char> = input.chars().collect();
+
+            if position >= letters.len() {
+                continue;
+            }
+
+            let key: String = letters[..position]
+                .iter()
+                .chain(letters[(position + 1)..].iter())
+                .collect();
+
+            match seen.get(&key) {
+                // the same ID listed twice doesn't differ at all
+                Some(other_input) if other_input != input => {
+                    return Some((other_input, input));
+                }
+                _ => {
+                    seen.insert(key, input);
+                }
+            }
+        }
+    }
+
+    return None;
+}
+
+pub const SOLVER: Solver = Solver {
+    year: 2018,
+    day: 2,
+    input: include_str!("input.txt"),
+    part_1: |input_string| {
+        let inputs: Vec<&str> = input_string.split_whitespace().collect();
+        let (num_of_two, num_of_three) = part_1(&inputs);
+        return Ok((num_of_two * num_of_three).to_string());
+    },
+    part_2: Some(|input_string| {
+        let inputs: Vec<&str> = input_string.split_whitespace().collect();
+        return part_2(&inputs)
+            .map(|(input, other_input)| common_letters(input, other_input))
+            .ok_or_else(|| "No two IDs differ by exactly one character".to_string());
+    }),
+    examples: &[
+        Example {
+            input: include_str!("../examples/1.txt"),
+            part_1: Some("12"),
+            part_2: None,
+        },
+        Example {
+            input: include_str!("../examples/2.txt"),
+            part_1: None,
+            part_2: Some("fgij"),
+        },
+    ],
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_1() {
+        let inputs: Vec<&str> = include_str!("../examples/1.txt")
+            .split_whitespace()
+            .collect();
+        assert_eq!(part_1(&inputs), (4, 3));
+
+        let input_string = include_str!("input.txt");
+        let inputs: Vec<&str> = input_string.split_whitespace().collect();
+        assert_eq!(part_1(&inputs), (248, 25));
+    }
+
+    #[test]
+    fn test_part_2() {
+        let inputs: Vec<&str> = include_str!("../examples/2.txt")
+            .split_whitespace()
+            .collect();
+        assert_eq!(part_2(&inputs), Some(("fghij", "fguij")));
+        assert_eq!(common_letters("fghij", "fguij"), "fgij");
+
+        // the first and the last letters differ, and IDs of other lengths never match
+        assert_eq!(part_2(&["xbc", "abc"]), Some(("xbc", "abc")));
+        assert_eq!(part_2(&["abx", "abc"]), Some(("abx", "abc")));
+        assert_eq!(part_2(&["ab", "abc", "xbcd"]), None);
+
+        // the same ID twice doesn't count
+        assert_eq!(part_2(&["abc", "abc", "xyz"]), None);
+
+        let input_string = include_str!("input.txt");
+        let inputs: Vec<&str> = input_string.split_whitespace().collect();
+        let (input, other_input) = part_2(&inputs).unwrap();
+        assert_eq!(
+            common_letters(input, other_input),
+            "xpysnnkqrbuhefmcajodplyzw"
+        );
+    }
+}
@@ -0,0 +1,274 @@
+// https://adventofcode.com/2018/day/3
+
+// imports
+
+use solver::{Example, Solver};
+
+// part 1
+
+#[derive(Debug, PartialEq, Clone, Hash, Eq)]
+pub struct Fabric {
+    pub id: String,
+
+    // starting coordinates
+    left: i32,
+    top: i32,
+
+    // size
+    height: i32,
+    width: i32,
+}
+
+impl Fabric {
+    fn right(&self) -> i32 {
+        return self.left + self.width;
+    }
+
+    fn bottom(&self) -> i32 {
+        return self.top + self.height;
+    }
+}
+
+// which square inches of the fabric are within two or more claims.
+//
+// every claim only adds to the four corners of its rectangle in a difference array,
+// and a prefix sum over the difference array turns it into the number of claims of every square inch.
+// that's linear in the number of claims and the area of the fabric, however large and many the claims are.
+struct ClaimMap {
+    width: usize,
+    height: usize,
+    // the number of square inches claimed more than once with x' < x and y' < y,
+    // indexed by y * (width + 1) + x
+    overlapping: Vec<i32>,
+}
+
+impl ClaimMap {
+    fn new(fabrics: &[Fabric]) -> ClaimMap {
+        let width = fabrics
+            .iter()
+            .map(|fabric| fabric.right())
+            .max()
+            .unwrap_or(0) as usize;
+        let height = fabrics
+            .iter()
+            .map(|fabric| fabric.bottom())
+            .max()
+            .unwrap_or(0) as usize;
+
+        // one more row and column, for the corners past the right and bottom edges
+        let mut differences: Vec<i32> = vec![0; (width + 1) * (height + 1)];
+        let index = |x: i32, y: i32| -> usize {
+            return y as usize * (width + 1) + x as usize;
+        };
+
+        for fabric in fabrics {
+            differences[index(fabric.left, fabric.top)] += 1;
+            differences[index(fabric.right(), fabric.top)] -= 1;
+            differences[index(fabric.left, fabric.bottom())] -= 1;
+            differences[index(fabric.right(), fabric.bottom())] += 1;
+        }
+
+        let mut counts: Vec<i32> = vec![0; width * height];
+        let mut overlapping: Vec<i32> = vec![0; (width + 1) * (height + 1)];
+
+        for y in 0..height {
+            for x in 0..width {
+                let above = if y > 0 {
+                    counts[(y - 1) * width + x]
+                } else {
+                    0
+                };
+                let left = if x > 0 { counts[y * width + x - 1] } else { 0 };
+                let above_left = if x > 0 && y > 0 {
+                    counts[(y - 1) * width + x - 1]
+                } else {
+                    0
+                };
+
+                let count = differences[y * (width + 1) + x] + above + left - above_left;
+                counts[y * width + x] = count;
+
+                overlapping[(y + 1) * (width + 1) + x + 1] = (count >= 2) as i32
+                    + overlapping[y * (width + 1) + x + 1]
+                    + overlapping[(y + 1) * (width + 1) + x]
+                    - overlapping[y * (width + 1) + x];
+            }
+        }
+
+        return ClaimMap {
+            width,
+            height,
+            overlapping,
+        };
+    }
+
+    // the number of square inches within two or more claims
+    fn num_of_overlapping(&self) -> i32 {
+        let (width, height) = (self.width, self.height);
+        return self.overlapping[height * (width + 1) + width];
+    }
+
+    // whether no square inch of a claim is claimed by any other claim
+    fn is_intact(&self, fabric: &Fabric) -> bool {
+        let index = |x: i32, y: i32| -> usize {
+            return y as usize * (self.width + 1) + x as usize;
+        };
+
+        let overlapping = self.overlapping[index(fabric.right(), fabric.bottom())]
+            - self.overlapping[index(fabric.left, fabric.bottom())]
+            - self.overlapping[index(fabric.right(), fabric.top)]
+            + self.overlapping[index(fabric.left, fabric.top)];
+
+        return overlapping == 0;
+    }
+}
+
+pub fn parse_to_fabric(input: &str) -> Fabric {
+    let mut parts = input.split_whitespace();
+
+    let id = parts.next().unwrap();
+
+    let (left, top): (i32, i32) = {
+        let location_string = parts.nth(1).unwrap().to_string();
+
+        let location_string: String = location_string
+            .chars()
+            // ignore the last charcter which is expected to be a colon :
+            .take(location_string.len() - 1)
+            .collect();
+
+        let locations: Vec<i32> = location_string
+            .split(',')
+            .map(|x| -> i32 { return x.parse().unwrap() })
+            .collect();
+
+        (*locations.get(0).unwrap(), *locations.get(1).unwrap())
+    };
+
+    let (width, height): (i32, i32) = {
+        let size_string = parts.next().unwrap().to_string();
+
+        let sizes: Vec<i32> = size_string
+            .split('x')
+            .map(|x| -> i32 { return x.parse().unwrap() })
+            .collect();
+
+        (*sizes.get(0).unwrap(), *sizes.get(1).unwrap())
+    };
+
+    Fabric {
+        id: id.to_string(),
+        left: left,
+        top: top,
+        height: height,
+        width: width,
+    }
+}
+
+pub fn part_1(fabrics: &[Fabric]) -> i32 {
+    return ClaimMap::new(fabrics).num_of_overlapping();
+}
+
+// part 2
+
+pub fn part_2(fabrics: &[Fabric]) -> Vec<&Fabric> {
+    let claim_map = ClaimMap::new(fabrics);
+
+    return fabrics
+        .iter()
+        .filter(|fabric| claim_map.is_intact(fabric))
+        .collect();
+}
+
+pub const SOLVER: Solver = Solver {
+    year: 2018,
+    day: 3,
+    input: include_str!("input.txt"),
+    part_1: |input_string| {
+        let fabrics: Vec<Fabric> = input_string.lines().map(parse_to_fabric).collect();
+        return Ok(part_1(&fabrics).to_string());
+    },
+    part_2: Some(|input_string| {
+        let fabrics: Vec<Fabric> = input_string.lines().map(parse_to_fabric).collect();
+        let ids: Vec<&str> = part_2(&fabrics)
+            .into_iter()
+            .map(|fabric| fabric.id.trim_start_matches('#'))
+            .collect();
+        return Ok(ids.join(","));
+    }),
+    examples: &[Example {
+        input: include_str!("../examples/1.txt"),
+        part_1: Some("4"),
+        part_2: Some("3"),
+    }],
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_to_fabric() {
+        let expected = Fabric {
+            id: "#123".to_string(),
+            left: 3,
+            top: 2,
+            height: 4,
+            width: 5,
+        };
+
+        assert_eq!(parse_to_fabric("#123 @ 3,2: 5x4"), expected);
+    }
+
+    #[test]
+    fn test_overlap() {
+        let fabrics: Vec<Fabric> = include_str!("../examples/1.txt")
+            .lines()
+            .map(parse_to_fabric)
+            .collect();
+
+        assert_eq!(part_1(&fabrics), 4);
+        assert_eq!(part_2(&fabrics), vec![&fabrics[2]]);
+
+        let claim_map = ClaimMap::new(&fabrics);
+        assert_eq!((claim_map.width, claim_map.height), (7, 7));
+        assert!(!claim_map.is_intact(&parse_to_fabric("#4 @ 4,4: 1x1")));
+        assert!(claim_map.is_intact(&parse_to_fabric("#4 @ 5,3: 2x2")));
+
+        // claims that only touch at their edges don't overlap
+        let fabrics = vec![
+            parse_to_fabric("#1 @ 0,0: 2x2"),
+            parse_to_fabric("#2 @ 2,0: 2x2"),
+            parse_to_fabric("#3 @ 0,2: 4x1"),
+        ];
+        assert_eq!(part_1(&fabrics), 0);
+        assert_eq!(part_2(&fabrics).len(), 3);
+
+        // the same square inch claimed three times only counts once
+        let fabrics = vec![
+            parse_to_fabric("#1 @ 0,0: 3x3"),
+            parse_to_fabric("#2 @ 1,1: 3x3"),
+            parse_to_fabric("#3 @ 2,2: 3x3"),
+        ];
+        assert_eq!(part_1(&fabrics), 4 + 4 - 1);
+        assert!(part_2(&fabrics).is_empty());
+
+        assert_eq!(part_1(&[]), 0);
+    }
+
+    #[test]
+    fn test_puzzle_input() {
+        let fabrics: Vec<Fabric> = include_str!("input.txt")
+            .lines()
+            .map(parse_to_fabric)
+            .collect();
+
+        assert_eq!(part_1(&fabrics), 96569);
+
+        let intact: Vec<&str> = part_2(&fabrics)
+            .iter()
+            .map(|fabric| fabric.id.as_str())
+            .collect();
+        assert_eq!(intact, vec!["#1023"]);
+    }
+}
@@ -0,0 +1,351 @@
+// https://adventofcode.com/2018/day/4
+
+// imports
+
+use solver::{Example, Solver};
+use std::collections::BTreeMap;
+
+// types
+
+pub type GuardID = i32;
+pub type Minute = i32;
+type Occurences = i32;
+
+// the fields are in order of significance, so timestamps are ordered chronologically
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+struct Timestamp {
+    year: i32,
+    month: i32,
+    day: i32,
+    hour: i32,
+    minute: Minute,
+}
+
+#[derive(Debug, PartialEq)]
+enum GuardState {
+    BeginsShift(GuardID),
+    Sleeps,
+    Wakes,
+}
+
+#[derive(Debug, PartialEq)]
+struct Record {
+    timestamp: Timestamp,
+    state: GuardState,
+}
+
+// how many times a guard was asleep on every minute of the midnight hour
+#[derive(Debug, Clone, PartialEq)]
+pub struct SleepHistogram {
+    occurences: [Occurences; 60],
+}
+
+impl SleepHistogram {
+    fn new() -> SleepHistogram {
+        SleepHistogram {
+            occurences: [0; 60],
+        }
+    }
+
+    // the guard falls asleep on slept_at, and is awake again on woke_up_at
+    fn add_nap(&mut self, slept_at: Minute, woke_up_at: Minute) {
+        assert!(0 <= slept_at && slept_at < woke_up_at && woke_up_at <= 60);
+
+        for minute in slept_at..woke_up_at {
+            self.occurences[minute as usize] += 1;
+        }
+    }
+
+    pub fn minutes_slept(&self) -> i32 {
+        return self.occurences.iter().sum();
+    }
+
+    // What minute does that guard spend asleep the most?
+    // ties go to the earliest minute, and there's none if the guard never slept.
+    fn get_minute_slept_most_at(&self) -> Option<(Minute, Occurences)> {
+        let (minute, occurences) = self
+            .occurences
+            .iter()
+            .enumerate()
+            .map(|(minute, occurences)| (minute as Minute, *occurences))
+            .min_by_key(|(minute, occurences)| (-occurences, *minute))
+            .unwrap();
+
+        if occurences == 0 {
+            return None;
+        }
+
+        return Some((minute, occurences));
+    }
+}
+
+// helpers
+
+// [1518-11-01 00:05]
+fn parse_timestamp(input: &str) -> Result<Timestamp, String> {
+    let invalid = || format!("Invalid timestamp: {}", input);
+
+    let inner = input
+        .trim()
+        .strip_prefix('[')
+        .and_then(|x| x.strip_suffix(']'))
+        .ok_or_else(invalid)?;
+
+    let numbers: Vec<i32> = inner
+        .split(&['-', ' ', ':'][..])
+        .map(|x| x.parse().map_err(|_| invalid()))
+        .collect::<Result<Vec<i32>, String>>()?;
+
+    match numbers.as_slice() {
+        [year, month, day, hour, minute] => {
+            if !(0..60).contains(minute) {
+                return Err(invalid());
+            }
+
+            return Ok(Timestamp {
+                year: *year,
+                month: *month,
+                day: *day,
+                hour: *hour,
+                minute: *minute,
+            });
+        }
+        _ => {
+            return Err(invalid());
+        }
+    }
+}
+
+// [1518-11-01 00:00] Guard #10 begins shift
+fn parse_record(input: &str) -> Result<Record, String> {
+    let end_of_timestamp = input
+        .find(']')
+        .ok_or_else(|| format!("Missing timestamp: {}", input))?;
+    let (timestamp, state_string) = input.split_at(end_of_timestamp + 1);
+
+    let timestamp = parse_timestamp(timestamp)?;
+    let state_string = state_string.trim();
+
+    let state: GuardState = if state_string == "wakes up" {
+        GuardState::Wakes
+    } else if state_string == "falls asleep" {
+        GuardState::Sleeps
+    } else if state_string.starts_with("Guard") {
+        let guard_id: GuardID = state_string
+            .split_whitespace()
+            .nth(1)
+            .and_then(|x| x.strip_prefix('#'))
+            .and_then(|x| x.parse().ok())
+            .ok_or_else(|| format!("Invalid guard: {}", input))?;
+        GuardState::BeginsShift(guard_id)
+    } else {
+        return Err(format!("Unknown record: {}", input));
+    };
+
+    return Ok(Record { timestamp, state });
+}
+
+// the records are sorted by their timestamps, and replayed to build up a histogram for every guard
+fn get_sleep_histograms(
+    mut records: Vec<Record>,
+) -> Result<BTreeMap<GuardID, SleepHistogram>, String> {
+    records.sort_by_key(|record| record.timestamp);
+
+    let mut histograms: BTreeMap<GuardID, SleepHistogram> = BTreeMap::new();
+    let mut current_guard: Option<GuardID> = None;
+    let mut slept_at: Option<Minute> = None;
+
+    for record in records {
+        match record.state {
+            GuardState::BeginsShift(guard_id) => {
+                if slept_at.is_some() {
+                    return Err(format!(
+                        "Guard #{} begins shift at {:?} while the last guard is asleep",
+                        guard_id, record.timestamp
+                    ));
+                }
+
+                current_guard = Some(guard_id);
+                histograms
+                    .entry(guard_id)
+                    .or_insert_with(SleepHistogram::new);
+            }
+            GuardState::Sleeps => {
+                if current_guard.is_none() || slept_at.is_some() {
+                    return Err(format!("Unexpected falls asleep at {:?}", record.timestamp));
+                }
+
+                slept_at = Some(record.timestamp.minute);
+            }
+            GuardState::Wakes => {
+                let (guard_id, minute) = match (current_guard, slept_at) {
+                    (Some(guard_id), Some(minute)) => (guard_id, minute),
+                    _ => {
+                        return Err(format!("Unexpected wakes up at {:?}", record.timestamp));
+                    }
+                };
+
+                histograms
+                    .get_mut(&guard_id)
+                    .unwrap()
+                    .add_nap(minute, record.timestamp.minute);
+
+                slept_at = None;
+            }
+        }
+    }
+
+    return Ok(histograms);
+}
+
+pub fn parse_input(input_string: &str) -> Result<BTreeMap<GuardID, SleepHistogram>, String> {
+    let records: Vec<Record> = input_string
+        .trim()
+        .lines()
+        .map(parse_record)
+        .collect::<Result<Vec<Record>, String>>()?;
+
+    return get_sleep_histograms(records);
+}
+
+// Strategy 1: Find the guard that has the most minutes asleep. What minute does that guard spend asleep the most?
+pub fn strategy_1(histograms: &BTreeMap<GuardID, SleepHistogram>) -> Option<(GuardID, Minute)> {
+    let (guard_id, histogram) = histograms
+        .iter()
+        .max_by_key(|(guard_id, histogram)| (histogram.minutes_slept(), -**guard_id))?;
+
+    let (minute, _occurences) = histogram.get_minute_slept_most_at()?;
+
+    return Some((*guard_id, minute));
+}
+
+// Strategy 2: Of all guards, which guard is most frequently asleep on the same minute?
+pub fn strategy_2(histograms: &BTreeMap<GuardID, SleepHistogram>) -> Option<(GuardID, Minute)> {
+    let (guard_id, minute, _occurences) = histograms
+        .iter()
+        .filter_map(|(guard_id, histogram)| {
+            return histogram
+                .get_minute_slept_most_at()
+                .map(|(minute, occurences)| (*guard_id, minute, occurences));
+        })
+        .max_by_key(|(guard_id, minute, occurences)| (*occurences, -minute, -guard_id))?;
+
+    return Some((guard_id, minute));
+}
+
+pub const SOLVER: Solver = Solver {
+    year: 2018,
+    day: 4,
+    input: include_str!("input.txt"),
+    part_1: |input_string| {
+        let histograms = parse_input(input_string)?;
+        return strategy_1(&histograms)
+            .map(|(guard_id, minute)| (guard_id * minute).to_string())
+            .ok_or_else(|| "No guard found".to_string());
+    },
+    part_2: Some(|input_string| {
+        let histograms = parse_input(input_string)?;
+        return strategy_2(&histograms)
+            .map(|(guard_id, minute)| (guard_id * minute).to_string())
+            .ok_or_else(|| "No guard found".to_string());
+    }),
+    examples: &[Example {
+        input: include_str!("../examples/1.txt"),
+        part_1: Some("240"),
+        part_2: Some("4455"),
+    }],
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_record() {
+        assert_eq!(
+            parse_record("[1518-11-01 23:58] Guard #99 begins shift"),
+            Ok(Record {
+                timestamp: Timestamp {
+                    year: 1518,
+                    month: 11,
+                    day: 1,
+                    hour: 23,
+                    minute: 58,
+                },
+                state: GuardState::BeginsShift(99),
+            })
+        );
+        assert_eq!(
+            parse_record("[1518-11-02 00:40] falls asleep")
+                .unwrap()
+                .state,
+            GuardState::Sleeps
+        );
+        assert_eq!(
+            parse_record("[1518-11-02 00:50] wakes up").unwrap().state,
+            GuardState::Wakes
+        );
+
+        assert!(parse_record("[1518-11-02 00:50 wakes up").is_err());
+        assert!(parse_record("[1518-11-02 00:60] wakes up").is_err());
+        assert!(parse_record("[1518-11-02] wakes up").is_err());
+        assert!(parse_record("[1518-11-02 00:50] Guard 10 begins shift").is_err());
+        assert!(parse_record("[1518-11-02 00:50] snores").is_err());
+
+        // timestamps are ordered chronologically, and not by the order of the lines
+        assert!(
+            parse_timestamp("[1518-09-24 23:59]").unwrap()
+                < parse_timestamp("[1518-10-02 00:00]").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_sleep_histogram() {
+        let mut histogram = SleepHistogram::new();
+        assert_eq!(histogram.get_minute_slept_most_at(), None);
+
+        histogram.add_nap(5, 25);
+        histogram.add_nap(30, 55);
+        histogram.add_nap(24, 29);
+
+        assert_eq!(histogram.minutes_slept(), 50);
+        assert_eq!(histogram.occurences[4], 0);
+        assert_eq!(histogram.occurences[24], 2);
+        assert_eq!(histogram.occurences[29], 0);
+        assert_eq!(histogram.get_minute_slept_most_at(), Some((24, 2)));
+
+        // ties go to the earliest minute
+        histogram.add_nap(50, 60);
+        assert_eq!(histogram.get_minute_slept_most_at(), Some((24, 2)));
+    }
+
+    #[test]
+    fn test_strategies() {
+        // the records are shuffled; they're sorted by their timestamps
+        let input_string = include_str!("../examples/1.txt");
+
+        let histograms = parse_input(input_string).unwrap();
+        assert_eq!(histograms.len(), 2);
+        assert_eq!(histograms[&10].minutes_slept(), 50);
+        assert_eq!(histograms[&99].minutes_slept(), 30);
+        assert_eq!(histograms[&99].get_minute_slept_most_at(), Some((45, 3)));
+
+        assert_eq!(strategy_1(&histograms), Some((10, 24)));
+        assert_eq!(strategy_2(&histograms), Some((99, 45)));
+
+        // a guard that never sleeps has no sleepiest minute
+        let histograms = parse_input("[1518-11-01 00:00] Guard #10 begins shift").unwrap();
+        assert_eq!(strategy_1(&histograms), None);
+        assert_eq!(strategy_2(&histograms), None);
+
+        assert!(parse_input("[1518-11-01 00:05] falls asleep").is_err());
+        assert!(parse_input(
+            "[1518-11-01 00:00] Guard #10 begins shift\n[1518-11-01 00:25] wakes up"
+        )
+        .is_err());
+
+        let histograms = parse_input(include_str!("input.txt")).unwrap();
+        assert_eq!(strategy_1(&histograms), Some((401, 21)));
+        assert_eq!(strategy_2(&histograms), Some((2689, 31)));
+    }
+}
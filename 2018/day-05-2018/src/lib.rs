@@ -0,0 +1,149 @@
+// https://adventofcode.com/2018/day/5
+
+// imports
+
+use rayon::prelude::*;
+use solver::{Example, Solver};
+use std::collections::HashSet;
+use tracing::debug;
+
+// helpers
+
+fn is_same_type(x: char, y: char) -> bool {
+    return x.to_lowercase().to_string() == y.to_lowercase().to_string();
+}
+
+fn is_opposite_polarity(x: char, y: char) -> bool {
+    return (x.is_uppercase() && y.is_lowercase()) || (y.is_uppercase() && x.is_lowercase());
+}
+
+fn does_react(x: char, y: char) -> bool {
+    return is_same_type(x, y) && is_opposite_polarity(x, y);
+}
+
+// react the units of a polymer in a single pass.
+// the stack holds the units that have been fully reacted so far; every unit either reacts with the unit on top,
+// which removes both, or is pushed onto the stack.
+fn react(units: impl Iterator<Item = char>) -> Vec<char> {
+    let mut stack: Vec<char> = vec![];
+
+    for unit in units {
+        match stack.last() {
+            Some(last_unit) if does_react(*last_unit, unit) => {
+                stack.pop();
+            }
+            _ => {
+                stack.push(unit);
+            }
+        }
+    }
+
+    return stack;
+}
+
+pub fn part_1(input: &str) -> String {
+    return react(input.trim().chars()).into_iter().collect();
+}
+
+pub fn part_2(input: &str) -> String {
+    // removing a unit type and then reacting gives the same polymer as removing it from the already reacted polymer,
+    // and then reacting that; so every search starts from the shorter reacted polymer
+    let reacted = react(input.trim().chars());
+
+    let mut unique_types: Vec<char> = reacted
+        .iter()
+        .map(|unit| unit.to_ascii_lowercase())
+        .collect::<HashSet<char>>()
+        .into_iter()
+        .collect();
+    unique_types.sort_unstable();
+
+    let result = unique_types
+        .par_iter()
+        .map(|character: &char| -> String {
+            let units = reacted
+                .iter()
+                .cloned()
+                .filter(|x| -> bool { return !is_same_type(*x, *character) });
+
+            let polymer: String = react(units).into_iter().collect();
+            debug!(unit_type = %character, length = polymer.len(), "removed a unit type");
+
+            return polymer;
+        })
+        // the shortest polymer; ties go to the earliest unit type
+        .min_by_key(|polymer| polymer.len());
+
+    return result.unwrap_or_else(|| reacted.into_iter().collect());
+}
+
+pub const SOLVER: Solver = Solver {
+    year: 2018,
+    day: 5,
+    input: include_str!("input.txt"),
+    part_1: |input_string| Ok(part_1(input_string).len().to_string()),
+    part_2: Some(|input_string| Ok(part_2(input_string).len().to_string())),
+    examples: &[Example {
+        input: include_str!("../examples/1.txt"),
+        part_1: Some("10"),
+        part_2: Some("4"),
+    }],
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_same_type() {
+        assert_eq!(is_same_type('a', 'a'), true);
+        assert_eq!(is_same_type('a', 'A'), true);
+        assert_eq!(is_same_type('a', 'b'), false);
+    }
+
+    #[test]
+    fn test_is_opposite_polarity() {
+        assert_eq!(is_opposite_polarity('a', 'a'), false);
+        assert_eq!(is_opposite_polarity('B', 'B'), false);
+        assert_eq!(is_opposite_polarity('a', 'A'), true);
+        assert_eq!(is_opposite_polarity('A', 'a'), true);
+        assert_eq!(is_opposite_polarity('a', 'B'), true);
+    }
+
+    #[test]
+    fn test_does_react() {
+        assert_eq!(does_react('a', 'a'), false);
+        assert_eq!(does_react('A', 'A'), false);
+        assert_eq!(does_react('a', 'A'), true);
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(
+            part_1(include_str!("../examples/1.txt")),
+            "dabCBAcaDA".to_string()
+        );
+
+        // reactions cascade outwards
+        assert_eq!(part_1("abcCBA"), "".to_string());
+        assert_eq!(part_1("abBAc"), "c".to_string());
+        assert_eq!(part_1("aA\n"), "".to_string());
+        assert_eq!(part_1("aAbBxaAbB"), "x".to_string());
+        assert_eq!(part_1(""), "".to_string());
+
+        assert_eq!(part_1(include_str!("input.txt")).len(), 9154);
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(
+            part_2(include_str!("../examples/1.txt")),
+            "daDA".to_string()
+        );
+
+        // nothing is left to remove
+        assert_eq!(part_2("aA"), "".to_string());
+
+        assert_eq!(part_2(include_str!("input.txt")).len(), 4556);
+    }
+}
@@ -0,0 +1,202 @@
+// https://adventofcode.com/2018/day/6
+
+// imports
+
+use geometry::{get_manhattan_distance, BoundingBox, Coordinate, Distance};
+use solver::{Example, Solver};
+
+// helpers
+
+pub type Area = i32;
+
+// the puzzle's limit on the total distance to every coordinate for the safe region
+pub const SAFE_DISTANCE: Distance = 10000;
+
+fn parse_to_coord(input: &str) -> Coordinate {
+    let result: Vec<i32> = input
+        .split(',')
+        .map(|x| -> i32 { x.trim().parse().unwrap() })
+        .collect();
+
+    (result[0], result[1])
+}
+
+fn parse_input(input_string: &str) -> Vec<Coordinate> {
+    return input_string.trim().lines().map(parse_to_coord).collect();
+}
+
+// the index of the coordinate closest to a position, if no other coordinate is just as close
+fn get_closest(destinations: &[Coordinate], position: Coordinate) -> Option<usize> {
+    let mut closest: Option<(usize, Distance)> = None;
+    let mut is_tied = false;
+
+    for (index, destination) in destinations.iter().enumerate() {
+        let distance = get_manhattan_distance(position, *destination);
+
+        match closest {
+            Some((_index, closest_distance)) if distance > closest_distance => {}
+            Some((_index, closest_distance)) if distance == closest_distance => {
+                is_tied = true;
+            }
+            _ => {
+                closest = Some((index, distance));
+                is_tied = false;
+            }
+        }
+    }
+
+    if is_tied {
+        return None;
+    }
+
+    return closest.map(|(index, _distance)| index);
+}
+
+// the area of the region of every coordinate, or None for the regions that are infinite.
+//
+// past the bounding box of the coordinates, moving away from the box brings a position
+// one step further away from every coordinate alike, so the closest coordinate never changes.
+// so a region that owns a position on the edge of the bounding box goes on forever,
+// and every other region is entirely within the box.
+fn get_region_areas(destinations: &[Coordinate]) -> Vec<Option<Area>> {
+    let mut areas: Vec<Option<Area>> = vec![Some(0); destinations.len()];
+
+    let bounding_box = match BoundingBox::from_coordinates(destinations) {
+        None => {
+            return areas;
+        }
+        Some(bounding_box) => bounding_box,
+    };
+
+    for position in bounding_box.coordinates() {
+        let index = match get_closest(destinations, position) {
+            None => {
+                continue;
+            }
+            Some(index) => index,
+        };
+
+        if bounding_box.is_on_edge(position) {
+            areas[index] = None;
+            continue;
+        }
+
+        if let Some(area) = areas[index].as_mut() {
+            *area += 1;
+        }
+    }
+
+    return areas;
+}
+
+pub fn part_1(input_string: &str) -> Option<Area> {
+    let destinations = parse_input(input_string);
+
+    return get_region_areas(&destinations).into_iter().flatten().max();
+}
+
+// the number of positions whose total distance to every coordinate is less than max_total_distance.
+//
+// a position at a distance of d outside of the bounding box is at least d further away from every coordinate
+// than the nearest position on the box, so the region can't reach past max_total_distance / (number of coordinates).
+fn get_safe_region_size(destinations: &[Coordinate], max_total_distance: Distance) -> Area {
+    let bounding_box = match BoundingBox::from_coordinates(destinations) {
+        None => {
+            return 0;
+        }
+        Some(bounding_box) => bounding_box,
+    };
+
+    let margin = max_total_distance / destinations.len() as i32 + 1;
+
+    let size_of_region = bounding_box
+        .expand(margin)
+        .coordinates()
+        .filter(|position| {
+            let total: Distance = destinations
+                .iter()
+                .map(|destination| get_manhattan_distance(*position, *destination))
+                .sum();
+
+            return total < max_total_distance;
+        })
+        .count();
+
+    return size_of_region as Area;
+}
+
+pub fn part_2(input_string: &str, max_total_distance: Distance) -> Area {
+    let destinations = parse_input(input_string);
+
+    return get_safe_region_size(&destinations, max_total_distance);
+}
+
+pub const SOLVER: Solver = Solver {
+    year: 2018,
+    day: 6,
+    input: include_str!("input.txt"),
+    part_1: |input_string| {
+        return part_1(input_string)
+            .map(|largest_region_size| largest_region_size.to_string())
+            .ok_or_else(|| "No region found".to_string());
+    },
+    part_2: Some(|input_string| Ok(part_2(input_string, SAFE_DISTANCE).to_string())),
+    examples: &[Example {
+        input: include_str!("../examples/1.txt"),
+        part_1: Some("17"),
+        part_2: None,
+    }],
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_to_coord() {
+        assert_eq!(parse_to_coord("1, 6"), (1, 6));
+    }
+
+    #[test]
+    fn test_get_closest() {
+        let destinations = vec![(1, 1), (1, 5), (8, 3)];
+
+        assert_eq!(get_closest(&destinations, (0, 0)), Some(0));
+        assert_eq!(get_closest(&destinations, (8, 3)), Some(2));
+        // (1, 1) and (1, 5) are both 3 away
+        assert_eq!(get_closest(&destinations, (0, 3)), None);
+        assert_eq!(get_closest(&[], (0, 0)), None);
+    }
+
+    #[test]
+    fn test_part_1() {
+        let input = include_str!("../examples/1.txt");
+
+        assert_eq!(part_1(input), Some(17));
+
+        // only D and E are finite
+        assert_eq!(
+            get_region_areas(&parse_input(input)),
+            vec![None, None, None, Some(9), Some(17), None]
+        );
+
+        // a lone coordinate, or coordinates in a row, are all infinite
+        assert_eq!(part_1("3, 3"), None);
+        assert_eq!(part_1("0, 0\n5, 0\n10, 0"), None);
+
+        assert_eq!(part_1(include_str!("input.txt")), Some(3933));
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input = include_str!("../examples/1.txt");
+
+        assert_eq!(part_2(input, 32), 16);
+
+        // the region reaches past the bounding box
+        assert_eq!(part_2("0, 0", 2), 5);
+        assert_eq!(part_2("", 10), 0);
+
+        assert_eq!(part_2(include_str!("input.txt"), SAFE_DISTANCE), 41145);
+    }
+}
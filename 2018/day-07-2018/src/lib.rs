@@ -0,0 +1,438 @@
+// https://adventofcode.com/2018/day/7
+
+// imports
+
+use solver::{Example, Solver};
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::iter::FromIterator;
+
+// types
+
+#[derive(PartialEq, Hash, Eq, Clone, Debug)]
+struct Vertex(char);
+
+impl PartialOrd for Vertex {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        other.0.partial_cmp(&self.0)
+    }
+}
+
+impl Ord for Vertex {
+    fn cmp(&self, other: &Vertex) -> Ordering {
+        let ord = self.partial_cmp(other).unwrap();
+        match ord {
+            Ordering::Greater => Ordering::Less,
+            Ordering::Less => Ordering::Greater,
+            Ordering::Equal => ord,
+        }
+    }
+}
+
+struct Vertices {
+    set: HashSet<Vertex>,
+    order: BinaryHeap<Vertex>,
+}
+
+impl Vertices {
+    fn new() -> Vertices {
+        Vertices {
+            set: HashSet::new(),
+            order: BinaryHeap::new(),
+        }
+    }
+
+    fn len(&self) -> usize {
+        return self.set.len();
+    }
+
+    fn pop(&mut self) -> Option<Vertex> {
+        let popped = self.order.pop();
+
+        match popped {
+            None => None,
+            Some(popped) => {
+                self.set.remove(&popped);
+                return Some(popped);
+            }
+        }
+    }
+
+    fn get_vertices(&self) -> HashSet<Vertex> {
+        return self.set.clone();
+    }
+
+    fn has_vertex(&self, vertex: &Vertex) -> bool {
+        return self.set.contains(vertex);
+    }
+
+    fn add_vertex(&mut self, vertex: Vertex) {
+        if self.has_vertex(&vertex) {
+            return;
+        }
+
+        self.set.insert(vertex.clone());
+        self.order.push(vertex);
+    }
+}
+
+type Edges = HashMap<Vertex, Vertices>;
+
+fn parse_instructions(input: &str) -> (Vertex, Vertex) {
+    let tokens: Vec<&str> = input.split_whitespace().collect();
+
+    let first = tokens.get(1).unwrap();
+    let second = tokens.get(7).unwrap();
+
+    return (
+        Vertex(first.chars().next().unwrap()),
+        Vertex(second.chars().next().unwrap()),
+    );
+}
+
+// vertices that have remaining work, mapped to the vertices that need to be visited before them
+type RemainingWork = HashMap<Vertex, HashSet<Vertex>>;
+
+// the work queue with the vertices that have no pre-requisites, the edges of the graph,
+// and the pre-requisites of every other vertex
+fn build_graph(input_string: &str) -> (Vertices, Edges, RemainingWork) {
+    let dependency_edges: Vec<(Vertex, Vertex)> = input_string
+        .trim()
+        .lines()
+        .map(parse_instructions)
+        .collect();
+
+    // list of all vertices in the graph that have no pre-requisites
+    let mut root_vertices: HashSet<Vertex> = HashSet::new();
+    // list of direct edges mapping one vertex to a set of vertices
+    let mut edges: Edges = HashMap::new();
+    // vertices that have remaining work (vertices that need to be visited)
+    let mut remaining_work: RemainingWork = HashMap::new();
+
+    for (maybe_root, _) in &dependency_edges {
+        root_vertices.insert(maybe_root.clone());
+    }
+
+    for (first, second) in &dependency_edges {
+        root_vertices.remove(second);
+
+        edges
+            .entry(first.clone())
+            .and_modify(|x| {
+                x.add_vertex(second.clone());
+            })
+            .or_insert_with(|| {
+                let mut x = Vertices::new();
+                x.add_vertex(second.clone());
+                x
+            });
+
+        remaining_work
+            .entry(second.clone())
+            .and_modify(|x| {
+                x.insert(first.clone());
+            })
+            .or_insert_with(|| {
+                let mut x = HashSet::new();
+                x.insert(first.clone());
+                x
+            });
+    }
+
+    // the min-heap always ensures available work is ordered alphabetically
+    let mut work_queue = Vertices::new();
+
+    // add roots into work queue
+    for vertex in root_vertices {
+        work_queue.add_vertex(vertex);
+    }
+
+    return (work_queue, edges, remaining_work);
+}
+
+// once current_work is done, remove it from the remaining work of every vertex adjacent to it,
+// and add the vertices without any remaining work left to the work queue
+fn complete_work(
+    current_work: &Vertex,
+    edges: &Edges,
+    remaining_work: &mut RemainingWork,
+    work_queue: &mut Vertices,
+) {
+    let adjacent_vertices = match edges.get(current_work) {
+        None => {
+            return;
+        }
+        Some(adjacent_vertices) => adjacent_vertices.get_vertices(),
+    };
+
+    for adjacent_vertex in adjacent_vertices.into_iter() {
+        let should_delete = match remaining_work.get_mut(&adjacent_vertex) {
+            None => false,
+            Some(x) => {
+                x.remove(current_work);
+                x.is_empty()
+            }
+        };
+
+        if should_delete {
+            // adjacent vertex has no remaining work left, add it to the work queue
+
+            remaining_work.remove(&adjacent_vertex);
+            work_queue.add_vertex(adjacent_vertex);
+        }
+    }
+}
+
+pub fn part_1(input_string: &str) -> String {
+    let (mut work_queue, edges, mut remaining_work) = build_graph(input_string);
+
+    let mut work_order: Vec<String> = vec![];
+
+    while let Some(current_work) = work_queue.pop() {
+        // perform work 🛠️
+
+        let Vertex(name) = &current_work;
+        work_order.push(name.to_string());
+
+        complete_work(&current_work, &edges, &mut remaining_work, &mut work_queue);
+    }
+
+    let work_order: String = work_order.join("");
+
+    return work_order;
+}
+
+// every step takes 60 seconds plus an amount corresponding to its letter
+pub const BASE_WORKLOAD: i32 = 60;
+
+// you'll have the help of 4 elves, so 5 workers in all
+pub const MAX_WORKER_LIMIT: i32 = 5;
+
+fn parse_to_work_load(x: char, base_workload: i32) -> i32 {
+    return x as i32 - 65 + 1 + base_workload;
+}
+
+type RemainingSeconds = i32;
+
+// NOTE: RemainingSeconds is the amount of seconds required to complete the task identified by Vertex
+#[derive(PartialEq, Hash, Eq, Clone, Debug)]
+struct WorkTask(Vertex, RemainingSeconds);
+
+impl WorkTask {
+    fn from_vertex(vertex: Vertex, base_workload: i32) -> WorkTask {
+        let Vertex(name) = &vertex;
+
+        let work_load = parse_to_work_load(*name, base_workload);
+
+        WorkTask(vertex, work_load)
+    }
+
+    fn to_vertex(self) -> Vertex {
+        self.0
+    }
+
+    fn remaining_work(&self) -> RemainingSeconds {
+        return self.1;
+    }
+
+    fn progress_work(&mut self, progress_work: RemainingSeconds) {
+        self.1 = self.1 - progress_work;
+    }
+}
+
+impl PartialOrd for WorkTask {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        other.1.partial_cmp(&self.1)
+    }
+}
+
+impl Ord for WorkTask {
+    fn cmp(&self, other: &WorkTask) -> Ordering {
+        let ord = self.partial_cmp(other).unwrap();
+        match ord {
+            Ordering::Greater => Ordering::Less,
+            Ordering::Less => Ordering::Greater,
+            Ordering::Equal => ord,
+        }
+    }
+}
+
+pub fn part_2(input_string: &str, base_workload: i32, max_worker_limit: i32) -> i32 {
+    let (mut work_queue, edges, mut remaining_work) = build_graph(input_string);
+
+    // how long it took to complete all the tasks
+    let mut duration = 0;
+
+    // min-heap with WorkTask items sorted from smallest remaining work to the largest
+    let mut work_in_progress: BinaryHeap<WorkTask> = BinaryHeap::new();
+
+    loop {
+        // the last steps may still be in progress, even if there's nothing left to assign
+        if remaining_work.is_empty() && work_queue.len() == 0 && work_in_progress.is_empty() {
+            break;
+        }
+
+        // assign any available work to any available workers
+
+        while (work_in_progress.len() as i32) < max_worker_limit && work_queue.len() > 0 {
+            let current_work = work_queue.pop().unwrap();
+            work_in_progress.push(WorkTask::from_vertex(current_work, base_workload));
+        }
+
+        // get work task(s) that can complete first
+
+        let mut completed_work: Vec<WorkTask> = vec![];
+
+        // invariant: work_in_progress is non-empty
+        assert!(!work_in_progress.is_empty());
+
+        let task = work_in_progress.pop().unwrap();
+        let min_remaining_work = task.remaining_work();
+        completed_work.push(task);
+
+        // find any work task(s) that can also be completed simultaneously
+        while let Some(task) = work_in_progress.peek() {
+            if task.remaining_work() <= min_remaining_work {
+                let task = work_in_progress.pop().unwrap();
+                completed_work.push(task);
+            } else {
+                break;
+            }
+        }
+
+        duration += min_remaining_work;
+
+        {
+            // for each remaining work tasks in work_in_progress,
+            // min_remaining_work seconds would have occurred.
+            // shave min_remaining_work off for each task in work_in_progress
+
+            let tasks = work_in_progress.into_vec().into_iter().map(|mut task| {
+                // invariant: work_in_progress contain work tasks that have remaining work that is greater than min_remaining_work
+                assert!(task.remaining_work() > min_remaining_work);
+
+                task.progress_work(min_remaining_work);
+                task
+            });
+
+            work_in_progress = BinaryHeap::from_iter(tasks);
+        };
+
+        // for each completed_work, get their adjacent vertices,
+        // and add them to the work_queue only if their remaining work is completed
+
+        for current_work_task in completed_work {
+            let current_work = current_work_task.to_vertex();
+
+            complete_work(&current_work, &edges, &mut remaining_work, &mut work_queue);
+        }
+    }
+
+    return duration;
+}
+
+pub const SOLVER: Solver = Solver {
+    year: 2018,
+    day: 7,
+    input: include_str!("input.txt"),
+    part_1: |input_string| Ok(part_1(input_string)),
+    part_2: Some(|input_string| {
+        Ok(part_2(input_string, BASE_WORKLOAD, MAX_WORKER_LIMIT).to_string())
+    }),
+    examples: &[Example {
+        input: include_str!("../examples/1.txt"),
+        part_1: Some("CABDFE"),
+        part_2: None,
+    }],
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vertex_binary_heap() {
+        let mut heap: BinaryHeap<Vertex> = BinaryHeap::new();
+
+        heap.push(Vertex('Z'));
+        heap.push(Vertex('A'));
+        heap.push(Vertex('B'));
+
+        assert_eq!(heap.pop(), Some(Vertex('A')));
+        assert_eq!(heap.pop(), Some(Vertex('B')));
+        assert_eq!(heap.pop(), Some(Vertex('Z')));
+    }
+
+    #[test]
+    fn test_part_1() {
+        let input = include_str!("../examples/1.txt");
+
+        assert_eq!(part_1(input), "CABDFE".to_string());
+
+        assert_eq!(
+            part_1(include_str!("input.txt")),
+            "JDEKPFABTUHOQSXVYMLZCNIGRW".to_string()
+        );
+    }
+
+    #[test]
+    fn test_parse_to_work_load() {
+        let base_workload = 60;
+
+        assert_eq!(parse_to_work_load('A', base_workload), 61);
+        assert_eq!(parse_to_work_load('B', base_workload), 62);
+        assert_eq!(parse_to_work_load('C', base_workload), 63);
+        assert_eq!(parse_to_work_load('Z', base_workload), 86);
+    }
+
+    #[test]
+    fn test_worktask_binary_heap() {
+        let base_workload = 60;
+
+        let mut heap: BinaryHeap<WorkTask> = BinaryHeap::new();
+
+        heap.push(WorkTask::from_vertex(Vertex('B'), base_workload));
+        heap.push(WorkTask::from_vertex(Vertex('Z'), base_workload));
+        heap.push(WorkTask::from_vertex(Vertex('A'), base_workload));
+
+        assert_eq!(
+            heap.pop(),
+            Some(WorkTask::from_vertex(Vertex('A'), base_workload))
+        );
+        assert_eq!(
+            heap.pop(),
+            Some(WorkTask::from_vertex(Vertex('B'), base_workload))
+        );
+        assert_eq!(
+            heap.pop(),
+            Some(WorkTask::from_vertex(Vertex('Z'), base_workload))
+        );
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input = include_str!("../examples/1.txt");
+
+        let base_workload = 0;
+        let max_worker_limit = 2;
+
+        assert_eq!(part_2(input, base_workload, max_worker_limit), 15);
+
+        // a single worker does every step one after the other
+        assert_eq!(part_2(input, 0, 1), 21);
+
+        // B finishes first, while C is still in progress with nothing left to assign
+        let input = r###"
+Step A must be finished before step B can begin.
+Step A must be finished before step C can begin.
+        "###;
+        assert_eq!(part_2(input, 0, 2), 1 + 3);
+
+        assert_eq!(
+            part_2(include_str!("input.txt"), BASE_WORKLOAD, MAX_WORKER_LIMIT),
+            1048
+        );
+    }
+}
@@ -0,0 +1,206 @@
+// https://adventofcode.com/2018/day/8
+
+use solver::{Example, Solver};
+
+// a node of the license tree: a header with the number of child nodes and metadata entries,
+// followed by the child nodes, and then the metadata entries
+#[derive(Debug, PartialEq)]
+struct Node {
+    children: Vec<Node>,
+
+    metadata: Vec<i32>,
+}
+
+impl Node {
+    // the sum of the metadata entries of this node, and of every node below it
+    fn metadata_sum(&self) -> i32 {
+        let mut total: i32 = 0;
+
+        let mut stack = vec![self];
+
+        while let Some(node) = stack.pop() {
+            let metadata_sum: i32 = node.metadata.iter().sum();
+            total += metadata_sum;
+
+            stack.extend(node.children.iter());
+        }
+
+        return total;
+    }
+
+    // without children, the value of a node is the sum of its metadata entries.
+    // otherwise, the metadata entries are 1-based indices of the children, and the value is the sum of their values.
+    // indices that don't refer to a child are skipped.
+    fn value(&self) -> i32 {
+        if self.children.is_empty() {
+            return self.metadata.iter().sum();
+        }
+
+        let mut total_value = 0;
+
+        for nth_child in &self.metadata {
+            let index = *nth_child - 1;
+
+            if index < 0 {
+                continue;
+            }
+
+            if let Some(child_node) = self.children.get(index as usize) {
+                total_value += child_node.value();
+            }
+        }
+
+        return total_value;
+    }
+}
+
+fn parse_node<I: Iterator<Item = i32>>(iter: &mut I) -> Result<Node, String> {
+    let mut next_number = |what: &str| -> Result<i32, String> {
+        return iter
+            .next()
+            .ok_or_else(|| format!("Unexpected end of input, expected {}", what));
+    };
+
+    // parse headers
+    let num_of_child_nodes = next_number("the number of child nodes")?;
+    let num_of_meta_entries = next_number("the number of metadata entries")?;
+
+    if num_of_child_nodes < 0 || num_of_meta_entries < 0 {
+        return Err(format!(
+            "Invalid header: {} {}",
+            num_of_child_nodes, num_of_meta_entries
+        ));
+    }
+
+    let children = (0..num_of_child_nodes)
+        .map(|_| parse_node(iter))
+        .collect::<Result<Vec<Node>, String>>()?;
+
+    let metadata: Vec<i32> = iter.take(num_of_meta_entries as usize).collect();
+
+    if metadata.len() < num_of_meta_entries as usize {
+        return Err(format!(
+            "Unexpected end of input, expected {} metadata entries, got {}",
+            num_of_meta_entries,
+            metadata.len()
+        ));
+    }
+
+    return Ok(Node { children, metadata });
+}
+
+// the root node of the tree of the license file
+fn parse_tree(input_string: &str) -> Result<Node, String> {
+    let numbers = input_string
+        .split_whitespace()
+        .map(|x| -> Result<i32, String> {
+            return x.parse().map_err(|_| format!("Invalid number: {}", x));
+        })
+        .collect::<Result<Vec<i32>, String>>()?;
+
+    let mut iter = numbers.into_iter();
+
+    let root_node = parse_node(&mut iter)?;
+
+    let num_of_leftovers = iter.count();
+    if num_of_leftovers > 0 {
+        return Err(format!(
+            "Unexpected {} numbers after the root node",
+            num_of_leftovers
+        ));
+    }
+
+    return Ok(root_node);
+}
+
+pub fn part_1(input_string: &str) -> i32 {
+    return parse_tree(input_string).unwrap().metadata_sum();
+}
+
+pub fn part_2(input_string: &str) -> i32 {
+    return parse_tree(input_string).unwrap().value();
+}
+
+pub const SOLVER: Solver = Solver {
+    year: 2018,
+    day: 8,
+    input: include_str!("input.txt"),
+    part_1: |input_string| Ok(part_1(input_string).to_string()),
+    part_2: Some(|input_string| Ok(part_2(input_string).to_string())),
+    examples: &[Example {
+        input: include_str!("../examples/1.txt"),
+        part_1: Some("138"),
+        part_2: Some("66"),
+    }],
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_tree() {
+        let input_string = "2 3 0 3 10 11 12 1 1 0 1 99 2 1 1 2";
+
+        // A has B and C as children, and C has D as a child
+        let node_d = Node {
+            children: vec![],
+            metadata: vec![99],
+        };
+        let node_c = Node {
+            children: vec![node_d],
+            metadata: vec![2],
+        };
+        let node_b = Node {
+            children: vec![],
+            metadata: vec![10, 11, 12],
+        };
+        let node_a = Node {
+            children: vec![node_b, node_c],
+            metadata: vec![1, 1, 2],
+        };
+
+        assert_eq!(parse_tree(input_string), Ok(node_a));
+
+        assert_eq!(
+            parse_tree("0 1 7\n"),
+            Ok(Node {
+                children: vec![],
+                metadata: vec![7],
+            })
+        );
+
+        assert!(parse_tree("").is_err());
+        assert!(parse_tree("1 1 0 1").is_err());
+        assert!(parse_tree("0 3 1 2").is_err());
+        assert!(parse_tree("0 1 1 2").is_err());
+        assert!(parse_tree("0 1 x").is_err());
+        assert!(parse_tree("-1 1 1").is_err());
+    }
+
+    #[test]
+    fn test_part_1() {
+        let input_string = include_str!("../examples/1.txt");
+
+        assert_eq!(part_1(input_string), 138);
+
+        assert_eq!(part_1(include_str!("input.txt")), 38567);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input_string = include_str!("../examples/1.txt");
+
+        assert_eq!(part_2(input_string), 66);
+
+        let root_node = parse_tree(input_string).unwrap();
+        assert_eq!(root_node.children[0].value(), 33);
+        // C refers to a second child that doesn't exist
+        assert_eq!(root_node.children[1].value(), 0);
+
+        // index 0 and out of range indices are skipped
+        assert_eq!(part_2("1 3 0 1 5 0 1 2"), 5);
+
+        assert_eq!(part_2(include_str!("input.txt")), 24453);
+    }
+}
@@ -0,0 +1,208 @@
+// https://adventofcode.com/2018/day/9
+
+// the circle of marbles is a ring buffer, rotated so that the current marble is always at its head.
+// marbles are only ever placed or removed a few marbles away from the current marble,
+// so every turn is a constant number of rotations, pushes, and pops, however many marbles are in the circle.
+
+use solver::{Example, Solver};
+use std::collections::VecDeque;
+
+pub type Score = usize;
+
+struct GameState {
+    state: VecDeque<usize>,
+    // number of marbles added
+    num_of_marbles_added: usize,
+    players: Vec<Score>,
+}
+
+impl GameState {
+    fn new(num_of_players: usize, last_marble: usize) -> GameState {
+        assert!(num_of_players > 0);
+
+        // each player has an initial score of 0
+        let players = vec![0; num_of_players];
+
+        // the head of the ring buffer is always the current position
+        let mut state = VecDeque::with_capacity(last_marble + 1);
+        state.push_front(0);
+
+        GameState {
+            // initially, the marble numbered 0 is placed within the circle
+            state,
+            num_of_marbles_added: 1,
+            players,
+        }
+    }
+
+    fn max_score(&self) -> Score {
+        return *self.players.iter().max().unwrap();
+    }
+
+    fn move_clockwise(&mut self, amount: usize) {
+        // the first items are moved to the end of the ring buffer.
+        // going around the whole circle ends up at the same marble, which matters while the circle is small.
+        let amount = amount % self.state.len();
+        self.state.rotate_left(amount);
+    }
+
+    fn move_counter_clockwise(&mut self, amount: usize) {
+        // the last items are moved to the front of the ring buffer
+        let amount = amount % self.state.len();
+        self.state.rotate_right(amount);
+    }
+
+    fn add_marble(&mut self) {
+        let value_of_next_marble = self.num_of_marbles_added;
+
+        if (value_of_next_marble % 23) == 0 {
+            // zero-based index
+            let current_player = (value_of_next_marble - 1) % self.players.len();
+
+            // add new marble to current player's score
+            self.players[current_player] += value_of_next_marble;
+
+            // the marble 7 marbles counter-clockwise from the current marble is removed from the circle
+            // and also added to the current player's score.
+            self.move_counter_clockwise(7);
+            let removed_marble = self.state.pop_front().unwrap();
+            self.players[current_player] += removed_marble;
+
+            // The marble located immediately clockwise of the marble that was removed becomes the new current marble.
+
+            self.num_of_marbles_added += 1;
+            return;
+        }
+
+        // update game state
+
+        self.move_clockwise(2);
+        self.state.push_front(value_of_next_marble);
+        self.num_of_marbles_added += 1;
+    }
+}
+
+// 10 players; last marble is worth 1618 points
+fn parse_input(input_string: &str) -> Result<(usize, usize), String> {
+    let inputs: Vec<&str> = input_string.split_whitespace().collect();
+
+    let parse_number = |index: usize| -> Result<usize, String> {
+        return inputs
+            .get(index)
+            .and_then(|x| x.parse().ok())
+            .ok_or_else(|| format!("Invalid input: {}", input_string.trim()));
+    };
+
+    let num_of_players = parse_number(0)?;
+    let last_marble = parse_number(6)?;
+
+    if num_of_players == 0 {
+        return Err("Expected at least one player".to_string());
+    }
+
+    return Ok((num_of_players, last_marble));
+}
+
+// the winning score once every marble up to last_marble has been placed
+fn play(num_of_players: usize, last_marble: usize) -> Score {
+    // init marble game with the first marble in the circle
+    let mut game_state: GameState = GameState::new(num_of_players, last_marble);
+
+    for _idx in 1..=last_marble {
+        game_state.add_marble();
+    }
+
+    return game_state.max_score();
+}
+
+pub fn part_1(input_string: &str) -> Result<Score, String> {
+    let (num_of_players, last_marble) = parse_input(input_string)?;
+
+    return Ok(play(num_of_players, last_marble));
+}
+
+pub fn part_2(input_string: &str) -> Result<Score, String> {
+    let (num_of_players, last_marble) = parse_input(input_string)?;
+
+    return Ok(play(num_of_players, last_marble * 100));
+}
+
+pub const SOLVER: Solver = Solver {
+    year: 2018,
+    day: 9,
+    input: include_str!("input.txt"),
+    part_1: |input_string| Ok(part_1(input_string)?.to_string()),
+    part_2: Some(|input_string| Ok(part_2(input_string)?.to_string())),
+    examples: &[
+        Example {
+            input: include_str!("../examples/1.txt"),
+            part_1: Some("32"),
+            part_2: None,
+        },
+        Example {
+            input: include_str!("../examples/2.txt"),
+            part_1: Some("8317"),
+            part_2: None,
+        },
+        Example {
+            input: include_str!("../examples/3.txt"),
+            part_1: Some("146373"),
+            part_2: None,
+        },
+        Example {
+            input: include_str!("../examples/4.txt"),
+            part_1: Some("2764"),
+            part_2: None,
+        },
+        Example {
+            input: include_str!("../examples/5.txt"),
+            part_1: Some("54718"),
+            part_2: None,
+        },
+        Example {
+            input: include_str!("../examples/6.txt"),
+            part_1: Some("37305"),
+            part_2: None,
+        },
+    ],
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(part_1(include_str!("../examples/1.txt")), Ok(32));
+        assert_eq!(part_1(include_str!("../examples/2.txt")), Ok(8317));
+        assert_eq!(part_1(include_str!("../examples/3.txt")), Ok(146373));
+        assert_eq!(part_1(include_str!("../examples/4.txt")), Ok(2764));
+        assert_eq!(part_1(include_str!("../examples/5.txt")), Ok(54718));
+        assert_eq!(part_1(include_str!("../examples/6.txt")), Ok(37305));
+
+        // the marble 0 is still the only marble in the circle after the first turn
+        assert_eq!(play(1, 1), 0);
+        assert_eq!(play(9, 25), 32);
+
+        assert_eq!(part_1(include_str!("input.txt")), Ok(400493));
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(part_2(include_str!("input.txt")), Ok(3338341690));
+    }
+
+    #[test]
+    fn test_parse_input() {
+        assert_eq!(
+            parse_input("10 players; last marble is worth 1618 points\n"),
+            Ok((10, 1618))
+        );
+        assert!(parse_input("0 players; last marble is worth 1618 points").is_err());
+        assert!(parse_input("10 players").is_err());
+        assert!(parse_input("ten players; last marble is worth 1618 points").is_err());
+
+        assert!(part_1("10 players").is_err());
+        assert!(part_2("10 players").is_err());
+    }
+}
@@ -0,0 +1,292 @@
+// https://adventofcode.com/2018/day/11
+
+use rayon::prelude::*;
+use solver::{Example, Solver};
+
+// the puzzle's grid of fuel cells is 300x300
+const GRID_SIZE: usize = 300;
+
+// the grid serial number given as the puzzle input
+pub const GRID_SERIAL_NUMBER: &str = "4172";
+
+pub type Position = (usize, usize);
+pub type Power = i32;
+
+pub fn parse_grid_serial_number(input_string: &str) -> Result<i32, String> {
+    return input_string
+        .trim()
+        .parse()
+        .map_err(|_| format!("Invalid grid serial number: {}", input_string.trim()));
+}
+
+fn get_row_major_order_idx(x: usize, y: usize, width: usize) -> usize {
+    return width * y + x;
+}
+
+struct SummedAreaTable {
+    grid_size: usize,
+    inner_array: Vec<i32>,
+}
+
+// https://en.wikipedia.org/wiki/Summed-area_table
+impl SummedAreaTable {
+    fn new(grid_size: usize, grid_serial_number: i32) -> SummedAreaTable {
+        assert!(grid_size > 0);
+
+        // this will be a square summed-area table
+
+        let area = grid_size * grid_size;
+
+        // linear array: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+        let mut inner_array = vec![0; area];
+
+        // pre-populate the array with their corresponding power levels
+        for x in 0..grid_size {
+            for y in 0..grid_size {
+                let normalized_x = (x + 1) as i32;
+                let normalized_y = (y + 1) as i32;
+
+                let power_level = get_power_level(normalized_x, normalized_y, grid_serial_number);
+
+                let index = get_row_major_order_idx(x, y, grid_size);
+                inner_array[index] = power_level;
+            }
+        }
+
+        // for each fuel cell (item in the array), the sum of all the values above
+        // and to the left of (x, y), inclusive
+        for x in 0..grid_size {
+            for y in 0..grid_size {
+                // sum itself
+                let mut sum: i32 = inner_array[get_row_major_order_idx(x, y, grid_size)];
+
+                // to the left
+                if x > 0 {
+                    sum += inner_array[get_row_major_order_idx(x - 1, y, grid_size)];
+                }
+
+                // above
+                if y > 0 {
+                    sum += inner_array[get_row_major_order_idx(x, y - 1, grid_size)];
+                }
+
+                // above and to the left
+                if x > 0 && y > 0 {
+                    sum -= inner_array[get_row_major_order_idx(x - 1, y - 1, grid_size)];
+                }
+
+                inner_array[get_row_major_order_idx(x, y, grid_size)] = sum;
+            }
+        }
+
+        SummedAreaTable {
+            grid_size,
+            inner_array,
+        }
+    }
+
+    // the total power of the square with its top-left corner at the 0-based (x, y)
+    fn get_spanned_square(&self, x: usize, y: usize, sub_grid_size: usize) -> i32 {
+        assert!(x < self.grid_size);
+        assert!(y < self.grid_size);
+        assert!(sub_grid_size <= self.grid_size);
+        assert!(x + sub_grid_size <= self.grid_size);
+        assert!(y + sub_grid_size <= self.grid_size);
+
+        // top-left corner
+        let top_left = if x > 0 && y > 0 {
+            self.inner_array[get_row_major_order_idx(x - 1, y - 1, self.grid_size)]
+        } else {
+            0
+        };
+
+        // invariant: end_x - x + 1 = sub_grid_size
+        let end_x = sub_grid_size + x - 1;
+        // invariant: end_y - y + 1 = sub_grid_size
+        let end_y = sub_grid_size + y - 1;
+
+        // top-right corner
+        let top_right = if y > 0 {
+            self.inner_array[get_row_major_order_idx(end_x, y - 1, self.grid_size)]
+        } else {
+            0
+        };
+
+        // bottom-left corner
+        let bottom_left = if x > 0 {
+            self.inner_array[get_row_major_order_idx(x - 1, end_y, self.grid_size)]
+        } else {
+            0
+        };
+
+        // bottom-right corner
+        let bottom_right = self.inner_array[get_row_major_order_idx(end_x, end_y, self.grid_size)];
+
+        return bottom_right + top_left - top_right - bottom_left;
+    }
+}
+
+fn get_power_level(x: i32, y: i32, grid_serial_number: i32) -> i32 {
+    assert!(x > 0);
+    assert!(y > 0);
+
+    let rack_id = x + 10;
+    let power_level = rack_id * y;
+    let power_level = power_level + grid_serial_number;
+    let power_level = power_level * rack_id;
+
+    // keep only the hundreds digit of the power level; numbers below 100 have a hundreds digit of 0
+    let hundreds_digit = (power_level / 100).abs() % 10;
+
+    return hundreds_digit - 5;
+}
+
+// the 1-based top-left corner and total power of the square of the given size with the largest total power.
+// ties go to the top-most, and then the left-most square.
+fn find_best_square(
+    summed_area_table: &SummedAreaTable,
+    sub_grid_size: usize,
+) -> Option<(Position, Power)> {
+    let grid_size = summed_area_table.grid_size;
+
+    if sub_grid_size == 0 || sub_grid_size > grid_size {
+        return None;
+    }
+
+    let mut best: Option<(Position, Power)> = None;
+
+    for y in 1..=(grid_size - sub_grid_size + 1) {
+        for x in 1..=(grid_size - sub_grid_size + 1) {
+            let total = summed_area_table.get_spanned_square(x - 1, y - 1, sub_grid_size);
+
+            match best {
+                Some((_position, largest_total_power)) if total <= largest_total_power => {}
+                _ => {
+                    best = Some(((x, y), total));
+                }
+            }
+        }
+    }
+
+    return best;
+}
+
+// the top-left corner of the 3x3 square with the largest total power
+pub fn part_1(grid_serial_number: i32) -> (Position, Power) {
+    let summed_area_table = SummedAreaTable::new(GRID_SIZE, grid_serial_number);
+
+    return find_best_square(&summed_area_table, 3).unwrap();
+}
+
+// the top-left corner and size of the square of any size with the largest total power.
+//
+// with the summed-area table, the total power of a square takes constant time regardless of its size,
+// so every size is searched in O(n^3) rather than O(n^5).
+pub fn part_2(grid_serial_number: i32) -> (Position, usize) {
+    let summed_area_table = SummedAreaTable::new(GRID_SIZE, grid_serial_number);
+
+    let (position, _total, sub_grid_size) = (1..=GRID_SIZE)
+        .into_par_iter()
+        .map(|sub_grid_size: usize| {
+            let (position, total) = find_best_square(&summed_area_table, sub_grid_size).unwrap();
+            return (position, total, sub_grid_size);
+        })
+        .max_by_key(|(_position, total, sub_grid_size)| (*total, -(*sub_grid_size as i32)))
+        .unwrap();
+
+    return (position, sub_grid_size);
+}
+
+pub const SOLVER: Solver = Solver {
+    year: 2018,
+    day: 11,
+    input: GRID_SERIAL_NUMBER,
+    part_1: |input_string| {
+        let ((x, y), _power) = part_1(parse_grid_serial_number(input_string)?);
+        return Ok(format!("{},{}", x, y));
+    },
+    part_2: Some(|input_string| {
+        let ((x, y), sub_grid_size) = part_2(parse_grid_serial_number(input_string)?);
+        return Ok(format!("{},{},{}", x, y, sub_grid_size));
+    }),
+    examples: &[
+        Example {
+            input: include_str!("../examples/1.txt"),
+            part_1: Some("33,45"),
+            part_2: Some("90,269,16"),
+        },
+        Example {
+            input: include_str!("../examples/2.txt"),
+            part_1: Some("21,61"),
+            part_2: Some("232,251,12"),
+        },
+    ],
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_power_level() {
+        assert_eq!(get_power_level(3, 5, 8), 4);
+        assert_eq!(get_power_level(122, 79, 57), -5);
+        assert_eq!(get_power_level(217, 196, 39), 0);
+        assert_eq!(get_power_level(101, 153, 71), 4);
+
+        // a power level below 100 has a hundreds digit of 0
+        assert_eq!(get_power_level(1, 1, -11), -5);
+    }
+
+    #[test]
+    fn test_summed_area_table() {
+        let summed_area_table = SummedAreaTable::new(GRID_SIZE, 18);
+
+        // the total power of a square is the sum of the power levels of its fuel cells
+        let total: i32 = (33..=35)
+            .flat_map(|x| (45..=47).map(move |y| get_power_level(x, y, 18)))
+            .sum();
+        assert_eq!(total, 29);
+        assert_eq!(summed_area_table.get_spanned_square(32, 44, 3), 29);
+
+        assert_eq!(
+            summed_area_table.get_spanned_square(0, 0, 1),
+            get_power_level(1, 1, 18)
+        );
+        assert_eq!(
+            summed_area_table.get_spanned_square(299, 299, 1),
+            get_power_level(300, 300, 18)
+        );
+
+        let summed_area_table = SummedAreaTable::new(GRID_SIZE, 42);
+        assert_eq!(summed_area_table.get_spanned_square(20, 60, 3), 30);
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(
+            part_1(parse_grid_serial_number(include_str!("../examples/1.txt")).unwrap()),
+            ((33, 45), 29)
+        );
+        assert_eq!(
+            part_1(parse_grid_serial_number(include_str!("../examples/2.txt")).unwrap()),
+            ((21, 61), 30)
+        );
+
+        assert_eq!(part_1(4172), ((243, 43), 29));
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(
+            part_2(parse_grid_serial_number(include_str!("../examples/1.txt")).unwrap()),
+            ((90, 269), 16)
+        );
+        assert_eq!(
+            part_2(parse_grid_serial_number(include_str!("../examples/2.txt")).unwrap()),
+            ((232, 251), 12)
+        );
+
+        assert_eq!(part_2(4172), ((236, 151), 15));
+    }
+}
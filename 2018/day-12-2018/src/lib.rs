@@ -0,0 +1,327 @@
+// https://adventofcode.com/2018/day/12
+
+// imports
+
+use cycle::find_cycle;
+use solver::{Example, Solver};
+
+// helpers and types
+
+pub type PotIndex = i64;
+
+// whether a pot has a plant in the next generation, for every pattern of the five pots LLCRR around it.
+// a pattern is read as a binary number, with the left-most pot as the most significant bit.
+pub type Rules = [bool; 32];
+
+fn parse_plant(x: char) -> Result<bool, String> {
+    match x {
+        '#' => Ok(true),
+        '.' => Ok(false),
+        _ => Err(format!("Invalid pot: {}", x)),
+    }
+}
+
+fn parse_plants(input: &str) -> Result<Vec<bool>, String> {
+    return input.trim().chars().map(parse_plant).collect();
+}
+
+// a row of pots that goes on forever in both directions.
+// only the pots from the left-most to the right-most plant are kept, along with the number of the left-most one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pots {
+    // the number of the left-most pot with a plant
+    offset: PotIndex,
+    plants: Vec<bool>,
+}
+
+impl Pots {
+    // the pots starting at offset, with the empty pots on both ends trimmed away
+    fn new(offset: PotIndex, mut plants: Vec<bool>) -> Pots {
+        let leading_empty_pots = plants.iter().take_while(|has_plant| !**has_plant).count();
+
+        if leading_empty_pots == plants.len() {
+            return Pots {
+                offset: 0,
+                plants: vec![],
+            };
+        }
+
+        plants.drain(..leading_empty_pots);
+
+        while plants.last() == Some(&false) {
+            plants.pop();
+        }
+
+        return Pots {
+            offset: offset + leading_empty_pots as PotIndex,
+            plants,
+        };
+    }
+
+    fn has_plant(&self, pot_index: PotIndex) -> bool {
+        let index = pot_index - self.offset;
+
+        if index < 0 {
+            return false;
+        }
+
+        return *self.plants.get(index as usize).unwrap_or(&false);
+    }
+
+    // a plant can only spread up to two pots past the plants on either end
+    fn next_generation(&self, rules: &Rules) -> Pots {
+        let start = self.offset - 2;
+        let end = self.offset + self.plants.len() as PotIndex + 2;
+
+        let plants: Vec<bool> = (start..end)
+            .map(|pot_index| {
+                let pattern = ((pot_index - 2)..=(pot_index + 2)).fold(0, |pattern, index| {
+                    return (pattern << 1) | (self.has_plant(index) as usize);
+                });
+
+                return rules[pattern];
+            })
+            .collect();
+
+        return Pots::new(start, plants);
+    }
+
+    // the sum of the numbers of every pot with a plant
+    fn sum(&self) -> PotIndex {
+        return self
+            .plants
+            .iter()
+            .enumerate()
+            .filter(|(_index, has_plant)| **has_plant)
+            .map(|(index, _has_plant)| self.offset + index as PotIndex)
+            .sum();
+    }
+
+    fn num_of_plants(&self) -> PotIndex {
+        return self.plants.iter().filter(|has_plant| **has_plant).count() as PotIndex;
+    }
+
+    pub fn to_string(&self) -> String {
+        return self
+            .plants
+            .iter()
+            .map(|has_plant| if *has_plant { '#' } else { '.' })
+            .collect();
+    }
+}
+
+// ...## => #
+fn parse_rule(input: &str) -> Result<(usize, bool), String> {
+    let invalid = || format!("Invalid rule: {}", input);
+
+    let mut parts = input.split("=>");
+
+    let pattern = parse_plants(parts.next().ok_or_else(invalid)?)?;
+    let next = parse_plants(parts.next().ok_or_else(invalid)?)?;
+
+    if pattern.len() != 5 || next.len() != 1 || parts.next().is_some() {
+        return Err(invalid());
+    }
+
+    let pattern = pattern.into_iter().fold(0, |pattern, has_plant| {
+        (pattern << 1) | (has_plant as usize)
+    });
+
+    return Ok((pattern, next[0]));
+}
+
+pub fn parse_input(input_string: &str) -> Result<(Pots, Rules), String> {
+    let mut lines = input_string.trim().lines();
+
+    let initial_state = lines
+        .next()
+        .and_then(|line| line.trim().strip_prefix("initial state:"))
+        .ok_or_else(|| "Missing initial state".to_string())?;
+
+    let pots = Pots::new(0, parse_plants(initial_state)?);
+
+    // patterns without a rule don't grow a plant
+    let mut rules: Rules = [false; 32];
+
+    for line in lines {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let (pattern, next) = parse_rule(line)?;
+        rules[pattern] = next;
+    }
+
+    if rules[0] {
+        return Err("Plants would grow in every empty pot".to_string());
+    }
+
+    return Ok((pots, rules));
+}
+
+// the plants of the puzzle settle into a pattern within a couple hundred generations.
+// rules that keep growing the pattern would never repeat, so the search gives up after this many.
+const MAX_GENERATIONS: usize = 1_000;
+
+// the sum of the numbers of the pots with a plant after some generations.
+//
+// the plants eventually settle into a pattern that repeats as it moves along the pots,
+// so the pots are compared without where they are. once the pattern repeats,
+// every pass through the cycle moves every plant by the same number of pots,
+// and the sum grows by the same amount.
+fn sum_after(input_string: &str, generations: usize) -> Result<PotIndex, String> {
+    let (pots, rules) = parse_input(input_string)?;
+
+    let (cycle, states) = find_cycle(
+        pots,
+        |pots| pots.next_generation(&rules),
+        |pots| pots.plants.clone(),
+        generations.min(MAX_GENERATIONS),
+    );
+
+    let cycle = match cycle {
+        None if generations <= MAX_GENERATIONS => {
+            return Ok(states[generations].sum());
+        }
+        None => {
+            return Err(format!(
+                "The plants don't settle into a repeating pattern within {} generations",
+                MAX_GENERATIONS
+            ));
+        }
+        Some(cycle) => cycle,
+    };
+
+    let (passes, generation) = cycle.fast_forward(generations);
+
+    let shift_per_pass = states[cycle.start + cycle.length].offset - states[cycle.start].offset;
+    let pots = &states[generation];
+
+    return Ok(pots.sum() + passes as PotIndex * shift_per_pass * pots.num_of_plants());
+}
+
+pub fn part_1(input_string: &str) -> Result<PotIndex, String> {
+    return sum_after(input_string, 20);
+}
+
+pub fn part_2(input_string: &str) -> Result<PotIndex, String> {
+    return sum_after(input_string, 50_000_000_000);
+}
+
+pub const SOLVER: Solver = Solver {
+    year: 2018,
+    day: 12,
+    input: include_str!("input.txt"),
+    part_1: |input_string| Ok(part_1(input_string)?.to_string()),
+    part_2: Some(|input_string| Ok(part_2(input_string)?.to_string())),
+    examples: &[Example {
+        input: include_str!("../examples/1.txt"),
+        part_1: Some("325"),
+        part_2: None,
+    }],
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../examples/1.txt");
+
+    #[test]
+    fn test_parse_input() {
+        let (pots, rules) = parse_input(EXAMPLE).unwrap();
+
+        assert_eq!(pots.offset, 0);
+        assert_eq!(pots.to_string(), "#..#.#..##......###...###");
+        assert_eq!(rules.iter().filter(|has_plant| **has_plant).count(), 14);
+        assert!(rules[0b00011]);
+        assert!(!rules[0b00001]);
+
+        assert_eq!(parse_rule("#.#.# => #"), Ok((0b10101, true)));
+        assert!(parse_rule("#.#. => #").is_err());
+        assert!(parse_rule("#.#.# => x").is_err());
+        assert!(parse_rule("#.#.#").is_err());
+
+        assert!(parse_input("#..#").is_err());
+        assert!(parse_input("initial state: #..#\n\n..... => #").is_err());
+    }
+
+    #[test]
+    fn test_next_generation() {
+        let (mut pots, rules) = parse_input(EXAMPLE).unwrap();
+
+        pots = pots.next_generation(&rules);
+        assert_eq!(pots.offset, 0);
+        assert_eq!(pots.to_string(), "#...#....#.....#..#..#..#");
+
+        pots = pots.next_generation(&rules);
+        assert_eq!(pots.offset, 0);
+        assert_eq!(pots.to_string(), "##..##...##....#..#..#..##");
+
+        // the plants spread to the left of pot 0
+        pots = pots.next_generation(&rules);
+        assert_eq!(pots.offset, -1);
+        assert_eq!(pots.to_string(), "#.#...#..#.#....#..#..#...#");
+
+        for _generation in 4..=20 {
+            pots = pots.next_generation(&rules);
+        }
+        assert_eq!(pots.offset, -2);
+        assert_eq!(pots.to_string(), "#....##....#####...#######....#.#..##");
+
+        // without plants, there's nothing left to spread
+        let pots = Pots::new(5, vec![false, false]);
+        assert_eq!(pots, Pots::new(0, vec![]));
+        assert_eq!(pots.next_generation(&rules), pots);
+        assert_eq!(pots.sum(), 0);
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(part_1(EXAMPLE), Ok(325));
+
+        assert_eq!(part_1(include_str!("input.txt")), Ok(2911));
+    }
+
+    #[test]
+    fn test_part_2() {
+        // fast-forwarding through the cycle lands on the same sum as going generation by generation
+        for input_string in &[EXAMPLE, include_str!("input.txt")] {
+            let (mut pots, rules) = parse_input(input_string).unwrap();
+
+            for generations in 1..=500 {
+                pots = pots.next_generation(&rules);
+
+                if generations % 50 == 0 {
+                    assert_eq!(sum_after(input_string, generations), Ok(pots.sum()));
+                }
+            }
+        }
+
+        assert_eq!(part_2(include_str!("input.txt")), Ok(2500000000695));
+    }
+
+    #[test]
+    fn test_no_cycle() {
+        // a pot keeps its plant, and grows one if the pot to its left has one, so the row of plants only gets longer
+        let rules: Vec<String> = (0..32)
+            .filter(|pattern| pattern & 0b01100 != 0)
+            .map(|pattern: usize| {
+                let pots: String = (0..5)
+                    .rev()
+                    .map(|bit| if pattern >> bit & 1 == 1 { '#' } else { '.' })
+                    .collect();
+                return format!("{} => #", pots);
+            })
+            .collect();
+        let input_string = format!("initial state: #\n\n{}", rules.join("\n"));
+
+        assert_eq!(sum_after(&input_string, 3), Ok(6));
+        assert_eq!(
+            sum_after(&input_string, MAX_GENERATIONS),
+            Ok((0..=MAX_GENERATIONS as PotIndex).sum())
+        );
+        assert!(sum_after(&input_string, MAX_GENERATIONS + 1).is_err());
+        assert!(part_2(&input_string).is_err());
+    }
+}
@@ -0,0 +1,1787 @@
+// https://adventofcode.com/2018/day/13
+
+// imports
+
+use core::cmp::Ordering;
+use solver::{Example, Solver};
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::path::Path;
+use tracing::{debug, trace};
+use vector_image::Drawing;
+
+// code
+
+pub type Coordinate = (i32, i32);
+
+// ticks are counted from 1
+pub type Tick = usize;
+
+// sort coordinates according to their reading order
+fn reading_order(first_coord: &Coordinate, second_coord: &Coordinate) -> Ordering {
+    let (x1, y1) = first_coord;
+    let (x2, y2) = second_coord;
+
+    if y1 != y2 {
+        return y1.cmp(y2);
+    }
+
+    return x1.cmp(x2);
+}
+
+#[derive(PartialEq, Hash, Eq, Clone, Debug)]
+struct OrderedCoordinate(Coordinate);
+
+impl PartialOrd for OrderedCoordinate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        return Some(reading_order(&self.0, &other.0));
+    }
+}
+
+impl Ord for OrderedCoordinate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let ord = self.partial_cmp(other).unwrap();
+        return ord;
+    }
+}
+
+impl Into<OrderedCoordinate> for Coordinate {
+    fn into(self) -> OrderedCoordinate {
+        return OrderedCoordinate(self);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Track {
+    // |
+    Vertical,
+    // -
+    Horizontal,
+    // +
+    Intersection,
+
+    // curves
+    // invariant: Curves connect exactly two perpendicular pieces of track
+
+    // match configuration:
+    //   /-
+    //   |
+    BottomAndRight,
+
+    // match configuration:
+    //    |
+    //   -/
+    TopAndLeft,
+
+    // match configuration:
+    //   -\
+    //    |
+    BottomAndLeft,
+    // match configuration:
+    //   |
+    //   \-
+    TopAndRight,
+}
+
+impl Track {
+    fn to_string(&self) -> String {
+        let result = match self {
+            Track::Vertical => "|",
+            Track::Horizontal => "-",
+            Track::Intersection => "+",
+            Track::BottomAndRight | Track::TopAndLeft => "/",
+            Track::BottomAndLeft | Track::TopAndRight => "\\",
+        };
+
+        return result.to_string();
+    }
+
+    // the directions of the pieces of track that this piece connects to
+    fn get_connections(&self) -> Vec<Orientation> {
+        match self {
+            Track::Vertical => vec![Orientation::Up, Orientation::Down],
+            Track::Horizontal => vec![Orientation::Left, Orientation::Right],
+            Track::Intersection => vec![
+                Orientation::Up,
+                Orientation::Down,
+                Orientation::Left,
+                Orientation::Right,
+            ],
+            Track::BottomAndRight => vec![Orientation::Down, Orientation::Right],
+            Track::TopAndLeft => vec![Orientation::Up, Orientation::Left],
+            Track::BottomAndLeft => vec![Orientation::Down, Orientation::Left],
+            Track::TopAndRight => vec![Orientation::Up, Orientation::Right],
+        }
+    }
+}
+
+fn is_horizontal(cell: char) -> bool {
+    match cell {
+        '-' | '+' => true,
+        _ => false,
+    }
+}
+
+fn is_vertical(cell: char) -> bool {
+    match cell {
+        '|' | '+' => true,
+        _ => false,
+    }
+}
+
+fn is_curve(cell: char) -> bool {
+    match cell {
+        '/' | '\\' => true,
+        _ => false,
+    }
+}
+
+// a curve connects a horizontal and a vertical neighbor, and which two is told apart by looking at them.
+// straight pieces and intersections are looked at first; adjacent curves are only looked at if that's not
+// enough to tell, such as in tight loops:
+//   /\
+//   \/
+fn classify_curve(
+    cell_map: &HashMap<Coordinate, char>,
+    position: Coordinate,
+    cell: char,
+) -> Result<Track, String> {
+    let (x, y) = position;
+
+    // the two configurations of the curve, with the horizontal and the vertical neighbor they connect
+    let configurations = match cell {
+        '/' => [
+            (Track::BottomAndRight, (x + 1, y), (x, y + 1)),
+            (Track::TopAndLeft, (x - 1, y), (x, y - 1)),
+        ],
+        '\\' => [
+            (Track::BottomAndLeft, (x - 1, y), (x, y + 1)),
+            (Track::TopAndRight, (x + 1, y), (x, y - 1)),
+        ],
+        _ => {
+            unreachable!("Not a curve: {}", cell);
+        }
+    };
+
+    for include_curves in [false, true] {
+        let connects = |neighbor: &Coordinate, is_connected: fn(char) -> bool| -> bool {
+            match cell_map.get(neighbor) {
+                None => false,
+                Some(cell) => is_connected(*cell) || (include_curves && is_curve(*cell)),
+            }
+        };
+
+        let matches: Vec<&Track> = configurations
+            .iter()
+            .filter(|(_track, horizontal, vertical)| {
+                return connects(horizontal, is_horizontal) && connects(vertical, is_vertical);
+            })
+            .map(|(track, _horizontal, _vertical)| track)
+            .collect();
+
+        if matches.len() == 1 {
+            return Ok(matches[0].clone());
+        }
+    }
+
+    let (x, y) = position;
+    return Err(format!(
+        "Invalid placement of track at {},{}: {} doesn't connect one horizontal and one vertical piece of track",
+        x, y, cell
+    ));
+}
+
+pub type Map = HashMap<Coordinate, Track>;
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum TurningOption {
+    Left,
+    Straight,
+    Right,
+}
+
+impl TurningOption {
+    fn next(&self) -> TurningOption {
+        match self {
+            TurningOption::Left => TurningOption::Straight,
+            TurningOption::Straight => TurningOption::Right,
+            TurningOption::Right => TurningOption::Left,
+        }
+    }
+
+    fn to_name(&self) -> &str {
+        match self {
+            TurningOption::Left => "left",
+            TurningOption::Straight => "straight",
+            TurningOption::Right => "right",
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
+pub enum Orientation {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Orientation {
+    fn opposite(&self) -> Orientation {
+        match self {
+            Orientation::Up => Orientation::Down,
+            Orientation::Down => Orientation::Up,
+            Orientation::Left => Orientation::Right,
+            Orientation::Right => Orientation::Left,
+        }
+    }
+
+    // the position one step away in this direction
+    fn next_position(&self, position: Coordinate) -> Coordinate {
+        let (x, y) = position;
+
+        match self {
+            Orientation::Up => (x, y - 1),
+            Orientation::Down => (x, y + 1),
+            Orientation::Left => (x - 1, y),
+            Orientation::Right => (x + 1, y),
+        }
+    }
+
+    fn to_name(&self) -> &str {
+        match self {
+            Orientation::Up => "up",
+            Orientation::Down => "down",
+            Orientation::Left => "left",
+            Orientation::Right => "right",
+        }
+    }
+
+    fn turn(&self, turning_option: &TurningOption) -> Orientation {
+        match self {
+            Orientation::Up => match turning_option {
+                TurningOption::Left => Orientation::Left,
+                TurningOption::Straight => self.clone(),
+                TurningOption::Right => Orientation::Right,
+            },
+            Orientation::Down => match turning_option {
+                TurningOption::Left => Orientation::Right,
+                TurningOption::Straight => self.clone(),
+                TurningOption::Right => Orientation::Left,
+            },
+            Orientation::Left => match turning_option {
+                TurningOption::Left => Orientation::Down,
+                TurningOption::Straight => self.clone(),
+                TurningOption::Right => Orientation::Up,
+            },
+            Orientation::Right => match turning_option {
+                TurningOption::Left => Orientation::Up,
+                TurningOption::Straight => self.clone(),
+                TurningOption::Right => Orientation::Down,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
+struct Cart {
+    // carts are numbered in reading order of their initial positions, and keep their number as they move
+    id: usize,
+    // the direction the cart is facing
+    orientation: Orientation,
+    // current position
+    position: Coordinate,
+    // when a cart arrives at an intersection, this rule determines the cart's
+    // next destination
+    turning_option: TurningOption,
+}
+
+impl Cart {
+    fn is_cart(cell: char) -> bool {
+        match cell {
+            '^' | 'v' | '<' | '>' => true,
+            _ => false,
+        }
+    }
+
+    fn new(id: usize, cell: char, position: Coordinate) -> Cart {
+        assert!(Cart::is_cart(cell));
+
+        let orientation = match cell {
+            '^' => Orientation::Up,
+            'v' => Orientation::Down,
+            '<' => Orientation::Left,
+            '>' => Orientation::Right,
+            _ => {
+                unreachable!();
+            }
+        };
+
+        Cart {
+            id,
+            orientation,
+            position,
+            turning_option: TurningOption::Left,
+        }
+    }
+
+    fn to_string(&self) -> String {
+        let orientation = match self.orientation {
+            Orientation::Up => "^",
+            Orientation::Down => "v",
+            Orientation::Left => "<",
+            Orientation::Right => ">",
+        };
+
+        return orientation.to_string();
+    }
+
+    fn tick(&self, map: &Map) -> Cart {
+        // generate next position
+
+        let next_position = self.orientation.next_position(self.position);
+
+        // generate next orientation
+
+        let next_track: Track = match map.get(&next_position) {
+            None => {
+                assert!(false, "No track found at: {:?}", next_position);
+                unreachable!();
+            }
+            Some(track) => track.clone(),
+        };
+
+        let mut next_orientation = self.orientation.clone();
+        let mut next_turning_option = self.turning_option.clone();
+
+        match next_track {
+            Track::BottomAndRight => {
+                next_orientation = match self.orientation {
+                    Orientation::Up => Orientation::Right,
+                    Orientation::Left => Orientation::Down,
+                    _ => {
+                        unreachable!("Unexpected orientation: {:?}", self.orientation);
+                    }
+                }
+            }
+            Track::TopAndLeft => {
+                next_orientation = match self.orientation {
+                    Orientation::Down => Orientation::Left,
+                    Orientation::Right => Orientation::Up,
+                    _ => {
+                        unreachable!("Unexpected orientation: {:?}", self.orientation);
+                    }
+                }
+            }
+            Track::BottomAndLeft => {
+                next_orientation = match self.orientation {
+                    Orientation::Up => Orientation::Left,
+                    Orientation::Right => Orientation::Down,
+                    _ => {
+                        unreachable!("Unexpected orientation: {:?}", self.orientation);
+                    }
+                }
+            }
+            Track::TopAndRight => {
+                next_orientation = match self.orientation {
+                    Orientation::Down => Orientation::Right,
+                    Orientation::Left => Orientation::Up,
+                    _ => {
+                        unreachable!("Unexpected orientation: {:?}", self.orientation);
+                    }
+                }
+            }
+            Track::Intersection => {
+                next_orientation = self.orientation.turn(&self.turning_option);
+                next_turning_option = self.turning_option.next();
+            }
+            Track::Vertical => {
+                match self.orientation {
+                    Orientation::Up | Orientation::Down => {}
+                    _ => {
+                        unreachable!("Unexpected orientation: {:?}", self.orientation);
+                    }
+                };
+            }
+            Track::Horizontal => {
+                match self.orientation {
+                    Orientation::Left | Orientation::Right => {}
+                    _ => {
+                        unreachable!("Unexpected orientation: {:?}", self.orientation);
+                    }
+                };
+            }
+        }
+
+        Cart {
+            id: self.id,
+            orientation: next_orientation,
+            position: next_position,
+            turning_option: next_turning_option,
+        }
+    }
+}
+
+// positions of the crashes of a tick, in the order they happened
+pub type CrashedCarts = Vec<Coordinate>;
+
+// a cart moving by one piece of track
+#[derive(Debug, Clone, PartialEq)]
+pub struct Move {
+    pub id: usize,
+    pub from: Coordinate,
+    pub to: Coordinate,
+    // the direction the cart is facing after the move
+    pub orientation: Orientation,
+    // where the cart turns at the next intersection
+    pub turning_option: TurningOption,
+    // the id of the cart it crashed into, if any
+    pub crashed_into: Option<usize>,
+}
+
+impl Move {
+    pub fn to_string(&self) -> String {
+        let (from_x, from_y) = self.from;
+        let (to_x, to_y) = self.to;
+
+        let mut result = format!(
+            "cart {}: {},{} -> {},{} facing {}, turning {} next",
+            self.id,
+            from_x,
+            from_y,
+            to_x,
+            to_y,
+            self.orientation.to_name(),
+            self.turning_option.to_name()
+        );
+
+        if let Some(other_id) = self.crashed_into {
+            result.push_str(&format!(", crashed into cart {}", other_id));
+        }
+
+        return result;
+    }
+}
+
+pub struct Carts {
+    // in reading order at the start of every tick
+    carts: Vec<Cart>,
+    // the index of the cart at every occupied position
+    occupied: HashMap<Coordinate, usize>,
+    // the moves of the last tick, in the order they happened
+    moves: Vec<Move>,
+}
+
+impl Carts {
+    fn new() -> Carts {
+        Carts {
+            carts: vec![],
+            occupied: HashMap::new(),
+            moves: vec![],
+        }
+    }
+
+    fn add_cart(&mut self, cart: Cart) {
+        self.occupied.insert(cart.position, self.carts.len());
+        self.carts.push(cart);
+    }
+
+    fn get_cart(&self, position: &Coordinate) -> Option<&Cart> {
+        return self.occupied.get(position).map(|index| &self.carts[*index]);
+    }
+
+    pub fn num_of_carts(&self) -> usize {
+        return self.carts.len();
+    }
+
+    pub fn get_moves(&self) -> &[Move] {
+        return &self.moves;
+    }
+
+    pub fn tick(&mut self, map: &Map) -> Option<CrashedCarts> {
+        let mut crashed_positions: CrashedCarts = vec![];
+
+        // carts move one at a time, starting from the top-left
+        self.carts
+            .sort_by_key(|cart| -> OrderedCoordinate { cart.position.into() });
+        self.occupied = self
+            .carts
+            .iter()
+            .enumerate()
+            .map(|(index, cart)| (cart.position, index))
+            .collect();
+
+        let mut crashed = vec![false; self.carts.len()];
+        self.moves.clear();
+
+        for index in 0..self.carts.len() {
+            if crashed[index] {
+                // current cart was crashed by another cart that moved before itself.
+                continue;
+            }
+
+            let current_position = self.carts[index].position;
+            let next_cart = self.carts[index].tick(map);
+            self.occupied.remove(&current_position);
+
+            // does the next cart collide with any other cart, whether it has moved or not?
+            // the other cart is removed right away, so that carts moving onto the crash site later
+            // in the tick don't crash into it
+            let other_index = self.occupied.remove(&next_cart.position);
+
+            self.moves.push(Move {
+                id: next_cart.id,
+                from: current_position,
+                to: next_cart.position,
+                orientation: next_cart.orientation.clone(),
+                turning_option: next_cart.turning_option.clone(),
+                crashed_into: other_index.map(|other_index| self.carts[other_index].id),
+            });
+
+            if let Some(other_index) = other_index {
+                debug!(
+                    cart = next_cart.id,
+                    other_cart = self.carts[other_index].id,
+                    position = ?next_cart.position,
+                    "carts crashed"
+                );
+                crashed_positions.push(next_cart.position);
+                crashed[index] = true;
+                crashed[other_index] = true;
+                continue;
+            }
+
+            self.occupied.insert(next_cart.position, index);
+            self.carts[index] = next_cart;
+        }
+
+        // invariant: by the end of this tick, only carts that haven't crashed remain
+
+        let carts = std::mem::take(&mut self.carts);
+        for (cart, crashed) in carts.into_iter().zip(crashed) {
+            if !crashed {
+                self.add_cart(cart);
+            }
+        }
+
+        if !crashed_positions.is_empty() {
+            return Some(crashed_positions);
+        }
+
+        return None;
+    }
+}
+
+// render the map with the carts overlaid, and the crash sites as X.
+// with colors, carts and crash sites stand out from the track.
+pub fn render(map: &Map, carts: &Carts, crash_sites: &[Coordinate], use_colors: bool) -> String {
+    let max_x = map.keys().map(|(x, _y)| *x).max().unwrap_or(-1);
+    let max_y = map.keys().map(|(_x, y)| *y).max().unwrap_or(-1);
+
+    let rows: Vec<String> = (0..=max_y)
+        .map(|y| {
+            let row: String = (0..=max_x)
+                .map(|x| {
+                    let position = (x, y);
+
+                    if crash_sites.contains(&position) {
+                        if use_colors {
+                            return terminal::colorize("X", terminal::RED);
+                        }
+                        return "X".to_string();
+                    }
+
+                    if let Some(cart) = carts.get_cart(&position) {
+                        if use_colors {
+                            return terminal::colorize(&cart.to_string(), terminal::YELLOW);
+                        }
+                        return cart.to_string();
+                    }
+
+                    match map.get(&position) {
+                        None => " ".to_string(),
+                        Some(track) => track.to_string(),
+                    }
+                })
+                .collect();
+
+            return row.trim_end().to_string();
+        })
+        .collect();
+
+    return rows.join("\n");
+}
+
+// pad every row with spaces to the width of the longest row, since maps are whitespace-significant.
+// rows of different widths are a sign that an editor stripped their trailing spaces, which is warned about.
+pub fn pad_rows(input_string: &str) -> (String, Vec<String>) {
+    let rows: Vec<&str> = input_string
+        .lines()
+        .map(|row| row.trim_end_matches('\r'))
+        .collect();
+
+    let width = rows
+        .iter()
+        .map(|row| row.chars().count())
+        .max()
+        .unwrap_or(0);
+    let num_of_short_rows = rows
+        .iter()
+        .filter(|row| row.chars().count() < width)
+        .count();
+
+    let mut warnings = vec![];
+    if num_of_short_rows > 0 {
+        warnings.push(format!(
+            "{} of {} rows are shorter than {} characters; trailing spaces may have been stripped, so they're padded back",
+            num_of_short_rows,
+            rows.len(),
+            width
+        ));
+    }
+
+    let padded: Vec<String> = rows
+        .iter()
+        .map(|row| format!("{:<width$}", row, width = width))
+        .collect();
+
+    return (padded.join("\n"), warnings);
+}
+
+// read a map from a file as is, rather than through anything that could trim it, and pad its rows
+pub fn read_map(path: &Path) -> Result<(String, Vec<String>), String> {
+    let bytes =
+        std::fs::read(path).map_err(|err| format!("Unable to read {}: {}", path.display(), err))?;
+    let input_string = String::from_utf8(bytes)
+        .map_err(|err| format!("Unable to read {}: {}", path.display(), err))?;
+
+    return Ok(pad_rows(&input_string));
+}
+
+pub fn parse_input(input_string: &str) -> Result<(Map, Carts), String> {
+    let mut carts: Carts = Carts::new();
+
+    let map: Map = {
+        let mut map: Map = HashMap::new();
+
+        let mut cell_map: HashMap<Coordinate, char> = HashMap::new();
+
+        for (y, line) in input_string.lines().enumerate() {
+            for (x, cell) in line.chars().enumerate() {
+                let position: Coordinate = (x as i32, y as i32);
+                trace!(?position, %cell, "parsing cell");
+
+                // add carts
+                if Cart::is_cart(cell) {
+                    let cart = Cart::new(carts.carts.len(), cell, position);
+                    carts.add_cart(cart);
+                }
+
+                // convert cart to appropriate track
+                let cell = match cell {
+                    'v' | '^' => '|',
+                    '<' | '>' => '-',
+                    _ => cell,
+                };
+
+                cell_map.insert(position, cell);
+            }
+        }
+
+        // in reading order, so that the first invalid cell is the one reported
+        let mut positions: Vec<&Coordinate> = cell_map.keys().collect();
+        positions.sort_by(|first, second| reading_order(first, second));
+
+        for position in positions {
+            let position = *position;
+            let cell = &cell_map[&position];
+
+            match cell {
+                '|' => {
+                    map.insert(position, Track::Vertical);
+                }
+                '-' => {
+                    map.insert(position, Track::Horizontal);
+                }
+                '+' => {
+                    map.insert(position, Track::Intersection);
+                }
+                '/' | '\\' => {
+                    map.insert(position, classify_curve(&cell_map, position, *cell)?);
+                }
+                ' ' => {}
+                _ => {
+                    let (x, y) = position;
+                    return Err(format!("Unknown cell at {},{}: {:?}", x, y, cell));
+                }
+            }
+        }
+
+        map
+    };
+
+    return Ok((map, carts));
+}
+
+// check that every piece of track leads to pieces of track that lead back to it.
+// this catches dangling ends, curves that don't connect two perpendicular pieces, and intersections
+// that are missing one of their four connections.
+pub fn validate(map: &Map) -> Result<(), Vec<String>> {
+    let mut positions: Vec<Coordinate> = map.keys().cloned().collect();
+    positions.sort_by(reading_order);
+
+    let mut errors: Vec<String> = vec![];
+
+    for position in positions {
+        let (x, y) = position;
+        let track = map.get(&position).unwrap();
+
+        for direction in track.get_connections() {
+            let neighbor = direction.next_position(position);
+
+            match map.get(&neighbor) {
+                None => {
+                    errors.push(format!(
+                        "Dangling track at {},{}: {} leads {} to nothing",
+                        x,
+                        y,
+                        track.to_string(),
+                        direction.to_name()
+                    ));
+                }
+                Some(other_track) => {
+                    if !other_track
+                        .get_connections()
+                        .contains(&direction.opposite())
+                    {
+                        let (other_x, other_y) = neighbor;
+                        errors.push(format!(
+                            "Disconnected track at {},{}: {} leads {} to {} at {},{}, which doesn't lead back",
+                            x,
+                            y,
+                            track.to_string(),
+                            direction.to_name(),
+                            other_track.to_string(),
+                            other_x,
+                            other_y
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    return Ok(());
+}
+
+// carts crash the moment they move onto another cart, so the first crash is the
+// first one of the tick, which isn't necessarily the first one in reading order
+pub fn part_1(input_string: &str) -> Result<Coordinate, String> {
+    return Ok(simulate(input_string)?.first_crash);
+}
+
+pub fn part_2(input_string: &str) -> Result<Coordinate, String> {
+    let report = simulate(input_string)?;
+
+    match report.last_cart {
+        Some(position) => Ok(position),
+        None if report.collisions.len() * 2 == report.stats.len() => {
+            Err("Every cart crashed".to_string())
+        }
+        None => Err("The carts never reduce to one".to_string()),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CartStats {
+    pub id: usize,
+    // the ticks the cart made it through without crashing
+    pub ticks_survived: Tick,
+    // the pieces of track the cart moved along
+    pub distance: usize,
+    // the intersections at which the cart turned left or right, rather than going straight
+    pub intersection_turns: usize,
+}
+
+// what happens on the track, from the first tick until at most one cart is left
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrackReport {
+    // position of the first crash (part 1)
+    pub first_crash: Coordinate,
+    // every crash, in the order they happened
+    pub crashes: Vec<(Tick, Coordinate)>,
+    // the ids of the carts of every crash, in the same order as the crashes:
+    // the cart that moved, and the cart it ran into
+    pub collisions: Vec<(usize, usize)>,
+    // position of the last cart, at the end of the tick in which it's left alone (part 2).
+    // None if every cart crashed, or if the carts that are left never crash into each other.
+    pub last_cart: Option<Coordinate>,
+    // the stats of every cart, indexed by id
+    pub stats: Vec<CartStats>,
+}
+
+// tick until at most one cart is left, or until the carts are back in a state they were in before,
+// after which they'd never crash again.
+// the map is expected to be valid; carts that run off the track panic.
+pub fn simulate(input_string: &str) -> Result<TrackReport, String> {
+    let (map, mut carts) = parse_input(input_string)?;
+
+    let mut crashes: Vec<(Tick, Coordinate)> = vec![];
+    let mut collisions: Vec<(usize, usize)> = vec![];
+    let mut seen: HashSet<Vec<Cart>> = HashSet::new();
+    let mut tick: Tick = 0;
+
+    let mut stats: Vec<CartStats> = (0..carts.num_of_carts())
+        .map(|id| CartStats {
+            id,
+            ticks_survived: 0,
+            distance: 0,
+            intersection_turns: 0,
+        })
+        .collect();
+    // the turn every cart takes at its next intersection
+    let mut turning_options = vec![TurningOption::Left; carts.num_of_carts()];
+
+    while carts.carts.len() > 1 {
+        let mut state = carts.carts.clone();
+        state.sort_by_key(|cart| cart.id);
+        if !seen.insert(state) {
+            break;
+        }
+
+        tick += 1;
+
+        if let Some(crashed_carts) = carts.tick(&map) {
+            for position in crashed_carts {
+                crashes.push((tick, position));
+            }
+        }
+
+        for next_move in carts.get_moves() {
+            let cart_stats = &mut stats[next_move.id];
+            cart_stats.distance += 1;
+
+            // the cart went through an intersection if it's on to its next turning option
+            let turning_option = &mut turning_options[next_move.id];
+            if *turning_option != next_move.turning_option {
+                if *turning_option != TurningOption::Straight {
+                    cart_stats.intersection_turns += 1;
+                }
+                *turning_option = next_move.turning_option.clone();
+            }
+
+            if let Some(other_id) = next_move.crashed_into {
+                collisions.push((next_move.id, other_id));
+            }
+        }
+
+        for cart in carts.carts.iter() {
+            stats[cart.id].ticks_survived = tick;
+        }
+    }
+
+    let first_crash = match crashes.first() {
+        None => {
+            return Err("No carts crash".to_string());
+        }
+        Some((_tick, position)) => *position,
+    };
+
+    let last_cart = if carts.carts.len() == 1 {
+        Some(carts.carts[0].position)
+    } else {
+        None
+    };
+
+    return Ok(TrackReport {
+        first_crash,
+        crashes,
+        collisions,
+        last_cart,
+        stats,
+    });
+}
+
+// the positions every cart goes through, indexed by id, from its initial position up to the tick of the first crash
+// of the report of the track
+pub fn get_trajectories(
+    input_string: &str,
+    report: &TrackReport,
+) -> Result<Vec<Vec<Coordinate>>, String> {
+    let (first_crash_tick, _position) = report.crashes[0];
+    let (map, mut carts) = parse_input(input_string)?;
+
+    let mut trajectories: Vec<Vec<Coordinate>> = vec![vec![]; carts.num_of_carts()];
+    for cart in carts.carts.iter() {
+        trajectories[cart.id].push(cart.position);
+    }
+
+    for _ in 0..first_crash_tick {
+        carts.tick(&map);
+        for next_move in carts.get_moves() {
+            trajectories[next_move.id].push(next_move.to);
+        }
+    }
+
+    return Ok(trajectories);
+}
+
+const TRACK_COLOR: vector_image::Color = [170, 170, 170];
+const CRASH_COLOR: vector_image::Color = [0, 0, 0];
+
+// the track in gray, with the trajectory of every cart up to the first crash drawn over it in a color of its own.
+// every piece of track is a square of 1 by 1, and carts drive through their centers.
+pub fn draw(input_string: &str) -> Result<Drawing, String> {
+    let (map, _carts) = parse_input(input_string)?;
+    let report = simulate(input_string)?;
+    let trajectories = get_trajectories(input_string, &report)?;
+    let first_crash = report.first_crash;
+
+    let to_point = |(x, y): Coordinate| -> vector_image::Point {
+        return (x as f64 + 0.5, y as f64 + 0.5);
+    };
+
+    // every piece of track leads from its center halfway to the centers of the pieces it connects to
+    let mut positions: Vec<&Coordinate> = map.keys().collect();
+    positions.sort_by(|first, second| reading_order(first, second));
+
+    let mut segments = vec![];
+    for position in positions {
+        let (x, y) = to_point(*position);
+        for orientation in map[position].get_connections() {
+            let (next_x, next_y) = to_point(orientation.next_position(*position));
+            segments.push(((x, y), ((x + next_x) / 2.0, (y + next_y) / 2.0)));
+        }
+    }
+
+    let mut drawing = Drawing::new();
+    drawing.add_segments(&segments, TRACK_COLOR, 0.4);
+
+    for (id, trajectory) in trajectories.iter().enumerate() {
+        let points: Vec<vector_image::Point> = trajectory.iter().cloned().map(to_point).collect();
+        let color = vector_image::get_color(id);
+
+        drawing.add_polyline(&points, color, 0.15);
+        drawing.add_circle(points[0], 0.25, color);
+    }
+
+    drawing.add_circle(to_point(first_crash), 0.4, CRASH_COLOR);
+
+    return Ok(drawing);
+}
+
+pub const SOLVER: Solver = Solver {
+    year: 2018,
+    day: 13,
+    input: include_str!("input.txt"),
+    part_1: |input_string| {
+        let (x, y) = part_1(input_string)?;
+        return Ok(format!("{},{}", x, y));
+    },
+    part_2: Some(|input_string| {
+        let (x, y) = part_2(input_string)?;
+        return Ok(format!("{},{}", x, y));
+    }),
+    examples: &[
+        Example {
+            input: include_str!("../examples/1.txt"),
+            part_1: Some("7,3"),
+            part_2: None,
+        },
+        Example {
+            input: include_str!("../examples/2.txt"),
+            part_1: None,
+            part_2: Some("6,4"),
+        },
+    ],
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // rectangular loops laid out in a grid, cols loops per row, each with a pair of carts driving
+    // towards each other along its top edge; the carts of loop i start gaps[i] apart.
+    // the loops don't touch, so every pair crashes on its own, at a tick that depends on its gap.
+    fn generate_loops(cols: usize, gaps: &[usize]) -> String {
+        let max_gap = gaps.iter().max().cloned().unwrap_or(1);
+        let width = max_gap + 3;
+        let rows = gaps.len().div_ceil(cols);
+
+        // every loop is 3 rows tall, with an empty row and column between loops
+        let mut grid = vec![vec![' '; cols * (width + 1)]; rows * 4];
+
+        for (index, gap) in gaps.iter().enumerate() {
+            let (x, y) = ((index % cols) * (width + 1), (index / cols) * 4);
+
+            grid[y][x] = '/';
+            grid[y][x + width - 1] = '\\';
+            grid[y + 1][x] = '|';
+            grid[y + 1][x + width - 1] = '|';
+            grid[y + 2][x] = '\\';
+            grid[y + 2][x + width - 1] = '/';
+
+            for dx in 1..(width - 1) {
+                grid[y][x + dx] = '-';
+                grid[y + 2][x + dx] = '-';
+            }
+
+            grid[y][x + 1] = '>';
+            grid[y][x + 1 + gap] = '<';
+        }
+
+        let rows: Vec<String> = grid
+            .into_iter()
+            .map(|row| row.into_iter().collect::<String>().trim_end().to_string())
+            .collect();
+
+        return rows.join("\n");
+    }
+
+    #[test]
+    fn test_ordered_coordinate() {
+        let test: Vec<Coordinate> = vec![(2, 27), (3, 26), (2, 26), (1, 26), (2, 25)];
+        let test: Vec<OrderedCoordinate> = test.into_iter().map(|x| x.into()).collect();
+        let expected = {
+            let mut test = test.clone();
+            test.reverse();
+            test
+        };
+
+        let mut actual = test.clone();
+        actual.sort();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let input_string = r###"
+/->-\
+|   v
+^   |
+\-<-/
+"###
+        .trim();
+
+        let (map, carts) = parse_input(input_string).unwrap();
+
+        // carts are in reading order, facing the way their glyph points
+        let actual: Vec<(usize, Coordinate, Orientation)> = carts
+            .carts
+            .iter()
+            .map(|cart| (cart.id, cart.position, cart.orientation.clone()))
+            .collect();
+        assert_eq!(
+            actual,
+            vec![
+                (0, (2, 0), Orientation::Right),
+                (1, (4, 1), Orientation::Down),
+                (2, (0, 2), Orientation::Up),
+                (3, (2, 3), Orientation::Left),
+            ]
+        );
+
+        for cart in carts.carts.iter() {
+            assert_eq!(cart.turning_option, TurningOption::Left);
+        }
+
+        // the track beneath a cart is the straight piece along its orientation
+        assert_eq!(map.get(&(2, 0)), Some(&Track::Horizontal));
+        assert_eq!(map.get(&(4, 1)), Some(&Track::Vertical));
+        assert_eq!(map.get(&(0, 2)), Some(&Track::Vertical));
+        assert_eq!(map.get(&(2, 3)), Some(&Track::Horizontal));
+
+        // the loop is closed, with nothing inside of it
+        assert_eq!(map.len(), 14);
+        assert_eq!(map.get(&(1, 1)), None);
+    }
+
+    #[test]
+    fn test_curves() {
+        let get_track = |input_string: &str, position: Coordinate| -> Track {
+            let (map, _carts) = parse_input(input_string).unwrap();
+            return map.get(&position).unwrap().clone();
+        };
+
+        assert_eq!(get_track("/-\n|", (0, 0)), Track::BottomAndRight);
+        assert_eq!(get_track(" |\n-/", (1, 1)), Track::TopAndLeft);
+        assert_eq!(get_track("-\\\n |", (1, 0)), Track::BottomAndLeft);
+        assert_eq!(get_track("|\n\\-", (0, 1)), Track::TopAndRight);
+
+        // curves next to intersections and carts
+        assert_eq!(get_track("/+\n^", (0, 0)), Track::BottomAndRight);
+        assert_eq!(get_track(" +\n</", (1, 1)), Track::TopAndLeft);
+        assert_eq!(get_track(">\\\n +", (1, 0)), Track::BottomAndLeft);
+        assert_eq!(get_track("v\n\\+", (0, 1)), Track::TopAndRight);
+
+        // curves next to curves
+        let input_string = "/\\\n\\/";
+        assert_eq!(get_track(input_string, (0, 0)), Track::BottomAndRight);
+        assert_eq!(get_track(input_string, (1, 0)), Track::BottomAndLeft);
+        assert_eq!(get_track(input_string, (0, 1)), Track::TopAndRight);
+        assert_eq!(get_track(input_string, (1, 1)), Track::TopAndLeft);
+
+        let input_string = r###"/-\/-\
+| || |
+\-/\-/"###;
+        assert_eq!(get_track(input_string, (2, 0)), Track::BottomAndLeft);
+        assert_eq!(get_track(input_string, (3, 0)), Track::BottomAndRight);
+        assert_eq!(get_track(input_string, (2, 2)), Track::TopAndLeft);
+        assert_eq!(get_track(input_string, (3, 2)), Track::TopAndRight);
+    }
+
+    #[test]
+    fn test_cart_tick() {
+        // a figure-eight through a single intersection
+        let input_string = r###"/>\
+| |
+\-+-\
+  | |
+  \-/"###;
+
+        let (map, carts) = parse_input(input_string).unwrap();
+        let mut cart = carts.get_cart(&(1, 0)).unwrap().clone();
+
+        let mut intersections = vec![];
+        for tick in 1..=27 {
+            cart = cart.tick(&map);
+            if cart.position == (2, 2) {
+                intersections.push((tick, cart.orientation.clone(), cart.turning_option.clone()));
+            }
+        }
+
+        // the cart turns left, goes straight, turns right, then starts over
+        assert_eq!(
+            intersections,
+            vec![
+                (3, Orientation::Right, TurningOption::Straight),
+                (11, Orientation::Up, TurningOption::Right),
+                (19, Orientation::Down, TurningOption::Left),
+                (27, Orientation::Down, TurningOption::Straight),
+            ]
+        );
+
+        // every curve is followed on the way
+        cart = carts.get_cart(&(1, 0)).unwrap().clone();
+        let mut positions = vec![];
+        for _ in 0..17 {
+            cart = cart.tick(&map);
+            positions.push((cart.position, cart.orientation.clone()));
+        }
+        assert_eq!(positions[0], ((2, 0), Orientation::Down));
+        assert_eq!(positions[4], ((4, 2), Orientation::Down));
+        assert_eq!(positions[6], ((4, 4), Orientation::Left));
+        assert_eq!(positions[8], ((2, 4), Orientation::Up));
+        assert_eq!(positions[12], ((2, 0), Orientation::Left));
+        assert_eq!(positions[14], ((0, 0), Orientation::Down));
+        assert_eq!(positions[16], ((0, 2), Orientation::Right));
+    }
+
+    #[test]
+    fn test_carts_tick() {
+        let input_string = r###"->--<-
+-->---"###;
+
+        let (map, mut carts) = parse_input(input_string).unwrap();
+
+        assert_eq!(carts.tick(&map), None);
+
+        // the carts are looked up by their new positions
+        assert_eq!(carts.carts.len(), 3);
+        assert_eq!(
+            carts.get_cart(&(2, 0)).unwrap().orientation,
+            Orientation::Right
+        );
+        assert_eq!(
+            carts.get_cart(&(3, 0)).unwrap().orientation,
+            Orientation::Left
+        );
+        assert_eq!(
+            carts.get_cart(&(3, 1)).unwrap().orientation,
+            Orientation::Right
+        );
+        assert!(carts.get_cart(&(1, 0)).is_none());
+
+        // the cart on the left moves first, into the cart on the right
+        assert_eq!(carts.tick(&map), Some(vec![(3, 0)]));
+        assert_eq!(carts.carts.len(), 1);
+        assert!(carts.get_cart(&(3, 0)).is_none());
+        assert!(carts.get_cart(&(2, 0)).is_none());
+        assert_eq!(
+            carts.get_cart(&(4, 1)).unwrap().orientation,
+            Orientation::Right
+        );
+        assert_eq!(carts.get_cart(&(4, 1)).unwrap().id, 2);
+    }
+
+    #[test]
+    fn test_cart_ids() {
+        // carts chase each other around a loop, so their reading order changes from tick to tick
+        let input_string = r###"/->-\
+|   |
+\-<-/"###;
+
+        let (map, mut carts) = parse_input(input_string).unwrap();
+        assert_eq!(carts.get_cart(&(2, 0)).unwrap().id, 0);
+        assert_eq!(carts.get_cart(&(2, 2)).unwrap().id, 1);
+
+        let mut reference_carts: Vec<Cart> = carts.carts.clone();
+
+        for _ in 0..100 {
+            assert_eq!(carts.tick(&map), None);
+            reference_carts = reference_carts.iter().map(|cart| cart.tick(&map)).collect();
+
+            for reference_cart in reference_carts.iter() {
+                assert_eq!(
+                    carts.get_cart(&reference_cart.position),
+                    Some(reference_cart)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        let validate_input = |input_string: &str| -> Result<(), Vec<String>> {
+            let (map, _carts) = parse_input(input_string).unwrap();
+            return validate(&map);
+        };
+
+        assert_eq!(validate_input(include_str!("input.txt")), Ok(()));
+
+        let input_string = r###"/->-\
+|   |  /----\
+| /-+--+-\  |
+| | |  | v  |
+\-+-/  \-+--/
+  \------/"###;
+        assert_eq!(validate_input(input_string), Ok(()));
+
+        assert_eq!(
+            validate_input("->-"),
+            Err(vec![
+                "Dangling track at 0,0: - leads left to nothing".to_string(),
+                "Dangling track at 2,0: - leads right to nothing".to_string(),
+            ])
+        );
+
+        // a curve that doesn't close the loop
+        let input_string = r###"/-\
+| |
+\--"###;
+        assert_eq!(
+            validate_input(input_string),
+            Err(vec![
+                "Disconnected track at 2,1: | leads down to - at 2,2, which doesn't lead back"
+                    .to_string(),
+                "Dangling track at 2,2: - leads right to nothing".to_string(),
+            ])
+        );
+
+        // an intersection that's only connected to the left and to the right
+        let input_string = r###"/-+-\
+\---/"###;
+        assert_eq!(
+            validate_input(input_string),
+            Err(vec![
+                "Dangling track at 2,0: + leads up to nothing".to_string(),
+                "Disconnected track at 2,0: + leads down to - at 2,1, which doesn't lead back"
+                    .to_string(),
+            ])
+        );
+
+        let input_string = r###"/-\
+| |
+\-/
+ | "###;
+        assert_eq!(
+            validate_input(input_string),
+            Err(vec![
+                "Disconnected track at 1,3: | leads up to - at 1,2, which doesn't lead back"
+                    .to_string(),
+                "Dangling track at 1,3: | leads down to nothing".to_string(),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let input_string = r###"/->-\
+|   |  /----\
+| /-+--+-\  |
+| | |  | v  |
+\-+-/  \-+--/
+  \------/"###;
+
+        let (map, mut carts) = parse_input(input_string).unwrap();
+        for _ in 0..13 {
+            carts.tick(&map);
+        }
+        let crash_sites = carts.tick(&map).unwrap();
+
+        // the state of the first example when the carts crash, as shown in the puzzle
+        assert_eq!(
+            render(&map, &carts, &crash_sites, false),
+            r###"/---\
+|   |  /----\
+| /-+--+-\  |
+| | |  X |  |
+\-+-/  \-+--/
+  \------/"###
+        );
+
+        let colored = render(&map, &carts, &crash_sites, true);
+        let (_map, initial_carts) = parse_input(input_string).unwrap();
+        let initial_colored = render(&map, &initial_carts, &[], true);
+        assert!(colored.contains(&terminal::colorize("X", terminal::RED)));
+        assert!(initial_colored.contains(&terminal::colorize(">", terminal::YELLOW)));
+    }
+
+    #[test]
+    fn test_render_round_trip() {
+        // trailing spaces aren't rendered, since they're never track
+        let round_trip = |input_string: &str| {
+            let (map, carts) = parse_input(input_string).unwrap();
+            let expected: Vec<&str> = input_string.lines().map(|line| line.trim_end()).collect();
+
+            assert_eq!(render(&map, &carts, &[], false), expected.join("\n"));
+        };
+
+        round_trip(
+            r###"/->-\
+|   |  /----\
+| /-+--+-\  |
+| | |  | v  |
+\-+-/  \-+--/
+  \------/"###,
+        );
+
+        round_trip(
+            r###"/>-<\
+|   |
+| /<+-\
+| | | v
+\>+</ |
+  |   ^
+  \<->/"###,
+        );
+
+        round_trip("/\\/\\\n\\/\\/");
+
+        round_trip(include_str!("input.txt"));
+    }
+
+    #[test]
+    fn test_moves() {
+        let input_string = r###"/->-+-<\
+\----+-/"###;
+
+        let (map, mut carts) = parse_input(input_string).unwrap();
+
+        carts.tick(&map);
+        let moves: Vec<String> = carts.get_moves().iter().map(|x| x.to_string()).collect();
+        assert_eq!(
+            moves,
+            vec![
+                "cart 0: 2,0 -> 3,0 facing right, turning left next",
+                "cart 1: 6,0 -> 5,0 facing left, turning left next",
+            ]
+        );
+
+        // both carts reach the intersection; the first one turns left, and the second one runs into it
+        carts.tick(&map);
+        assert_eq!(
+            carts.get_moves(),
+            &[
+                Move {
+                    id: 0,
+                    from: (3, 0),
+                    to: (4, 0),
+                    orientation: Orientation::Up,
+                    turning_option: TurningOption::Straight,
+                    crashed_into: None,
+                },
+                Move {
+                    id: 1,
+                    from: (5, 0),
+                    to: (4, 0),
+                    orientation: Orientation::Down,
+                    turning_option: TurningOption::Straight,
+                    crashed_into: Some(0),
+                },
+            ]
+        );
+        assert_eq!(
+            carts.get_moves()[1].to_string(),
+            "cart 1: 5,0 -> 4,0 facing down, turning straight next, crashed into cart 0"
+        );
+    }
+
+    #[test]
+    fn test_simulate() {
+        let input_string = r###"/>-<\
+|   |
+| /<+-\
+| | | v
+\>+</ |
+  |   ^
+  \<->/"###;
+
+        let report = simulate(input_string).unwrap();
+        assert_eq!(report.first_crash, (2, 0));
+        assert_eq!(
+            report.crashes,
+            vec![(1, (2, 0)), (1, (2, 4)), (1, (6, 4)), (3, (2, 4))]
+        );
+        assert_eq!(report.collisions, vec![(1, 0), (5, 4), (6, 3), (7, 2)]);
+        assert_eq!(report.last_cart, Some((6, 4)));
+
+        // the last cart survives every tick, and the carts of the first three crashes survive none,
+        // although every one of them moves before it crashes
+        let ticks_survived: Vec<Tick> = report.stats.iter().map(|x| x.ticks_survived).collect();
+        assert_eq!(ticks_survived, vec![0, 0, 2, 0, 0, 0, 0, 2, 3]);
+        let distances: Vec<usize> = report.stats.iter().map(|x| x.distance).collect();
+        assert_eq!(distances, vec![1, 1, 3, 1, 1, 1, 1, 3, 3]);
+
+        let input_string = include_str!("input.txt");
+        let report = simulate(input_string).unwrap();
+        assert_eq!(part_1(input_string), Ok(report.first_crash));
+        assert_eq!(part_2(input_string).ok(), report.last_cart);
+        assert_eq!(report.crashes.len(), (17 - 1) / 2);
+
+        // every cart crashes
+        let report = simulate("->-<-").unwrap();
+        assert_eq!(report.crashes, vec![(1, (2, 0))]);
+        assert_eq!(report.last_cart, None);
+
+        // carts that chase each other around a loop never crash
+        let input_string = r###"/->-\
+|   |
+\-<-/"###;
+        assert!(simulate(input_string).is_err());
+
+        // the two carts on the right are left chasing each other after the carts on the left crash
+        let input_string = r###"/->-<-\/->-\
+\-----/|   |
+       \-<-/"###;
+        let report = simulate(input_string).unwrap();
+        assert_eq!(report.crashes, vec![(1, (3, 0))]);
+        assert_eq!(report.last_cart, None);
+    }
+
+    #[test]
+    fn test_examples() {
+        // the first example from the puzzle: two carts on a straight line
+        let input_string = r###"|
+v
+|
+|
+|
+^
+|"###;
+
+        let (map, mut carts) = parse_input(input_string).unwrap();
+        assert_eq!(carts.tick(&map), None);
+        assert_eq!(render(&map, &carts, &[], false), "|\n|\nv\n|\n^\n|\n|");
+        assert_eq!(carts.tick(&map), Some(vec![(0, 3)]));
+        assert_eq!(
+            render(&map, &carts, &[(0, 3)], false),
+            "|\n|\n|\nX\n|\n|\n|"
+        );
+
+        // the second example from the puzzle
+        let input_string = r###"/->-\
+|   |  /----\
+| /-+--+-\  |
+| | |  | v  |
+\-+-/  \-+--/
+  \------/"###;
+
+        let (map, mut carts) = parse_input(input_string).unwrap();
+        carts.tick(&map);
+        assert_eq!(
+            render(&map, &carts, &[], false),
+            r###"/-->\
+|   |  /----\
+| /-+--+-\  |
+| | |  | |  |
+\-+-/  \->--/
+  \------/"###
+        );
+
+        assert_eq!(
+            simulate(input_string),
+            Ok(TrackReport {
+                first_crash: (7, 3),
+                crashes: vec![(14, (7, 3))],
+                collisions: vec![(0, 1)],
+                last_cart: None,
+                stats: vec![
+                    CartStats {
+                        id: 0,
+                        ticks_survived: 13,
+                        distance: 14,
+                        intersection_turns: 2,
+                    },
+                    CartStats {
+                        id: 1,
+                        ticks_survived: 13,
+                        distance: 14,
+                        intersection_turns: 1,
+                    },
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn test_stress() {
+        let cols = 8;
+        let gaps: Vec<usize> = (0..48).map(|index| 1 + (index * 7) % 11).collect();
+        let input_string = generate_loops(cols, &gaps);
+
+        let (map, carts) = parse_input(&input_string).unwrap();
+        assert_eq!(validate(&map), Ok(()));
+        assert_eq!(carts.num_of_carts(), 96);
+
+        // the carts of a loop meet halfway, or on the cart facing right if the gap is odd.
+        // crashes of the same tick happen in reading order, since every crash is on the top row of its loop.
+        let width = gaps.iter().max().unwrap() + 3;
+        let mut expected: Vec<(Tick, Coordinate)> = gaps
+            .iter()
+            .enumerate()
+            .map(|(index, gap)| {
+                let (x, y) = ((index % cols) * (width + 1), (index / cols) * 4);
+                let tick = gap.div_ceil(2);
+                return (tick, ((x + 1 + tick) as i32, y as i32));
+            })
+            .collect();
+        expected.sort_by(|(first_tick, first_coord), (second_tick, second_coord)| {
+            return first_tick
+                .cmp(second_tick)
+                .then_with(|| reading_order(first_coord, second_coord));
+        });
+
+        let report = simulate(&input_string).unwrap();
+        assert_eq!(report.crashes, expected);
+        assert_eq!(report.first_crash, expected[0].1);
+        assert_eq!(report.last_cart, None);
+        assert_eq!(part_1(&input_string), Ok(expected[0].1));
+        assert_eq!(part_2(&input_string), Err("Every cart crashed".to_string()));
+
+        // a lone cart on a loop of its own outlasts the rest
+        let input_string = input_string.replacen('<', "-", 1);
+        assert_eq!(parse_input(&input_string).unwrap().1.num_of_carts(), 95);
+        assert_eq!(part_2(&input_string), Ok((7, 0)));
+    }
+
+    #[test]
+    fn test_draw() {
+        let input_string = r###"/->-\
+|   |  /----\
+| /-+--+-\  |
+| | |  | v  |
+\-+-/  \-+--/
+  \------/"###;
+
+        let report = simulate(input_string).unwrap();
+        let trajectories = get_trajectories(input_string, &report).unwrap();
+        assert_eq!(trajectories.len(), 2);
+        assert_eq!(trajectories[0][..4], [(2, 0), (3, 0), (4, 0), (4, 1)]);
+        assert_eq!(trajectories[1][..3], [(9, 3), (9, 4), (10, 4)]);
+        // both carts are on the track for all 14 ticks, and they crash into each other
+        assert_eq!(trajectories[0].len(), 15);
+        assert_eq!(trajectories[1].len(), 15);
+        assert_eq!(trajectories[1].last(), Some(&(7, 3)));
+        assert_eq!(trajectories[0][13], (7, 4));
+
+        let svg = draw(input_string).unwrap().to_svg();
+        assert!(svg.starts_with(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0.3 0.25 12.4 5.45\">"
+        ));
+        // the track, a path and a start for every cart, and the crash
+        assert_eq!(svg.matches("<path").count(), 3);
+        assert_eq!(svg.matches("<circle").count(), 3);
+        assert!(svg.contains("<path d=\"M2.5 0.5 L3.5 0.5 L4.5 0.5 L4.5 1.5"));
+        assert!(svg.contains("<circle cx=\"7.5\" cy=\"3.5\" r=\"0.4\" fill=\"#000000\"/>"));
+
+        assert!(draw("->--").is_err());
+    }
+
+    #[test]
+    fn test_pad_rows() {
+        // the puzzle input keeps its trailing spaces
+        let (padded, warnings) = pad_rows(include_str!("input.txt"));
+        assert_eq!(padded, include_str!("input.txt").trim_end_matches('\n'));
+        assert!(warnings.is_empty());
+
+        let input_string = "/->-\\\r\n|   |  /----\\\r\n\\---/  \\----/\r\n";
+        let (padded, warnings) = pad_rows(input_string);
+        assert_eq!(padded, "/->-\\        \n|   |  /----\\\n\\---/  \\----/");
+        assert_eq!(
+            warnings,
+            vec!["1 of 3 rows are shorter than 13 characters; trailing spaces may have been stripped, so they're padded back"]
+        );
+
+        assert_eq!(pad_rows(""), ("".to_string(), vec![]));
+    }
+
+    #[test]
+    fn test_read_map() {
+        let path = std::env::temp_dir().join(format!(
+            "advent-of-code-day-13-2018-map-test-{}.txt",
+            std::process::id()
+        ));
+
+        std::fs::write(&path, "  /-\\\n->--/\n").unwrap();
+        let (map, warnings) = read_map(&path).unwrap();
+        assert_eq!(map, "  /-\\\n->--/");
+        assert!(warnings.is_empty());
+
+        std::fs::write(&path, "/->-\\   \n\\---/").unwrap();
+        let (map, warnings) = read_map(&path).unwrap();
+        assert_eq!(map, "/->-\\   \n\\---/   ");
+        assert_eq!(warnings.len(), 1);
+
+        std::fs::write(&path, [0xff, 0xfe]).unwrap();
+        assert!(read_map(&path).is_err());
+
+        std::fs::remove_file(&path).unwrap();
+        assert!(read_map(&path).is_err());
+    }
+
+    #[test]
+    fn ambiguous_curve() {
+        let input_string = r###" |
+-/-
+ |"###;
+
+        assert_eq!(
+            parse_input(input_string).err(),
+            Some("Invalid placement of track at 1,1: / doesn't connect one horizontal and one vertical piece of track".to_string())
+        );
+
+        assert_eq!(
+            parse_input("-->-#").err(),
+            Some("Unknown cell at 4,0: '#'".to_string())
+        );
+        assert!(part_1("").is_err());
+        assert!((SOLVER.part_1)("-/-").is_err());
+    }
+
+    #[test]
+    fn test_no_crash() {
+        // a lone cart, and two carts chasing each other around a loop, go round forever
+        let input_string = r###"/->-\
+|   |
+\---/"###;
+        assert_eq!(part_1(input_string), Err("No carts crash".to_string()));
+        assert!(part_2(input_string).is_err());
+
+        let input_string = r###"/->-\
+|   |
+\-<-/"###;
+        assert_eq!(part_1(input_string), Err("No carts crash".to_string()));
+        assert!((SOLVER.part_1)(input_string).is_err());
+        assert!((SOLVER.part_2.unwrap())(input_string).is_err());
+
+        // the two carts on the right are left chasing each other after the carts on the left crash
+        let input_string = r###"/->-<-\/->-\
+\-----/|   |
+       \-<-/"###;
+        assert_eq!(part_1(input_string), Ok((3, 0)));
+        assert_eq!(
+            part_2(input_string),
+            Err("The carts never reduce to one".to_string())
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn near_miss() {
+        let input_string = r###"
+   |
+   |
+->-+---
+   |
+   |
+   ^
+"###;
+
+        let _ = part_1(input_string);
+    }
+
+    #[test]
+    fn test_part_1() {
+        let input_string = include_str!("../examples/1.txt");
+
+        assert_eq!(part_1(input_string), Ok((7, 3)));
+
+        // first crash occurs from left to right
+        let input_string = r###"->-<-->-<--"###;
+
+        assert_eq!(part_1(input_string), Ok((2, 0)));
+
+        // carts shouldn't pass through each other
+        let input_string = r###"--->--<---"###;
+
+        assert_eq!(part_1(input_string), Ok((5, 0)));
+
+        let input_string = r###"|
+|
+v
+|
+|
+^
+|
+|
+"###;
+
+        assert_eq!(part_1(input_string), Ok((0, 4)));
+
+        let input_string = r###"->+<-
+  ^  "###;
+
+        assert_eq!(part_1(input_string), Ok((2, 0)));
+
+        let input_string = r###"-->>--"###;
+
+        assert_eq!(part_1(input_string), Ok((3, 0)));
+
+        // the crash of the first row happens first, even if it's to the right of the crash of the second row
+        let input_string = r###"----><-
+-><----"###;
+
+        assert_eq!(part_1(input_string), Ok((5, 0)));
+
+        let input_string = include_str!("input.txt");
+
+        assert_eq!(part_1(input_string), Ok((76, 108)));
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input_string = include_str!("../examples/2.txt");
+
+        assert_eq!(part_2(input_string), Ok((6, 4)));
+
+        // carts are removed the moment they crash, so the cart from the left drives through the crash site
+        // and turns left at the intersection
+        let input_string = r###"  v
+  |
+>-+<--
+  |"###;
+
+        assert_eq!(part_2(input_string), Ok((2, 2)));
+
+        // the last cart standing is where it is at the end of the tick
+        let input_string = r###"->-<--<-"###;
+
+        assert_eq!(part_2(input_string), Ok((5, 0)));
+    }
+}
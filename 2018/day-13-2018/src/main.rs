@@ -2,741 +2,362 @@
 
 // imports
 
-use core::cmp::Ordering;
-use std::collections::BTreeMap;
-use std::collections::HashMap;
-use std::collections::HashSet;
+use day_13_2018::{
+    draw, pad_rows, parse_input, part_1, part_2, read_map, render, simulate, validate, Carts, Map,
+    Tick, TrackReport,
+};
+use solver::load_input;
+use std::path::Path;
+use std::time::Duration;
 
 // code
 
-type Coordinate = (i32, i32);
-
-// sort coordinates according to their reading order
-fn reading_order(first_coord: &Coordinate, second_coord: &Coordinate) -> Ordering {
-    let (x1, y1) = first_coord;
-    let (x2, y2) = second_coord;
-
-    if y1 != y2 {
-        return y1.cmp(y2);
-    }
-
-    return x1.cmp(x2);
-}
-
-#[derive(PartialEq, Hash, Eq, Clone, Debug)]
-struct OrderedCoordinate(Coordinate);
-
-impl OrderedCoordinate {
-    fn coordinate(&self) -> Coordinate {
-        return self.0.clone();
-    }
-}
-
-impl PartialOrd for OrderedCoordinate {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        return Some(reading_order(&self.0, &other.0));
-    }
-}
-
-impl Ord for OrderedCoordinate {
-    fn cmp(&self, other: &Self) -> Ordering {
-        let ord = self.partial_cmp(other).unwrap();
-        return ord;
-    }
-}
-
-impl Into<OrderedCoordinate> for Coordinate {
-    fn into(self) -> OrderedCoordinate {
-        return OrderedCoordinate(self);
-    }
-}
-
-#[derive(Debug, Clone)]
-enum Track {
-    // |
-    Vertical,
-    // -
-    Horizontal,
-    // +
-    Intersection,
-
-    // curves
-    // invariant: Curves connect exactly two perpendicular pieces of track
-
-    // match configuration:
-    //   /-
-    //   |
-    BottomAndRight,
-
-    // match configuration:
-    //    |
-    //   -/
-    TopAndLeft,
-
-    // match configuration:
-    //   -\
-    //    |
-    BottomAndLeft,
-    // match configuration:
-    //   |
-    //   \-
-    TopAndRight,
-}
-
-impl Track {
-    fn to_string(&self) -> String {
-        let result = match self {
-            Track::Vertical => "|",
-            Track::Horizontal => "-",
-            Track::Intersection => "+",
-            Track::BottomAndRight | Track::TopAndLeft => "/",
-            Track::BottomAndLeft | Track::TopAndRight => "\\",
-        };
-
-        return result.to_string();
-    }
-}
-
-fn is_horizontal(cell: char) -> bool {
-    match cell {
-        '-' | '+' => true,
-        _ => false,
-    }
+struct Options {
+    // path to the map; - reads from stdin
+    path: Option<String>,
+    // redraw the track every tick
+    animate: bool,
+    // time between the ticks of the animation
+    delay: Duration,
+    // log the moves of every cart on every tick
+    trace: bool,
+    // stop the trace after this tick
+    stop_at: Option<Tick>,
+    // write the track and the trajectories of the carts up to the first crash to an SVG at this path
+    svg_path: Option<String>,
+    // print the stats of every cart and crash
+    show_stats: bool,
 }
 
-fn is_vertical(cell: char) -> bool {
-    match cell {
-        '|' | '+' => true,
-        _ => false,
-    }
-}
+// parse command line arguments:
+// [--animate] [--delay <milliseconds>] [--trace] [--stop-at <tick>] [--svg <path>] [--stats] [path]
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let mut options = Options {
+        path: None,
+        animate: false,
+        delay: Duration::from_millis(100),
+        trace: false,
+        stop_at: None,
+        svg_path: None,
+        show_stats: false,
+    };
 
-type Map = HashMap<Coordinate, Track>;
+    let mut args = args.iter();
 
-#[derive(Debug, PartialEq, Eq, Hash, Clone)]
-enum TurningOption {
-    Left,
-    Straight,
-    Right,
-}
-
-impl TurningOption {
-    fn next(&self) -> TurningOption {
-        match self {
-            TurningOption::Left => TurningOption::Straight,
-            TurningOption::Straight => TurningOption::Right,
-            TurningOption::Right => TurningOption::Left,
-        }
-    }
-}
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--animate" => {
+                options.animate = true;
+            }
+            "--delay" => {
+                let delay: u64 = args
+                    .next()
+                    .ok_or_else(|| "Expected milliseconds after --delay".to_string())?
+                    .parse()
+                    .map_err(|_| "Invalid milliseconds for --delay".to_string())?;
+                options.delay = Duration::from_millis(delay);
+            }
+            "--trace" => {
+                options.trace = true;
+            }
+            "--stop-at" => {
+                let tick = args
+                    .next()
+                    .ok_or_else(|| "Expected a tick after --stop-at".to_string())?
+                    .parse()
+                    .map_err(|_| "Invalid tick for --stop-at".to_string())?;
+                options.stop_at = Some(tick);
+            }
+            "--stats" => {
+                options.show_stats = true;
+            }
+            "--svg" => {
+                let path = args
+                    .next()
+                    .ok_or_else(|| "Expected a path after --svg".to_string())?;
+                options.svg_path = Some(path.clone());
+            }
+            _ => {
+                if arg.starts_with("--") {
+                    return Err(format!("Unknown argument: {}", arg));
+                }
 
-#[derive(Debug, Eq, PartialEq, Hash, Clone)]
-enum Orientation {
-    Up,
-    Down,
-    Left,
-    Right,
-}
+                if options.path.is_some() {
+                    return Err(format!("Unexpected argument: {}", arg));
+                }
 
-impl Orientation {
-    fn turn(&self, turning_option: &TurningOption) -> Orientation {
-        match self {
-            Orientation::Up => match turning_option {
-                TurningOption::Left => Orientation::Left,
-                TurningOption::Straight => self.clone(),
-                TurningOption::Right => Orientation::Right,
-            },
-            Orientation::Down => match turning_option {
-                TurningOption::Left => Orientation::Right,
-                TurningOption::Straight => self.clone(),
-                TurningOption::Right => Orientation::Left,
-            },
-            Orientation::Left => match turning_option {
-                TurningOption::Left => Orientation::Down,
-                TurningOption::Straight => self.clone(),
-                TurningOption::Right => Orientation::Up,
-            },
-            Orientation::Right => match turning_option {
-                TurningOption::Left => Orientation::Up,
-                TurningOption::Straight => self.clone(),
-                TurningOption::Right => Orientation::Down,
-            },
+                options.path = Some(arg.clone());
+            }
         }
     }
-}
 
-#[derive(Debug, Eq, PartialEq, Hash, Clone)]
-struct Cart {
-    orientation: Orientation,
-    // current position
-    position: Coordinate,
-    // when a cart arrives at an intersection, this rule determines the cart's
-    // next destination
-    turning_option: TurningOption,
+    return Ok(options);
 }
 
-impl Cart {
-    fn is_cart(cell: char) -> bool {
-        match cell {
-            '^' | 'v' | '<' | '>' => true,
-            _ => false,
-        }
-    }
-
-    fn new(cell: char, position: Coordinate) -> Cart {
-        assert!(Cart::is_cart(cell));
+// the frames of every tick until at most one cart is left, starting from the initial state
+fn get_frames(map: Map, mut carts: Carts) -> impl Iterator<Item = String> {
+    let mut tick = 0;
+    let mut is_done = false;
 
-        let orientation = match cell {
-            '^' => Orientation::Up,
-            'v' => Orientation::Down,
-            '<' => Orientation::Left,
-            '>' => Orientation::Right,
-            _ => {
-                unreachable!();
-            }
-        };
-
-        Cart {
-            orientation,
-            position,
-            turning_option: TurningOption::Left,
+    return std::iter::from_fn(move || {
+        if is_done {
+            return None;
         }
-    }
 
-    fn to_string(&self) -> String {
-        let orientation = match self.orientation {
-            Orientation::Up => "^",
-            Orientation::Down => "v",
-            Orientation::Left => "<",
-            Orientation::Right => ">",
+        // crashes are shown for the tick they happen in
+        let crash_sites = if tick > 0 {
+            carts.tick(&map).unwrap_or_default()
+        } else {
+            vec![]
         };
 
-        return orientation.to_string();
-    }
-
-    fn tick(&self, map: &Map) -> Cart {
-        let (x, y) = self.position;
+        is_done = carts.num_of_carts() <= 1;
 
-        // println!("{:?}", self.position);
+        let frame = format!(
+            "Tick: {}\n{}\nCarts: {}\n",
+            tick,
+            render(&map, &carts, &crash_sites, true),
+            carts.num_of_carts()
+        );
 
-        // generate next position
+        tick += 1;
 
-        let next_position = match self.orientation {
-            Orientation::Up => (x, y - 1),
-            Orientation::Down => (x, y + 1),
-            Orientation::Left => (x - 1, y),
-            Orientation::Right => (x + 1, y),
-        };
+        return Some(frame);
+    });
+}
 
-        // generate next orientation
+// the moves of every tick until at most one cart is left, or until the tick to stop at
+fn get_trace(map: Map, mut carts: Carts, stop_at: Option<Tick>) -> impl Iterator<Item = String> {
+    let mut tick = 0;
 
-        let next_track: Track = match map.get(&next_position) {
-            None => {
-                assert!(false, "No track found at: {:?}", next_position);
-                unreachable!();
-            }
-            Some(track) => track.clone(),
-        };
-
-        let mut next_orientation = self.orientation.clone();
-        let mut next_turning_option = self.turning_option.clone();
-
-        match next_track {
-            Track::BottomAndRight => {
-                next_orientation = match self.orientation {
-                    Orientation::Up => Orientation::Right,
-                    Orientation::Left => Orientation::Down,
-                    _ => {
-                        unreachable!("Unexpected orientation: {:?}", self.orientation);
-                    }
-                }
-            }
-            Track::TopAndLeft => {
-                next_orientation = match self.orientation {
-                    Orientation::Down => Orientation::Left,
-                    Orientation::Right => Orientation::Up,
-                    _ => {
-                        unreachable!("Unexpected orientation: {:?}", self.orientation);
-                    }
-                }
-            }
-            Track::BottomAndLeft => {
-                next_orientation = match self.orientation {
-                    Orientation::Up => Orientation::Left,
-                    Orientation::Right => Orientation::Down,
-                    _ => {
-                        unreachable!("Unexpected orientation: {:?}", self.orientation);
-                    }
-                }
-            }
-            Track::TopAndRight => {
-                next_orientation = match self.orientation {
-                    Orientation::Down => Orientation::Right,
-                    Orientation::Left => Orientation::Up,
-                    _ => {
-                        unreachable!("Unexpected orientation: {:?}", self.orientation);
-                    }
-                }
-            }
-            Track::Intersection => {
-                next_orientation = self.orientation.turn(&self.turning_option);
-                next_turning_option = self.turning_option.next();
-            }
-            Track::Vertical => {
-                match self.orientation {
-                    Orientation::Up | Orientation::Down => {}
-                    _ => {
-                        unreachable!("Unexpected orientation: {:?}", self.orientation);
-                    }
-                };
-            }
-            Track::Horizontal => {
-                match self.orientation {
-                    Orientation::Left | Orientation::Right => {}
-                    _ => {
-                        unreachable!("Unexpected orientation: {:?}", self.orientation);
-                    }
-                };
-            }
+    return std::iter::from_fn(move || {
+        if carts.num_of_carts() <= 1 || stop_at == Some(tick) {
+            return None;
         }
 
-        Cart {
-            orientation: next_orientation,
-            position: next_position,
-            turning_option: next_turning_option,
-        }
-    }
-}
+        carts.tick(&map);
+        tick += 1;
 
-type CrashedCarts = HashSet<Coordinate>;
+        let mut lines = vec![format!("Tick: {}", tick)];
+        lines.extend(carts.get_moves().iter().map(|x| x.to_string()));
 
-struct Carts {
-    carts: BTreeMap<OrderedCoordinate, Cart>,
+        return Some(lines.join("\n"));
+    });
 }
 
-impl Carts {
-    fn new() -> Carts {
-        Carts {
-            carts: BTreeMap::new(),
-        }
-    }
-
-    fn add_cart(&mut self, cart: Cart) {
-        self.carts.insert(cart.position.into(), cart);
+// a table of the stats of every cart, followed by every crash
+fn get_stats_table(report: &TrackReport) -> String {
+    let mut lines = vec![format!(
+        "{:<6}{:>16}{:>10}{:>20}",
+        "cart", "ticks survived", "distance", "intersection turns"
+    )];
+
+    for stats in report.stats.iter() {
+        lines.push(format!(
+            "{:<6}{:>16}{:>10}{:>20}",
+            stats.id, stats.ticks_survived, stats.distance, stats.intersection_turns
+        ));
     }
 
-    fn get_cart(&self, position: &Coordinate) -> Option<&Cart> {
-        let position: Coordinate = position.clone();
-        return self.carts.get(&position.into()).clone();
+    for ((tick, (x, y)), (id, other_id)) in report.crashes.iter().zip(report.collisions.iter()) {
+        lines.push(format!(
+            "Crash at {},{} on tick {}: cart {} ran into cart {}",
+            x, y, tick, id, other_id
+        ));
     }
 
-    fn tick(&mut self, map: &Map) -> Option<CrashedCarts> {
-        let mut crashed_positions: HashSet<Coordinate> = HashSet::new();
-
-        let (_, next_carts) = self.carts.iter().fold(
-            (self.carts.clone(), BTreeMap::new()),
-            |acc, (_current_position, current_cart)| {
-                let (mut prev_carts, mut next_carts): (
-                    BTreeMap<OrderedCoordinate, Cart>,
-                    BTreeMap<OrderedCoordinate, Cart>,
-                ) = acc;
-
-                // remove current cart from the current map state
-                prev_carts.remove(&current_cart.position.into());
-
-                if crashed_positions.contains(&current_cart.position) {
-                    // current cart was crashed by another cart that moved before itself.
-                    return (prev_carts, next_carts);
-                }
-
-                let next_cart = current_cart.tick(&map);
-
-                // does the next cart collide with any other cart in the map state?
-                if prev_carts.contains_key(&next_cart.position.into()) {
-                    crashed_positions.insert(next_cart.position);
-                    return (prev_carts, next_carts);
-                }
-
-                // does the next cart collide with carts that already have moved?
-                if next_carts.contains_key(&next_cart.position.into()) {
-                    crashed_positions.insert(next_cart.position);
-                    next_carts.remove(&next_cart.position.into());
-                    return (prev_carts, next_carts);
-                }
+    return lines.join("\n");
+}
 
-                next_carts.insert(next_cart.position.into(), next_cart);
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let options = parse_args(&args).unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        std::process::exit(1);
+    });
+
+    // a map file is read as is, so that nothing trims its rows on the way in
+    let map = match options.path.as_deref() {
+        Some(path) if path != "-" => read_map(Path::new(path)),
+        path => load_input(path).map(|input_string| pad_rows(&input_string)),
+    };
 
-                return (prev_carts, next_carts);
-            },
-        );
+    let (input_string, warnings) = map.unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        std::process::exit(1);
+    });
 
-        // invariant: by the end of this tick, next_carts contain carts that haven't crashed
+    for warning in warnings {
+        eprintln!("Warning: {}", warning);
+    }
 
-        self.carts = next_carts;
+    let (map, carts) = parse_input(&input_string).unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        std::process::exit(1);
+    });
 
-        if crashed_positions.len() > 0 {
-            return Some(crashed_positions);
+    if let Err(errors) = validate(&map) {
+        for error in errors {
+            eprintln!("{}", error);
         }
-
-        return None;
+        std::process::exit(1);
     }
-}
-
-#[allow(dead_code)]
-fn print_map(map: &Map, carts: &Carts, max_x: i32, max_y: i32) {
-    for y in 0..=max_y {
-        for x in 0..=max_x {
-            let position = (x, y);
-
-            match carts.get_cart(&position) {
-                None => match map.get(&position) {
-                    None => {
-                        print!(" ");
-                    }
-                    Some(track) => {
-                        print!("{}", track.to_string());
-                    }
-                },
-                Some(cart) => {
-                    print!("{}", cart.to_string());
-                }
-            }
-        }
 
-        println!("");
+    if let Some(path) = &options.svg_path {
+        draw(&input_string)
+            .and_then(|drawing| drawing.save(Path::new(path)))
+            .unwrap_or_else(|err| {
+                eprintln!("{}", err);
+                std::process::exit(1);
+            });
+        return;
     }
-}
-
-fn parse_input(input_string: &str) -> (Map, Carts) {
-    #[allow(unused_variables)]
-    let num_of_lines = input_string.lines().into_iter().count() as i32;
-    #[allow(unused_variables)]
-    let num_of_cols = input_string
-        .lines()
-        .into_iter()
-        .map(|x| x.len())
-        .max()
-        .unwrap() as i32;
-
-    let mut carts: Carts = Carts::new();
-
-    let map: Map = {
-        let mut map: Map = HashMap::new();
-
-        let mut cell_map: HashMap<Coordinate, char> = HashMap::new();
-
-        for (y, line) in input_string.lines().enumerate() {
-            for (x, cell) in line.chars().enumerate() {
-                let position: Coordinate = (x as i32, y as i32);
-                // println!("{:?} {}", position, cell);
-
-                // add carts
-                if Cart::is_cart(cell) {
-                    let cart = Cart::new(cell, position);
-                    carts.add_cart(cart);
-                }
-
-                // convert cart to appropriate track
-                let cell = match cell {
-                    'v' | '^' => '|',
-                    '<' | '>' => '-',
-                    _ => cell,
-                };
 
-                cell_map.insert(position, cell);
-            }
-        }
-
-        for (position, cell) in cell_map.iter() {
-            let (x, y) = position.clone();
-            let position = position.clone();
-
-            match cell {
-                '|' => {
-                    map.insert(position, Track::Vertical);
-                }
-                '-' => {
-                    map.insert(position, Track::Horizontal);
-                }
-                '+' => {
-                    map.insert(position, Track::Intersection);
-                }
-                '/' => {
-                    // match configuration:
-                    //   /-
-                    //   |
-                    let is_configuration_1 = {
-                        let valid_right_side = match cell_map.get(&(x + 1, y)) {
-                            None => false,
-                            Some(cell) => is_horizontal(*cell),
-                        };
-
-                        let valid_bottom_side = match cell_map.get(&(x, y + 1)) {
-                            None => false,
-                            Some(cell) => is_vertical(*cell),
-                        };
-
-                        valid_right_side && valid_bottom_side
-                    };
-
-                    // match configuration:
-                    //    |
-                    //   -/
-                    let is_configuration_2 = {
-                        let valid_left_side = match cell_map.get(&(x - 1, y)) {
-                            None => false,
-                            Some(cell) => is_horizontal(*cell),
-                        };
-
-                        let valid_top_side = match cell_map.get(&(x, y - 1)) {
-                            None => false,
-                            Some(cell) => is_vertical(*cell),
-                        };
-
-                        valid_left_side && valid_top_side
-                    };
-
-                    if is_configuration_1 && !is_configuration_2 {
-                        map.insert(position, Track::BottomAndRight);
-                        continue;
-                    }
-
-                    if !is_configuration_1 && is_configuration_2 {
-                        map.insert(position, Track::TopAndLeft);
-                        continue;
-                    }
-
-                    assert!(
-                        false,
-                        format!("Invalid placement of track: / at {:?}", position)
-                    );
-                }
-                '\\' => {
-                    // match configuration:
-                    //   -\
-                    //    |
-                    let is_configuration_1 = {
-                        let valid_left_side = match cell_map.get(&(x - 1, y)) {
-                            None => false,
-                            Some(cell) => is_horizontal(*cell),
-                        };
-
-                        let valid_bottom_side = match cell_map.get(&(x, y + 1)) {
-                            None => false,
-                            Some(cell) => is_vertical(*cell),
-                        };
-
-                        valid_left_side && valid_bottom_side
-                    };
-
-                    // match configuration:
-                    //   |
-                    //   \-
-                    let is_configuration_2 = {
-                        let valid_top_side = match cell_map.get(&(x, y - 1)) {
-                            None => false,
-                            Some(cell) => is_vertical(*cell),
-                        };
-
-                        let valid_right_side = match cell_map.get(&(x + 1, y)) {
-                            None => false,
-                            Some(cell) => is_horizontal(*cell),
-                        };
-
-                        valid_top_side && valid_right_side
-                    };
-
-                    if is_configuration_1 && !is_configuration_2 {
-                        map.insert(position, Track::BottomAndLeft);
-                        continue;
-                    }
-
-                    if !is_configuration_1 && is_configuration_2 {
-                        map.insert(position, Track::TopAndRight);
-                        continue;
-                    }
-
-                    assert!(
-                        false,
-                        format!("Invalid placement of track: \\ at {:?}", position)
-                    );
-                }
-                ' ' => {}
-                _ => {
-                    assert!(false, "Unknown cell at {:?}: {}", position, cell);
-                }
-            }
+    if options.trace {
+        for moves in get_trace(map, carts, options.stop_at) {
+            println!("{}", moves);
         }
+        return;
+    }
 
-        map
-    };
-
-    return (map, carts);
-}
-
-fn part_1(input_string: &str) -> Coordinate {
-    let (map, mut carts) = parse_input(input_string);
-
-    // print_map(&map, &carts, num_of_cols - 1, num_of_lines - 1);
-
-    loop {
-        let crashed_carts = carts.tick(&map);
-        // num_of_ticks += 1;
-        // print_map(&map, &carts, num_of_cols - 1, num_of_lines - 1);
-        // println!("==========");
-        // use std::thread;
-        // use std::time::Duration;
-        // thread::sleep(Duration::from_millis(100));
-
-        match crashed_carts {
-            None => {}
-            Some(crashed_carts) => {
-                // not: 29,104
-
-                let mut crashed_carts: Vec<Coordinate> = crashed_carts.into_iter().collect();
-                crashed_carts.sort();
-
-                // println!("{:?}", crashed_carts);
-                // println!("crashed at tick: {}", num_of_ticks);
-
-                return *crashed_carts.first().unwrap();
-            }
-        }
+    if options.animate {
+        terminal::animate(get_frames(map, carts), options.delay).unwrap_or_else(|err| {
+            eprintln!("{}", err);
+            std::process::exit(1);
+        });
+        return;
     }
-}
 
-fn part_2(input_string: &str) -> Option<Coordinate> {
-    let (map, mut carts) = parse_input(input_string);
+    match part_1(&input_string) {
+        Ok((x, y)) => println!("Part 1: {},{}", x, y),
+        Err(err) => println!("Part 1: {}", err),
+    }
 
-    // print_map(&map, &carts, num_of_cols - 1, num_of_lines - 1);
+    match part_2(&input_string) {
+        Ok((x, y)) => println!("Part 2: {},{}", x, y),
+        Err(err) => println!("Part 2: {}", err),
+    }
 
-    loop {
-        carts.tick(&map);
-        // num_of_ticks += 1;
-        // print_map(&map, &carts, num_of_cols - 1, num_of_lines - 1);
-        // println!("==========");
-        // use std::thread;
-        // use std::time::Duration;
-        // thread::sleep(Duration::from_millis(100));
-
-        if carts.carts.len() <= 1 {
-            return carts
-                .carts
-                .iter()
-                .map(|(position, _cart)| -> Coordinate {
-                    return position.coordinate();
-                })
-                .into_iter()
-                .next();
+    if options.show_stats {
+        match simulate(&input_string) {
+            Ok(report) => println!("{}", get_stats_table(&report)),
+            Err(err) => println!("{}", err),
         }
     }
 }
 
-fn main() {
-    let input_string = include_str!("input.txt");
-
-    let crashed_position = part_1(input_string);
-
-    println!("Part 1: {:?}", crashed_position);
-
-    let survivor = part_2(input_string);
-    println!("Part 2: {:?}", survivor);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
-    fn test_ordered_coordinate() {
-        let test: Vec<Coordinate> = vec![(2, 27), (3, 26), (2, 26), (1, 26), (2, 25)];
-        let test: Vec<OrderedCoordinate> = test.into_iter().map(|x| x.into()).collect();
-        let expected = {
-            let mut test = test.clone();
-            test.reverse();
-            test
+    fn test_parse_args() {
+        let to_args = |args: Vec<&str>| -> Vec<String> {
+            return args.into_iter().map(|x| x.to_string()).collect();
         };
 
-        let mut actual = test.clone();
-        actual.sort();
-
-        assert_eq!(actual, expected);
+        let options = parse_args(&to_args(vec![])).unwrap();
+        assert!(!options.animate);
+        assert_eq!(options.delay, Duration::from_millis(100));
+
+        let options = parse_args(&to_args(vec!["--animate", "--delay", "250"])).unwrap();
+        assert!(options.animate);
+        assert_eq!(options.delay, Duration::from_millis(250));
+
+        assert!(parse_args(&to_args(vec!["--foo"])).is_err());
+        assert!(parse_args(&to_args(vec!["a.txt", "b.txt"])).is_err());
+        assert!(parse_args(&to_args(vec!["--delay"])).is_err());
+        assert!(parse_args(&to_args(vec!["--delay", "x"])).is_err());
+
+        assert!(!options.trace);
+        assert_eq!(options.stop_at, None);
+        let options = parse_args(&to_args(vec!["--trace", "--stop-at", "10"])).unwrap();
+        assert!(options.trace);
+        assert_eq!(options.stop_at, Some(10));
+        assert!(parse_args(&to_args(vec!["--stop-at"])).is_err());
+        assert!(parse_args(&to_args(vec!["--stop-at", "-1"])).is_err());
+
+        assert_eq!(options.svg_path, None);
+        let options = parse_args(&to_args(vec!["--svg", "tracks.svg"])).unwrap();
+        assert_eq!(options.svg_path, Some("tracks.svg".to_string()));
+        assert!(parse_args(&to_args(vec!["--svg"])).is_err());
+
+        assert!(!options.show_stats);
+        let options = parse_args(&to_args(vec!["--stats"])).unwrap();
+        assert!(options.show_stats);
+
+        assert_eq!(options.path, None);
+        let options = parse_args(&to_args(vec!["--stats", "tracks.txt"])).unwrap();
+        assert_eq!(options.path, Some("tracks.txt".to_string()));
     }
 
     #[test]
-    #[should_panic]
-    fn near_miss() {
-        let input_string = r###"
-   |
-   |
-->-+---
-   |
-   |
-   ^
-"###;
-
-        part_1(input_string);
+    fn test_frames() {
+        let input_string = r###"/>-<\
+|   |
+| /<+-\
+| | | v
+\>+</ |
+  |   ^
+  \<->/"###;
+
+        let (map, carts) = parse_input(input_string).unwrap();
+        let frames: Vec<String> = get_frames(map, carts).collect();
+
+        // the initial state, and then every tick until the last cart is left
+        assert_eq!(frames.len(), 4);
+        assert!(frames[0].starts_with("Tick: 0\n"));
+        assert!(frames[0].ends_with("Carts: 9\n"));
+        assert!(frames[1].contains('X'));
+        assert!(frames[3].starts_with("Tick: 3\n"));
+        assert!(frames[3].ends_with("Carts: 1\n"));
     }
 
     #[test]
-    fn test_part_1() {
-        let input_string = r###"/->-\
-|   |  /----\
-| /-+--+-\  |
-| | |  | v  |
-\-+-/  \-+--/
-  \------/
-        "###;
-
-        assert_eq!(part_1(input_string), (7, 3));
-
-        // first crash occurs from left to right
-        let input_string = r###"->-<-->-<--"###;
-
-        assert_eq!(part_1(input_string), (2, 0));
+    fn test_trace() {
+        let input_string = r###"/->-+-<\
+\----+-/"###;
 
-        // carts shouldn't pass through each other
-        let input_string = r###"--->--<---"###;
-
-        assert_eq!(part_1(input_string), (5, 0));
-
-        let input_string = r###"|
-|
-v
-|
-|
-^
-|
-|
-"###;
-
-        assert_eq!(part_1(input_string), (0, 4));
-
-        let input_string = r###"->+<-
-  ^  "###;
-
-        assert_eq!(part_1(input_string), (2, 0));
-
-        let input_string = r###"-->>--"###;
+        let get_moves = |stop_at: Option<Tick>| -> Vec<String> {
+            let (map, carts) = parse_input(input_string).unwrap();
+            return get_trace(map, carts, stop_at).collect();
+        };
 
-        assert_eq!(part_1(input_string), (3, 0));
+        assert_eq!(
+            get_moves(None),
+            vec![
+                "Tick: 1\ncart 0: 2,0 -> 3,0 facing right, turning left next\ncart 1: 6,0 -> 5,0 facing left, turning left next",
+                "Tick: 2\ncart 0: 3,0 -> 4,0 facing up, turning straight next\ncart 1: 5,0 -> 4,0 facing down, turning straight next, crashed into cart 0",
+            ]
+        );
 
-        let input_string = include_str!("input.txt");
+        let trace = get_moves(Some(1));
+        assert_eq!(trace.len(), 1);
+        assert!(trace[0].starts_with("Tick: 1\n"));
 
-        assert_eq!(part_1(input_string), (76, 108));
+        assert_eq!(get_moves(Some(0)).len(), 0);
     }
 
     #[test]
-    fn test_part_2() {
-        let input_string = r###"/>-<\
-|   |
-| /<+-\
-| | | v
-\>+</ |
-  |   ^
-  \<->/
-        "###;
-
-        assert_eq!(part_2(input_string), Some((6, 4)));
+    fn test_stats_table() {
+        let input_string = r###"/->-\
+|   |  /----\
+| /-+--+-\  |
+| | |  | v  |
+\-+-/  \-+--/
+  \------/"###;
+
+        let report = simulate(input_string).unwrap();
+
+        assert_eq!(
+            get_stats_table(&report),
+            r###"
+cart    ticks survived  distance  intersection turns
+0                   13        14                   2
+1                   13        14                   1
+Crash at 7,3 on tick 14: cart 0 ran into cart 1
+            "###
+            .trim()
+        );
     }
 }
@@ -0,0 +1,235 @@
+// https://adventofcode.com/2018/day/14
+
+use solver::{Example, Solver};
+
+// the number of recipes given as the puzzle input
+pub const INPUT: &str = "540391";
+
+type Recipe = u8;
+
+// the scores of the recipes, and the recipes the two elves are currently on
+struct Scoreboard {
+    recipes: Vec<Recipe>,
+    first_elf: usize,
+    second_elf: usize,
+}
+
+impl Scoreboard {
+    fn new() -> Scoreboard {
+        Scoreboard {
+            recipes: vec![3, 7],
+            first_elf: 0,
+            second_elf: 1,
+        }
+    }
+
+    // the elves combine their current recipes into one or two new recipes, and move on to their next recipes.
+    // returns the number of recipes that were added.
+    fn step(&mut self) -> usize {
+        let recipe_1 = self.recipes[self.first_elf];
+        let recipe_2 = self.recipes[self.second_elf];
+
+        // the digits of the sum of the scores are the scores of the new recipes
+        let recipe_result = recipe_1 + recipe_2;
+
+        let num_of_new_recipes = if recipe_result >= 10 {
+            self.recipes.push(recipe_result / 10);
+            self.recipes.push(recipe_result % 10);
+            2
+        } else {
+            self.recipes.push(recipe_result);
+            1
+        };
+
+        let len = self.recipes.len();
+        self.first_elf = (self.first_elf + 1 + recipe_1 as usize) % len;
+        self.second_elf = (self.second_elf + 1 + recipe_2 as usize) % len;
+
+        return num_of_new_recipes;
+    }
+}
+
+fn parse_recipes(input: &str) -> Result<Vec<Recipe>, String> {
+    return input
+        .trim()
+        .chars()
+        .map(|x| {
+            return x
+                .to_digit(10)
+                .map(|digit| digit as Recipe)
+                .ok_or_else(|| format!("Invalid score: {}", x));
+        })
+        .collect();
+}
+
+// the scores of the ten recipes after the first num_of_recipes recipes
+pub fn part_1(num_of_recipes: usize) -> String {
+    let mut scoreboard = Scoreboard::new();
+
+    while scoreboard.recipes.len() < num_of_recipes + 10 {
+        scoreboard.step();
+    }
+
+    return scoreboard.recipes[num_of_recipes..(num_of_recipes + 10)]
+        .iter()
+        .map(|recipe| (b'0' + recipe) as char)
+        .collect();
+}
+
+// the number of recipes to the left of the first appearance of the scores.
+//
+// the scores can only first appear at the end of the scoreboard, ending at one of the recipes that was just added,
+// so only the end of the scoreboard is checked after every step.
+pub fn part_2(scores: &str) -> Result<usize, String> {
+    let needle = parse_recipes(scores)?;
+
+    if needle.is_empty() {
+        return Err("No scores to look for".to_string());
+    }
+
+    let mut scoreboard = Scoreboard::new();
+
+    // the scores could be within the starting recipes
+    let mut num_of_new_recipes = scoreboard.recipes.len();
+
+    loop {
+        let len = scoreboard.recipes.len();
+
+        for end in (len - num_of_new_recipes + 1)..=len {
+            if end >= needle.len() && scoreboard.recipes[(end - needle.len())..end] == needle[..] {
+                return Ok(end - needle.len());
+            }
+        }
+
+        num_of_new_recipes = scoreboard.step();
+    }
+}
+
+pub const SOLVER: Solver = Solver {
+    year: 2018,
+    day: 14,
+    input: INPUT,
+    part_1: |input_string| {
+        let num_of_recipes = input_string
+            .trim()
+            .parse()
+            .map_err(|_| format!("Invalid number of recipes: {}", input_string.trim()))?;
+        return Ok(part_1(num_of_recipes));
+    },
+    part_2: Some(|input_string| Ok(part_2(input_string)?.to_string())),
+    examples: &[
+        Example {
+            input: include_str!("../examples/1.txt"),
+            part_1: Some("5158916779"),
+            part_2: None,
+        },
+        Example {
+            input: include_str!("../examples/2.txt"),
+            part_1: Some("0124515891"),
+            part_2: None,
+        },
+        Example {
+            input: include_str!("../examples/3.txt"),
+            part_1: Some("9251071085"),
+            part_2: None,
+        },
+        Example {
+            input: include_str!("../examples/4.txt"),
+            part_1: Some("5941429882"),
+            part_2: None,
+        },
+        Example {
+            input: include_str!("../examples/5.txt"),
+            part_1: None,
+            part_2: Some("9"),
+        },
+        Example {
+            input: include_str!("../examples/6.txt"),
+            part_1: None,
+            part_2: Some("5"),
+        },
+        Example {
+            input: include_str!("../examples/7.txt"),
+            part_1: None,
+            part_2: Some("18"),
+        },
+        Example {
+            input: include_str!("../examples/8.txt"),
+            part_1: None,
+            part_2: Some("2018"),
+        },
+    ],
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scoreboard() {
+        let mut scoreboard = Scoreboard::new();
+
+        assert_eq!(scoreboard.step(), 2);
+        assert_eq!(scoreboard.recipes, vec![3, 7, 1, 0]);
+        assert_eq!((scoreboard.first_elf, scoreboard.second_elf), (0, 1));
+
+        assert_eq!(scoreboard.step(), 2);
+        assert_eq!(scoreboard.recipes, vec![3, 7, 1, 0, 1, 0]);
+        assert_eq!((scoreboard.first_elf, scoreboard.second_elf), (4, 3));
+
+        assert_eq!(scoreboard.step(), 1);
+        assert_eq!(scoreboard.recipes, vec![3, 7, 1, 0, 1, 0, 1]);
+        assert_eq!((scoreboard.first_elf, scoreboard.second_elf), (6, 4));
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(part_1(9), "5158916779".to_string());
+        assert_eq!(part_1(5), "0124515891".to_string());
+        assert_eq!(part_1(18), "9251071085".to_string());
+        assert_eq!(part_1(2018), "5941429882".to_string());
+
+        assert_eq!(part_1(540391), "1474315445".to_string());
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(part_2(include_str!("../examples/5.txt")), Ok(9));
+        assert_eq!(part_2(include_str!("../examples/6.txt")), Ok(5));
+        assert_eq!(part_2(include_str!("../examples/7.txt")), Ok(18));
+        assert_eq!(part_2(include_str!("../examples/8.txt")), Ok(2018));
+
+        // within the starting recipes
+        assert_eq!(part_2("37"), Ok(0));
+        assert_eq!(part_2("7"), Ok(1));
+        // a partial match that overlaps with the actual match: 3, 7, 1, 0, 1, 0, 1, ...
+        assert_eq!(part_2("1010"), Ok(2));
+
+        // the first appearance of every short run of scores matches a search through the whole scoreboard,
+        // whether the run ends on the first or the second of two recipes added in the same step
+        let mut scoreboard = Scoreboard::new();
+        while scoreboard.recipes.len() < 1000 {
+            scoreboard.step();
+        }
+        for start in 0..100 {
+            for len in 1..=6 {
+                let needle = &scoreboard.recipes[start..(start + len)];
+                let expected = scoreboard
+                    .recipes
+                    .windows(len)
+                    .position(|window| window == needle);
+                let scores: String = needle
+                    .iter()
+                    .map(|recipe| (b'0' + recipe) as char)
+                    .collect();
+
+                assert_eq!(part_2(&scores).ok(), expected);
+            }
+        }
+
+        assert!(part_2("").is_err());
+        assert!(part_2("5a").is_err());
+
+        assert_eq!(part_2("540391"), Ok(20278122));
+    }
+}
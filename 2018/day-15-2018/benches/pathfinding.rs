@@ -0,0 +1,73 @@
+// benchmark one full round of the real input for each pathfinding strategy,
+// and a whole combat in a generated arena for the strategies fast enough to fight one repeatedly.
+//
+// run with: cargo bench -p day-15-2018
+
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use day_15_2018::{
+    generate_arena, parse_input_with_config, simulate, CombatConfig, PathfindingStrategy,
+};
+
+fn bench_pathfinding_strategies(c: &mut Criterion) {
+    let input_string = include_str!("../src/input.txt");
+
+    let strategies = vec![
+        (
+            "per-target dijkstra",
+            PathfindingStrategy::PerTargetDijkstra,
+        ),
+        ("single bfs", PathfindingStrategy::SingleBfs),
+        ("a*", PathfindingStrategy::AStar),
+    ];
+
+    let mut group = c.benchmark_group("one round");
+
+    for (name, strategy) in strategies {
+        let config = CombatConfig {
+            strategy,
+            ..CombatConfig::default()
+        };
+        let map = parse_input_with_config(input_string, config);
+
+        group.bench_function(name, |b| {
+            b.iter_batched(
+                || map.clone(),
+                |mut map| map.execute_round(),
+                BatchSize::SmallInput,
+            )
+        });
+    }
+
+    group.finish();
+}
+
+// a combat fought to the end, so that a slowdown in the later rounds,
+// where the units are spread out and far from their targets, is noticed too
+fn bench_full_combat(c: &mut Criterion) {
+    let arena = generate_arena(40, 40, 0.25, 20, 20, 2018);
+
+    let strategies = vec![
+        ("single bfs", PathfindingStrategy::SingleBfs),
+        ("a*", PathfindingStrategy::AStar),
+    ];
+
+    let mut group = c.benchmark_group("full combat");
+    // every iteration fights more than a hundred rounds
+    group.sample_size(10);
+
+    for (name, strategy) in strategies {
+        let config = CombatConfig {
+            strategy,
+            ..CombatConfig::default()
+        };
+
+        assert_eq!(simulate(&arena, &config).outcome, 63759);
+
+        group.bench_function(name, |b| b.iter(|| simulate(&arena, &config)));
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_pathfinding_strategies, bench_full_combat);
+criterion_main!(benches);
@@ -0,0 +1,2023 @@
+// https://adventofcode.com/2018/day/15
+
+// imports
+use rayon::prelude::*;
+
+use core::cmp::Ordering;
+use solver::{Example, Solver};
+use std::collections::BTreeMap;
+use std::collections::BinaryHeap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use tracing::{debug, trace};
+
+// code
+
+#[derive(Debug)]
+pub enum RoundState {
+    Incomplete,
+    Complete,
+}
+
+trait Transitions {
+    fn up(&self) -> Coordinate;
+    fn down(&self) -> Coordinate;
+    fn left(&self) -> Coordinate;
+    fn right(&self) -> Coordinate;
+}
+
+pub type Coordinate = (i32, i32);
+
+type Path = Vec<Coordinate>;
+
+impl Transitions for Coordinate {
+    fn up(&self) -> Coordinate {
+        let (x, y) = self;
+        return (*x, y - 1);
+    }
+
+    fn down(&self) -> Coordinate {
+        let (x, y) = self;
+        return (*x, y + 1);
+    }
+
+    fn left(&self) -> Coordinate {
+        let (x, y) = self;
+        return (x - 1, *y);
+    }
+
+    fn right(&self) -> Coordinate {
+        let (x, y) = self;
+        return (x + 1, *y);
+    }
+}
+
+#[derive(PartialEq, Hash, Eq, Clone, Debug)]
+struct DistanceCoordinate(Distance, Coordinate);
+
+impl PartialOrd for DistanceCoordinate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        // reversed for the binary heap which is a max-heap
+        if self.0 != other.0 {
+            return Some(other.0.cmp(&self.0));
+        }
+        return Some(reading_order(&other.1, &self.1));
+    }
+}
+
+impl Ord for DistanceCoordinate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let ord = self.partial_cmp(other).unwrap();
+        return ord;
+        // match ord {
+        //     Ordering::Greater => Ordering::Less,
+        //     Ordering::Less => Ordering::Greater,
+        //     Ordering::Equal => ord,
+        // }
+    }
+}
+
+impl Into<Coordinate> for DistanceCoordinate {
+    fn into(self) -> Coordinate {
+        return self.1;
+    }
+}
+
+// adapted from day 6
+// https://math.stackexchange.com/a/139604/10247
+type Distance = i32;
+fn get_manhattan_distance(start: Coordinate, end: Coordinate) -> Distance {
+    let (a, b) = start;
+    let (c, d) = end;
+
+    return (a - c).abs() + (b - d).abs();
+}
+
+// sort coordinates according to their reading order
+fn reading_order(first_coord: &Coordinate, second_coord: &Coordinate) -> Ordering {
+    let (x1, y1) = first_coord;
+    let (x2, y2) = second_coord;
+
+    if y1 != y2 {
+        return y1.cmp(y2);
+    }
+
+    return x1.cmp(x2);
+}
+
+#[derive(Debug, Clone)]
+enum MapState {
+    Wall,
+    Cavern,
+}
+
+type Terrain = HashMap<Coordinate, MapState>;
+type UnitPlacement = BTreeMap<Coordinate, Unit>;
+
+// strategies for choosing the next move of a unit.
+// all of them are expected to choose the exact same move.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PathfindingStrategy {
+    // Dijkstra from every square in range of a target back towards the unit
+    PerTargetDijkstra,
+    // a single BFS from the unit to pick the nearest square in range,
+    // followed by a BFS from that square to pick the first step
+    SingleBfs,
+    // same as PerTargetDijkstra, but guided by the manhattan distance towards the unit
+    AStar,
+}
+
+// parameters of the combat that can be tweaked for experimentation
+#[derive(Debug, Clone)]
+pub struct CombatConfig {
+    // starting hit points of every unit
+    pub hit_points: i32,
+    // attack power of the goblins
+    pub attack_power: i32,
+    // attack power of the elves; part 2 searches for the lowest boosted value
+    pub elf_attack_power: i32,
+    pub strategy: PathfindingStrategy,
+}
+
+impl Default for CombatConfig {
+    fn default() -> Self {
+        CombatConfig {
+            hit_points: 200,
+            attack_power: 3,
+            elf_attack_power: 3,
+            strategy: PathfindingStrategy::PerTargetDijkstra,
+        }
+    }
+}
+
+impl CombatConfig {
+    // generate candidate elf attack powers for part 2.
+    //
+    // since every unit starts with the same hit points, only attack powers that reduce the number of hits
+    // needed to kill a goblin can change the outcome of the combat.
+    // for 200 hit points, this yields: 4, 5, ..., 17, 19, 20, 23, 25, 29, 34, 40, 50, 67, 100, 200
+    //
+    // interesting hint from: https://www.reddit.com/r/adventofcode/comments/a6nw3b/2018_day_15_part_2_optimisation_ideas/ebxkt28/
+    fn elf_attack_power_candidates(&self) -> Vec<i32> {
+        let hits_to_kill = |attack_power: i32| -> i32 {
+            return (self.hit_points + attack_power - 1) / attack_power;
+        };
+
+        let start = std::cmp::max(self.elf_attack_power, 1);
+
+        return ((start + 1)..=std::cmp::max(self.hit_points, start + 1))
+            .filter(|attack_power| hits_to_kill(*attack_power) != hits_to_kill(attack_power - 1))
+            .collect();
+    }
+}
+
+// aggregates of a team at the end of a round
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TeamStats {
+    pub units_alive: usize,
+    pub total_hit_points: i32,
+    // damage dealt by the team during the round
+    pub damage_dealt: i32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Kill {
+    pub killer_id: usize,
+    pub killer_type: UnitType,
+    pub victim_id: usize,
+    pub victim_type: UnitType,
+    // where the victim died
+    pub position: Coordinate,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RoundStats {
+    // rounds are numbered starting from 1
+    pub round: usize,
+    // the last round of combat usually ends before every unit took its turn
+    pub completed: bool,
+    pub elves: TeamStats,
+    pub goblins: TeamStats,
+    pub kills: Vec<Kill>,
+}
+
+impl RoundStats {
+    fn new(round: usize) -> RoundStats {
+        RoundStats {
+            round,
+            completed: false,
+            elves: TeamStats::default(),
+            goblins: TeamStats::default(),
+            kills: vec![],
+        }
+    }
+
+    fn get_team_mut(&mut self, unit_type: &UnitType) -> &mut TeamStats {
+        match unit_type {
+            UnitType::Elf => &mut self.elves,
+            UnitType::Goblin => &mut self.goblins,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CombatSummary {
+    pub num_of_rounds_completed: i32,
+    pub sum_hit_points: i32,
+    pub outcome: i32,
+    pub winner: Option<UnitType>,
+    pub num_of_elves_died: usize,
+    pub rounds: Vec<RoundStats>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Map {
+    terrain: Terrain,
+    units: UnitPlacement,
+    num_of_elves_died: usize,
+    config: CombatConfig,
+    round_stats: Vec<RoundStats>,
+}
+
+impl Map {
+    fn new(config: CombatConfig) -> Map {
+        Map {
+            terrain: HashMap::new(),
+            units: BTreeMap::new(),
+            num_of_elves_died: 0,
+            config,
+            round_stats: vec![],
+        }
+    }
+
+    fn with_elf_attack_power(&mut self, elf_attack_power: i32) {
+        for (_, unit) in self.units.iter_mut() {
+            if unit.is_elf() {
+                unit.attack_power = elf_attack_power;
+            }
+        }
+    }
+
+    pub fn to_string(&self) -> String {
+        let max_x = self
+            .terrain
+            .iter()
+            .map(|((x, _y), _map_state)| x)
+            .max()
+            .unwrap();
+        let max_y = self
+            .terrain
+            .iter()
+            .map(|((_x, y), _map_state)| y)
+            .max()
+            .unwrap();
+
+        let mut map_string: Vec<String> = vec![];
+
+        for y in 0..=*max_y {
+            let mut row_string = String::from("");
+
+            for x in 0..=*max_x {
+                let position = (x, y);
+
+                match self.terrain.get(&position) {
+                    None => {
+                        row_string.push_str("#");
+                    }
+                    Some(map_state) => {
+                        match map_state {
+                            MapState::Wall => {
+                                // invariant: a unit cannot be within a wall
+                                assert!(!self.units.contains_key(&position));
+                                row_string.push_str("#");
+                            }
+                            MapState::Cavern => match self.units.get(&position) {
+                                None => {
+                                    row_string.push_str(".");
+                                }
+                                Some(unit) => {
+                                    row_string.push_str(&unit.to_string());
+                                }
+                            },
+                        }
+                    }
+                }
+            }
+
+            map_string.push(row_string);
+        }
+
+        return map_string.join("\n");
+    }
+
+    #[allow(dead_code)]
+    fn to_string_with_health(&self) -> String {
+        let max_x = self
+            .terrain
+            .iter()
+            .map(|((x, _y), _map_state)| x)
+            .max()
+            .unwrap();
+        let max_y = self
+            .terrain
+            .iter()
+            .map(|((_x, y), _map_state)| y)
+            .max()
+            .unwrap();
+
+        let mut map_string: Vec<String> = vec![];
+
+        for y in 0..=*max_y {
+            let mut row_string = String::from("");
+            let mut visited_units = vec![];
+
+            for x in 0..=*max_x {
+                let position = (x, y);
+
+                match self.terrain.get(&position) {
+                    None => {
+                        row_string.push_str("#");
+                    }
+                    Some(map_state) => {
+                        match map_state {
+                            MapState::Wall => {
+                                // invariant: a unit cannot be within a wall
+                                assert!(!self.units.contains_key(&position));
+                                row_string.push_str("#");
+                            }
+                            MapState::Cavern => match self.units.get(&position) {
+                                None => {
+                                    row_string.push_str(".");
+                                }
+                                Some(unit) => {
+                                    visited_units.push(unit.to_health_string());
+                                    row_string.push_str(&unit.to_string());
+                                }
+                            },
+                        }
+                    }
+                }
+            }
+
+            if visited_units.len() >= 1 {
+                let foo = visited_units.join(", ");
+                row_string.push_str(&format!("  {}", foo));
+            }
+
+            map_string.push(row_string);
+        }
+
+        return map_string.join("\n");
+    }
+
+    fn insert(&mut self, position: Coordinate, cell: char) {
+        match cell {
+            '#' => {
+                self.terrain.insert(position, MapState::Wall);
+            }
+            '.' => {
+                self.terrain.insert(position, MapState::Cavern);
+            }
+            'G' => {
+                self.terrain.insert(position, MapState::Cavern);
+                self.units
+                    .insert(position, Unit::new_goblin(self.units.len(), &self.config));
+            }
+            'E' => {
+                self.terrain.insert(position, MapState::Cavern);
+                self.units
+                    .insert(position, Unit::new_elf(self.units.len(), &self.config));
+            }
+            _ => {
+                assert!(false, "Unknown cell: {}", cell);
+            }
+        }
+    }
+
+    fn is_wall(&self, position: Coordinate) -> bool {
+        match self.terrain.get(&position) {
+            None => true,
+            Some(map_state) => match map_state {
+                MapState::Wall => true,
+                MapState::Cavern => false,
+            },
+        }
+    }
+
+    fn is_occupied(&self, position: Coordinate) -> bool {
+        if self.is_wall(position) {
+            return true;
+        }
+
+        // check if the position is occupied by a unit
+        return self.units.contains_key(&position);
+    }
+
+    fn get_elves(&self) -> Vec<(Coordinate, Unit)> {
+        return self
+            .units
+            .par_iter()
+            .filter(|(_position, unit)| {
+                return unit.is_elf();
+            })
+            .map(|(position, unit)| (position.clone(), unit.clone()))
+            .collect();
+    }
+
+    fn has_elves(&self) -> bool {
+        return self
+            .units
+            .iter()
+            .filter(|(_position, unit)| {
+                return unit.is_elf();
+            })
+            .next()
+            .is_some();
+    }
+
+    fn get_goblins(&self) -> Vec<(Coordinate, Unit)> {
+        return self
+            .units
+            .par_iter()
+            .filter(|(_position, unit)| {
+                return unit.is_goblin();
+            })
+            .map(|(position, unit)| (position.clone(), unit.clone()))
+            .collect();
+    }
+
+    fn has_goblins(&self) -> bool {
+        return self
+            .units
+            .iter()
+            .filter(|(_position, unit)| {
+                return unit.is_goblin();
+            })
+            .next()
+            .is_some();
+    }
+
+    // checks if a round can be executed
+    fn can_run_round(&self) -> bool {
+        if self.units.is_empty() {
+            return false;
+        }
+
+        let units: Vec<(Coordinate, Unit)> = {
+            let mut units: Vec<(Coordinate, Unit)> = self.units.clone().into_par_iter().collect();
+
+            units.sort_by(|item_1, item_2| {
+                let (pos_1, _) = item_1;
+                let (pos_2, _) = item_2;
+
+                return reading_order(pos_1, pos_2);
+            });
+            units
+        };
+
+        let (_position, unit) = units.iter().next().unwrap();
+
+        if unit.is_elf() {
+            return self.has_goblins();
+        }
+
+        if unit.is_goblin() {
+            return self.has_elves();
+        }
+
+        unreachable!();
+    }
+    // get open squares adjacent to position
+    fn get_adjacent_open_squares(&self, position: Coordinate) -> Vec<Coordinate> {
+        let coords = vec![
+            position.up(),
+            position.down(),
+            position.left(),
+            position.right(),
+        ];
+
+        return coords
+            .into_par_iter()
+            .filter(|coord| {
+                return !self.is_occupied(*coord);
+            })
+            .collect();
+    }
+
+    fn get_targets(&self, unit: &Unit) -> Vec<(Coordinate, Unit)> {
+        if unit.is_elf() {
+            return self.get_goblins();
+        } else if unit.is_goblin() {
+            return self.get_elves();
+        } else {
+            unreachable!();
+        };
+    }
+
+    fn get_attackable_target(
+        &self,
+        position_of_unit: &Coordinate,
+        attacking_unit: &Unit,
+    ) -> Option<(Coordinate, Unit)> {
+        // check if this unit is still alive.
+        if !self.units.contains_key(&position_of_unit) {
+            return None;
+        }
+
+        let targets = self.get_targets(attacking_unit);
+
+        let adjacent_targets: Vec<(Coordinate, Unit)> = {
+            let mut adjacent_targets: Vec<(Coordinate, Unit)> = targets
+                .par_iter()
+                .map(|(position_of_target, target)| -> (Coordinate, Unit) {
+                    (position_of_target.clone(), (*target).clone())
+                })
+                .filter(|(position_of_target, _target)| {
+                    return get_manhattan_distance(*position_of_unit, *position_of_target) <= 1;
+                })
+                .collect();
+
+            adjacent_targets.sort_by(|item_1, item_2| {
+                let (position_of_target_1, target_1) = item_1;
+                let (position_of_target_2, target_2) = item_2;
+
+                // the adjacent target with the fewest hit points is selected;
+                if target_1.hit_points != target_2.hit_points {
+                    return target_1.hit_points.cmp(&target_2.hit_points);
+                }
+
+                // in a tie, the adjacent target with the fewest hit points which is first in reading order is selected.
+                return reading_order(position_of_target_1, position_of_target_2);
+            });
+
+            adjacent_targets
+        };
+
+        if adjacent_targets.len() >= 1 {
+            let (position_of_target, chosen_target) = adjacent_targets.first().unwrap();
+            return Some((*position_of_target, chosen_target.clone()));
+        }
+
+        return None;
+    }
+
+    // choose the next square the unit at position_of_unit moves into; if any
+    fn get_next_move(
+        &self,
+        position_of_unit: Coordinate,
+        targets: Vec<(Coordinate, Unit)>,
+    ) -> Option<Coordinate> {
+        match self.config.strategy {
+            PathfindingStrategy::PerTargetDijkstra => {
+                self.get_next_move_per_target(position_of_unit, targets, get_reachable_path)
+            }
+            PathfindingStrategy::SingleBfs => {
+                self.get_next_move_single_bfs(position_of_unit, targets)
+            }
+            PathfindingStrategy::AStar => {
+                self.get_next_move_per_target(position_of_unit, targets, get_reachable_path_a_star)
+            }
+        }
+    }
+
+    // find a path from every square in range of a target, and choose the first step of the shortest one
+    fn get_next_move_per_target(
+        &self,
+        position_of_unit: Coordinate,
+        targets: Vec<(Coordinate, Unit)>,
+        find_path: fn(&Map, Coordinate, Coordinate) -> Option<Path>,
+    ) -> Option<Coordinate> {
+        let mut reachable_paths: Vec<(Coordinate, Path)> = targets
+            .into_par_iter()
+            .map(|(position_of_target, _target)| {
+                // for each target, identify open squares adjacent to position_of_target
+                let adjacent_open_squares = self.get_adjacent_open_squares(position_of_target);
+
+                let reachable_paths: Vec<(Coordinate, Path)> = adjacent_open_squares
+                    .into_par_iter()
+                    .map(|reachable_square| {
+                        let path = find_path(self, position_of_unit, reachable_square);
+                        return (reachable_square, path);
+                    })
+                    .filter(|(_reachable_square, path)| {
+                        // filter out un-reachable squares
+                        return path.is_some();
+                    })
+                    .map(|(reachable_square, path)| {
+                        return (reachable_square, path.unwrap());
+                    })
+                    .filter(|(_reachable_square, path)| {
+                        // only consider non-empty paths
+                        return path.len() >= 1;
+                    })
+                    .collect();
+                return reachable_paths;
+            })
+            .reduce(
+                || vec![],
+                |mut acc: Vec<(Coordinate, Path)>,
+                 reachable_paths: Vec<(Coordinate, Path)>|
+                 -> Vec<(Coordinate, Path)> {
+                    acc.extend(reachable_paths);
+                    return acc;
+                },
+            );
+
+        reachable_paths.sort_by(|item_1, item_2| {
+            let (reachable_square_1, path_1) = item_1;
+            let (reachable_square_2, path_2) = item_2;
+
+            let len_1 = path_1.len();
+            let len_2 = path_2.len();
+
+            if len_1 != len_2 {
+                return len_1.cmp(&len_2);
+            }
+
+            return reading_order(reachable_square_1, reachable_square_2);
+        });
+
+        return reachable_paths
+            .first()
+            .map(|(_reachable_square, path)| *path.first().unwrap());
+    }
+
+    // breadth-first search from start, until the nearest squares satisfying is_goal are found.
+    // returns those squares, and their distance from start.
+    fn find_nearest_squares<F>(&self, start: Coordinate, is_goal: F) -> (Vec<Coordinate>, Distance)
+    where
+        F: Fn(Coordinate) -> bool,
+    {
+        let mut distances: HashMap<Coordinate, Distance> = HashMap::new();
+        let mut queue: VecDeque<Coordinate> = VecDeque::new();
+
+        let mut nearest_squares = vec![];
+        let mut nearest_distance = 0;
+
+        distances.insert(start, 0);
+        queue.push_back(start);
+
+        while let Some(current_position) = queue.pop_front() {
+            let current_distance = *distances.get(&current_position).unwrap();
+
+            if !nearest_squares.is_empty() && current_distance > nearest_distance {
+                // squares further away are not needed
+                break;
+            }
+
+            if is_goal(current_position) {
+                nearest_squares.push(current_position);
+                nearest_distance = current_distance;
+                continue;
+            }
+
+            for adjacent_square in self.get_adjacent_open_squares(current_position) {
+                if distances.contains_key(&adjacent_square) {
+                    continue;
+                }
+
+                distances.insert(adjacent_square, current_distance + 1);
+                queue.push_back(adjacent_square);
+            }
+        }
+
+        nearest_squares.sort_by(reading_order);
+
+        return (nearest_squares, nearest_distance);
+    }
+
+    // choose the nearest square in range of a target using a single BFS from the unit,
+    // then choose the first step using a BFS from the chosen square
+    fn get_next_move_single_bfs(
+        &self,
+        position_of_unit: Coordinate,
+        targets: Vec<(Coordinate, Unit)>,
+    ) -> Option<Coordinate> {
+        let squares_in_range: HashSet<Coordinate> = targets
+            .iter()
+            .flat_map(|(position_of_target, _target)| {
+                self.get_adjacent_open_squares(*position_of_target)
+            })
+            .collect();
+
+        if squares_in_range.is_empty() {
+            return None;
+        }
+
+        // in a tie, the square which is first in reading order is chosen
+        let (nearest_squares, _distance) = self.find_nearest_squares(position_of_unit, |square| {
+            squares_in_range.contains(&square)
+        });
+        let chosen_square = *nearest_squares.first()?;
+
+        // in a tie, the step which is first in reading order is chosen
+        let (steps, _distance) = self.find_nearest_squares(chosen_square, |square| {
+            get_manhattan_distance(position_of_unit, square) == 1
+        });
+
+        return steps.first().copied();
+    }
+
+    // the attacking unit deals damage to the chosen target, which is removed from the map if it dies
+    fn resolve_attack(
+        &mut self,
+        unit: &Unit,
+        position_of_target: Coordinate,
+        chosen_target: Unit,
+        round: &mut RoundStats,
+    ) {
+        assert!(self.units.contains_key(&position_of_target));
+
+        let mut chosen_target: Unit = chosen_target;
+
+        let hit_points_before = chosen_target.hit_points;
+
+        unit.attack(&mut chosen_target);
+
+        // overkill damage is not counted
+        let damage_dealt = hit_points_before - std::cmp::max(chosen_target.hit_points, 0);
+        round.get_team_mut(&unit.unit_type).damage_dealt += damage_dealt;
+
+        if chosen_target.is_dead() {
+            self.units.remove(&position_of_target);
+            if chosen_target.is_elf() {
+                self.num_of_elves_died += 1;
+            }
+
+            round.kills.push(Kill {
+                killer_id: unit.id,
+                killer_type: unit.unit_type,
+                victim_id: chosen_target.id,
+                victim_type: chosen_target.unit_type,
+                position: position_of_target,
+            });
+        } else {
+            self.units.insert(position_of_target, chosen_target);
+        }
+    }
+
+    fn get_team_stats(&self, unit_type: &UnitType) -> TeamStats {
+        let team: Vec<&Unit> = self
+            .units
+            .values()
+            .filter(|unit| &unit.unit_type == unit_type)
+            .collect();
+
+        return TeamStats {
+            units_alive: team.len(),
+            total_hit_points: team.iter().map(|unit| unit.hit_points).sum(),
+            damage_dealt: 0,
+        };
+    }
+
+    // statistics of every round executed so far
+    pub fn get_round_stats(&self) -> &Vec<RoundStats> {
+        return &self.round_stats;
+    }
+
+    // returns true if combat has ended (i.e. round didn't run)
+    pub fn execute_round(&mut self) -> RoundState {
+        let mut round = RoundStats::new(self.round_stats.len() + 1);
+
+        let round_state = self.execute_turns(&mut round);
+
+        round.completed = match round_state {
+            RoundState::Complete => true,
+            RoundState::Incomplete => false,
+        };
+
+        // a round where no unit attacked and that didn't complete is not recorded; i.e. combat had already ended
+        if round.completed || round.elves.damage_dealt > 0 || round.goblins.damage_dealt > 0 {
+            let elves = self.get_team_stats(&UnitType::Elf);
+            let goblins = self.get_team_stats(&UnitType::Goblin);
+
+            round.elves = TeamStats {
+                damage_dealt: round.elves.damage_dealt,
+                ..elves
+            };
+            round.goblins = TeamStats {
+                damage_dealt: round.goblins.damage_dealt,
+                ..goblins
+            };
+
+            self.round_stats.push(round);
+        }
+
+        return round_state;
+    }
+
+    fn execute_turns(&mut self, round: &mut RoundStats) -> RoundState {
+        if !self.can_run_round() {
+            return RoundState::Incomplete;
+        }
+
+        // track number of actions performed this round by each unit.
+        // an action is either a movement or an attack
+        let mut num_of_actions_performed = 0;
+
+        let position_of_units: Vec<(Coordinate, Unit)> = {
+            let mut units = vec![];
+            for (position_of_unit, expected_unit) in self.units.clone().into_iter() {
+                units.push((position_of_unit, expected_unit));
+            }
+
+            units.sort_by(|item_1, item_2| {
+                let (pos_1, _) = item_1;
+                let (pos_2, _) = item_2;
+
+                return reading_order(pos_1, pos_2);
+            });
+
+            units
+        };
+
+        for (position_of_unit, expected_unit) in position_of_units {
+            let unit = match self.units.get(&position_of_unit) {
+                None => {
+                    // no unit here; it probably died
+                    continue;
+                }
+                Some(actual_unit) => {
+                    if expected_unit.id != actual_unit.id {
+                        // the unit we were expecting to be here probably died,
+                        // and another unit moved into its place
+                        continue;
+                    }
+
+                    if actual_unit.is_dead() {
+                        continue;
+                    }
+
+                    actual_unit.clone()
+                }
+            };
+
+            assert!(unit.is_alive());
+
+            // Each unit begins its turn by identifying all possible targets (enemy units).
+            let targets = self.get_targets(&unit);
+
+            // If no targets remain, combat ends.
+            if targets.len() == 0 {
+                return RoundState::Incomplete;
+            }
+
+            // If the unit is already in range of a target,
+            // it does not move, but continues its turn with an attack.
+            if let Some((position_of_target, chosen_target)) =
+                self.get_attackable_target(&position_of_unit, &unit)
+            {
+                num_of_actions_performed += 1;
+
+                self.resolve_attack(&unit, position_of_target, chosen_target, round);
+
+                continue;
+            }
+
+            // Otherwise, since it is not in range of a target, it moves.
+
+            if let Some(next_move) = self.get_next_move(position_of_unit, targets) {
+                self.units.remove(&position_of_unit);
+                self.units.insert(next_move, unit.clone());
+
+                num_of_actions_performed += 1;
+
+                // After moving (or if the unit began its turn in range of a target), the unit attacks.
+                if let Some((position_of_target, chosen_target)) =
+                    self.get_attackable_target(&next_move, &unit)
+                {
+                    num_of_actions_performed += 1;
+
+                    self.resolve_attack(&unit, position_of_target, chosen_target, round);
+                }
+            }
+        }
+
+        // combat has ended if no action was performed
+        if num_of_actions_performed <= 0 {
+            return RoundState::Incomplete;
+        }
+
+        return RoundState::Complete;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UnitType {
+    Goblin,
+    Elf,
+}
+
+#[derive(Debug, Clone)]
+struct Unit {
+    id: usize,
+    unit_type: UnitType,
+    hit_points: i32,
+    attack_power: i32,
+}
+
+impl Unit {
+    fn new(unit_type: UnitType, id: usize, config: &CombatConfig) -> Unit {
+        let attack_power = match unit_type {
+            UnitType::Goblin => config.attack_power,
+            UnitType::Elf => config.elf_attack_power,
+        };
+
+        Unit {
+            id,
+            unit_type,
+            hit_points: config.hit_points,
+            attack_power,
+        }
+    }
+
+    fn attack(&self, other_unit: &mut Unit) {
+        other_unit.hit_points = other_unit.hit_points - self.attack_power;
+    }
+
+    fn new_elf(id: usize, config: &CombatConfig) -> Unit {
+        Unit::new(UnitType::Elf, id, config)
+    }
+
+    fn new_goblin(id: usize, config: &CombatConfig) -> Unit {
+        Unit::new(UnitType::Goblin, id, config)
+    }
+
+    fn to_string(&self) -> String {
+        match self.unit_type {
+            UnitType::Goblin => "G".to_string(),
+            UnitType::Elf => "E".to_string(),
+        }
+    }
+
+    fn to_health_string(&self) -> String {
+        return format!("{}({})", self.to_string(), self.hit_points);
+    }
+
+    fn is_alive(&self) -> bool {
+        return self.hit_points > 0;
+    }
+
+    fn is_dead(&self) -> bool {
+        return self.hit_points <= 0;
+    }
+
+    fn is_elf(&self) -> bool {
+        match self.unit_type {
+            UnitType::Elf => true,
+            _ => false,
+        }
+    }
+
+    fn is_goblin(&self) -> bool {
+        match self.unit_type {
+            UnitType::Goblin => true,
+            _ => false,
+        }
+    }
+}
+
+// checks if there is an open path between start and end
+// an open path means a set of coordinates which are not either a wall or occupied by a unit
+// if a path exists, then the vector containing the coordinates from start to end is returned
+fn get_reachable_path(map: &Map, start: Coordinate, end: Coordinate) -> Option<Vec<Coordinate>> {
+    if start == end {
+        return Some(vec![]);
+    }
+
+    // invariant: manhattan distance between start and end is at least 1
+
+    if map.is_wall(start) || map.is_wall(end) {
+        return None;
+    }
+
+    if map.is_occupied(end) {
+        return None;
+    }
+
+    // NOTE: start could be occupied
+
+    let mut available_squares: BinaryHeap<DistanceCoordinate> = BinaryHeap::new();
+    // keep track of the best minimum distances for a coordinate
+    let mut distances: HashMap<Coordinate, Distance> = HashMap::new();
+    let mut best_edges: HashMap<Coordinate, Coordinate> = HashMap::new();
+
+    // backtrack from end towards start
+    available_squares.push(DistanceCoordinate(0, end));
+    distances.insert(end, 0);
+
+    while let Some(current_square) = available_squares.pop() {
+        // invariant: current_square has the lowest cost
+        // in case of a tie, positions are sorted according to the reading order
+
+        let DistanceCoordinate(current_distance, current_position) = current_square;
+
+        if get_manhattan_distance(start, current_position) <= 1 {
+            let mut path = vec![current_position];
+            let mut current = current_position;
+            while current != end {
+                let nearest_edge = best_edges.get(&current).unwrap();
+                path.push(*nearest_edge);
+                current = *nearest_edge;
+            }
+
+            return Some(path);
+        }
+
+        match distances.get(&current_position) {
+            None => {
+                unreachable!();
+            }
+            Some(best_distance) => {
+                if current_distance > *best_distance {
+                    continue;
+                }
+            }
+        }
+
+        for adjacent_square in map.get_adjacent_open_squares(current_position) {
+            let adjacent_distance = current_distance + 1;
+
+            match distances.get(&adjacent_square) {
+                None => {
+                    best_edges.insert(adjacent_square, current_position);
+                    distances.insert(adjacent_square, adjacent_distance);
+                    available_squares.push(DistanceCoordinate(adjacent_distance, adjacent_square));
+                }
+                Some(best_distance) => {
+                    // NOTE: this potentially adds duplicates to the available_squares min-heap;
+                    // but that's fine :P
+                    // see: https://www3.cs.stonybrook.edu/~rezaul/papers/TR-07-54.pdf
+
+                    if adjacent_distance < *best_distance {
+                        distances.insert(adjacent_square, adjacent_distance);
+                        available_squares
+                            .push(DistanceCoordinate(adjacent_distance, adjacent_square));
+                        best_edges.insert(adjacent_square, current_position);
+                    }
+                }
+            }
+        }
+    }
+
+    return None;
+}
+
+// same as get_reachable_path, but the search is guided towards start using the manhattan distance.
+fn get_reachable_path_a_star(map: &Map, start: Coordinate, end: Coordinate) -> Option<Path> {
+    if start == end {
+        return Some(vec![]);
+    }
+
+    if map.is_wall(start) || map.is_wall(end) {
+        return None;
+    }
+
+    if map.is_occupied(end) {
+        return None;
+    }
+
+    // the search ends on any square adjacent to start; so this heuristic never overestimates
+    let heuristic = |position: Coordinate| -> Distance {
+        return std::cmp::max(get_manhattan_distance(start, position) - 1, 0);
+    };
+
+    // squares are prioritized by their estimated total distance
+    let mut available_squares: BinaryHeap<DistanceCoordinate> = BinaryHeap::new();
+    let mut distances: HashMap<Coordinate, Distance> = HashMap::new();
+    let mut best_edges: HashMap<Coordinate, Coordinate> = HashMap::new();
+
+    available_squares.push(DistanceCoordinate(heuristic(end), end));
+    distances.insert(end, 0);
+
+    let mut best_square: Option<(Distance, Coordinate)> = None;
+
+    while let Some(current_square) = available_squares.pop() {
+        let DistanceCoordinate(estimated_distance, current_position) = current_square;
+
+        // all squares adjacent to start with the shortest distance have been found.
+        if let Some((best_distance, _)) = best_square {
+            if estimated_distance > best_distance {
+                break;
+            }
+        }
+
+        let current_distance = *distances.get(&current_position).unwrap();
+
+        if estimated_distance > current_distance + heuristic(current_position) {
+            // stale entry
+            continue;
+        }
+
+        if get_manhattan_distance(start, current_position) <= 1 {
+            // unlike Dijkstra, squares with the same distance are not guaranteed to be visited in reading order;
+            // so keep looking for squares with the same distance.
+            best_square = match best_square {
+                Some((best_distance, best_position))
+                    if best_distance < current_distance
+                        || (best_distance == current_distance
+                            && reading_order(&best_position, &current_position)
+                                == Ordering::Less) =>
+                {
+                    Some((best_distance, best_position))
+                }
+                _ => Some((current_distance, current_position)),
+            };
+            continue;
+        }
+
+        for adjacent_square in map.get_adjacent_open_squares(current_position) {
+            let adjacent_distance = current_distance + 1;
+
+            let is_better = match distances.get(&adjacent_square) {
+                None => true,
+                Some(best_distance) => adjacent_distance < *best_distance,
+            };
+
+            if is_better {
+                distances.insert(adjacent_square, adjacent_distance);
+                best_edges.insert(adjacent_square, current_position);
+                available_squares.push(DistanceCoordinate(
+                    adjacent_distance + heuristic(adjacent_square),
+                    adjacent_square,
+                ));
+            }
+        }
+    }
+
+    return best_square.map(|(_best_distance, best_position)| {
+        let mut path = vec![best_position];
+        let mut current = best_position;
+        while current != end {
+            let nearest_edge = best_edges.get(&current).unwrap();
+            path.push(*nearest_edge);
+            current = *nearest_edge;
+        }
+        path
+    });
+}
+
+// splitmix64; a small deterministic pseudo-random number generator for generating arenas
+struct Random(u64);
+
+impl Random {
+    fn new(seed: u64) -> Random {
+        Random(seed)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        return z ^ (z >> 31);
+    }
+
+    // uniformly distributed within [0, 1)
+    fn next_f64(&mut self) -> f64 {
+        return (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+    }
+
+    // uniformly distributed within [0, upper_bound)
+    fn next_below(&mut self, upper_bound: usize) -> usize {
+        return (self.next_u64() % (upper_bound as u64)) as usize;
+    }
+}
+
+// find the largest set of connected open cells
+fn get_largest_cavern(cells: &[Vec<char>]) -> Vec<(usize, usize)> {
+    let height = cells.len();
+    let width = cells[0].len();
+
+    let mut visited = vec![vec![false; width]; height];
+    let mut largest_cavern = vec![];
+
+    for y in 0..height {
+        for x in 0..width {
+            if visited[y][x] || cells[y][x] != '.' {
+                continue;
+            }
+
+            let mut cavern = vec![];
+            let mut queue = VecDeque::new();
+
+            visited[y][x] = true;
+            queue.push_back((x, y));
+
+            while let Some((x, y)) = queue.pop_front() {
+                cavern.push((x, y));
+
+                // walls surround the arena; so adjacent cells are always within bounds
+                let adjacent = vec![(x, y - 1), (x - 1, y), (x + 1, y), (x, y + 1)];
+
+                for (x, y) in adjacent {
+                    if visited[y][x] || cells[y][x] != '.' {
+                        continue;
+                    }
+                    visited[y][x] = true;
+                    queue.push_back((x, y));
+                }
+            }
+
+            if cavern.len() > largest_cavern.len() {
+                largest_cavern = cavern;
+            }
+        }
+    }
+
+    return largest_cavern;
+}
+
+// generate a random arena surrounded by walls.
+// only the largest cavern is kept open, so that every unit is able to reach any other unit.
+pub fn generate_arena(
+    width: usize,
+    height: usize,
+    wall_density: f64,
+    elves: usize,
+    goblins: usize,
+    seed: u64,
+) -> String {
+    assert!(width >= 3 && height >= 3, "Arena is too small");
+
+    let mut random = Random::new(seed);
+
+    let mut cells = vec![vec!['#'; width]; height];
+
+    for row in cells.iter_mut().take(height - 1).skip(1) {
+        for cell in row.iter_mut().take(width - 1).skip(1) {
+            if random.next_f64() >= wall_density {
+                *cell = '.';
+            }
+        }
+    }
+
+    let mut cavern = get_largest_cavern(&cells);
+
+    for row in cells.iter_mut() {
+        for cell in row.iter_mut() {
+            *cell = '#';
+        }
+    }
+
+    for (x, y) in cavern.iter() {
+        cells[*y][*x] = '.';
+    }
+
+    assert!(
+        cavern.len() >= elves + goblins,
+        "Not enough open squares for {} units: {}",
+        elves + goblins,
+        cavern.len()
+    );
+
+    // partial Fisher-Yates shuffle to choose the positions of the units
+    for index in 0..(elves + goblins) {
+        let chosen = index + random.next_below(cavern.len() - index);
+        cavern.swap(index, chosen);
+
+        let (x, y) = cavern[index];
+        cells[y][x] = if index < elves { 'E' } else { 'G' };
+    }
+
+    return cells
+        .into_iter()
+        .map(|row| row.into_iter().collect::<String>())
+        .collect::<Vec<String>>()
+        .join("\n");
+}
+
+// combat begins in a series of rounds
+// in each round, a unit takes a turn, resolving all of its actions before completing their turn
+// invariant: units cannot attack nor move diagonally
+
+// invariant: Units cannot move into walls or other units.
+
+// Rules for units
+// On each unit's turn, it tries to move into range of an enemy (if it isn't already) and then attack (if it is in range).
+// If the unit is already in range of a target, it does not move, but continues its turn with an attack.
+// Otherwise, since it is not in range of a target, it moves.
+
+pub fn parse_input(input_string: &str) -> Map {
+    return parse_input_with_config(input_string, CombatConfig::default());
+}
+
+pub fn parse_input_with_config(input_string: &str, config: CombatConfig) -> Map {
+    let mut map = Map::new(config);
+
+    for (y, line) in input_string.trim().lines().enumerate() {
+        for (x, map_state_as_char) in line.chars().enumerate() {
+            let position: Coordinate = (x as i32, y as i32);
+
+            map.insert(position, map_state_as_char);
+        }
+    }
+
+    return map;
+}
+
+// run the combat until it ends
+fn run_combat(map: &mut Map) -> CombatSummary {
+    let mut num_of_rounds_completed = 0;
+    loop {
+        let round_state = map.execute_round();
+        match round_state {
+            RoundState::Complete => {
+                num_of_rounds_completed += 1;
+                trace!(
+                    round = num_of_rounds_completed,
+                    "round completed\n{}",
+                    map.to_string()
+                );
+            }
+            RoundState::Incomplete => {
+                break;
+            }
+        }
+    }
+
+    let sum_hit_points: i32 = map
+        .units
+        .iter()
+        .map(|(_key, unit)| unit)
+        .fold(0, |acc, unit| {
+            return acc + unit.hit_points;
+        });
+
+    let winner = map.units.values().next().map(|unit| unit.unit_type);
+
+    return CombatSummary {
+        num_of_rounds_completed,
+        sum_hit_points,
+        outcome: num_of_rounds_completed * sum_hit_points,
+        winner,
+        num_of_elves_died: map.num_of_elves_died,
+        rounds: map.round_stats.clone(),
+    };
+}
+
+// run the combat and collect statistics of every round
+pub fn simulate(input_string: &str, config: &CombatConfig) -> CombatSummary {
+    let mut map = parse_input_with_config(input_string, config.clone());
+    return run_combat(&mut map);
+}
+
+fn process_map(mut map: Map) -> i32 {
+    let CombatSummary {
+        num_of_rounds_completed,
+        sum_hit_points,
+        ..
+    } = run_combat(&mut map);
+
+    debug!(
+        num_of_rounds_completed,
+        sum_hit_points,
+        "combat ended\n{}",
+        map.to_string()
+    );
+
+    let result = num_of_rounds_completed * sum_hit_points;
+
+    return result;
+}
+
+pub fn part_1(input_string: &str, config: &CombatConfig) -> i32 {
+    return process_map(parse_input_with_config(input_string, config.clone()));
+}
+
+pub fn part_2(input_string: &str, config: &CombatConfig) -> i32 {
+    let original_map = parse_input_with_config(input_string, config.clone());
+
+    'attack_power_loop: for elf_attack_power in config.elf_attack_power_candidates() {
+        let mut map = original_map.clone();
+
+        map.with_elf_attack_power(elf_attack_power);
+
+        let mut num_of_rounds_completed = 0;
+        loop {
+            let round_state = map.execute_round();
+
+            if map.num_of_elves_died > 0 {
+                debug!(
+                    elf_attack_power,
+                    num_of_rounds_completed, "an elf died, trying more attack power"
+                );
+                continue 'attack_power_loop;
+            }
+
+            match round_state {
+                RoundState::Complete => {
+                    num_of_rounds_completed += 1;
+                }
+                RoundState::Incomplete => {
+                    break;
+                }
+            }
+        }
+
+        let sum_hit_points: i32 = map
+            .units
+            .iter()
+            .map(|(_key, unit)| unit)
+            .fold(0, |acc, unit| {
+                return acc + unit.hit_points;
+            });
+
+        debug!(
+            elf_attack_power,
+            num_of_rounds_completed,
+            sum_hit_points,
+            "every elf survived\n{}",
+            map.to_string()
+        );
+
+        let result = num_of_rounds_completed * sum_hit_points;
+
+        return result;
+    }
+
+    return 0;
+}
+
+pub const SOLVER: Solver = Solver {
+    year: 2018,
+    day: 15,
+    input: include_str!("input.txt"),
+    part_1: |input_string| Ok(part_1(input_string, &CombatConfig::default()).to_string()),
+    part_2: Some(|input_string| Ok(part_2(input_string, &CombatConfig::default()).to_string())),
+    examples: &[
+        Example {
+            input: include_str!("../examples/1.txt"),
+            part_1: Some("27730"),
+            part_2: Some("4988"),
+        },
+        Example {
+            input: include_str!("../examples/2.txt"),
+            part_1: Some("36334"),
+            part_2: None,
+        },
+        Example {
+            input: include_str!("../examples/3.txt"),
+            part_1: Some("39514"),
+            part_2: Some("31284"),
+        },
+        Example {
+            input: include_str!("../examples/4.txt"),
+            part_1: Some("27755"),
+            part_2: Some("3478"),
+        },
+        Example {
+            input: include_str!("../examples/5.txt"),
+            part_1: Some("28944"),
+            part_2: Some("6474"),
+        },
+        Example {
+            input: include_str!("../examples/6.txt"),
+            part_1: Some("18740"),
+            part_2: Some("1140"),
+        },
+    ],
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coord_reading_order() {
+        let test: Vec<Coordinate> = vec![(2, 27), (3, 26), (2, 26), (1, 26), (2, 25)];
+        let expected = {
+            let mut x = test.clone();
+            x.reverse();
+            x
+        };
+
+        {
+            let mut test = test.clone();
+            test.sort_by(reading_order);
+
+            assert_eq!(test, expected);
+        }
+
+        {
+            let mut available_squares: BinaryHeap<DistanceCoordinate> = BinaryHeap::new();
+
+            let items = vec![
+                DistanceCoordinate(5, (1, 26)),
+                DistanceCoordinate(5, (2, 25)),
+                DistanceCoordinate(4, (2, 30)),
+            ];
+            available_squares.extend(items);
+
+            let mut actual: Vec<Coordinate> = vec![];
+            while let Some(item) = available_squares.pop() {
+                actual.push(item.into());
+            }
+
+            assert_eq!(actual, vec![(2, 30), (2, 25), (1, 26)]);
+        }
+    }
+
+    #[test]
+    fn test_combat_config() {
+        let config = CombatConfig::default();
+
+        assert_eq!(
+            config.elf_attack_power_candidates(),
+            vec![
+                4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 19, 20, 23, 25, 29, 34, 40, 50,
+                67, 100, 200
+            ]
+        );
+
+        let config = CombatConfig {
+            hit_points: 10,
+            attack_power: 3,
+            elf_attack_power: 5,
+            ..CombatConfig::default()
+        };
+
+        assert_eq!(config.elf_attack_power_candidates(), vec![10]);
+    }
+
+    #[test]
+    fn test_custom_combat_config() {
+        let input_string = include_str!("../examples/1.txt").trim();
+
+        let config = CombatConfig {
+            elf_attack_power: 15,
+            ..CombatConfig::default()
+        };
+
+        let map = parse_input_with_config(input_string, config.clone());
+
+        for (_position, unit) in map.units.iter() {
+            assert_eq!(unit.hit_points, 200);
+            if unit.is_elf() {
+                assert_eq!(unit.attack_power, 15);
+            } else {
+                assert_eq!(unit.attack_power, 3);
+            }
+        }
+
+        assert_eq!(part_1(input_string, &config), 4988);
+    }
+
+    #[test]
+    fn test_pathfinding_strategies() {
+        let input_strings = vec![
+            include_str!("../examples/1.txt"),
+            r###"
+#########
+#G......#
+#.E.#...#
+#..##..G#
+#...##..#
+#...#...#
+#.G...G.#
+#.....G.#
+#########
+            "###,
+            r###"
+#######
+#.E..G#
+#.#####
+#G#####
+#######
+            "###,
+        ];
+
+        for input_string in input_strings {
+            let input_string = input_string.trim();
+
+            let mut expected = parse_input(input_string);
+
+            let strategies = vec![PathfindingStrategy::SingleBfs, PathfindingStrategy::AStar];
+
+            let mut maps: Vec<Map> = strategies
+                .into_iter()
+                .map(|strategy| {
+                    let config = CombatConfig {
+                        strategy,
+                        ..CombatConfig::default()
+                    };
+                    parse_input_with_config(input_string, config)
+                })
+                .collect();
+
+            loop {
+                let round_state = expected.execute_round();
+
+                for map in maps.iter_mut() {
+                    map.execute_round();
+                    assert_eq!(
+                        map.to_string_with_health(),
+                        expected.to_string_with_health()
+                    );
+                }
+
+                if let RoundState::Incomplete = round_state {
+                    break;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_simulate() {
+        let input_string = include_str!("../examples/1.txt").trim();
+
+        let summary = simulate(input_string, &CombatConfig::default());
+
+        assert_eq!(summary.num_of_rounds_completed, 47);
+        assert_eq!(summary.sum_hit_points, 590);
+        assert_eq!(summary.outcome, 27730);
+        assert_eq!(summary.winner, Some(UnitType::Goblin));
+        assert_eq!(summary.num_of_elves_died, 2);
+
+        assert_eq!(summary.rounds.len(), 47);
+        assert!(summary.rounds.iter().all(|round| round.completed));
+
+        let first_round = summary.rounds.first().unwrap();
+        assert_eq!(first_round.round, 1);
+        assert_eq!(first_round.elves.units_alive, 2);
+        assert_eq!(first_round.goblins.units_alive, 4);
+
+        let last_round = summary.rounds.last().unwrap();
+        assert_eq!(last_round.round, 47);
+        assert_eq!(last_round.elves.units_alive, 0);
+        assert_eq!(last_round.elves.total_hit_points, 0);
+        assert_eq!(last_round.goblins.units_alive, 4);
+        assert_eq!(last_round.goblins.total_hit_points, 590);
+
+        let kills: Vec<&Kill> = summary
+            .rounds
+            .iter()
+            .flat_map(|round| round.kills.iter())
+            .collect();
+        assert_eq!(kills.len(), 2);
+        assert!(kills
+            .iter()
+            .all(|kill| kill.victim_type == UnitType::Elf && kill.killer_type == UnitType::Goblin));
+
+        // every elf lost all of their hit points
+        let damage_dealt_by_goblins: i32 = summary
+            .rounds
+            .iter()
+            .map(|round| round.goblins.damage_dealt)
+            .sum();
+        assert_eq!(damage_dealt_by_goblins, 2 * 200);
+
+        let damage_dealt_by_elves: i32 = summary
+            .rounds
+            .iter()
+            .map(|round| round.elves.damage_dealt)
+            .sum();
+        assert_eq!(damage_dealt_by_elves, 4 * 200 - 590);
+    }
+
+    #[test]
+    fn test_generate_arena() {
+        let arena = generate_arena(20, 10, 0.3, 3, 4, 42);
+
+        assert_eq!(arena, generate_arena(20, 10, 0.3, 3, 4, 42));
+        assert_ne!(arena, generate_arena(20, 10, 0.3, 3, 4, 43));
+
+        let rows: Vec<&str> = arena.lines().collect();
+        assert_eq!(rows.len(), 10);
+        assert!(rows.iter().all(|row| row.len() == 20));
+        assert!(rows[0].chars().all(|cell| cell == '#'));
+        assert!(rows[9].chars().all(|cell| cell == '#'));
+        assert!(rows
+            .iter()
+            .all(|row| row.starts_with('#') && row.ends_with('#')));
+
+        assert_eq!(arena.matches('E').count(), 3);
+        assert_eq!(arena.matches('G').count(), 4);
+
+        let map = parse_input(&arena);
+        assert_eq!(map.to_string(), arena);
+
+        // every open square is connected to each other
+        let cells: Vec<Vec<char>> = arena
+            .replace('E', ".")
+            .replace('G', ".")
+            .lines()
+            .map(|row| row.chars().collect())
+            .collect();
+        let num_of_open_squares = cells
+            .iter()
+            .flat_map(|row| row.iter())
+            .filter(|cell| **cell == '.')
+            .count();
+        assert_eq!(get_largest_cavern(&cells).len(), num_of_open_squares);
+    }
+
+    #[test]
+    fn test_stress() {
+        // large enough for units to crowd each other and die off over more than a hundred rounds,
+        // while staying quick to run in a debug build; see benches/pathfinding.rs for a larger arena
+        let arena = generate_arena(32, 32, 0.25, 12, 12, 2018);
+
+        for strategy in [PathfindingStrategy::SingleBfs, PathfindingStrategy::AStar].iter() {
+            let config = CombatConfig {
+                strategy: *strategy,
+                ..CombatConfig::default()
+            };
+
+            let mut map = parse_input_with_config(&arena, config);
+
+            let mut num_of_units = map.units.len();
+            assert_eq!(num_of_units, 24);
+
+            let mut num_of_rounds_completed = 0;
+
+            // run the combat to the end
+            while let RoundState::Complete = map.execute_round() {
+                num_of_rounds_completed += 1;
+
+                // units never appear out of nowhere
+                assert!(map.units.len() <= num_of_units);
+                num_of_units = map.units.len();
+
+                for (position, unit) in map.units.iter() {
+                    assert!(!map.is_wall(*position));
+                    assert!(unit.is_alive());
+                }
+            }
+
+            // only one team is left standing
+            let winner = map.units.values().next().unwrap().unit_type;
+            assert!(map.units.values().all(|unit| unit.unit_type == winner));
+
+            let sum_hit_points: i32 = map.units.values().map(|unit| unit.hit_points).sum();
+            assert_eq!(num_of_rounds_completed, 145);
+            assert_eq!(num_of_rounds_completed * sum_hit_points, 34510);
+        }
+    }
+
+    #[test]
+    fn test_map() {
+        let input_string = r###"
+#########
+#G..G..G#
+#.......#
+#.......#
+#G..E..G#
+#.......#
+#.......#
+#G..G..G#
+#########
+        "###
+        .trim();
+
+        let map = parse_input(input_string);
+
+        assert_eq!(map.to_string(), input_string);
+
+        assert_eq!(map.is_wall((0, 0)), true);
+        assert_eq!(map.is_occupied((0, 0)), true);
+
+        assert_eq!(map.is_wall((1, 1)), false);
+        assert_eq!(map.is_occupied((1, 1)), true);
+
+        assert_eq!(map.is_wall((2, 1)), false);
+        assert_eq!(map.is_occupied((2, 1)), false);
+
+        assert_eq!(map.get_elves().len(), 1);
+        assert_eq!(map.has_elves(), true);
+        assert_eq!(map.get_goblins().len(), 8);
+        assert_eq!(map.has_goblins(), true);
+    }
+
+    #[test]
+    fn test_is_reachable() {
+        let input_string = r###"
+###################
+#.E...............#
+#################.#
+#...G.............#
+###################
+        "###
+        .trim();
+
+        let map = parse_input(input_string);
+
+        assert_eq!(
+            get_reachable_path(
+                &map,
+                (2, 1), /* position of the elf */
+                (5, 3)  /* position of square adjacent to goblin */
+            )
+            .map(|path| path.len()),
+            Some(29)
+        );
+    }
+
+    #[test]
+    fn test_scenario() {
+        let input_string = r###"
+#########
+#G..G..G#
+#.......#
+#.......#
+#G..E..G#
+#.......#
+#.......#
+#G..G..G#
+#########
+        "###
+        .trim();
+
+        let mut map = parse_input(input_string);
+
+        // round 1
+        map.execute_round();
+        assert_eq!(
+            map.to_string(),
+            r###"
+#########
+#.G...G.#
+#...G...#
+#...E..G#
+#.G.....#
+#.......#
+#G..G..G#
+#.......#
+#########
+        "###
+            .trim()
+        );
+
+        // round 2
+        map.execute_round();
+        assert_eq!(
+            map.to_string(),
+            r###"
+#########
+#..G.G..#
+#...G...#
+#.G.E.G.#
+#.......#
+#G..G..G#
+#.......#
+#.......#
+#########
+        "###
+            .trim()
+        );
+
+        // round 3
+        map.execute_round();
+        assert_eq!(
+            map.to_string(),
+            r###"
+#########
+#.......#
+#..GGG..#
+#..GEG..#
+#G..G...#
+#......G#
+#.......#
+#.......#
+#########
+        "###
+            .trim()
+        );
+    }
+
+    #[test]
+    fn test_part_1() {
+        let input_string = include_str!("../examples/1.txt").trim();
+
+        assert_eq!(part_1(input_string, &CombatConfig::default()), 27730);
+
+        let input_string = include_str!("../examples/2.txt").trim();
+
+        assert_eq!(part_1(input_string, &CombatConfig::default()), 36334);
+
+        let input_string = include_str!("../examples/3.txt").trim();
+
+        assert_eq!(part_1(input_string, &CombatConfig::default()), 39514);
+
+        let input_string = include_str!("../examples/4.txt").trim();
+
+        assert_eq!(part_1(input_string, &CombatConfig::default()), 27755);
+
+        let input_string = include_str!("../examples/5.txt").trim();
+
+        assert_eq!(part_1(input_string, &CombatConfig::default()), 28944);
+
+        let input_string = include_str!("../examples/6.txt").trim();
+
+        assert_eq!(part_1(input_string, &CombatConfig::default()), 18740);
+
+        // from: https://www.reddit.com/r/adventofcode/comments/a6f100/day_15_details_easy_to_be_wrong_on/
+
+        let input_string = r###"
+####
+##E#
+#GG#
+####
+        "###
+        .trim();
+
+        assert_eq!(part_1(input_string, &CombatConfig::default()), 67 * 200);
+
+        let input_string = r###"
+#####
+#GG##
+#.###
+#..E#
+#.#G#
+#.E##
+#####
+        "###
+        .trim();
+
+        assert_eq!(part_1(input_string, &CombatConfig::default()), 71 * 197);
+    }
+
+    #[test]
+    fn test_edge_case_1() {
+        let input_string = r###"
+#######
+#.E..G#
+#.#####
+#G#####
+#######
+        "###
+        .trim();
+
+        let mut map = parse_input(input_string);
+
+        // round 1
+        // after round 1, the elf should not be going left
+        // src: https://www.reddit.com/r/adventofcode/comments/a6f100/day_15_details_easy_to_be_wrong_on/ebvkuxr/
+        map.execute_round();
+        assert_eq!(
+            map.to_string(),
+            r###"
+#######
+#..EG.#
+#G#####
+#.#####
+#######
+        "###
+            .trim()
+        );
+    }
+
+    #[test]
+    fn test_part_2_with_elf_attack() {
+        fn part_2_with_elf_attack(input_string: &str, elf_attack_power: i32) -> i32 {
+            let mut map = parse_input(input_string);
+            map.with_elf_attack_power(elf_attack_power);
+            return process_map(map);
+        }
+
+        let input_string = include_str!("../examples/1.txt").trim();
+
+        assert_eq!(part_2_with_elf_attack(input_string, 15), 4988);
+
+        let input_string = include_str!("../examples/3.txt").trim();
+
+        assert_eq!(part_2_with_elf_attack(input_string, 4), 31284);
+
+        let input_string = include_str!("../examples/4.txt").trim();
+
+        assert_eq!(part_2_with_elf_attack(input_string, 15), 3478);
+
+        let input_string = include_str!("../examples/5.txt").trim();
+
+        assert_eq!(part_2_with_elf_attack(input_string, 12), 6474);
+
+        let input_string = include_str!("../examples/6.txt").trim();
+
+        assert_eq!(part_2_with_elf_attack(input_string, 34), 1140);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input_string = include_str!("../examples/1.txt").trim();
+
+        assert_eq!(part_2(input_string, &CombatConfig::default()), 4988);
+
+        let input_string = include_str!("../examples/3.txt").trim();
+
+        assert_eq!(part_2(input_string, &CombatConfig::default()), 31284);
+
+        let input_string = include_str!("../examples/4.txt").trim();
+
+        assert_eq!(part_2(input_string, &CombatConfig::default()), 3478);
+
+        let input_string = include_str!("../examples/5.txt").trim();
+
+        assert_eq!(part_2(input_string, &CombatConfig::default()), 6474);
+
+        let input_string = include_str!("../examples/6.txt").trim();
+
+        assert_eq!(part_2(input_string, &CombatConfig::default()), 1140);
+    }
+}
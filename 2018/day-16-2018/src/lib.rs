@@ -0,0 +1,323 @@
+// https://adventofcode.com/2018/day/16
+
+// imports
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use elfcode::{Instruction, Opcode, Value, ALL_OPCODES};
+use solver::{Example, Solver};
+
+// types
+
+type Registers = Vec<Value>;
+
+// an instruction as written down for the wrist device: opcode number, input A, input B, output register C
+type RawInstruction = [Value; 4];
+
+type OpcodeMap = HashMap<Value, Opcode>;
+
+// the effect of an instruction on the registers
+#[derive(Debug, PartialEq)]
+struct Sample {
+    before: Registers,
+    instruction: RawInstruction,
+    after: Registers,
+}
+
+// helpers
+
+// [3, 2, 1, 1]
+fn parse_registers(input: &str) -> Result<Registers, String> {
+    let invalid = || format!("Invalid registers: {}", input);
+
+    let registers = input
+        .trim()
+        .strip_prefix('[')
+        .and_then(|x| x.strip_suffix(']'))
+        .ok_or_else(invalid)?
+        .split(',')
+        .map(|x| x.trim().parse().map_err(|_| invalid()))
+        .collect::<Result<Registers, String>>()?;
+
+    if registers.len() != 4 {
+        return Err(invalid());
+    }
+
+    return Ok(registers);
+}
+
+// 9 2 1 2
+fn parse_raw_instruction(input: &str) -> Result<RawInstruction, String> {
+    let invalid = || format!("Invalid instruction: {}", input);
+
+    let numbers = input
+        .split_whitespace()
+        .map(|x| x.parse().map_err(|_| invalid()))
+        .collect::<Result<Vec<Value>, String>>()?;
+
+    match numbers.as_slice() {
+        [opcode_number, a, b, c] => {
+            return Ok([*opcode_number, *a, *b, *c]);
+        }
+        _ => {
+            return Err(invalid());
+        }
+    }
+}
+
+// the samples, followed by the test program
+fn parse_input(input_string: &str) -> Result<(Vec<Sample>, Vec<RawInstruction>), String> {
+    let mut samples = vec![];
+    let mut program = vec![];
+
+    let mut lines = input_string.trim().lines().map(|line| line.trim());
+
+    while let Some(line) = lines.next() {
+        if line.is_empty() {
+            continue;
+        }
+
+        if let Some(before) = line.strip_prefix("Before:") {
+            let before = parse_registers(before)?;
+
+            let instruction = lines
+                .next()
+                .ok_or_else(|| "Missing instruction of a sample".to_string())
+                .and_then(parse_raw_instruction)?;
+
+            let after = lines
+                .next()
+                .and_then(|line| line.strip_prefix("After:"))
+                .ok_or_else(|| "Missing registers after a sample".to_string())
+                .and_then(parse_registers)?;
+
+            samples.push(Sample {
+                before,
+                instruction,
+                after,
+            });
+            continue;
+        }
+
+        program.push(parse_raw_instruction(line)?);
+    }
+
+    return Ok((samples, program));
+}
+
+fn to_instruction(opcode: Opcode, instruction: &RawInstruction) -> Instruction {
+    let [_opcode_number, a, b, c] = *instruction;
+    return Instruction::new(opcode, a, b, c);
+}
+
+// the opcodes that behave like the sample
+fn matching_opcodes(sample: &Sample) -> HashSet<Opcode> {
+    return ALL_OPCODES
+        .iter()
+        .filter(|opcode| {
+            let mut registers = sample.before.clone();
+
+            return to_instruction(**opcode, &sample.instruction)
+                .execute(&mut registers)
+                .is_ok()
+                && registers == sample.after;
+        })
+        .cloned()
+        .collect();
+}
+
+// the opcode of every opcode number.
+//
+// every sample narrows down the opcodes an opcode number could be. whenever an opcode number is down to one opcode,
+// that opcode is ruled out for every other opcode number, until every opcode number is down to one opcode.
+fn resolve_opcodes(samples: &[Sample]) -> Result<OpcodeMap, String> {
+    let mut candidates: HashMap<Value, HashSet<Opcode>> = HashMap::new();
+
+    for sample in samples {
+        let matched_opcodes = matching_opcodes(sample);
+
+        let opcode_number = sample.instruction[0];
+        let opcodes = candidates
+            .entry(opcode_number)
+            .or_insert_with(|| ALL_OPCODES.iter().cloned().collect());
+
+        opcodes.retain(|opcode| matched_opcodes.contains(opcode));
+    }
+
+    let mut opcode_map: OpcodeMap = HashMap::new();
+
+    while !candidates.is_empty() {
+        let (opcode_number, opcode) = candidates
+            .iter()
+            .find(|(_opcode_number, opcodes)| opcodes.len() == 1)
+            .map(|(opcode_number, opcodes)| (*opcode_number, *opcodes.iter().next().unwrap()))
+            .ok_or_else(|| {
+                let mut unresolved: Vec<Value> = candidates.keys().cloned().collect();
+                unresolved.sort_unstable();
+                return format!("Unable to tell apart the opcodes of {:?}", unresolved);
+            })?;
+
+        candidates.remove(&opcode_number);
+
+        for opcodes in candidates.values_mut() {
+            opcodes.remove(&opcode);
+        }
+
+        opcode_map.insert(opcode_number, opcode);
+    }
+
+    return Ok(opcode_map);
+}
+
+// the registers after running the program, starting with every register at 0
+fn run_program(opcode_map: &OpcodeMap, program: &[RawInstruction]) -> Result<Registers, String> {
+    let mut registers: Registers = vec![0; 4];
+
+    for instruction in program {
+        let opcode = opcode_map
+            .get(&instruction[0])
+            .ok_or_else(|| format!("Unknown opcode number: {}", instruction[0]))?;
+
+        to_instruction(*opcode, instruction).execute(&mut registers)?;
+    }
+
+    return Ok(registers);
+}
+
+// the number of samples that behave like three or more opcodes
+pub fn part_1(input_string: &str) -> Result<usize, String> {
+    let (samples, _program) = parse_input(input_string)?;
+
+    let num_of_samples = samples
+        .iter()
+        .filter(|sample| matching_opcodes(sample).len() >= 3)
+        .count();
+
+    return Ok(num_of_samples);
+}
+
+// the value of register 0 after running the test program
+pub fn part_2(input_string: &str) -> Result<Value, String> {
+    let (samples, program) = parse_input(input_string)?;
+
+    let opcode_map = resolve_opcodes(&samples)?;
+    let registers = run_program(&opcode_map, &program)?;
+
+    return Ok(registers[0]);
+}
+
+pub const SOLVER: Solver = Solver {
+    year: 2018,
+    day: 16,
+    input: include_str!("input.txt"),
+    part_1: |input_string| Ok(part_1(input_string)?.to_string()),
+    part_2: Some(|input_string| Ok(part_2(input_string)?.to_string())),
+    examples: &[Example {
+        input: include_str!("../examples/1.txt"),
+        part_1: Some("1"),
+        part_2: None,
+    }],
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_input() {
+        let input_string = r###"
+Before: [3, 2, 1, 1]
+9 2 1 2
+After:  [3, 2, 2, 1]
+
+
+
+9 0 0 3
+        "###;
+
+        let (samples, program) = parse_input(input_string).unwrap();
+
+        assert_eq!(
+            samples,
+            vec![Sample {
+                before: vec![3, 2, 1, 1],
+                instruction: [9, 2, 1, 2],
+                after: vec![3, 2, 2, 1],
+            }]
+        );
+        assert_eq!(program, vec![[9, 0, 0, 3]]);
+
+        assert!(parse_input("Before: [3, 2, 1, 1]\n9 2 1 2").is_err());
+        assert!(parse_input("Before: [3, 2, 1]\n9 2 1 2\nAfter:  [3, 2, 2, 1]").is_err());
+        assert!(parse_input("9 2 1").is_err());
+    }
+
+    #[test]
+    fn test_matching_opcodes() {
+        let sample = Sample {
+            before: vec![3, 2, 1, 1],
+            instruction: [9, 2, 1, 2],
+            after: vec![3, 2, 2, 1],
+        };
+
+        let expected: HashSet<Opcode> = vec![Opcode::Mulr, Opcode::Addi, Opcode::Seti]
+            .into_iter()
+            .collect();
+        assert_eq!(matching_opcodes(&sample), expected);
+
+        // an output register that doesn't exist rules out every opcode
+        let sample = Sample {
+            before: vec![3, 2, 1, 1],
+            instruction: [9, 2, 1, 4],
+            after: vec![3, 2, 2, 1],
+        };
+        assert!(matching_opcodes(&sample).is_empty());
+    }
+
+    #[test]
+    fn test_resolve_opcodes() {
+        let samples = vec![
+            // seti or setr, as register 3 and value 3 are both 3
+            Sample {
+                before: vec![0, 1, 2, 3],
+                instruction: [1, 3, 100, 0],
+                after: vec![3, 1, 2, 3],
+            },
+            // only setr copies register 1
+            Sample {
+                before: vec![5, 6, 7, 8],
+                instruction: [0, 1, 100, 3],
+                after: vec![5, 6, 7, 6],
+            },
+        ];
+
+        let opcode_map = resolve_opcodes(&samples[1..]).unwrap();
+        assert_eq!(opcode_map[&0], Opcode::Setr);
+
+        // opcode number 1 can be told apart once setr is taken by opcode number 0
+        assert!(resolve_opcodes(&samples[..1]).is_err());
+        let opcode_map = resolve_opcodes(&samples).unwrap();
+        assert_eq!(opcode_map[&1], Opcode::Seti);
+
+        assert_eq!(
+            run_program(&opcode_map, &[[1, 7, 0, 2], [0, 2, 0, 0]]),
+            Ok(vec![7, 0, 7, 0])
+        );
+        assert!(run_program(&opcode_map, &[[2, 7, 0, 2]]).is_err());
+    }
+
+    #[test]
+    fn test_part_1() {
+        let input_string = include_str!("../examples/1.txt");
+
+        assert_eq!(part_1(input_string), Ok(1));
+
+        assert_eq!(part_1(include_str!("input.txt")), Ok(624));
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(part_2(include_str!("input.txt")), Ok(584));
+    }
+}
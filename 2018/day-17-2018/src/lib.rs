@@ -0,0 +1,508 @@
+// https://adventofcode.com/2018/day/17
+
+// imports
+
+use solver::{Example, Solver};
+use std::collections::HashMap;
+
+// code
+
+type Coordinate = (i32, i32);
+
+// position of the water spring
+const WATER_SPRING: Coordinate = (500, 0);
+
+trait Transitions {
+    fn down(&self) -> Coordinate;
+    fn left(&self) -> Coordinate;
+    fn right(&self) -> Coordinate;
+}
+
+impl Transitions for Coordinate {
+    fn down(&self) -> Coordinate {
+        let (x, y) = self;
+        return (*x, y + 1);
+    }
+
+    fn left(&self) -> Coordinate {
+        let (x, y) = self;
+        return (x - 1, *y);
+    }
+
+    fn right(&self) -> Coordinate {
+        let (x, y) = self;
+        return (x + 1, *y);
+    }
+}
+
+enum Water {
+    AtRest,
+    Flowing,
+}
+
+enum MapState {
+    Clay,
+    Water(Water),
+}
+
+type Terrain = HashMap<Coordinate, MapState>;
+
+// the terrain within the scan, which covers every vein of clay
+pub struct Map {
+    terrain: Terrain,
+
+    // the bounds of the scan
+    min_x: i32,
+    max_x: i32,
+    min_y: i32,
+    max_y: i32,
+}
+
+impl Map {
+    fn new(clay_coordinates: &[Coordinate]) -> Result<Self, String> {
+        if clay_coordinates.is_empty() {
+            return Err("No clay in the scan".to_string());
+        }
+
+        let xs = clay_coordinates.iter().map(|(x, _y)| *x);
+        let ys = clay_coordinates.iter().map(|(_x, y)| *y);
+
+        let mut map = Map {
+            terrain: HashMap::new(),
+            min_x: xs.clone().min().unwrap(),
+            max_x: xs.max().unwrap(),
+            min_y: ys.clone().min().unwrap(),
+            max_y: ys.max().unwrap(),
+        };
+
+        for coordinate in clay_coordinates {
+            map.insert_clay(coordinate)?;
+        }
+
+        return Ok(map);
+    }
+
+    // the number of tiles of water, flowing or at rest, within the y bounds of the scan
+    fn num_of_water_tiles(&self) -> usize {
+        return self
+            .terrain
+            .keys()
+            .filter(|(_x, y)| self.min_y <= *y && *y <= self.max_y)
+            .filter(|position| self.is_water(position))
+            .count();
+    }
+
+    // the number of tiles of water at rest, within the y bounds of the scan
+    fn num_of_rested_water_tiles(&self) -> usize {
+        return self
+            .terrain
+            .keys()
+            .filter(|(_x, y)| self.min_y <= *y && *y <= self.max_y)
+            .filter(|position| self.is_water_at_rest(position))
+            .count();
+    }
+
+    // water below the lowest clay falls forever
+    fn is_coord_out_of_bounds(&self, position: &Coordinate) -> bool {
+        let (_x, y) = position;
+
+        return *y > self.max_y || *y < 0;
+    }
+
+    fn insert_clay(&mut self, clay_coordinate: &Coordinate) -> Result<(), String> {
+        // clay can never be right where the water spring is positioned
+        if clay_coordinate == &WATER_SPRING {
+            return Err("Clay at the water spring".to_string());
+        }
+
+        self.terrain.insert(*clay_coordinate, MapState::Clay);
+
+        return Ok(());
+    }
+
+    // the map as drawn in the puzzle: from the spring down to the lowest clay,
+    // with a column on either side of the clay for the water that spills over the edges.
+    // + is the spring, # is clay, ~ is water at rest, | is flowing water, and . is sand.
+    pub fn to_string(&self) -> String {
+        let rows: Vec<String> = (0..=self.max_y)
+            .map(|y| {
+                return ((self.min_x - 1)..=(self.max_x + 1))
+                    .map(|x| {
+                        let position = (x, y);
+
+                        match self.terrain.get(&position) {
+                            None if position == WATER_SPRING => '+',
+                            None => '.',
+                            Some(MapState::Clay) => '#',
+                            Some(MapState::Water(Water::AtRest)) => '~',
+                            Some(MapState::Water(Water::Flowing)) => '|',
+                        }
+                    })
+                    .collect();
+            })
+            .collect();
+
+        return rows.join("\n");
+    }
+
+    fn is_clay(&self, position: &Coordinate) -> bool {
+        match self.terrain.get(&position) {
+            None => {
+                return false;
+            }
+            Some(map_state) => {
+                match map_state {
+                    MapState::Clay => {
+                        return true;
+                    }
+                    _ => {
+                        return false;
+                    }
+                };
+            }
+        }
+    }
+
+    fn is_dry_sand(&self, position: &Coordinate) -> bool {
+        return self.terrain.get(&position).is_none();
+    }
+
+    fn upgrade_water(&mut self, position: &Coordinate) {
+        match self.terrain.get(&position) {
+            None => {
+                self.terrain
+                    .insert(*position, MapState::Water(Water::Flowing));
+            }
+            Some(map_state) => {
+                match map_state {
+                    MapState::Water(water_state) => match water_state {
+                        Water::Flowing => {
+                            self.terrain
+                                .insert(*position, MapState::Water(Water::AtRest));
+                        }
+                        Water::AtRest => {}
+                    },
+                    MapState::Clay => {
+                        unreachable!();
+                    }
+                };
+            }
+        }
+    }
+
+    fn is_water_at_rest(&self, position: &Coordinate) -> bool {
+        match self.terrain.get(&position) {
+            None => {
+                return false;
+            }
+            Some(map_state) => match map_state {
+                MapState::Clay => {
+                    return false;
+                }
+                MapState::Water(water_state) => match water_state {
+                    Water::Flowing => false,
+                    Water::AtRest => true,
+                },
+            },
+        }
+    }
+
+    fn is_water_flowing(&self, position: &Coordinate) -> bool {
+        match self.terrain.get(&position) {
+            None => {
+                return false;
+            }
+            Some(map_state) => match map_state {
+                MapState::Clay => {
+                    return false;
+                }
+                MapState::Water(water_state) => match water_state {
+                    Water::Flowing => true,
+                    Water::AtRest => false,
+                },
+            },
+        }
+    }
+
+    fn is_water(&self, position: &Coordinate) -> bool {
+        match self.terrain.get(&position) {
+            None => {
+                return false;
+            }
+            Some(map_state) => match map_state {
+                MapState::Clay => {
+                    return false;
+                }
+                MapState::Water(_water_state) => {
+                    return true;
+                }
+            },
+        }
+    }
+
+    fn flood(&mut self, position: &Coordinate) {
+        if self.is_coord_out_of_bounds(position) {
+            return;
+        }
+
+        if self.is_water_flowing(position) {
+            return;
+        }
+
+        if self.is_clay(position) || self.is_water_at_rest(position) {
+            return;
+        }
+
+        if self.is_dry_sand(position) {
+            self.upgrade_water(position);
+        }
+
+        // flood downward
+        let down_position = position.down();
+
+        self.flood(&down_position);
+
+        if !(self.is_clay(&down_position) || self.is_water_at_rest(&down_position)) {
+            return;
+        }
+
+        // flood left
+        let mut water_at_rest = vec![];
+        water_at_rest.push(position.clone());
+
+        let mut current = position.left();
+        let has_left_wall;
+
+        loop {
+            if self.is_clay(&current) || self.is_water_at_rest(&current) {
+                has_left_wall = true;
+                break;
+            }
+
+            if self.is_dry_sand(&current) {
+                self.upgrade_water(&current);
+            }
+
+            let down = current.down();
+            self.flood(&down);
+
+            if !(self.is_clay(&down) || self.is_water_at_rest(&down)) {
+                has_left_wall = false;
+                break;
+            }
+
+            water_at_rest.push(current);
+
+            current = current.left();
+        }
+
+        // flood right
+        let mut current = position.right();
+        let has_right_wall;
+
+        loop {
+            if self.is_clay(&current) || self.is_water_at_rest(&current) {
+                has_right_wall = true;
+                break;
+            }
+
+            if self.is_dry_sand(&current) {
+                self.upgrade_water(&current);
+            }
+
+            let down = current.down();
+            self.flood(&down);
+
+            if !(self.is_clay(&down) || self.is_water_at_rest(&down)) {
+                has_right_wall = false;
+                break;
+            }
+
+            water_at_rest.push(current);
+
+            current = current.right();
+        }
+
+        if has_left_wall && has_right_wall {
+            for current in water_at_rest {
+                self.upgrade_water(&current);
+            }
+        }
+    }
+
+    pub fn run_flood(&mut self) {
+        self.flood(&WATER_SPRING.down());
+    }
+}
+
+// x=495, y=2..7
+// y=7, x=495..501
+fn parse_vein(input: &str) -> Result<Vec<Coordinate>, String> {
+    let invalid = || format!("Invalid vein of clay: {}", input);
+
+    let tokens: Vec<&str> = input.split(',').map(|s| s.trim()).collect();
+
+    let (axis, range) = match tokens.as_slice() {
+        [axis, range] => (*axis, *range),
+        _ => {
+            return Err(invalid());
+        }
+    };
+
+    let parse_value = |x: &str| -> Result<i32, String> {
+        return x.trim().parse().map_err(|_| invalid());
+    };
+
+    let (axis_name, value) = axis.split_at(axis.find('=').ok_or_else(invalid)?);
+    let value = parse_value(&value[1..])?;
+
+    let (range_name, range) = range.split_at(range.find('=').ok_or_else(invalid)?);
+    let (start, end) = {
+        let range = &range[1..];
+        let separator = range.find("..").ok_or_else(invalid)?;
+        (
+            parse_value(&range[..separator])?,
+            parse_value(&range[(separator + 2)..])?,
+        )
+    };
+
+    if start > end {
+        return Err(invalid());
+    }
+
+    match (axis_name, range_name) {
+        ("x", "y") => {
+            return Ok((start..=end).map(|y| (value, y)).collect());
+        }
+        ("y", "x") => {
+            return Ok((start..=end).map(|x| (x, value)).collect());
+        }
+        _ => {
+            return Err(invalid());
+        }
+    }
+}
+
+pub fn generate_map(input_string: &str) -> Result<Map, String> {
+    let mut clay_coordinates: Vec<Coordinate> = vec![];
+
+    for line in input_string.trim().lines() {
+        clay_coordinates.extend(parse_vein(line)?);
+    }
+
+    return Map::new(&clay_coordinates);
+}
+
+// the number of tiles the water can reach
+pub fn part_1(input_string: &str) -> Result<usize, String> {
+    let mut map = generate_map(input_string)?;
+    map.run_flood();
+
+    return Ok(map.num_of_water_tiles());
+}
+
+// the number of tiles of water left when the spring runs dry
+pub fn part_2(input_string: &str) -> Result<usize, String> {
+    let mut map = generate_map(input_string)?;
+    map.run_flood();
+
+    return Ok(map.num_of_rested_water_tiles());
+}
+
+pub const SOLVER: Solver = Solver {
+    year: 2018,
+    day: 17,
+    input: include_str!("input.txt"),
+    part_1: |input_string| Ok(part_1(input_string)?.to_string()),
+    part_2: Some(|input_string| Ok(part_2(input_string)?.to_string())),
+    examples: &[Example {
+        input: include_str!("../examples/1.txt"),
+        part_1: Some("57"),
+        part_2: Some("29"),
+    }],
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../examples/1.txt");
+
+    #[test]
+    fn test_parse_vein() {
+        assert_eq!(
+            parse_vein("x=495, y=2..4"),
+            Ok(vec![(495, 2), (495, 3), (495, 4)])
+        );
+        assert_eq!(parse_vein("y=7, x=495..496"), Ok(vec![(495, 7), (496, 7)]));
+        assert_eq!(parse_vein("y=7, x=495..495"), Ok(vec![(495, 7)]));
+
+        assert!(parse_vein("x=495, x=2..4").is_err());
+        assert!(parse_vein("x=495, y=4..2").is_err());
+        assert!(parse_vein("x=495, y=2").is_err());
+        assert!(parse_vein("x=495").is_err());
+        assert!(parse_vein("z=495, y=2..4").is_err());
+
+        assert!(generate_map("").is_err());
+        assert!(generate_map("x=500, y=0..1").is_err());
+    }
+
+    #[test]
+    fn test_map() {
+        let expected = r###"
+......+.......
+............#.
+.#..#.......#.
+.#..#..#......
+.#..#..#......
+.#.....#......
+.#.....#......
+.#######......
+..............
+..............
+....#.....#...
+....#.....#...
+....#.....#...
+....#######...
+        "###;
+
+        let mut map = generate_map(EXAMPLE).unwrap();
+
+        assert_eq!(map.to_string(), expected.trim());
+
+        map.run_flood();
+
+        let expected = r###"
+......+.......
+......|.....#.
+.#..#||||...#.
+.#..#~~#|.....
+.#..#~~#|.....
+.#~~~~~#|.....
+.#~~~~~#|.....
+.#######|.....
+........|.....
+...|||||||||..
+...|#~~~~~#|..
+...|#~~~~~#|..
+...|#~~~~~#|..
+...|#######|..
+        "###;
+
+        assert_eq!(map.to_string(), expected.trim());
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(part_1(EXAMPLE), Ok(57));
+
+        assert_eq!(part_1(include_str!("input.txt")), Ok(33004));
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(part_2(EXAMPLE), Ok(29));
+
+        assert_eq!(part_2(include_str!("input.txt")), Ok(23294));
+    }
+}
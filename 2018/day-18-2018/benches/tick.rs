@@ -0,0 +1,176 @@
+// benchmark a single minute of the lumber collection area on the real input.
+//
+// run with: cargo bench -p day-18-2018
+
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use day_18_2018::{generate_area, Area, PackedArea};
+use std::collections::HashMap;
+
+type Coordinate = (i32, i32);
+
+// the area as a HashMap of acres, where every neighbor lookup is a hash probe
+#[derive(Clone)]
+struct HashMapArea {
+    area: HashMap<Coordinate, char>,
+    max_y: i32,
+    max_x: i32,
+}
+
+impl HashMapArea {
+    fn new(input_string: &str) -> Self {
+        let mut area = HashMap::new();
+        let mut max_y = 0;
+        let mut max_x = 0;
+
+        for (y, line) in input_string.trim().lines().enumerate() {
+            for (x, acre) in line.trim().chars().enumerate() {
+                area.insert((x as i32, y as i32), acre);
+                max_y = std::cmp::max(max_y, y as i32);
+                max_x = std::cmp::max(max_x, x as i32);
+            }
+        }
+
+        return HashMapArea { area, max_y, max_x };
+    }
+
+    fn tick(&mut self) {
+        let mut next_area = HashMap::with_capacity(self.area.len());
+
+        for (position, acre) in self.area.iter() {
+            let (x, y) = *position;
+            let mut num_of_trees = 0;
+            let mut num_of_lumberyards = 0;
+
+            for dy in -1..=1 {
+                for dx in -1..=1 {
+                    if (dx, dy) == (0, 0) {
+                        continue;
+                    }
+
+                    match self.area.get(&(x + dx, y + dy)) {
+                        Some('|') => num_of_trees += 1,
+                        Some('#') => num_of_lumberyards += 1,
+                        _ => {}
+                    }
+                }
+            }
+
+            let next_acre = match acre {
+                '.' if num_of_trees >= 3 => '|',
+                '|' if num_of_lumberyards >= 3 => '#',
+                '#' if num_of_lumberyards == 0 || num_of_trees == 0 => '.',
+                _ => *acre,
+            };
+
+            next_area.insert(*position, next_acre);
+        }
+
+        self.area = next_area;
+    }
+
+    fn to_string(&self) -> String {
+        let rows: Vec<String> = (0..=self.max_y)
+            .map(|y| (0..=self.max_x).map(|x| self.area[&(x, y)]).collect())
+            .collect();
+
+        return rows.join("\n");
+    }
+}
+
+fn bench_tick(c: &mut Criterion) {
+    let input_string = include_str!("../src/input.txt");
+
+    let hashmap_area = HashMapArea::new(input_string);
+    let area = generate_area(input_string);
+
+    // both boards agree on the first few minutes
+    let mut expected = hashmap_area.clone();
+    let mut actual = area.clone();
+    for _ in 0..10 {
+        expected.tick();
+        actual.step();
+        assert_eq!(expected.to_string(), actual.to_string());
+    }
+
+    let mut group = c.benchmark_group("tick");
+
+    group.bench_function("hashmap", |b| {
+        b.iter_batched(
+            || hashmap_area.clone(),
+            |mut area| area.tick(),
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.bench_function("double-buffered vec", |b| {
+        b.iter_batched(
+            || area.clone(),
+            |mut area| area.step(),
+            BatchSize::SmallInput,
+        )
+    });
+
+    let packed_area = PackedArea::from_area(&area);
+
+    group.bench_function("bit-packed", |b| {
+        b.iter_batched(
+            || packed_area.clone(),
+            |mut area| area.step(),
+            BatchSize::SmallInput,
+        )
+    });
+
+    // once the area settles into its cycle, only the neighborhoods of the changed acres are looked at
+    let mut settled_area = area.clone();
+    for _ in 0..500 {
+        settled_area.step();
+    }
+
+    group.bench_function("double-buffered vec, settled", |b| {
+        b.iter_batched(
+            || settled_area.clone(),
+            |mut area| area.step(),
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.finish();
+}
+
+// a board much larger than the puzzle input, where the parallel sweep pays off
+fn bench_generated(c: &mut Criterion) {
+    let area = Area::generate(300, 300, 0.3, 0.1, 2018);
+    let parallel_area = area.clone().with_parallel(true);
+    let packed_area = PackedArea::from_area(&area);
+
+    let mut group = c.benchmark_group("tick 300x300");
+
+    group.bench_function("double-buffered vec", |b| {
+        b.iter_batched(
+            || area.clone(),
+            |mut area| area.step(),
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.bench_function("double-buffered vec, parallel", |b| {
+        b.iter_batched(
+            || parallel_area.clone(),
+            |mut area| area.step(),
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.bench_function("bit-packed", |b| {
+        b.iter_batched(
+            || packed_area.clone(),
+            |mut area| area.step(),
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_tick, bench_generated);
+criterion_main!(benches);
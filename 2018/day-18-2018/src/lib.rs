@@ -0,0 +1,1626 @@
+// https://adventofcode.com/2018/day/18
+
+// imports
+
+use grid_image::{render_grid, Color, RgbaImage};
+use rayon::prelude::*;
+use solver::{Example, Solver};
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+use std::sync::Arc;
+
+mod packed;
+pub use packed::{PackedArea, Snapshot};
+
+// code
+
+pub type Coordinate = (i32, i32);
+
+trait Transitions {
+    fn up(&self) -> Coordinate;
+    fn down(&self) -> Coordinate;
+    fn left(&self) -> Coordinate;
+    fn right(&self) -> Coordinate;
+}
+
+impl Transitions for Coordinate {
+    fn up(&self) -> Coordinate {
+        let (x, y) = self;
+        return (*x, y - 1);
+    }
+
+    fn down(&self) -> Coordinate {
+        let (x, y) = self;
+        return (*x, y + 1);
+    }
+
+    fn left(&self) -> Coordinate {
+        let (x, y) = self;
+        return (x - 1, *y);
+    }
+
+    fn right(&self) -> Coordinate {
+        let (x, y) = self;
+        return (x + 1, *y);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Acre {
+    Ground,
+    Tree,
+    Lumberyard,
+}
+
+impl Acre {
+    fn from_char(acre: char) -> Self {
+        match acre {
+            '.' => Acre::Ground,
+            '|' => Acre::Tree,
+            '#' => Acre::Lumberyard,
+            _ => {
+                unreachable!();
+            }
+        }
+    }
+
+    fn to_char(self) -> char {
+        match self {
+            Acre::Ground => '.',
+            Acre::Tree => '|',
+            Acre::Lumberyard => '#',
+        }
+    }
+
+    pub fn to_color(self) -> &'static str {
+        match self {
+            // dark grey
+            Acre::Ground => "\x1b[90m",
+            // green
+            Acre::Tree => "\x1b[32m",
+            // brown
+            Acre::Lumberyard => "\x1b[38;5;130m",
+        }
+    }
+
+    pub fn to_rgb(self) -> Color {
+        match self {
+            // dark grey
+            Acre::Ground => [64, 64, 64],
+            // green
+            Acre::Tree => [34, 139, 34],
+            // brown
+            Acre::Lumberyard => [139, 90, 43],
+        }
+    }
+}
+
+// the number of acres of every kind adjacent to an acre.
+// acres at the edges of the area have fewer than 8 adjacent acres.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct NeighborCounts {
+    pub ground: u8,
+    pub trees: u8,
+    pub lumberyards: u8,
+}
+
+impl NeighborCounts {
+    // branch-free, since the kinds of adjacent acres are hard to predict
+    fn add(&mut self, acre: Acre) {
+        self.ground += (acre == Acre::Ground) as u8;
+        self.trees += (acre == Acre::Tree) as u8;
+        self.lumberyards += (acre == Acre::Lumberyard) as u8;
+    }
+
+    fn remove(&mut self, acre: Acre) {
+        self.ground -= (acre == Acre::Ground) as u8;
+        self.trees -= (acre == Acre::Tree) as u8;
+        self.lumberyards -= (acre == Acre::Lumberyard) as u8;
+    }
+}
+
+impl std::ops::AddAssign for NeighborCounts {
+    fn add_assign(&mut self, other: Self) {
+        self.ground += other.ground;
+        self.trees += other.trees;
+        self.lumberyards += other.lumberyards;
+    }
+}
+
+// the transition rule of the puzzle
+pub fn puzzle_rule(acre: &Acre, counts: &NeighborCounts) -> Acre {
+    match acre {
+        Acre::Ground => {
+            // An open acre will become filled with trees if three or more
+            // adjacent acres contained trees. Otherwise, nothing happens.
+            if counts.trees >= 3 {
+                return Acre::Tree;
+            }
+
+            return Acre::Ground;
+        }
+        Acre::Tree => {
+            // An acre filled with trees will become a lumberyard if three or more
+            // adjacent acres were lumberyards. Otherwise, nothing happens.
+            if counts.lumberyards >= 3 {
+                return Acre::Lumberyard;
+            }
+            return Acre::Tree;
+        }
+        Acre::Lumberyard => {
+            // An acre containing a lumberyard will remain a lumberyard if it was adjacent
+            // to at least one other lumberyard and at least one acre containing trees.
+            // Otherwise, it becomes open.
+            if counts.lumberyards >= 1 && counts.trees >= 1 {
+                return Acre::Lumberyard;
+            }
+
+            return Acre::Ground;
+        }
+    }
+}
+
+// what an acre becomes by the end of a minute, given the acres adjacent to it at the beginning of the minute
+pub type Rule = dyn Fn(&Acre, &NeighborCounts) -> Acre + Send + Sync;
+
+#[derive(Clone)]
+pub enum TransitionRule {
+    Puzzle,
+    Custom(Arc<Rule>),
+}
+
+impl TransitionRule {
+    pub fn custom(rule: impl Fn(&Acre, &NeighborCounts) -> Acre + Send + Sync + 'static) -> Self {
+        return TransitionRule::Custom(Arc::new(rule));
+    }
+
+    pub fn apply(&self, acre: &Acre, counts: &NeighborCounts) -> Acre {
+        match self {
+            TransitionRule::Puzzle => puzzle_rule(acre, counts),
+            TransitionRule::Custom(rule) => rule(acre, counts),
+        }
+    }
+}
+
+impl Default for TransitionRule {
+    fn default() -> Self {
+        return TransitionRule::Puzzle;
+    }
+}
+
+impl std::fmt::Debug for TransitionRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            TransitionRule::Puzzle => write!(f, "Puzzle"),
+            TransitionRule::Custom(_) => write!(f, "Custom"),
+        }
+    }
+}
+
+// https://en.wikipedia.org/wiki/ANSI_escape_code#Colors
+const ANSI_RESET: &str = "\x1b[0m";
+
+// a minute only looks at the neighborhoods of the acres that changed in the previous minute
+// if at most 1 / SPARSE_TICK_RATIO of the acres changed.
+// every changed acre dirties up to 9 acres, so past that a full sweep is cheaper.
+const SPARSE_TICK_RATIO: usize = 9;
+
+// call f with every row of a buffer of acres, and collect what it returns for each row.
+// the rows are split across threads if parallel is set.
+fn map_rows<T: Send, R: Send>(
+    buffer: &mut [T],
+    width: usize,
+    parallel: bool,
+    f: impl Fn(usize, &mut [T]) -> R + Send + Sync,
+) -> Vec<R> {
+    if width == 0 {
+        return vec![];
+    }
+
+    if parallel {
+        return buffer
+            .par_chunks_mut(width)
+            .enumerate()
+            .map(|(y, row)| f(y, row))
+            .collect();
+    }
+
+    return buffer
+        .chunks_mut(width)
+        .enumerate()
+        .map(|(y, row)| f(y, row))
+        .collect();
+}
+
+// the acres are stored row by row in a flat buffer.
+// the next minute is computed into a second buffer, and the buffers are swapped afterwards.
+//
+// an acre can only change if an acre in its neighborhood changed in the previous minute,
+// so once few acres change, only those neighborhoods are looked at.
+// this holds for any transition rule, as long as it's the same every minute.
+#[derive(Debug, Clone)]
+pub struct Area {
+    // indexed by y * width + x
+    acres: Vec<Acre>,
+    next_acres: Vec<Acre>,
+    // indices of the acres that changed in the previous minute;
+    // None if unknown, e.g. for a freshly generated area
+    changed: Option<Vec<usize>>,
+    // the acres whose neighborhood changed in the previous minute
+    dirty: Vec<bool>,
+    // the acres adjacent to every acre, counted at the start of a full sweep
+    neighbor_counts: Vec<NeighborCounts>,
+    // the acres of every row summed over a sliding window of 3 acres
+    row_sums: Vec<NeighborCounts>,
+    rule: TransitionRule,
+    // sweep the rows of the area in parallel
+    parallel: bool,
+    // the edges of the area wrap around, so that every acre has 8 adjacent acres
+    toroidal: bool,
+    max_y: i32,
+    max_x: i32,
+}
+
+// the next_acres buffer, the changed and dirty acres, and the counts are bookkeeping, and aren't part of the state of the area.
+// neither are the transition rule and how the area is ticked.
+impl PartialEq for Area {
+    fn eq(&self, other: &Self) -> bool {
+        return self.max_y == other.max_y && self.max_x == other.max_x && self.acres == other.acres;
+    }
+}
+
+impl Eq for Area {}
+
+impl Hash for Area {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.max_y.hash(state);
+        self.max_x.hash(state);
+        self.acres.hash(state);
+    }
+}
+
+impl Area {
+    pub fn new() -> Self {
+        Area {
+            acres: vec![],
+            next_acres: vec![],
+            changed: None,
+            dirty: vec![],
+            neighbor_counts: vec![],
+            row_sums: vec![],
+            rule: TransitionRule::Puzzle,
+            parallel: false,
+            toroidal: false,
+            max_y: -1,
+            max_x: -1,
+        }
+    }
+
+    fn from_rows(rows: Vec<Vec<Acre>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map(|row| row.len()).unwrap_or(0);
+        assert!(rows.iter().all(|row| row.len() == width));
+
+        let acres: Vec<Acre> = rows.into_iter().flatten().collect();
+
+        return Area {
+            next_acres: acres.clone(),
+            acres,
+            changed: None,
+            dirty: vec![],
+            neighbor_counts: vec![],
+            row_sums: vec![],
+            rule: TransitionRule::Puzzle,
+            parallel: false,
+            toroidal: false,
+            max_y: height as i32 - 1,
+            max_x: width as i32 - 1,
+        };
+    }
+
+    fn get_width(&self) -> usize {
+        return (self.max_x + 1) as usize;
+    }
+
+    fn get_height(&self) -> usize {
+        return (self.max_y + 1) as usize;
+    }
+
+    fn get_index(&self, position: &Coordinate) -> Option<usize> {
+        let (x, y) = *position;
+
+        if x < 0 || y < 0 || x > self.max_x || y > self.max_y {
+            return None;
+        }
+
+        return Some(y as usize * self.get_width() + x as usize);
+    }
+
+    pub fn get(&self, position: &Coordinate) -> Option<Acre> {
+        return self.get_index(position).map(|index| self.acres[index]);
+    }
+
+    pub fn resource_value(&self) -> usize {
+        return self.num_of_lumberyards() * self.num_of_trees();
+    }
+
+    // the resource value after some minutes, leaving the area as it is.
+    // the areas are only ticked through until one repeats, so any number of minutes is fast.
+    pub fn resource_value_after(&self, minutes: usize) -> usize {
+        let (cycle, resource_values) = find_cycle_within(self, minutes);
+        return fast_forward(cycle.as_ref(), &resource_values, minutes);
+    }
+
+    pub fn snapshot(&self) -> Snapshot {
+        return Snapshot::from_area(self);
+    }
+
+    // a hash of the acres of the area that's the same from run to run;
+    // areas with the same fingerprint are very likely, but not certain, to be the same.
+    pub fn fingerprint(&self) -> u64 {
+        return self.snapshot().fingerprint();
+    }
+
+    pub fn num_of_trees(&self) -> usize {
+        return self.acres.iter().filter(|s| **s == Acre::Tree).count();
+    }
+
+    pub fn num_of_lumberyards(&self) -> usize {
+        return self
+            .acres
+            .iter()
+            .filter(|s| **s == Acre::Lumberyard)
+            .count();
+    }
+
+    pub fn to_string(&self) -> String {
+        let map_string: Vec<String> = self
+            .acres
+            .chunks(self.get_width().max(1))
+            .map(|row| row.iter().map(|acre| acre.to_char()).collect())
+            .collect();
+
+        return map_string.join("\n");
+    }
+
+    // to_string, with every acre colored
+    pub fn render(&self) -> String {
+        let map_string: Vec<String> = self
+            .acres
+            .chunks(self.get_width().max(1))
+            .map(|row| {
+                let mut row_string = String::from("");
+                for acre in row {
+                    row_string.push_str(acre.to_color());
+                    row_string.push(acre.to_char());
+                }
+                row_string.push_str(ANSI_RESET);
+                return row_string;
+            })
+            .collect();
+
+        return map_string.join("\n");
+    }
+
+    // every acre is a square of tile_size pixels
+    pub fn to_image(&self, tile_size: u32) -> RgbaImage {
+        return render_grid(self.get_width(), self.get_height(), tile_size, |x, y| {
+            return self.acres[y * self.get_width() + x].to_rgb();
+        });
+    }
+
+    // the area grows to include the position; acres that weren't inserted yet are ground.
+    // growing moves every acre, so areas are best built with generate_area.
+    pub fn insert(&mut self, position: Coordinate, acre: char) {
+        let (x, y) = position;
+        assert!(x >= 0 && y >= 0);
+
+        if x > self.max_x || y > self.max_y {
+            let max_x = std::cmp::max(x, self.max_x);
+            let max_y = std::cmp::max(y, self.max_y);
+
+            let mut rows = vec![vec![Acre::Ground; (max_x + 1) as usize]; (max_y + 1) as usize];
+            for (index, acre) in self.acres.iter().enumerate() {
+                rows[index / self.get_width()][index % self.get_width()] = *acre;
+            }
+
+            let rule = self.rule.clone();
+            let parallel = self.parallel;
+            let toroidal = self.toroidal;
+            *self = Area::from_rows(rows)
+                .with_rule(rule)
+                .with_parallel(parallel)
+                .with_toroidal(toroidal);
+        }
+
+        let index = self.get_index(&position).unwrap();
+        self.acres[index] = Acre::from_char(acre);
+        self.changed = None;
+    }
+
+    // generate a random area, where every acre is a tree or a lumberyard with the given probabilities,
+    // and open ground otherwise
+    pub fn generate(
+        width: usize,
+        height: usize,
+        tree_density: f64,
+        yard_density: f64,
+        seed: u64,
+    ) -> Self {
+        assert!(
+            tree_density >= 0.0 && yard_density >= 0.0 && tree_density + yard_density <= 1.0,
+            "Invalid densities: {} trees, {} lumberyards",
+            tree_density,
+            yard_density
+        );
+
+        let mut random = Random::new(seed);
+
+        let rows: Vec<Vec<Acre>> = (0..height)
+            .map(|_| {
+                (0..width)
+                    .map(|_| {
+                        let sample = random.next_f64();
+                        if sample < tree_density {
+                            return Acre::Tree;
+                        }
+                        if sample < tree_density + yard_density {
+                            return Acre::Lumberyard;
+                        }
+                        return Acre::Ground;
+                    })
+                    .collect()
+            })
+            .collect();
+
+        return Area::from_rows(rows);
+    }
+
+    pub fn with_rule(mut self, rule: TransitionRule) -> Self {
+        self.rule = rule;
+        // the changed acres are only meaningful for the rule that changed them
+        self.changed = None;
+        return self;
+    }
+
+    pub fn get_rule(&self) -> &TransitionRule {
+        return &self.rule;
+    }
+
+    // full sweeps of the area are split across threads by rows.
+    // this only pays off for large areas.
+    pub fn with_parallel(mut self, parallel: bool) -> Self {
+        self.parallel = parallel;
+        return self;
+    }
+
+    // acres at the edges of a toroidal area are adjacent to the acres at the opposite edges,
+    // instead of having fewer adjacent acres.
+    // in areas less than 3 acres wide or tall, an acre can be adjacent to the same acre more than once,
+    // or to itself.
+    pub fn with_toroidal(mut self, toroidal: bool) -> Self {
+        self.toroidal = toroidal;
+        // acres that didn't change may still change with their new neighbors
+        self.changed = None;
+        return self;
+    }
+
+    pub fn is_toroidal(&self) -> bool {
+        return self.toroidal;
+    }
+
+    pub fn step(&mut self) {
+        // Changes happen across all acres simultaneously,
+        // each of them using the state of all acres at the beginning of the minute
+        // and changing to their new form by the end of that same minute.
+        // Changes that happen during the minute don't affect each other.
+
+        match self.changed.take() {
+            Some(changed) if changed.len() * SPARSE_TICK_RATIO <= self.acres.len() => {
+                self.tick_sparse(&changed);
+            }
+            _ => {
+                self.tick_full();
+            }
+        }
+    }
+
+    // a full sweep is done in two passes: the acres adjacent to every acre are counted first,
+    // and the rule is applied to every acre afterwards
+    fn tick_full(&mut self) {
+        self.count_neighbors();
+
+        let width = self.get_width();
+
+        // the rows of next_acres are written while the rest of the area is read
+        let mut next_acres = std::mem::take(&mut self.next_acres);
+
+        let changed = map_rows(&mut next_acres, width, self.parallel, |y, next_row| {
+            let mut changed = vec![];
+
+            for (x, next_acre) in next_row.iter_mut().enumerate() {
+                let index = y * width + x;
+
+                // ✨ magic
+                *next_acre = self
+                    .rule
+                    .apply(&self.acres[index], &self.neighbor_counts[index]);
+
+                if *next_acre != self.acres[index] {
+                    changed.push(index);
+                }
+            }
+
+            return changed;
+        });
+
+        self.next_acres = next_acres;
+        std::mem::swap(&mut self.acres, &mut self.next_acres);
+        self.changed = Some(changed.concat());
+    }
+
+    // count the acres adjacent to every acre into neighbor_counts.
+    // the acres of every row are summed over a sliding window of 3 acres,
+    // and the counts of an acre are then the sums of the 3 rows around it, less the acre itself.
+    fn count_neighbors(&mut self) {
+        let width = self.get_width();
+        let height = self.get_height();
+        let toroidal = self.toroidal;
+        let acres = &self.acres;
+
+        let mut row_sums = std::mem::take(&mut self.row_sums);
+        row_sums.resize(acres.len(), NeighborCounts::default());
+
+        map_rows(&mut row_sums, width, self.parallel, |y, row| {
+            let row_acres = &acres[(y * width)..((y + 1) * width)];
+
+            if toroidal {
+                for (x, window) in row.iter_mut().enumerate() {
+                    *window = NeighborCounts::default();
+                    window.add(row_acres[(x + width - 1) % width]);
+                    window.add(row_acres[x]);
+                    window.add(row_acres[(x + 1) % width]);
+                }
+                return;
+            }
+
+            // the acres from x - 1 to x + 1
+            let mut window = NeighborCounts::default();
+            window.add(row_acres[0]);
+
+            for x in 0..width {
+                if x + 1 < width {
+                    window.add(row_acres[x + 1]);
+                }
+                if x >= 2 {
+                    window.remove(row_acres[x - 2]);
+                }
+                row[x] = window;
+            }
+        });
+
+        let mut neighbor_counts = std::mem::take(&mut self.neighbor_counts);
+        neighbor_counts.resize(acres.len(), NeighborCounts::default());
+
+        map_rows(&mut neighbor_counts, width, self.parallel, |y, row| {
+            for (x, counts) in row.iter_mut().enumerate() {
+                let index = y * width + x;
+
+                *counts = row_sums[index];
+                counts.remove(acres[index]);
+
+                if toroidal {
+                    *counts += row_sums[((y + height - 1) % height) * width + x];
+                    *counts += row_sums[((y + 1) % height) * width + x];
+                    continue;
+                }
+
+                if y > 0 {
+                    *counts += row_sums[index - width];
+                }
+                if y + 1 < height {
+                    *counts += row_sums[index + width];
+                }
+            }
+        });
+
+        self.row_sums = row_sums;
+        self.neighbor_counts = neighbor_counts;
+    }
+
+    // only look at the neighborhoods of the acres that changed in the previous minute
+    fn tick_sparse(&mut self, previously_changed: &[usize]) {
+        let width = self.get_width();
+
+        self.dirty.clear();
+        self.dirty.resize(self.acres.len(), false);
+
+        for index in previously_changed {
+            let (x, y) = ((index % width) as i32, (index / width) as i32);
+            for dy in -1..=1 {
+                for dx in -1..=1 {
+                    if let Some(index) = self.get_neighbor_index(&(x + dx, y + dy)) {
+                        self.dirty[index] = true;
+                    }
+                }
+            }
+        }
+
+        // every dirty acre is computed from the acres at the beginning of the minute,
+        // before any of them are updated
+        let mut updates: Vec<(usize, Acre)> = vec![];
+
+        for index in 0..self.acres.len() {
+            if !self.dirty[index] {
+                continue;
+            }
+
+            let position = ((index % width) as i32, (index / width) as i32);
+            let counts = self.get_neighbor_counts(&position);
+            let next_acre = self.rule.apply(&self.acres[index], &counts);
+
+            if next_acre != self.acres[index] {
+                updates.push((index, next_acre));
+            }
+        }
+
+        let mut changed = Vec::with_capacity(updates.len());
+        for (index, acre) in updates {
+            self.acres[index] = acre;
+            changed.push(index);
+        }
+
+        self.changed = Some(changed);
+    }
+
+    fn get_neighbor_counts(&self, position: &Coordinate) -> NeighborCounts {
+        let adjacent_positions = [
+            // clockwise
+            position.up(),
+            position.up().right(),
+            position.right(),
+            position.down().right(),
+            position.down(),
+            position.down().left(),
+            position.left(),
+            position.up().left(),
+        ];
+
+        let mut counts = NeighborCounts::default();
+
+        for adjacent_position in adjacent_positions.iter() {
+            if let Some(index) = self.get_neighbor_index(adjacent_position) {
+                counts.add(self.acres[index]);
+            }
+        }
+
+        return counts;
+    }
+
+    // the index of an acre in the neighborhood of another; positions past the edges wrap around
+    // in toroidal areas, and are outside of the area otherwise
+    fn get_neighbor_index(&self, position: &Coordinate) -> Option<usize> {
+        if !self.toroidal || self.acres.is_empty() {
+            return self.get_index(position);
+        }
+
+        let (x, y) = *position;
+        let x = x.rem_euclid(self.get_width() as i32);
+        let y = y.rem_euclid(self.get_height() as i32);
+
+        return self.get_index(&(x, y));
+    }
+}
+
+impl Default for Area {
+    fn default() -> Self {
+        return Area::new();
+    }
+}
+
+// splitmix64; a small deterministic pseudo-random number generator for generating areas
+struct Random(u64);
+
+impl Random {
+    fn new(seed: u64) -> Random {
+        Random(seed)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        return z ^ (z >> 31);
+    }
+
+    // uniformly distributed within [0, 1)
+    fn next_f64(&mut self) -> f64 {
+        return (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+    }
+}
+
+impl FromStr for Area {
+    type Err = String;
+
+    fn from_str(input_string: &str) -> Result<Self, Self::Err> {
+        let mut rows: Vec<Vec<Acre>> = vec![];
+
+        for (y, line) in input_string.trim().lines().enumerate() {
+            let row = line
+                .trim()
+                .chars()
+                .enumerate()
+                .map(|(x, acre)| match acre {
+                    '.' | '|' | '#' => Ok(Acre::from_char(acre)),
+                    _ => Err(format!("Invalid acre at {},{}: {:?}", x, y, acre)),
+                })
+                .collect::<Result<Vec<Acre>, String>>()?;
+
+            if let Some(first_row) = rows.first() {
+                if row.len() != first_row.len() {
+                    return Err(format!(
+                        "Invalid row {}: expected {} acres, found {}",
+                        y,
+                        first_row.len(),
+                        row.len()
+                    ));
+                }
+            }
+
+            rows.push(row);
+        }
+
+        return Ok(Area::from_rows(rows));
+    }
+}
+
+pub fn generate_area(input_string: &str) -> Area {
+    return input_string.parse().unwrap();
+}
+
+pub fn part_1(input_string: &str, ticks: i32) -> usize {
+    return generate_area(input_string).resource_value_after(ticks as usize);
+}
+
+// a sequence of areas that repeats forever once reached
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cycle {
+    // the minute at which the first area of the cycle is reached
+    pub start: usize,
+    // number of minutes after which the areas repeat
+    pub length: usize,
+}
+
+impl Cycle {
+    // the area stops changing at the start of the cycle
+    pub fn is_fixed_point(&self) -> bool {
+        return self.length == 1;
+    }
+}
+
+// tick until an area repeats.
+// returns the cycle, and the resource value of the area of every minute until the repeat.
+pub fn find_cycle(area: Area) -> (Cycle, Vec<usize>) {
+    let (cycle, resource_values) = find_cycle_within(&area, usize::MAX);
+    return (cycle.unwrap(), resource_values);
+}
+
+// tick until an area repeats, or for at most max_minutes.
+// returns the cycle if found, and the resource value of the area of every minute that was ticked through.
+fn find_cycle_within(area: &Area, max_minutes: usize) -> (Option<Cycle>, Vec<usize>) {
+    match area.get_rule() {
+        // the bit-packed area is much faster to tick and to hash,
+        // but only knows the puzzle's rule, with edges that don't wrap around
+        TransitionRule::Puzzle if !area.is_toroidal() => find_cycle_of(
+            PackedArea::from_area(area),
+            PackedArea::step,
+            PackedArea::resource_value,
+            PackedArea::snapshot,
+            Snapshot::fingerprint,
+            max_minutes,
+        ),
+        _ => find_cycle_of(
+            area.clone(),
+            Area::step,
+            Area::resource_value,
+            Area::snapshot,
+            Snapshot::fingerprint,
+            max_minutes,
+        ),
+    }
+}
+
+fn find_cycle_of<T>(
+    mut area: T,
+    step: impl Fn(&mut T),
+    resource_value: impl Fn(&T) -> usize,
+    snapshot: impl Fn(&T) -> Snapshot,
+    fingerprint: impl Fn(&Snapshot) -> u64,
+    max_minutes: usize,
+) -> (Option<Cycle>, Vec<usize>) {
+    // the minutes at which the areas with a fingerprint are seen
+    let mut seen: HashMap<u64, Vec<usize>> = HashMap::new();
+    // the area of every minute; areas with the same fingerprint are compared acre by acre,
+    // so that a collision can't be mistaken for a repeat
+    let mut snapshots: Vec<Snapshot> = vec![];
+    let mut resource_values: Vec<usize> = vec![];
+
+    let mut minute = 0;
+    let mut current = snapshot(&area);
+
+    loop {
+        let minutes = seen.entry(fingerprint(&current)).or_default();
+
+        if let Some(start) = minutes.iter().find(|start| snapshots[**start] == current) {
+            let cycle = Cycle {
+                start: *start,
+                length: minute - start,
+            };
+            return (Some(cycle), resource_values);
+        }
+
+        minutes.push(minute);
+        resource_values.push(resource_value(&area));
+
+        if minute == max_minutes {
+            return (None, resource_values);
+        }
+
+        step(&mut area);
+        let next = snapshot(&area);
+
+        // the area stopped changing, so every later minute is the same.
+        // this is caught without looking up the area among the ones seen.
+        if next == current {
+            let cycle = Cycle {
+                start: minute,
+                length: 1,
+            };
+            return (Some(cycle), resource_values);
+        }
+
+        snapshots.push(std::mem::replace(&mut current, next));
+        minute += 1;
+    }
+}
+
+// the resource value after some minutes, given the resource values of the minutes ticked through.
+// minutes past the end of the resource values are fast-forwarded within the cycle.
+fn fast_forward(cycle: Option<&Cycle>, resource_values: &[usize], minutes: usize) -> usize {
+    match cycle {
+        Some(cycle) if minutes >= cycle.start => {
+            let offset = (minutes - cycle.start) % cycle.length;
+            return resource_values[cycle.start + offset];
+        }
+        _ => {
+            return resource_values[minutes];
+        }
+    }
+}
+
+// the resource values after each of the given minutes, from a single search for the cycle
+pub fn get_resource_values_after(area: Area, minutes: &[usize]) -> (Vec<usize>, Cycle) {
+    let (cycle, resource_values) = find_cycle(area);
+
+    let values = minutes
+        .iter()
+        .map(|minutes| fast_forward(Some(&cycle), &resource_values, *minutes))
+        .collect();
+
+    return (values, cycle);
+}
+
+// the resource value after some minutes, without ticking past the first repeated area
+pub fn get_resource_value_after(input_string: &str, minutes: usize) -> (usize, Cycle) {
+    let (values, cycle) = get_resource_values_after(generate_area(input_string), &[minutes]);
+    return (values[0], cycle);
+}
+
+pub fn part_2(input_string: &str) -> (usize, Cycle) {
+    return get_resource_value_after(input_string, 1_000_000_000);
+}
+
+pub const SOLVER: Solver = Solver {
+    year: 2018,
+    day: 18,
+    input: include_str!("input.txt"),
+    part_1: |input_string| Ok(part_1(input_string, 10).to_string()),
+    part_2: Some(|input_string| {
+        let (resource_value, _cycle) = part_2(input_string);
+        return Ok(resource_value.to_string());
+    }),
+    examples: &[Example {
+        input: include_str!("../examples/1.txt"),
+        part_1: Some("1147"),
+        part_2: None,
+    }],
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn area_string() {
+        let expected_string = include_str!("input.txt");
+
+        let area = generate_area(expected_string);
+
+        assert_eq!(area.to_string(), expected_string);
+    }
+
+    #[test]
+    fn test_part_1() {
+        let input_string = include_str!("../examples/1.txt").trim();
+
+        assert_eq!(part_1(input_string, 10), 1147);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input_string = include_str!("input.txt");
+
+        let (result, cycle) = part_2(input_string);
+        assert_eq!(result, 188400);
+        assert_eq!(
+            cycle,
+            Cycle {
+                start: 461,
+                length: 28
+            }
+        );
+    }
+
+    #[test]
+    fn test_cycle_fast_forward() {
+        let input_string = include_str!("../examples/1.txt").trim();
+
+        let mut area = generate_area(input_string);
+        let (cycle, _resource_values) = find_cycle(area.clone());
+
+        // the fast-forwarded resource values agree with ticking minute by minute,
+        // before and after the cycle is reached
+        for minutes in 0..(cycle.start + 3 * cycle.length) {
+            let (resource_value, _cycle) = get_resource_value_after(input_string, minutes);
+            assert_eq!(resource_value, area.resource_value());
+            area.step();
+        }
+
+        assert_eq!(get_resource_value_after(input_string, 10).0, 1147);
+
+        let (values, cycle) = get_resource_values_after(generate_area(input_string), &[10, 0, 10]);
+        assert_eq!(
+            values,
+            vec![1147, generate_area(input_string).resource_value(), 1147]
+        );
+        assert_eq!(cycle, find_cycle(generate_area(input_string)).0);
+    }
+
+    #[test]
+    fn test_render() {
+        let area = generate_area(".|\n#.");
+
+        assert_eq!(
+            area.render(),
+            format!(
+                "{}.{}|{}\n{}#{}.{}",
+                Acre::Ground.to_color(),
+                Acre::Tree.to_color(),
+                ANSI_RESET,
+                Acre::Lumberyard.to_color(),
+                Acre::Ground.to_color(),
+                ANSI_RESET
+            )
+        );
+    }
+
+    #[test]
+    fn test_transition_rules() {
+        let input_string = include_str!("input.txt");
+
+        // the puzzle's rule, as a custom rule, takes the unpacked path of find_cycle
+        let area = generate_area(input_string).with_rule(TransitionRule::custom(puzzle_rule));
+        assert_eq!(find_cycle(area), find_cycle(generate_area(input_string)));
+
+        // nothing ever changes
+        let area = generate_area(input_string).with_rule(TransitionRule::custom(|acre, _| *acre));
+        let (cycle, resource_values) = find_cycle(area);
+        assert_eq!(
+            cycle,
+            Cycle {
+                start: 0,
+                length: 1
+            }
+        );
+        assert_eq!(resource_values, vec![255811]);
+
+        // trees grow on every open acre next to a tree, and nothing else happens
+        let rule = TransitionRule::custom(|acre, counts| match acre {
+            Acre::Ground if counts.trees >= 1 => Acre::Tree,
+            _ => *acre,
+        });
+        let mut area = generate_area("...\n.|.\n...\n#..").with_rule(rule);
+        area.step();
+        assert_eq!(area.to_string(), "|||\n|||\n|||\n#..");
+        area.step();
+        assert_eq!(area.to_string(), "|||\n|||\n|||\n#||");
+
+        // the rule survives the area growing
+        area.insert((3, 0), '.');
+        area.step();
+        assert_eq!(area.to_string(), "||||\n||||\n||||\n#|||");
+    }
+
+    #[test]
+    fn test_parallel_tick() {
+        let input_string = include_str!("input.txt");
+
+        let mut area = generate_area(input_string);
+        let mut parallel_area = area.clone().with_parallel(true);
+
+        for _ in 0..100 {
+            area.step();
+
+            // every minute is a full sweep
+            parallel_area.changed = None;
+            parallel_area.step();
+
+            assert_eq!(parallel_area, area);
+            assert_eq!(parallel_area.changed, area.changed);
+        }
+
+        let rule = TransitionRule::custom(|acre, counts| match acre {
+            Acre::Ground if counts.trees >= 1 => Acre::Tree,
+            _ => *acre,
+        });
+        let mut area = generate_area(input_string).with_rule(rule.clone());
+        let mut parallel_area = generate_area(input_string)
+            .with_rule(rule)
+            .with_parallel(true);
+
+        for _ in 0..10 {
+            area.step();
+            parallel_area.step();
+            assert_eq!(parallel_area, area);
+        }
+    }
+
+    #[test]
+    fn test_fixed_point() {
+        // the trees spread over the open acres, and then nothing changes
+        let input_string = r###"
+|.........
+|.........
+|.........
+|.........
+|.........
+    "###
+        .trim();
+
+        let (cycle, resource_values) = find_cycle(generate_area(input_string));
+        assert_eq!(
+            cycle,
+            Cycle {
+                start: 18,
+                length: 1
+            }
+        );
+        assert_eq!(resource_values.len(), cycle.start + 1);
+
+        let mut area = generate_area(input_string);
+        for _ in 0..cycle.start {
+            area.step();
+        }
+        let fixed_point = area.clone();
+        area.step();
+        assert_eq!(area, fixed_point);
+        assert_eq!(area.num_of_trees(), 50);
+
+        // without lumberyards, the resource value is 0 forever
+        let (resource_value, _cycle) = get_resource_value_after(input_string, 1_000_000_000);
+        assert_eq!(resource_value, 0);
+
+        // an area that never changes is a fixed point from the start
+        let (cycle, _resource_values) = find_cycle(generate_area("..\n.."));
+        assert_eq!(
+            cycle,
+            Cycle {
+                start: 0,
+                length: 1
+            }
+        );
+
+        let (_result, cycle) = part_2(include_str!("input.txt"));
+        assert!(!cycle.is_fixed_point());
+    }
+
+    #[test]
+    fn test_neighbor_counts() {
+        // the sliding window agrees with counting the adjacent acres one by one,
+        // including for areas of a single row or column
+        for input_string in [
+            include_str!("input.txt"),
+            "|",
+            "|#.|",
+            "|\n#\n.\n|",
+            "#|\n|#",
+        ] {
+            for parallel in [false, true] {
+                let mut area = generate_area(input_string).with_parallel(parallel);
+                area.count_neighbors();
+
+                for y in 0..area.get_height() {
+                    for x in 0..area.get_width() {
+                        let position = (x as i32, y as i32);
+                        let index = area.get_index(&position).unwrap();
+                        assert_eq!(
+                            area.neighbor_counts[index],
+                            area.get_neighbor_counts(&position)
+                        );
+                    }
+                }
+            }
+        }
+
+        let mut area = generate_area("#|\n|#");
+        area.count_neighbors();
+        assert_eq!(
+            area.neighbor_counts[0],
+            NeighborCounts {
+                ground: 0,
+                trees: 2,
+                lumberyards: 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_area_api() {
+        let input_string = include_str!("input.txt");
+        let area: Area = input_string.parse().unwrap();
+
+        assert_eq!(area.resource_value_after(0), area.resource_value());
+        assert_eq!(area.resource_value_after(10), 763804);
+        assert_eq!(area.resource_value_after(1_000_000_000), 188400);
+
+        // the area itself doesn't change
+        assert_eq!(area, generate_area(input_string));
+
+        // minutes before, at and past the start of the cycle agree with stepping minute by minute
+        let mut stepped_area = area.clone();
+        let mut resource_values = vec![];
+        for _ in 0..600 {
+            resource_values.push(stepped_area.resource_value());
+            stepped_area.step();
+        }
+        for minute in [1, 2, 100, 460, 461, 462, 488, 489, 490, 599] {
+            assert_eq!(
+                area.resource_value_after(minute),
+                resource_values[minute],
+                "after {} minutes",
+                minute
+            );
+        }
+
+        // custom rules are fast-forwarded too
+        let area = area.with_rule(TransitionRule::custom(|acre, _| *acre));
+        assert_eq!(area.resource_value_after(1_000_000_000), 255811);
+
+        assert_eq!(
+            "..#\n.|x".parse::<Area>(),
+            Err("Invalid acre at 2,1: 'x'".to_string())
+        );
+        assert_eq!(
+            "..#\n.|".parse::<Area>(),
+            Err("Invalid row 1: expected 3 acres, found 2".to_string())
+        );
+    }
+
+    #[test]
+    fn test_generate() {
+        let area = Area::generate(300, 200, 0.3, 0.1, 2018);
+        assert_eq!(area.get_width(), 300);
+        assert_eq!(area.get_height(), 200);
+
+        // roughly the given densities
+        let num_of_acres = 300 * 200;
+        assert!((area.num_of_trees() as f64 / num_of_acres as f64 - 0.3).abs() < 0.01);
+        assert!((area.num_of_lumberyards() as f64 / num_of_acres as f64 - 0.1).abs() < 0.01);
+
+        // the same seed generates the same area
+        assert_eq!(area, Area::generate(300, 200, 0.3, 0.1, 2018));
+        assert_ne!(area, Area::generate(300, 200, 0.3, 0.1, 2019));
+
+        let area = Area::generate(10, 10, 1.0, 0.0, 0);
+        assert_eq!(area.num_of_trees(), 100);
+        let area = Area::generate(10, 10, 0.0, 0.0, 0);
+        assert_eq!(area.resource_value(), 0);
+    }
+
+    #[test]
+    fn test_stress() {
+        let area = Area::generate(300, 300, 0.3, 0.1, 2018);
+
+        // every way of ticking the area agrees at the start
+        let mut parallel_area = area.clone().with_parallel(true);
+        let mut packed_area = PackedArea::from_area(&area);
+
+        for _ in 0..20 {
+            parallel_area.step();
+            packed_area.step();
+            assert_eq!(packed_area.to_area(), parallel_area);
+        }
+
+        // the bit-packed area is what the cycle search runs on
+        for _ in 20..10_000 {
+            packed_area.step();
+        }
+
+        let area = packed_area.to_area();
+        assert_eq!(PackedArea::from_area(&area), packed_area);
+        assert_eq!(
+            area.num_of_trees() + area.num_of_lumberyards() + area.to_string().matches('.').count(),
+            300 * 300
+        );
+        assert_eq!(area.resource_value(), packed_area.resource_value());
+    }
+
+    #[test]
+    fn test_to_image() {
+        let area = generate_area(".|\n#.\n||");
+        let image = area.to_image(3);
+
+        assert_eq!(image.dimensions(), (6, 9));
+
+        let get_rgb = |x: u32, y: u32| -> Color {
+            let [red, green, blue, _alpha] = image.get_pixel(x, y).0;
+            return [red, green, blue];
+        };
+        assert_eq!(get_rgb(0, 0), Acre::Ground.to_rgb());
+        assert_eq!(get_rgb(3, 2), Acre::Tree.to_rgb());
+        assert_eq!(get_rgb(2, 3), Acre::Lumberyard.to_rgb());
+        assert_eq!(get_rgb(5, 8), Acre::Tree.to_rgb());
+    }
+
+    #[test]
+    fn test_sparse_tick() {
+        let input_string = include_str!("input.txt");
+
+        let mut area = generate_area(input_string);
+        let mut full_sweep_area = area.clone();
+        let mut num_of_sparse_ticks = 0;
+
+        for _ in 0..600 {
+            if let Some(changed) = &area.changed {
+                if changed.len() * SPARSE_TICK_RATIO <= area.acres.len() {
+                    num_of_sparse_ticks += 1;
+                }
+            }
+
+            area.step();
+
+            // forget the changed acres, so that every minute is a full sweep
+            full_sweep_area.changed = None;
+            full_sweep_area.step();
+
+            assert_eq!(area, full_sweep_area);
+        }
+
+        assert!(num_of_sparse_ticks > 0);
+
+        // a lone tree never changes, and neither does anything around it
+        let mut area = generate_area(&".....\n..|..\n.....\n".repeat(4));
+        let expected = area.clone();
+        area.step();
+        assert_eq!(area.changed, Some(vec![]));
+        area.step();
+        assert_eq!(area, expected);
+    }
+
+    #[test]
+    fn test_toroidal() {
+        // the next minute of an area, with every adjacent position wrapped around independently
+        let wrapped_step = |area: &Area| -> Area {
+            let width = area.get_width() as i32;
+            let height = area.get_height() as i32;
+            let mut next_area = area.clone();
+
+            for y in 0..height {
+                for x in 0..width {
+                    let mut counts = NeighborCounts::default();
+                    for dy in -1..=1 {
+                        for dx in -1..=1 {
+                            if dx == 0 && dy == 0 {
+                                continue;
+                            }
+                            let position =
+                                ((x + dx).rem_euclid(width), (y + dy).rem_euclid(height));
+                            counts.add(area.get(&position).unwrap());
+                        }
+                    }
+
+                    let acre = puzzle_rule(&area.get(&(x, y)).unwrap(), &counts);
+                    let index = area.get_index(&(x, y)).unwrap();
+                    next_area.acres[index] = acre;
+                }
+            }
+
+            return next_area;
+        };
+
+        // boards that are too narrow or too short for their edges to be 3 acres apart
+        for (width, height) in [(50, 50), (13, 7), (1, 9), (9, 1), (2, 2), (1, 1)] {
+            let mut area = Area::generate(width, height, 0.4, 0.2, 7).with_toroidal(true);
+            let mut expected = area.clone();
+            let mut parallel_area = area.clone().with_parallel(true);
+            let mut full_sweep_area = area.clone();
+
+            for _ in 0..100 {
+                area.step();
+                expected = wrapped_step(&expected);
+                assert_eq!(area, expected);
+
+                parallel_area.changed = None;
+                parallel_area.step();
+                assert_eq!(parallel_area, expected);
+
+                full_sweep_area.changed = None;
+                full_sweep_area.step();
+                assert_eq!(full_sweep_area, expected);
+            }
+        }
+
+        // every acre of a 3x3 toroidal area is adjacent to every other acre,
+        // but with clipped edges, only the acre in the middle is adjacent to all three trees
+        let input_string = r###"
+|.|
+...
+|..
+    "###
+        .trim();
+        let mut area = generate_area(input_string);
+        area.step();
+        assert_eq!(area.to_string(), "|.|\n.|.\n|..");
+
+        let mut area = generate_area(input_string).with_toroidal(true);
+        assert!(area.is_toroidal());
+        area.step();
+        assert_eq!(area.to_string(), "|||\n|||\n|||");
+
+        // the cycle is found without the bit-packed area
+        let area = Area::generate(30, 30, 0.4, 0.2, 11).with_toroidal(true);
+        let (cycle, resource_values) = find_cycle(area.clone());
+        let mut expected = area.clone();
+        for _ in 0..(cycle.start + cycle.length) {
+            expected = wrapped_step(&expected);
+        }
+        let mut cycle_start = area;
+        for _ in 0..cycle.start {
+            cycle_start = wrapped_step(&cycle_start);
+        }
+        assert_eq!(expected, cycle_start);
+        assert_eq!(resource_values[cycle.start], cycle_start.resource_value());
+    }
+
+    #[test]
+    fn test_fingerprint() {
+        let input_string = include_str!("input.txt");
+
+        let mut area = generate_area(input_string);
+        let mut packed_area = PackedArea::from_area(&area);
+
+        // the fingerprint only depends on the acres
+        let other_area = generate_area(input_string)
+            .with_parallel(true)
+            .with_toroidal(true)
+            .with_rule(TransitionRule::custom(|acre, _| *acre));
+        assert_eq!(other_area.fingerprint(), area.fingerprint());
+
+        let mut fingerprints = HashSet::new();
+        for _ in 0..400 {
+            assert_eq!(packed_area.snapshot(), area.snapshot());
+            assert_eq!(packed_area.snapshot().fingerprint(), area.fingerprint());
+            fingerprints.insert(area.fingerprint());
+
+            area.step();
+            packed_area.step();
+        }
+
+        // none of the areas before the cycle repeat
+        assert_eq!(fingerprints.len(), 400);
+
+        // the fingerprint is the same from run to run
+        assert_eq!(Area::new().fingerprint(), 0x8820_1fb9_60ff_6465);
+        assert_eq!(generate_area("|#\n.|").fingerprint(), 0x9f53_4bfc_9135_f6e4);
+
+        // areas with the same acres in a different shape differ
+        assert_ne!(
+            generate_area("||").fingerprint(),
+            generate_area("|\n|").fingerprint()
+        );
+
+        // fingerprints that always collide slow the cycle search down, but don't change its results
+        let area = generate_area(input_string);
+        let expected = find_cycle_within(&area, usize::MAX);
+        let (cycle, resource_values) = find_cycle_of(
+            PackedArea::from_area(&area),
+            PackedArea::step,
+            PackedArea::resource_value,
+            PackedArea::snapshot,
+            |_| 0,
+            usize::MAX,
+        );
+        assert_eq!(cycle, expected.0);
+        assert_eq!(resource_values, expected.1);
+        assert_eq!(
+            cycle,
+            Some(Cycle {
+                start: 461,
+                length: 28
+            })
+        );
+    }
+
+    #[test]
+    fn example() {
+        let input_string = include_str!("../examples/1.txt").trim();
+
+        // the area after every minute, as printed in the puzzle
+        let snapshots = [
+            // after 1 minute
+            r###"
+.......##.
+......|###
+.|..|...#.
+..|#||...#
+..##||.|#|
+...#||||..
+||...|||..
+|||||.||.|
+||||||||||
+....||..|.
+    "###,
+            // after 2 minutes
+            r###"
+.......#..
+......|#..
+.|.|||....
+..##|||..#
+..###|||#|
+...#|||||.
+|||||||||.
+||||||||||
+||||||||||
+.|||||||||
+    "###,
+            // after 3 minutes
+            r###"
+.......#..
+....|||#..
+.|.||||...
+..###|||.#
+...##|||#|
+.||##|||||
+||||||||||
+||||||||||
+||||||||||
+||||||||||
+    "###,
+            // after 4 minutes
+            r###"
+.....|.#..
+...||||#..
+.|.#||||..
+..###||||#
+...###||#|
+|||##|||||
+||||||||||
+||||||||||
+||||||||||
+||||||||||
+    "###,
+            // after 5 minutes
+            r###"
+....|||#..
+...||||#..
+.|.##||||.
+..####|||#
+.|.###||#|
+|||###||||
+||||||||||
+||||||||||
+||||||||||
+||||||||||
+    "###,
+            // after 6 minutes
+            r###"
+...||||#..
+...||||#..
+.|.###|||.
+..#.##|||#
+|||#.##|#|
+|||###||||
+||||#|||||
+||||||||||
+||||||||||
+||||||||||
+    "###,
+            // after 7 minutes
+            r###"
+...||||#..
+..||#|##..
+.|.####||.
+||#..##||#
+||##.##|#|
+|||####|||
+|||###||||
+||||||||||
+||||||||||
+||||||||||
+    "###,
+            // after 8 minutes
+            r###"
+..||||##..
+..|#####..
+|||#####|.
+||#...##|#
+||##..###|
+||##.###||
+|||####|||
+||||#|||||
+||||||||||
+||||||||||
+    "###,
+            // after 9 minutes
+            r###"
+..||###...
+.||#####..
+||##...##.
+||#....###
+|##....##|
+||##..###|
+||######||
+|||###||||
+||||||||||
+||||||||||
+    "###,
+            // after 10 minutes
+            r###"
+.||##.....
+||###.....
+||##......
+|##.....##
+|##.....##
+|##....##|
+||##.####|
+||#####|||
+||||#|||||
+||||||||||
+    "###,
+        ];
+
+        let mut area = generate_area(input_string);
+        let mut packed_area = PackedArea::from_area(&area);
+
+        // initial
+        assert_eq!(area.to_string(), input_string);
+
+        for (minute, expected) in snapshots.iter().enumerate() {
+            area.step();
+            packed_area.step();
+
+            assert_eq!(
+                area.to_string(),
+                expected.trim(),
+                "after {} minutes",
+                minute + 1
+            );
+            assert_eq!(packed_area.to_area(), area, "after {} minutes", minute + 1);
+        }
+
+        assert_eq!(area.resource_value(), 1147);
+    }
+}
@@ -2,487 +2,326 @@
 
 // imports
 
-use rayon::prelude::*;
-use std::collections::HashMap;
-use std::hash::{Hash, Hasher};
+use day_18_2018::{get_resource_values_after, Area};
+use solver::load_input;
+use std::path::Path;
+use std::time::Duration;
 
 // code
 
-type Coordinate = (i32, i32);
-
-type CollectionArea = HashMap<Coordinate, Acre>;
-
-trait Transitions {
-    fn up(&self) -> Coordinate;
-    fn down(&self) -> Coordinate;
-    fn left(&self) -> Coordinate;
-    fn right(&self) -> Coordinate;
-}
-
-impl Transitions for Coordinate {
-    fn up(&self) -> Coordinate {
-        let (x, y) = self;
-        return (*x, y - 1);
-    }
-
-    fn down(&self) -> Coordinate {
-        let (x, y) = self;
-        return (*x, y + 1);
-    }
-
-    fn left(&self) -> Coordinate {
-        let (x, y) = self;
-        return (x - 1, *y);
-    }
-
-    fn right(&self) -> Coordinate {
-        let (x, y) = self;
-        return (x + 1, *y);
-    }
+// pixels per acre in GIF frames
+const GIF_TILE_SIZE: u32 = 4;
+
+struct Options {
+    // redraw the area every minute
+    animate: bool,
+    // time between the minutes of the animation
+    delay: Duration,
+    // number of minutes to animate
+    minutes: usize,
+    // write the animation to a GIF at this path
+    gif_path: Option<String>,
+    // print the counts of the acres of the first n minutes as CSV
+    csv_minutes: Option<usize>,
+    // tick the area with a parallel sweep of its rows.
+    // the answers are found with a bit-packed area that has no parallel sweep,
+    // so this only applies to --csv, --gif, --animate, or a --toroidal area.
+    parallel: bool,
+    // wrap the edges of the area around
+    toroidal: bool,
+    // path to the input file; - reads from stdin
+    path: Option<String>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-enum Acre {
-    Ground,
-    Tree,
-    Lumberyard,
-}
-
-impl Acre {
-    fn next(&self, adjacent_acres: Vec<Acre>) -> Self {
-        match self {
-            Acre::Ground => {
-                // An open acre will become filled with trees if three or more
-                // adjacent acres contained trees. Otherwise, nothing happens.
-                let num_of_adjacent_trees = adjacent_acres
-                    .par_iter()
-                    .filter(|s| **s == Acre::Tree)
-                    .count();
-
-                if num_of_adjacent_trees >= 3 {
-                    return Acre::Tree;
-                }
-
-                return self.clone();
+// parse command line arguments:
+// [--animate] [--gif <path>] [--delay <milliseconds>] [--minutes <n>] [--csv <n>] [--parallel] [--toroidal]
+// [path]
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let mut options = Options {
+        animate: false,
+        delay: Duration::from_millis(100),
+        minutes: 10,
+        gif_path: None,
+        csv_minutes: None,
+        parallel: false,
+        toroidal: false,
+        path: None,
+    };
+
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--animate" => {
+                options.animate = true;
             }
-            Acre::Tree => {
-                // An acre filled with trees will become a lumberyard if three or more
-                // adjacent acres were lumberyards. Otherwise, nothing happens.
-                let num_of_adjacent_lumberyards = adjacent_acres
-                    .par_iter()
-                    .filter(|s| **s == Acre::Lumberyard)
-                    .count();
-
-                if num_of_adjacent_lumberyards >= 3 {
-                    return Acre::Lumberyard;
-                }
-                return self.clone();
+            "--delay" => {
+                let delay: u64 = args
+                    .next()
+                    .ok_or_else(|| "Expected milliseconds after --delay".to_string())?
+                    .parse()
+                    .map_err(|_| "Invalid milliseconds for --delay".to_string())?;
+                options.delay = Duration::from_millis(delay);
             }
-            Acre::Lumberyard => {
-                // An acre containing a lumberyard will remain a lumberyard if it was adjacent
-                // to at least one other lumberyard and at least one acre containing trees.
-                // Otherwise, it becomes open.
-                let num_of_adjacent_lumberyards = adjacent_acres
-                    .par_iter()
-                    .filter(|s| **s == Acre::Lumberyard)
-                    .count();
-
-                let num_of_adjacent_trees = adjacent_acres
-                    .par_iter()
-                    .filter(|s| **s == Acre::Tree)
-                    .count();
-
-                if num_of_adjacent_lumberyards >= 1 && num_of_adjacent_trees >= 1 {
-                    return self.clone();
-                }
-
-                return Acre::Ground;
+            "--minutes" => {
+                options.minutes = args
+                    .next()
+                    .ok_or_else(|| "Expected a number of minutes after --minutes".to_string())?
+                    .parse()
+                    .map_err(|_| "Invalid number of minutes for --minutes".to_string())?;
             }
-        }
-    }
-}
-
-#[derive(Debug, Clone, Eq)]
-struct Area {
-    area: CollectionArea,
-    max_y: i32,
-    max_x: i32,
-}
-
-impl PartialEq for Area {
-    fn eq(&self, other: &Self) -> bool {
-        let equal_max_y = self.max_y == other.max_y;
-        let equal_max_x = self.max_x == other.max_x;
-        let equal_string = self.to_string() == other.to_string();
-
-        equal_max_y && equal_max_x && equal_string
-    }
-}
-
-impl Hash for Area {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        self.max_y.hash(state);
-        self.max_x.hash(state);
-        self.to_string().hash(state);
-    }
-}
-
-impl Area {
-    fn new() -> Self {
-        Area {
-            area: HashMap::new(),
-            max_y: 0,
-            max_x: 0,
-        }
-    }
-
-    fn num_of_trees(&self) -> usize {
-        return self.area.values().filter(|s| **s == Acre::Tree).count();
-    }
-
-    fn num_of_lumberyards(&self) -> usize {
-        return self
-            .area
-            .values()
-            .filter(|s| **s == Acre::Lumberyard)
-            .count();
-    }
-
-    #[allow(dead_code)]
-    fn to_string(&self) -> String {
-        let mut map_string: Vec<String> = vec![];
-
-        for y in 0..=self.max_y {
-            let mut row_string = String::from("");
-
-            for x in 0..=self.max_x {
-                let position = (x, y);
-
-                match self.area.get(&position) {
-                    None => {
-                        unreachable!();
-                    }
-                    Some(acre) => match acre {
-                        Acre::Ground => {
-                            row_string.push_str(".");
-                        }
-                        Acre::Tree => {
-                            row_string.push_str("|");
-                        }
-                        Acre::Lumberyard => {
-                            row_string.push_str("#");
-                        }
-                    },
-                }
+            "--gif" => {
+                let path = args
+                    .next()
+                    .ok_or_else(|| "Expected a path after --gif".to_string())?;
+                options.gif_path = Some(path.clone());
             }
-
-            map_string.push(row_string);
-        }
-
-        return map_string.join("\n");
-    }
-
-    fn insert(&mut self, position: Coordinate, acre: char) {
-        let (x, y) = position;
-
-        if x > self.max_x {
-            self.max_x = x;
-        }
-
-        if y > self.max_y {
-            self.max_y = y;
-        }
-
-        match acre {
-            '.' => {
-                self.area.insert(position, Acre::Ground);
+            "--parallel" => {
+                options.parallel = true;
             }
-            '|' => {
-                self.area.insert(position, Acre::Tree);
+            "--toroidal" => {
+                options.toroidal = true;
             }
-            '#' => {
-                self.area.insert(position, Acre::Lumberyard);
+            "--csv" => {
+                let minutes = args
+                    .next()
+                    .ok_or_else(|| "Expected a number of minutes after --csv".to_string())?
+                    .parse()
+                    .map_err(|_| "Invalid number of minutes for --csv".to_string())?;
+                options.csv_minutes = Some(minutes);
             }
             _ => {
-                unreachable!();
+                if arg.starts_with("--") {
+                    return Err(format!("Unknown argument: {}", arg));
+                }
+
+                if options.path.is_some() {
+                    return Err(format!("Unexpected argument: {}", arg));
+                }
+
+                options.path = Some(arg.clone());
             }
         }
     }
 
-    fn tick(&mut self) {
-        let prev_area = &self.area;
-
-        let next_area: CollectionArea = prev_area
-            .par_iter()
-            .map(|(coord, acre)| {
-                let adjacent = get_adjacent(&prev_area, &coord);
-
-                // Changes happen across all acres simultaneously,
-                // each of them using the state of all acres at the beginning of the minute
-                // and changing to their new form by the end of that same minute.
-                // Changes that happen during the minute don't affect each other.
-
-                // ✨ magic
-                let next_acre = acre.next(adjacent);
-
-                return (coord.clone(), next_acre);
-            })
-            .collect();
-
-        self.area = next_area;
+    let ticks_area = options.csv_minutes.is_some()
+        || options.gif_path.is_some()
+        || options.animate
+        || options.toroidal;
+
+    if options.parallel && !ticks_area {
+        return Err(
+            "--parallel only applies to --csv, --gif, --animate, or --toroidal; \
+             the answers are found with a bit-packed area that is ticked sequentially"
+                .to_string(),
+        );
     }
-}
 
-fn get_adjacent(area: &CollectionArea, position: &Coordinate) -> Vec<Acre> {
-    let adjacent: Vec<Coordinate> = vec![
-        // clockwise
-        position.up(),
-        position.up().right(),
-        position.right(),
-        position.down().right(),
-        position.down(),
-        position.down().left(),
-        position.left(),
-        position.up().left(),
-    ];
-
-    let result: Vec<Acre> = adjacent
-        .into_iter()
-        .map(|coord| area.get(&coord))
-        .filter(|s| s.is_some())
-        .map(|s| s.unwrap().clone())
-        .collect();
-
-    return result;
+    return Ok(options);
 }
 
-fn generate_area(input_string: &str) -> Area {
-    let mut area = Area::new();
-
-    for (y, line) in input_string.trim().lines().enumerate() {
-        let line = line.trim();
-
-        for (x, acre) in line.chars().enumerate() {
-            let position: Coordinate = (x as i32, y as i32);
-            area.insert(position, acre);
+// redraw the area every minute, starting from the initial area
+fn animate(mut area: Area, options: &Options) -> Result<(), String> {
+    let frames = (0..=options.minutes).map(|minute| {
+        if minute > 0 {
+            area.step();
         }
-    }
 
-    return area;
+        return format!(
+            "Minute: {}\n{}\nTrees: {} Lumberyards: {} Resource value: {}\n",
+            minute,
+            area.render(),
+            area.num_of_trees(),
+            area.num_of_lumberyards(),
+            area.resource_value()
+        );
+    });
+
+    return terminal::animate(frames, options.delay);
 }
 
-fn part_1(input_string: &str, ticks: i32) -> usize {
-    let mut area = generate_area(input_string);
-
-    for _ in 1..=ticks {
-        area.tick();
-    }
+// render every minute to a frame of a GIF, starting from the initial area
+fn save_gif(mut area: Area, path: &str, options: &Options) -> Result<(), String> {
+    let frames = (0..=options.minutes).map(|minute| {
+        if minute > 0 {
+            area.step();
+        }
+        return area.to_image(GIF_TILE_SIZE);
+    });
 
-    return area.num_of_lumberyards() * area.num_of_trees();
+    return grid_image::save_gif(Path::new(path), frames, options.delay);
 }
 
-fn part_2(input_string: &str) -> usize {
-    let mut area = generate_area(input_string);
-
-    let mut seen: HashMap<Area, i32> = HashMap::new();
-    // let mut lookup = HashMap::new();
-    let mut area_num = 0;
-
-    let mut ticks = 0;
+// the counts of the acres of every minute from 0 up to minutes, as CSV
+fn get_csv(mut area: Area, minutes: usize) -> String {
+    let mut csv = String::from("minute,trees,lumberyards,resource_value\n");
 
-    loop {
-        if seen.contains_key(&area) {
-            break;
+    for minute in 0..=minutes {
+        if minute > 0 {
+            area.step();
         }
-        seen.insert(area.clone(), area_num);
-        // lookup.insert(area_num, area.clone());
-        area.tick();
 
-        ticks += 1;
-        area_num += 1;
+        csv.push_str(&format!(
+            "{},{},{},{}\n",
+            minute,
+            area.num_of_trees(),
+            area.num_of_lumberyards(),
+            area.resource_value()
+        ));
     }
 
-    // invariant: area is the first area of the cycle
-
-    let cycle_length = seen.len() as i32 - seen.get(&area).unwrap() + 1;
-
-    let remaining_ticks = 1_000_000_000 - ticks;
-
-    // additional ticks to perform
-    let additional_ticks = remaining_ticks % (cycle_length - 1);
-
-    // additional ticks left to perform are mapped to [0, cycle_length), or
-    // [0, cycle_length - 1].
-    // with the current area being the first pattern in the cycle (i.e. 0 ticks to perform)
-
-    // let area_num = seen.get(&area).unwrap();
-    // let area = lookup.get(&(area_num + additional_ticks)).unwrap();
-
-    for _ in 1..=additional_ticks {
-        area.tick();
-    }
-
-    return area.num_of_lumberyards() * area.num_of_trees();
+    return csv;
 }
 
 fn main() {
-    let input_string = include_str!("input.txt");
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let options = parse_args(&args).unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        std::process::exit(1);
+    });
+
+    let input_string = load_input(options.path.as_deref()).unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        std::process::exit(1);
+    });
+
+    let area = input_string
+        .parse::<Area>()
+        .unwrap_or_else(|err| {
+            eprintln!("{}", err);
+            std::process::exit(1);
+        })
+        .with_parallel(options.parallel)
+        .with_toroidal(options.toroidal);
+
+    if let Some(minutes) = options.csv_minutes {
+        print!("{}", get_csv(area, minutes));
+        return;
+    }
 
-    let part_1_result = part_1(input_string, 10);
+    if let Some(path) = &options.gif_path {
+        save_gif(area, path, &options).unwrap_or_else(|err| {
+            eprintln!("{}", err);
+            std::process::exit(1);
+        });
+        return;
+    }
 
-    println!("Part 1: {}", part_1_result);
+    if options.animate {
+        animate(area, &options).unwrap_or_else(|err| {
+            eprintln!("{}", err);
+            std::process::exit(1);
+        });
+        return;
+    }
 
-    println!("Part 2: {}", part_2(input_string));
+    let (resource_values, cycle) = get_resource_values_after(area, &[10, 1_000_000_000]);
+    println!("Part 1: {}", resource_values[0]);
+    println!("Part 2: {}", resource_values[1]);
+
+    if cycle.is_fixed_point() {
+        println!("Steady state: reached at minute {}", cycle.start);
+    } else {
+        println!(
+            "Cycle: starts at minute {}, repeats every {} minutes",
+            cycle.start, cycle.length
+        );
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use day_18_2018::{generate_area, part_2};
 
     #[test]
-    fn area_string() {
-        let expected_string = include_str!("input.txt");
-
-        let area = generate_area(expected_string);
-
-        assert_eq!(area.to_string(), expected_string);
+    fn test_parse_args() {
+        let to_args = |args: Vec<&str>| -> Vec<String> {
+            return args.into_iter().map(|x| x.to_string()).collect();
+        };
+
+        let options = parse_args(&to_args(vec![])).unwrap();
+        assert!(!options.animate);
+        assert_eq!(options.delay, Duration::from_millis(100));
+        assert_eq!(options.minutes, 10);
+
+        let options = parse_args(&to_args(vec![
+            "--animate",
+            "--delay",
+            "250",
+            "--minutes",
+            "500",
+        ]))
+        .unwrap();
+        assert!(options.animate);
+        assert_eq!(options.delay, Duration::from_millis(250));
+        assert_eq!(options.minutes, 500);
+
+        assert!(parse_args(&to_args(vec!["--foo"])).is_err());
+        assert!(parse_args(&to_args(vec!["--delay"])).is_err());
+        assert!(parse_args(&to_args(vec!["--delay", "-1"])).is_err());
+        assert!(parse_args(&to_args(vec!["--minutes", "x"])).is_err());
+
+        assert_eq!(parse_args(&to_args(vec![])).unwrap().csv_minutes, None);
+        let options = parse_args(&to_args(vec!["--csv", "1000"])).unwrap();
+        assert_eq!(options.csv_minutes, Some(1000));
+        assert!(parse_args(&to_args(vec!["--csv"])).is_err());
+
+        assert_eq!(options.gif_path, None);
+        let options =
+            parse_args(&to_args(vec!["--gif", "forest.gif", "--minutes", "500"])).unwrap();
+        assert_eq!(options.gif_path, Some("forest.gif".to_string()));
+        assert_eq!(options.minutes, 500);
+        assert!(parse_args(&to_args(vec!["--gif"])).is_err());
+
+        assert!(!options.parallel);
+        let options = parse_args(&to_args(vec!["--parallel", "--csv", "10"])).unwrap();
+        assert!(options.parallel);
+
+        assert!(!options.toroidal);
+        let options = parse_args(&to_args(vec!["--toroidal", "--animate"])).unwrap();
+        assert!(options.toroidal);
+        assert!(options.animate);
+
+        // the answers are found without ticking the area itself
+        assert!(parse_args(&to_args(vec!["--parallel"])).is_err());
+        let options = parse_args(&to_args(vec!["--parallel", "--toroidal"])).unwrap();
+        assert!(options.parallel);
+
+        assert_eq!(options.path, None);
+        let options = parse_args(&to_args(vec!["--toroidal", "-"])).unwrap();
+        assert_eq!(options.path, Some("-".to_string()));
+        assert!(parse_args(&to_args(vec!["input.txt", "input.txt"])).is_err());
     }
 
     #[test]
-    fn test_part_1() {
-        let input_string = r###"
-.#.#...|#.
-.....#|##|
-.|..|...#.
-..|#.....#
-#.#|||#|#|
-...#.||...
-.|....|...
-||...#|.#|
-|.||||..|.
-...#.|..|.
-    "###
-        .trim();
-
-        assert_eq!(part_1(input_string, 10), 1147);
-    }
+    fn test_csv() {
+        let input_string = include_str!("../examples/1.txt").trim();
 
-    #[test]
-    fn example() {
-        let input_string = r###"
-.#.#...|#.
-.....#|##|
-.|..|...#.
-..|#.....#
-#.#|||#|#|
-...#.||...
-.|....|...
-||...#|.#|
-|.||||..|.
-...#.|..|.
-    "###
-        .trim();
-
-        let mut area = generate_area(input_string);
-
-        // initial
-        assert_eq!(area.to_string(), input_string);
-
-        // after 1 minute
-        area.tick();
-
-        let result = r###"
-.......##.
-......|###
-.|..|...#.
-..|#||...#
-..##||.|#|
-...#||||..
-||...|||..
-|||||.||.|
-||||||||||
-....||..|.
-    "###
-        .trim();
-
-        assert_eq!(area.to_string(), result);
-
-        // after 2 minutes
-        area.tick();
-
-        let result = r###"
-.......#..
-......|#..
-.|.|||....
-..##|||..#
-..###|||#|
-...#|||||.
-|||||||||.
-||||||||||
-||||||||||
-.|||||||||
-    "###
-        .trim();
-
-        assert_eq!(area.to_string(), result);
-
-        // after 3 minutes
-        area.tick();
-
-        let result = r###"
-.......#..
-....|||#..
-.|.||||...
-..###|||.#
-...##|||#|
-.||##|||||
-||||||||||
-||||||||||
-||||||||||
-||||||||||
-    "###
-        .trim();
-
-        assert_eq!(area.to_string(), result);
-
-        // after 4 minutes
-        area.tick();
-
-        let result = r###"
-.....|.#..
-...||||#..
-.|.#||||..
-..###||||#
-...###||#|
-|||##|||||
-||||||||||
-||||||||||
-||||||||||
-||||||||||
-    "###
-        .trim();
-
-        assert_eq!(area.to_string(), result);
-
-        // after 10 minutes
-        for _ in 5..=10 {
-            area.tick();
-        }
+        let csv = get_csv(generate_area(input_string), 10);
+        let lines: Vec<&str> = csv.lines().collect();
 
-        let result = r###"
-.||##.....
-||###.....
-||##......
-|##.....##
-|##.....##
-|##....##|
-||##.####|
-||#####|||
-||||#|||||
-||||||||||
-    "###
-        .trim();
-
-        assert_eq!(area.to_string(), result);
+        assert_eq!(lines.len(), 12);
+        assert_eq!(lines[0], "minute,trees,lumberyards,resource_value");
+        assert_eq!(lines[11], "10,37,31,1147");
+
+        // the rows repeat with the cycle found by part 2
+        let input_string = include_str!("input.txt");
+        let (_result, cycle) = part_2(input_string);
+
+        let csv = get_csv(generate_area(input_string), cycle.start + 2 * cycle.length);
+        let counts: Vec<&str> = csv
+            .lines()
+            .skip(1)
+            .map(|line| line.split_once(',').unwrap().1)
+            .collect();
+
+        for minute in cycle.start..(cycle.start + cycle.length) {
+            assert_eq!(counts[minute], counts[minute + cycle.length]);
+        }
+        assert_ne!(
+            counts[cycle.start - 1],
+            counts[cycle.start - 1 + cycle.length]
+        );
     }
 }
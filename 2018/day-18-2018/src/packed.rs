@@ -0,0 +1,334 @@
+// the lumber collection area packed into bits: one bit plane for the trees, and one for the lumberyards.
+// an acre in neither plane is open ground.
+//
+// every row starts at a new u64 word, and acre x of a row is bit x % 64 of word x / 64.
+// the bits past the width of the area are always 0.
+//
+// a minute is computed 64 acres at a time: the adjacent acres are the words of the neighboring rows,
+// shifted by one acre, and they're added up bit by bit into 4-bit counts.
+//
+// the two bit planes on their own are also a compact snapshot of any area, which the cycle search
+// keeps for every minute it ticks through.
+
+// imports
+
+use crate::{Acre, Area};
+use std::hash::{Hash, Hasher};
+
+// code
+
+const WORD_SIZE: usize = 64;
+
+// a count of 0 to 8 for each of the 64 acres of a word; bit i of counts[k] is bit k of the count of acre i
+type Counts = [u64; 4];
+
+fn add(counts: &mut Counts, bits: u64) {
+    let mut carry = bits;
+    for count in counts.iter_mut() {
+        let sum = *count ^ carry;
+        carry &= *count;
+        *count = sum;
+    }
+}
+
+fn at_least_one(counts: &Counts) -> u64 {
+    return counts[0] | counts[1] | counts[2] | counts[3];
+}
+
+fn at_least_three(counts: &Counts) -> u64 {
+    return (counts[0] & counts[1]) | counts[2] | counts[3];
+}
+
+// https://en.wikipedia.org/wiki/Fowler%E2%80%93Noll%E2%80%93Vo_hash_function
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+// the acres of an area, packed into bit planes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snapshot {
+    width: usize,
+    height: usize,
+    trees: Vec<u64>,
+    lumberyards: Vec<u64>,
+}
+
+impl Snapshot {
+    pub fn from_area(area: &Area) -> Self {
+        let width = area.get_width();
+        let height = area.get_height();
+        let words_per_row = width.div_ceil(WORD_SIZE);
+
+        let mut trees = vec![0; words_per_row * height];
+        let mut lumberyards = vec![0; words_per_row * height];
+
+        for (index, acre) in area.acres.iter().enumerate() {
+            let (x, y) = (index % width, index / width);
+            let word = y * words_per_row + x / WORD_SIZE;
+            let bit = 1 << (x % WORD_SIZE);
+
+            match acre {
+                Acre::Ground => {}
+                Acre::Tree => trees[word] |= bit,
+                Acre::Lumberyard => lumberyards[word] |= bit,
+            }
+        }
+
+        return Snapshot {
+            width,
+            height,
+            trees,
+            lumberyards,
+        };
+    }
+
+    // 64-bit FNV-1a hash of the dimensions and the bit planes, in little-endian byte order.
+    // unlike the Hash impls, this doesn't depend on the hasher, so it's the same from run to run.
+    pub fn fingerprint(&self) -> u64 {
+        let dimensions = [self.width as u64, self.height as u64];
+        let words = dimensions
+            .iter()
+            .chain(self.trees.iter())
+            .chain(self.lumberyards.iter());
+
+        let mut hash = FNV_OFFSET_BASIS;
+        for word in words {
+            for byte in word.to_le_bytes().iter() {
+                hash ^= *byte as u64;
+                hash = hash.wrapping_mul(FNV_PRIME);
+            }
+        }
+
+        return hash;
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PackedArea {
+    width: usize,
+    height: usize,
+    words_per_row: usize,
+    // indexed by y * words_per_row + x / 64
+    trees: Vec<u64>,
+    lumberyards: Vec<u64>,
+    // the next minute is computed into these, and they're swapped afterwards
+    next_trees: Vec<u64>,
+    next_lumberyards: Vec<u64>,
+}
+
+// the next_* buffers are scratch space, and aren't part of the state of the area
+impl PartialEq for PackedArea {
+    fn eq(&self, other: &Self) -> bool {
+        return self.width == other.width
+            && self.height == other.height
+            && self.trees == other.trees
+            && self.lumberyards == other.lumberyards;
+    }
+}
+
+impl Eq for PackedArea {}
+
+impl Hash for PackedArea {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.width.hash(state);
+        self.height.hash(state);
+        self.trees.hash(state);
+        self.lumberyards.hash(state);
+    }
+}
+
+impl PackedArea {
+    pub fn from_area(area: &Area) -> Self {
+        let Snapshot {
+            width,
+            height,
+            trees,
+            lumberyards,
+        } = Snapshot::from_area(area);
+
+        return PackedArea {
+            width,
+            height,
+            words_per_row: width.div_ceil(WORD_SIZE),
+            next_trees: trees.clone(),
+            next_lumberyards: lumberyards.clone(),
+            trees,
+            lumberyards,
+        };
+    }
+
+    pub fn to_area(&self) -> Area {
+        let rows: Vec<Vec<Acre>> = (0..self.height)
+            .map(|y| {
+                (0..self.width)
+                    .map(|x| {
+                        let word = y * self.words_per_row + x / WORD_SIZE;
+                        let bit = 1 << (x % WORD_SIZE);
+
+                        if self.trees[word] & bit != 0 {
+                            return Acre::Tree;
+                        }
+                        if self.lumberyards[word] & bit != 0 {
+                            return Acre::Lumberyard;
+                        }
+                        return Acre::Ground;
+                    })
+                    .collect()
+            })
+            .collect();
+
+        return Area::from_rows(rows);
+    }
+
+    // the bit planes, without the scratch buffers
+    pub fn snapshot(&self) -> Snapshot {
+        return Snapshot {
+            width: self.width,
+            height: self.height,
+            trees: self.trees.clone(),
+            lumberyards: self.lumberyards.clone(),
+        };
+    }
+
+    pub fn num_of_trees(&self) -> usize {
+        return self
+            .trees
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum();
+    }
+
+    pub fn num_of_lumberyards(&self) -> usize {
+        return self
+            .lumberyards
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum();
+    }
+
+    pub fn resource_value(&self) -> usize {
+        return self.num_of_lumberyards() * self.num_of_trees();
+    }
+
+    // the bits of the last word of a row that are within the width of the area
+    fn get_last_word_mask(&self) -> u64 {
+        let remainder = self.width % WORD_SIZE;
+        if remainder == 0 {
+            return !0;
+        }
+        return (1 << remainder) - 1;
+    }
+
+    // add the acres of a plane adjacent to the acres of word i of row y into counts.
+    // the acre itself is only added for the rows above and below.
+    fn add_adjacent(&self, plane: &[u64], counts: &mut Counts, y: usize, i: usize) {
+        let get_word = |y: isize, i: isize| -> u64 {
+            if y < 0 || y as usize >= self.height || i < 0 || i as usize >= self.words_per_row {
+                return 0;
+            }
+            return plane[y as usize * self.words_per_row + i as usize];
+        };
+
+        for dy in -1..=1 {
+            let row = y as isize + dy;
+            let word = get_word(row, i as isize);
+
+            // shift the acres to the left and to the right of every acre into its bit
+            let left = (word << 1) | (get_word(row, i as isize - 1) >> (WORD_SIZE - 1));
+            let right = (word >> 1) | (get_word(row, i as isize + 1) << (WORD_SIZE - 1));
+
+            add(counts, left);
+            add(counts, right);
+            if dy != 0 {
+                add(counts, word);
+            }
+        }
+    }
+
+    pub fn step(&mut self) {
+        let last_word_mask = self.get_last_word_mask();
+
+        for y in 0..self.height {
+            for i in 0..self.words_per_row {
+                let mut adjacent_trees: Counts = [0; 4];
+                let mut adjacent_lumberyards: Counts = [0; 4];
+                self.add_adjacent(&self.trees, &mut adjacent_trees, y, i);
+                self.add_adjacent(&self.lumberyards, &mut adjacent_lumberyards, y, i);
+
+                let index = y * self.words_per_row + i;
+                let trees = self.trees[index];
+                let lumberyards = self.lumberyards[index];
+                let ground = !(trees | lumberyards);
+
+                // open acres with three or more adjacent trees become trees,
+                // and trees with fewer than three adjacent lumberyards stay trees
+                let next_trees = (ground & at_least_three(&adjacent_trees))
+                    | (trees & !at_least_three(&adjacent_lumberyards));
+
+                // trees with three or more adjacent lumberyards become lumberyards,
+                // and lumberyards next to a lumberyard and a tree stay lumberyards
+                let next_lumberyards = (trees & at_least_three(&adjacent_lumberyards))
+                    | (lumberyards
+                        & at_least_one(&adjacent_lumberyards)
+                        & at_least_one(&adjacent_trees));
+
+                let mask = if i == self.words_per_row - 1 {
+                    last_word_mask
+                } else {
+                    !0
+                };
+
+                self.next_trees[index] = next_trees & mask;
+                self.next_lumberyards[index] = next_lumberyards & mask;
+            }
+        }
+
+        std::mem::swap(&mut self.trees, &mut self.next_trees);
+        std::mem::swap(&mut self.lumberyards, &mut self.next_lumberyards);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate_area;
+
+    #[test]
+    fn test_packed_area() {
+        let input_string = include_str!("input.txt");
+
+        let mut area = generate_area(input_string);
+        let mut packed_area = PackedArea::from_area(&area);
+        assert_eq!(packed_area.to_area(), area);
+
+        for _ in 0..600 {
+            area.step();
+            packed_area.step();
+
+            assert_eq!(packed_area.to_area(), area);
+            assert_eq!(packed_area.resource_value(), area.resource_value());
+        }
+
+        // rows that span several words, or end exactly at a word boundary
+        for width in [1, 63, 64, 65, 130] {
+            let row: String = (0..width)
+                .map(|x| match (x * 7 + x / 3) % 5 {
+                    0 | 1 => '|',
+                    2 => '#',
+                    _ => '.',
+                })
+                .collect();
+            let rows: Vec<String> = (0..5)
+                .map(|y| row[y % width..].to_string() + &row[..y % width])
+                .collect();
+
+            let mut area = generate_area(&rows.join("\n"));
+            let mut packed_area = PackedArea::from_area(&area);
+
+            for _ in 0..50 {
+                area.step();
+                packed_area.step();
+                assert_eq!(packed_area.to_area(), area);
+            }
+        }
+    }
+}
@@ -0,0 +1,380 @@
+// https://adventofcode.com/2018/day/19
+
+// imports
+
+use elfcode::{Flow, Instruction, Machine, Opcode, Program, Value, NUM_OF_REGISTERS};
+use solver::{Example, Solver};
+
+// code
+
+// the registers of the nested loops that sum the divisors of a number
+#[derive(Debug, PartialEq)]
+struct DivisorLoop {
+    // the index of the instruction that starts the inner loop over again
+    start: usize,
+    // the candidate divisor, counted up by the outer loop
+    divisor: usize,
+    // the multiplier, counted up by the inner loop
+    multiplier: usize,
+    // scratch register for the products and the comparisons
+    scratch: usize,
+    // the number whose divisors are summed
+    number: usize,
+    // the sum of the divisors found so far
+    sum: usize,
+}
+
+fn is_register(value: Value, register: usize) -> bool {
+    return value == register as Value;
+}
+
+// whether the instruction is the opcode with inputs a and b in either order, and output c
+fn is_commutative(instruction: &Instruction, opcode: Opcode, a: usize, b: usize, c: usize) -> bool {
+    if instruction.opcode != opcode || !is_register(instruction.c, c) {
+        return false;
+    }
+
+    return (is_register(instruction.a, a) && is_register(instruction.b, b))
+        || (is_register(instruction.a, b) && is_register(instruction.b, a));
+}
+
+fn is_increment(instruction: &Instruction, register: usize) -> bool {
+    return instruction.opcode == Opcode::Addi
+        && is_register(instruction.a, register)
+        && instruction.b == 1
+        && is_register(instruction.c, register);
+}
+
+fn is_jump_to(instruction: &Instruction, ip: usize, target: usize) -> bool {
+    // the instruction pointer moves on to the next instruction after the jump
+    return instruction.opcode == Opcode::Seti
+        && instruction.a + 1 == target as Value
+        && is_register(instruction.c, ip);
+}
+
+// recognize the nested loops that sum the divisors of a number, starting at the instruction at start:
+//
+//   start:      seti 1 _ M       M = 1
+//   start + 1:  mulr D M S       do { if D * M == N {
+//   start + 2:  eqrr S N S
+//   start + 3:  addr S ip ip
+//   start + 4:  addi ip 1 ip
+//   start + 5:  addr D A A           A += D
+//   start + 6:  addi M 1 M       } M += 1
+//   start + 7:  gtrr M N S       } while M <= N
+//   start + 8:  addr ip S ip
+//   start + 9:  seti start _ ip
+//   start + 10: addi D 1 D       D += 1, and the outer loop starts over while D <= N
+//   start + 11: gtrr D N S
+//   start + 12: addr S ip ip
+//   start + 13: seti (start - 1) _ ip
+//
+// the registers may be any register, and the inputs of addr, mulr, and eqrr may be in either order.
+fn find_divisor_loop(program: &Program, start: usize) -> Option<DivisorLoop> {
+    let ip = program.instruction_pointer_bound?;
+    let instructions = program.instructions.get(start..(start + 14))?;
+
+    let multiplier = instructions[0].c as usize;
+    if instructions[0].opcode != Opcode::Seti
+        || instructions[0].a != 1
+        || instructions[0].c < 0
+        || multiplier == ip
+    {
+        return None;
+    }
+
+    // mulr D M S
+    let product = &instructions[1];
+    if product.opcode != Opcode::Mulr || product.c < 0 {
+        return None;
+    }
+    let scratch = product.c as usize;
+    let divisor = if is_register(product.a, multiplier) {
+        product.b
+    } else if is_register(product.b, multiplier) {
+        product.a
+    } else {
+        return None;
+    };
+    if divisor < 0 {
+        return None;
+    }
+    let divisor = divisor as usize;
+
+    // eqrr S N S
+    let comparison = &instructions[2];
+    if comparison.opcode != Opcode::Eqrr || !is_register(comparison.c, scratch) {
+        return None;
+    }
+    let number = if is_register(comparison.a, scratch) {
+        comparison.b
+    } else if is_register(comparison.b, scratch) {
+        comparison.a
+    } else {
+        return None;
+    };
+    if number < 0 {
+        return None;
+    }
+    let number = number as usize;
+
+    // addr D A A
+    let accumulation = &instructions[5];
+    if accumulation.opcode != Opcode::Addr || accumulation.c < 0 {
+        return None;
+    }
+    let sum = accumulation.c as usize;
+
+    let registers = [ip, divisor, multiplier, scratch, number, sum];
+    let are_distinct = registers
+        .iter()
+        .enumerate()
+        .all(|(index, register)| !registers[(index + 1)..].contains(register));
+
+    let are_registers = registers
+        .iter()
+        .all(|register| *register < NUM_OF_REGISTERS);
+
+    let is_divisor_loop = are_distinct
+        && are_registers
+        && is_commutative(&instructions[3], Opcode::Addr, scratch, ip, ip)
+        && is_increment(&instructions[4], ip)
+        && is_commutative(accumulation, Opcode::Addr, divisor, sum, sum)
+        && is_increment(&instructions[6], multiplier)
+        && instructions[7]
+            == Instruction::new(
+                Opcode::Gtrr,
+                multiplier as Value,
+                number as Value,
+                scratch as Value,
+            )
+        && is_commutative(&instructions[8], Opcode::Addr, ip, scratch, ip)
+        && is_jump_to(&instructions[9], ip, start + 1)
+        && is_increment(&instructions[10], divisor)
+        && instructions[11]
+            == Instruction::new(
+                Opcode::Gtrr,
+                divisor as Value,
+                number as Value,
+                scratch as Value,
+            )
+        && is_commutative(&instructions[12], Opcode::Addr, scratch, ip, ip)
+        && start > 0
+        && is_jump_to(&instructions[13], ip, start);
+
+    if !is_divisor_loop {
+        return None;
+    }
+
+    return Some(DivisorLoop {
+        start,
+        divisor,
+        multiplier,
+        scratch,
+        number,
+        sum,
+    });
+}
+
+// the sum of the divisors of the number that are at least as large as from
+fn sum_of_divisors(number: Value, from: Value) -> Value {
+    let mut sum = 0;
+    let mut divisor = 1;
+
+    while divisor * divisor <= number {
+        if number % divisor == 0 {
+            let other_divisor = number / divisor;
+
+            if divisor >= from {
+                sum += divisor;
+            }
+
+            if other_divisor != divisor && other_divisor >= from {
+                sum += other_divisor;
+            }
+        }
+
+        divisor += 1;
+    }
+
+    return sum;
+}
+
+// run the program, skipping over the nested loops that sum the divisors of a number.
+//
+// whenever the inner loop is about to start over, the sum of the remaining divisors is added all at once,
+// and the registers are left as they are when both loops are done.
+fn run_with_fast_path(machine: &mut Machine) -> Result<(), String> {
+    let divisor_loop = (0..machine.program.instructions.len())
+        .find_map(|start| find_divisor_loop(&machine.program, start));
+
+    let divisor_loop = match divisor_loop {
+        None => {
+            machine.run()?;
+            return Ok(());
+        }
+        Some(divisor_loop) => divisor_loop,
+    };
+
+    machine.run_with_hook(|machine| {
+        if machine.instruction_pointer != divisor_loop.start as Value {
+            return Flow::Continue;
+        }
+
+        let registers = &mut machine.registers;
+        let number = registers[divisor_loop.number];
+        let divisor = registers[divisor_loop.divisor];
+
+        if divisor < 1 || divisor > number {
+            return Flow::Continue;
+        }
+
+        registers[divisor_loop.sum] += sum_of_divisors(number, divisor);
+        registers[divisor_loop.divisor] = number + 1;
+        registers[divisor_loop.multiplier] = number + 1;
+        registers[divisor_loop.scratch] = 1;
+
+        // both loops are done
+        machine.instruction_pointer = (divisor_loop.start + 14) as Value;
+
+        return Flow::Continue;
+    })?;
+
+    return Ok(());
+}
+
+// the value of register 0 once the program halts, with register 0 starting at the given value
+fn run_program(input_string: &str, register_0: Value) -> Result<Value, String> {
+    let program = Program::parse(input_string)?;
+
+    let mut machine = Machine::new(program);
+    machine.registers[0] = register_0;
+
+    run_with_fast_path(&mut machine)?;
+
+    return Ok(machine.registers[0]);
+}
+
+pub fn part_1(input_string: &str) -> Result<Value, String> {
+    return run_program(input_string, 0);
+}
+
+// A new background process immediately spins up in its place.
+// It appears identical, but on closer inspection, you notice that this time,
+// register 0 started with the value 1.
+pub fn part_2(input_string: &str) -> Result<Value, String> {
+    return run_program(input_string, 1);
+}
+
+pub const SOLVER: Solver = Solver {
+    year: 2018,
+    day: 19,
+    input: include_str!("input.txt"),
+    part_1: |input_string| Ok(part_1(input_string)?.to_string()),
+    part_2: Some(|input_string| Ok(part_2(input_string)?.to_string())),
+    examples: &[Example {
+        input: include_str!("../examples/1.txt"),
+        part_1: Some("6"),
+        part_2: None,
+    }],
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let input_string = include_str!("../examples/1.txt");
+
+        assert_eq!(part_1(input_string), Ok(6));
+
+        let program = Program::parse(input_string).unwrap();
+        assert_eq!(
+            (0..program.instructions.len()).find_map(|start| find_divisor_loop(&program, start)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_sum_of_divisors() {
+        assert_eq!(sum_of_divisors(1, 1), 1);
+        assert_eq!(sum_of_divisors(12, 1), 1 + 2 + 3 + 4 + 6 + 12);
+        assert_eq!(sum_of_divisors(12, 4), 4 + 6 + 12);
+        assert_eq!(sum_of_divisors(16, 1), 1 + 2 + 4 + 8 + 16);
+        assert_eq!(sum_of_divisors(13, 2), 13);
+    }
+
+    #[test]
+    fn test_find_divisor_loop() {
+        let program = Program::parse(include_str!("input.txt")).unwrap();
+
+        assert_eq!(
+            find_divisor_loop(&program, 2),
+            Some(DivisorLoop {
+                start: 2,
+                divisor: 3,
+                multiplier: 1,
+                scratch: 2,
+                number: 5,
+                sum: 0,
+            })
+        );
+        assert_eq!(find_divisor_loop(&program, 1), None);
+        assert_eq!(find_divisor_loop(&program, 3), None);
+    }
+
+    #[test]
+    fn test_fast_path() {
+        // the program with a small number runs just as well instruction by instruction
+        let input_string = r###"
+#ip 4
+seti 0 0 0
+seti 1 5 3
+seti 1 9 1
+mulr 3 1 2
+eqrr 2 5 2
+addr 2 4 4
+addi 4 1 4
+addr 3 0 0
+addi 1 1 1
+gtrr 1 5 2
+addr 4 2 4
+seti 2 9 4
+addi 3 1 3
+gtrr 3 5 2
+addr 2 4 4
+seti 1 8 4
+mulr 4 4 4
+        "###;
+
+        for number in &[1, 2, 12, 28, 97, 100] {
+            let program = Program::parse(input_string).unwrap();
+
+            let mut machine = Machine::new(program.clone());
+            machine.registers[5] = *number;
+            machine.run().unwrap();
+
+            let mut fast_machine = Machine::new(program);
+            fast_machine.registers[5] = *number;
+            run_with_fast_path(&mut fast_machine).unwrap();
+
+            assert_eq!(machine.registers[0], sum_of_divisors(*number, 1));
+            assert_eq!(fast_machine.registers, machine.registers);
+            assert_eq!(
+                fast_machine.instruction_pointer,
+                machine.instruction_pointer
+            );
+        }
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(part_1(include_str!("input.txt")), Ok(930));
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(part_2(include_str!("input.txt")), Ok(10628484));
+    }
+}
@@ -0,0 +1,409 @@
+// https://adventofcode.com/2018/day/20
+
+// imports
+
+use solver::{Example, Solver};
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+
+// code
+
+type Coordinate = (i32, i32);
+
+pub type Distance = usize;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Direction {
+    North,
+    South,
+    West,
+    East,
+}
+
+impl Direction {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            'N' => Some(Direction::North),
+            'S' => Some(Direction::South),
+            'W' => Some(Direction::West),
+            'E' => Some(Direction::East),
+            _ => None,
+        }
+    }
+
+    // the room on the other side of the door in this direction
+    fn step(self, (x, y): Coordinate) -> Coordinate {
+        match self {
+            Direction::North => (x, y - 1),
+            Direction::South => (x, y + 1),
+            Direction::West => (x - 1, y),
+            Direction::East => (x + 1, y),
+        }
+    }
+}
+
+// a branch group that is being parsed: ( ... | ... )
+struct BranchGroup {
+    // the rooms the routes were at when the branch group was opened.
+    // every option of the branch group starts from these rooms.
+    starts: HashSet<Coordinate>,
+    // the rooms where the options that were already parsed end up
+    ends: HashSet<Coordinate>,
+}
+
+// the doors of the facility; every room is mapped to the rooms behind its doors
+pub struct Map {
+    doors: HashMap<Coordinate, HashSet<Coordinate>>,
+}
+
+impl Map {
+    fn new() -> Self {
+        let mut doors = HashMap::new();
+        doors.insert((0, 0), HashSet::new());
+
+        Map { doors }
+    }
+
+    fn add_door(&mut self, from: Coordinate, to: Coordinate) {
+        self.doors.entry(from).or_default().insert(to);
+        self.doors.entry(to).or_default().insert(from);
+    }
+
+    fn has_door(&self, from: Coordinate, to: Coordinate) -> bool {
+        return self
+            .doors
+            .get(&from)
+            .map(|rooms| rooms.contains(&to))
+            .unwrap_or(false);
+    }
+
+    // the fewest number of doors to pass through to reach every room, starting from the current room at (0, 0)
+    fn room_distances(&self) -> HashMap<Coordinate, Distance> {
+        let mut distances: HashMap<Coordinate, Distance> = HashMap::new();
+        let mut queue: VecDeque<Coordinate> = VecDeque::new();
+
+        distances.insert((0, 0), 0);
+        queue.push_back((0, 0));
+
+        while let Some(room) = queue.pop_front() {
+            let distance = distances[&room];
+
+            for next_room in &self.doors[&room] {
+                if distances.contains_key(next_room) {
+                    continue;
+                }
+
+                distances.insert(*next_room, distance + 1);
+                queue.push_back(*next_room);
+            }
+        }
+
+        return distances;
+    }
+
+    // draw the map the way the puzzle does: X is the current room, . is a room, | and - are doors, # are walls
+    pub fn to_string(&self) -> String {
+        let xs = self.doors.keys().map(|(x, _y)| *x);
+        let ys = self.doors.keys().map(|(_x, y)| *y);
+
+        let min_x = xs.clone().min().unwrap();
+        let max_x = xs.max().unwrap();
+        let min_y = ys.clone().min().unwrap();
+        let max_y = ys.max().unwrap();
+
+        let width = 2 * (max_x - min_x + 1) + 1;
+        let wall = "#".repeat(width as usize);
+
+        let mut lines = vec![wall.clone()];
+
+        for y in min_y..=max_y {
+            let mut rooms = "#".to_string();
+            let mut walls_below = "#".to_string();
+
+            for x in min_x..=max_x {
+                if (x, y) == (0, 0) {
+                    rooms.push('X');
+                } else if self.doors.contains_key(&(x, y)) {
+                    rooms.push('.');
+                } else {
+                    rooms.push('#');
+                }
+
+                rooms.push(if self.has_door((x, y), (x + 1, y)) {
+                    '|'
+                } else {
+                    '#'
+                });
+
+                walls_below.push(if self.has_door((x, y), (x, y + 1)) {
+                    '-'
+                } else {
+                    '#'
+                });
+                walls_below.push('#');
+            }
+
+            lines.push(rooms);
+            lines.push(walls_below);
+        }
+
+        // the row below the last rooms is the outer wall
+        lines.pop();
+        lines.push(wall);
+
+        return lines.join("\n");
+    }
+}
+
+// follow every route described by the regex, and record every door that is passed through.
+//
+// the routes are followed with an explicit stack of the branch groups that are open; rather than following
+// every route one at a time, the set of rooms that the routes could be at is moved along all at once.
+pub fn parse_input(input_string: &str) -> Result<Map, String> {
+    let input_string = input_string.trim();
+
+    let regex = input_string
+        .strip_prefix('^')
+        .ok_or_else(|| "Expected the regex to start with ^".to_string())?
+        .strip_suffix('$')
+        .ok_or_else(|| "Expected the regex to end with $".to_string())?;
+
+    let mut map = Map::new();
+
+    let mut current: HashSet<Coordinate> = vec![(0, 0)].into_iter().collect();
+    let mut stack: Vec<BranchGroup> = vec![];
+
+    for c in regex.chars() {
+        match c {
+            '(' => {
+                stack.push(BranchGroup {
+                    starts: current.clone(),
+                    ends: HashSet::new(),
+                });
+            }
+            '|' => {
+                let branch_group = stack
+                    .last_mut()
+                    .ok_or_else(|| "Unexpected | outside of a branch group".to_string())?;
+
+                branch_group.ends.extend(current);
+                current = branch_group.starts.clone();
+            }
+            ')' => {
+                let mut branch_group = stack
+                    .pop()
+                    .ok_or_else(|| "Unexpected ) without a matching (".to_string())?;
+
+                // the routes continue from every room where one of the options ended up;
+                // an empty option ends up where the branch group started
+                branch_group.ends.extend(current);
+                current = branch_group.ends;
+            }
+            _ => {
+                let direction =
+                    Direction::from_char(c).ok_or_else(|| format!("Invalid character: {}", c))?;
+
+                current = current
+                    .into_iter()
+                    .map(|room| {
+                        let next_room = direction.step(room);
+                        map.add_door(room, next_room);
+                        return next_room;
+                    })
+                    .collect();
+            }
+        }
+    }
+
+    if !stack.is_empty() {
+        return Err("Expected ) to close every branch group".to_string());
+    }
+
+    return Ok(map);
+}
+
+// the largest number of doors to pass through to reach a room
+pub fn part_1(input_string: &str) -> Result<Distance, String> {
+    let map = parse_input(input_string)?;
+
+    let distance = map.room_distances().values().cloned().max().unwrap();
+
+    return Ok(distance);
+}
+
+// the number of rooms that require passing through at least 1000 doors to reach
+pub fn part_2(input_string: &str) -> Result<usize, String> {
+    let map = parse_input(input_string)?;
+
+    let num_of_rooms = map
+        .room_distances()
+        .values()
+        .filter(|distance| **distance >= 1000)
+        .count();
+
+    return Ok(num_of_rooms);
+}
+
+pub const SOLVER: Solver = Solver {
+    year: 2018,
+    day: 20,
+    input: include_str!("input.txt"),
+    part_1: |input_string| Ok(part_1(input_string)?.to_string()),
+    part_2: Some(|input_string| Ok(part_2(input_string)?.to_string())),
+    examples: &[
+        Example {
+            input: include_str!("../examples/1.txt"),
+            part_1: Some("3"),
+            part_2: None,
+        },
+        Example {
+            input: include_str!("../examples/2.txt"),
+            part_1: Some("10"),
+            part_2: None,
+        },
+        Example {
+            input: include_str!("../examples/3.txt"),
+            part_1: Some("18"),
+            part_2: None,
+        },
+        Example {
+            input: include_str!("../examples/4.txt"),
+            part_1: Some("23"),
+            part_2: None,
+        },
+        Example {
+            input: include_str!("../examples/5.txt"),
+            part_1: Some("31"),
+            part_2: None,
+        },
+    ],
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        let examples: Vec<(&str, Distance)> = vec![
+            (include_str!("../examples/1.txt"), 3),
+            (include_str!("../examples/2.txt"), 10),
+            (include_str!("../examples/3.txt"), 18),
+            (include_str!("../examples/4.txt"), 23),
+            (include_str!("../examples/5.txt"), 31),
+        ];
+
+        for (input_string, distance) in examples {
+            assert_eq!(part_1(input_string), Ok(distance));
+        }
+    }
+
+    #[test]
+    fn test_map() {
+        let map = parse_input("^WNE$").unwrap();
+        let expected = r###"
+#####
+#.|.#
+#-###
+#.|X#
+#####
+        "###;
+        assert_eq!(map.to_string(), expected.trim());
+
+        let map = parse_input("^ENWWW(NEEE|SSE(EE|N))$").unwrap();
+        let expected = r###"
+#########
+#.|.|.|.#
+#-#######
+#.|.|.|.#
+#-#####-#
+#.#.#X|.#
+#-#-#####
+#.|.|.|.#
+#########
+        "###;
+        assert_eq!(map.to_string(), expected.trim());
+
+        let map = parse_input("^ENNWSWW(NEWS|)SSSEEN(WNSE|)EE(SWEN|)NNN$").unwrap();
+        let expected = r###"
+###########
+#.|.#.|.#.#
+#-###-#-#-#
+#.|.|.#.#.#
+#-#####-#-#
+#.#.#X|.#.#
+#-#-#####-#
+#.#.|.|.|.#
+#-###-###-#
+#.|.|.#.|.#
+###########
+        "###;
+        assert_eq!(map.to_string(), expected.trim());
+    }
+
+    #[test]
+    fn test_nested_branches() {
+        // every option of the inner branch group continues from where the outer option left off
+        let map = parse_input("^N(E(N|S)|W)$").unwrap();
+
+        assert!(map.has_door((0, -1), (1, -1)));
+        assert!(map.has_door((1, -1), (1, -2)));
+        assert!(map.has_door((1, -1), (1, 0)));
+        assert!(map.has_door((0, -1), (-1, -1)));
+        assert!(!map.has_door((-1, -1), (-1, -2)));
+        assert_eq!(map.doors.len(), 6);
+
+        // the routes after a branch group continue from every room where its options ended up
+        let map = parse_input("^(N|S(E|W))N$").unwrap();
+
+        assert!(map.has_door((0, -1), (0, -2)));
+        assert!(map.has_door((1, 1), (1, 0)));
+        assert!(map.has_door((-1, 1), (-1, 0)));
+        assert_eq!(map.room_distances().values().cloned().max(), Some(3));
+    }
+
+    #[test]
+    fn test_empty_options() {
+        // an empty option skips the branch group entirely
+        let map = parse_input("^(N|)E$").unwrap();
+
+        assert!(map.has_door((0, 0), (0, -1)));
+        assert!(map.has_door((0, -1), (1, -1)));
+        assert!(map.has_door((0, 0), (1, 0)));
+
+        // an empty option can be anywhere within the branch group
+        let map = parse_input("^(|N)E$").unwrap();
+        assert!(map.has_door((0, 0), (1, 0)));
+        assert!(map.has_door((0, -1), (1, -1)));
+
+        // an empty option within a nested branch group
+        let map = parse_input("^N(E(S|)|)W$").unwrap();
+        assert!(map.has_door((0, -1), (-1, -1)));
+        assert!(map.has_door((1, -1), (0, -1)));
+        assert!(map.has_door((1, 0), (0, 0)));
+        assert_eq!(map.doors.len(), 5);
+
+        // a branch group with only empty options leads nowhere
+        let map = parse_input("^N(|)$").unwrap();
+        assert_eq!(map.doors.len(), 2);
+    }
+
+    #[test]
+    fn test_invalid_regex() {
+        assert!(parse_input("WNE$").is_err());
+        assert!(parse_input("^WNE").is_err());
+        assert!(parse_input("^W(N|E$").is_err());
+        assert!(parse_input("^WN)E$").is_err());
+        assert!(parse_input("^W|E$").is_err());
+        assert!(parse_input("^WXE$").is_err());
+    }
+
+    #[test]
+    fn test_puzzle() {
+        let input_string = include_str!("input.txt");
+
+        assert_eq!(part_1(input_string), Ok(3872));
+        assert_eq!(part_2(input_string), Ok(8600));
+    }
+}
@@ -0,0 +1,509 @@
+// https://adventofcode.com/2018/day/21
+
+// imports
+
+use std::collections::HashSet;
+
+use elfcode::{Flow, Instruction, Machine, Opcode, Program, Status, Value, NUM_OF_REGISTERS};
+use solver::Solver;
+
+// code
+
+// the only instruction that reads register 0: it compares register 0 with another register,
+// and the program halts once they're equal
+#[derive(Debug, PartialEq)]
+struct Comparison {
+    // the index of the instruction
+    index: usize,
+    // the register that register 0 is compared with
+    register: usize,
+}
+
+fn find_comparison(program: &Program) -> Result<Comparison, String> {
+    let reads_register_0 = |instruction: &Instruction| -> bool {
+        return (instruction.opcode.reads_register_a() && instruction.a == 0)
+            || (instruction.opcode.reads_register_b() && instruction.b == 0);
+    };
+
+    let comparisons: Vec<(usize, &Instruction)> = program
+        .instructions
+        .iter()
+        .enumerate()
+        .filter(|(_index, instruction)| reads_register_0(instruction))
+        .collect();
+
+    match comparisons.as_slice() {
+        [(index, instruction)] => {
+            let register = if instruction.a == 0 {
+                instruction.b
+            } else {
+                instruction.a
+            };
+
+            if instruction.opcode != Opcode::Eqrr
+                || register <= 0
+                || register as usize >= NUM_OF_REGISTERS
+                || Some(register as usize) == program.instruction_pointer_bound
+            {
+                return Err(format!(
+                    "Expected register 0 to be compared with another register: {}",
+                    instruction.to_string()
+                ));
+            }
+
+            return Ok(Comparison {
+                index: *index,
+                register: register as usize,
+            });
+        }
+        [] => {
+            return Err("Register 0 is never read".to_string());
+        }
+        _ => {
+            return Err("Register 0 is read by more than one instruction".to_string());
+        }
+    }
+}
+
+// the registers of the loop that divides a number by a constant, by counting up the quotient
+#[derive(Debug, PartialEq)]
+struct DivisionLoop {
+    // the index of the instruction that starts the loop over again
+    start: usize,
+    // the quotient, counted up by the loop
+    quotient: usize,
+    // scratch register for the products and the comparisons
+    scratch: usize,
+    // the number that is divided
+    number: usize,
+    // the constant the number is divided by
+    divisor: Value,
+}
+
+fn is_register(value: Value, register: usize) -> bool {
+    return value == register as Value;
+}
+
+fn is_increment(instruction: &Instruction, register: usize) -> bool {
+    return instruction.opcode == Opcode::Addi
+        && is_register(instruction.a, register)
+        && instruction.b == 1
+        && is_register(instruction.c, register);
+}
+
+fn is_jump_to(instruction: &Instruction, ip: usize, target: usize) -> bool {
+    // the instruction pointer moves on to the next instruction after the jump
+    return instruction.opcode == Opcode::Seti
+        && instruction.a + 1 == target as Value
+        && is_register(instruction.c, ip);
+}
+
+// recognize the loop that divides a number by a constant, starting at the instruction at start:
+//
+//   start:     addi Q 1 S        while (Q + 1) * K <= N {
+//   start + 1: muli S K S
+//   start + 2: gtrr S N S
+//   start + 3: addr S ip ip
+//   start + 4: addi ip 1 ip
+//   start + 5: seti (start + 7) _ ip
+//   start + 6: addi Q 1 Q            Q += 1
+//   start + 7: seti (start - 1) _ ip
+//                                }
+//
+// the registers may be any register, and the inputs of the addr may be in either order.
+fn find_division_loop(program: &Program, start: usize) -> Option<DivisionLoop> {
+    let ip = program.instruction_pointer_bound?;
+    let instructions = program.instructions.get(start..(start + 8))?;
+
+    // addi Q 1 S
+    let quotient = instructions[0].a;
+    let scratch = instructions[0].c;
+    if instructions[0].opcode != Opcode::Addi || instructions[0].b != 1 {
+        return None;
+    }
+
+    // muli S K S
+    let product = &instructions[1];
+    if product.opcode != Opcode::Muli
+        || product.a != scratch
+        || product.c != scratch
+        || product.b <= 0
+    {
+        return None;
+    }
+    let divisor = product.b;
+
+    // gtrr S N S
+    let comparison = &instructions[2];
+    if comparison.opcode != Opcode::Gtrr || comparison.a != scratch || comparison.c != scratch {
+        return None;
+    }
+    let number = comparison.b;
+
+    if [quotient, scratch, number]
+        .iter()
+        .any(|register| *register < 0 || *register as usize >= NUM_OF_REGISTERS)
+    {
+        return None;
+    }
+
+    let quotient = quotient as usize;
+    let scratch = scratch as usize;
+    let number = number as usize;
+
+    let registers = [ip, quotient, scratch, number];
+    let are_distinct = registers
+        .iter()
+        .enumerate()
+        .all(|(index, register)| !registers[(index + 1)..].contains(register));
+
+    let skip = &instructions[3];
+    let is_skip = skip.opcode == Opcode::Addr
+        && is_register(skip.c, ip)
+        && ((is_register(skip.a, scratch) && is_register(skip.b, ip))
+            || (is_register(skip.a, ip) && is_register(skip.b, scratch)));
+
+    let is_division_loop = are_distinct
+        && is_skip
+        && is_increment(&instructions[4], ip)
+        && is_jump_to(&instructions[5], ip, start + 8)
+        && is_increment(&instructions[6], quotient)
+        && is_jump_to(&instructions[7], ip, start);
+
+    if !is_division_loop {
+        return None;
+    }
+
+    return Some(DivisionLoop {
+        start,
+        quotient,
+        scratch,
+        number,
+        divisor,
+    });
+}
+
+// run the program until the hook stops it, skipping over the loop that divides a number by a constant.
+//
+// whenever the loop is about to start over, the quotient is counted up all at once,
+// and the registers are left as they are when the loop is done.
+fn run_with_fast_path(
+    machine: &mut Machine,
+    mut hook: impl FnMut(&mut Machine) -> Flow,
+) -> Result<Status, String> {
+    let division_loop = (0..machine.program.instructions.len())
+        .find_map(|start| find_division_loop(&machine.program, start));
+
+    return machine.run_with_hook(|machine| {
+        if let Some(division_loop) = &division_loop {
+            if machine.instruction_pointer == division_loop.start as Value {
+                let registers = &mut machine.registers;
+                let number = registers[division_loop.number];
+                let quotient = registers[division_loop.quotient];
+
+                if number >= 0 && quotient >= 0 {
+                    registers[division_loop.quotient] =
+                        quotient.max(number / division_loop.divisor);
+                    registers[division_loop.scratch] = 1;
+
+                    // the loop is done
+                    machine.instruction_pointer = (division_loop.start + 8) as Value;
+                }
+            }
+        }
+
+        return hook(machine);
+    });
+}
+
+// the values that register 0 is compared with, in order, until one of them comes up again.
+// the values are compared with register 0 while it's 0, so the program doesn't halt along the way.
+fn halting_values(
+    input_string: &str,
+    mut is_done: impl FnMut(&[Value]) -> bool,
+) -> Result<Vec<Value>, String> {
+    let program = Program::parse(input_string)?;
+    let comparison = find_comparison(&program)?;
+
+    let mut machine = Machine::new(program);
+
+    let mut values: Vec<Value> = vec![];
+    let mut seen: HashSet<Value> = HashSet::new();
+
+    run_with_fast_path(&mut machine, |machine| {
+        if machine.instruction_pointer != comparison.index as Value {
+            return Flow::Continue;
+        }
+
+        let value = machine.registers[comparison.register];
+
+        // the program would run exactly as it did before, from here on out
+        if !seen.insert(value) {
+            return Flow::Stop;
+        }
+
+        values.push(value);
+
+        if is_done(&values) {
+            return Flow::Stop;
+        }
+
+        return Flow::Continue;
+    })?;
+
+    return Ok(values);
+}
+
+// the value of register 0 that halts the program after the fewest instructions
+pub fn part_1(input_string: &str) -> Result<Value, String> {
+    let values = halting_values(input_string, |values| !values.is_empty())?;
+
+    return values
+        .first()
+        .cloned()
+        .ok_or_else(|| "The program halts before register 0 is compared".to_string());
+}
+
+// the value of register 0 that halts the program after the most instructions
+pub fn part_2(input_string: &str) -> Result<Value, String> {
+    let values = halting_values(input_string, |_values| false)?;
+
+    return values
+        .last()
+        .cloned()
+        .ok_or_else(|| "The program halts before register 0 is compared".to_string());
+}
+
+// the name of the register as it reads in the decompiled program
+fn register_name(register: Value, ip: Option<usize>) -> String {
+    if register >= 0 && ip == Some(register as usize) {
+        return "ip".to_string();
+    }
+
+    return format!("r{}", register);
+}
+
+// an input of the instruction; reading the bound register reads the index of the instruction itself
+fn decompile_input(value: Value, is_register: bool, index: usize, ip: Option<usize>) -> String {
+    if !is_register {
+        return value.to_string();
+    }
+
+    if value >= 0 && ip == Some(value as usize) {
+        return index.to_string();
+    }
+
+    return register_name(value, ip);
+}
+
+fn decompile_instruction(instruction: &Instruction, index: usize, ip: Option<usize>) -> String {
+    let opcode = instruction.opcode;
+    let a = decompile_input(instruction.a, opcode.reads_register_a(), index, ip);
+    let b = decompile_input(instruction.b, opcode.reads_register_b(), index, ip);
+
+    let operator = match opcode {
+        Opcode::Addr | Opcode::Addi => Some("+"),
+        Opcode::Mulr | Opcode::Muli => Some("*"),
+        Opcode::Banr | Opcode::Bani => Some("&"),
+        Opcode::Borr | Opcode::Bori => Some("|"),
+        Opcode::Setr | Opcode::Seti => None,
+        Opcode::Gtir | Opcode::Gtri | Opcode::Gtrr => Some(">"),
+        Opcode::Eqir | Opcode::Eqri | Opcode::Eqrr => Some("=="),
+    };
+
+    let is_jump = instruction.c >= 0 && ip == Some(instruction.c as usize);
+
+    if !is_jump {
+        let expression = match operator {
+            None => a,
+            Some(operator) => format!("{} {} {}", a, operator, b),
+        };
+
+        return format!("{} = {}", register_name(instruction.c, ip), expression);
+    }
+
+    // the instruction pointer moves on to the next instruction after the jump
+    let constant_a: Option<Value> = a.parse().ok();
+    let constant_b: Option<Value> = b.parse().ok();
+
+    let is_constant = constant_a.is_some() && (opcode.ignores_b() || constant_b.is_some());
+
+    if is_constant {
+        // every input is either a value or the bound register, which holds the index of the instruction
+        let mut registers = vec![0; NUM_OF_REGISTERS];
+        registers[instruction.c as usize] = index as Value;
+
+        if instruction.execute(&mut registers).is_ok() {
+            return format!("goto {}", registers[instruction.c as usize] + 1);
+        }
+    }
+
+    match (operator, constant_a, constant_b) {
+        (Some("+"), Some(a), None) => {
+            return format!("goto {} + {}", b, a + 1);
+        }
+        (Some("+"), None, Some(b)) => {
+            return format!("goto {} + {}", a, b + 1);
+        }
+        (None, _, _) => {
+            return format!("goto {} + 1", a);
+        }
+        (Some(operator), _, _) => {
+            return format!("goto ({} {} {}) + 1", a, operator, b);
+        }
+    }
+}
+
+// the program as pseudo-code, one line per instruction
+pub fn decompile(program: &Program) -> Vec<String> {
+    let ip = program.instruction_pointer_bound;
+
+    return program
+        .instructions
+        .iter()
+        .enumerate()
+        .map(|(index, instruction)| {
+            return format!(
+                "{:>3}: {}",
+                index,
+                decompile_instruction(instruction, index, ip)
+            );
+        })
+        .collect();
+}
+
+pub const SOLVER: Solver = Solver {
+    year: 2018,
+    day: 21,
+    input: include_str!("input.txt"),
+    part_1: |input_string| Ok(part_1(input_string)?.to_string()),
+    part_2: Some(|input_string| Ok(part_2(input_string)?.to_string())),
+    examples: &[],
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_comparison() {
+        let program = Program::parse(include_str!("input.txt")).unwrap();
+        assert_eq!(
+            find_comparison(&program),
+            Ok(Comparison {
+                index: 28,
+                register: 5
+            })
+        );
+
+        assert!(find_comparison(&Program::parse("#ip 1\nseti 1 0 2").unwrap()).is_err());
+        assert!(find_comparison(&Program::parse("#ip 1\naddr 0 2 2").unwrap()).is_err());
+        assert!(find_comparison(&Program::parse("#ip 1\neqrr 0 1 2").unwrap()).is_err());
+        assert!(
+            find_comparison(&Program::parse("#ip 1\neqrr 0 3 2\neqrr 0 4 2").unwrap()).is_err()
+        );
+    }
+
+    #[test]
+    fn test_find_division_loop() {
+        let program = Program::parse(include_str!("input.txt")).unwrap();
+
+        assert_eq!(
+            find_division_loop(&program, 18),
+            Some(DivisionLoop {
+                start: 18,
+                quotient: 3,
+                scratch: 2,
+                number: 4,
+                divisor: 256,
+            })
+        );
+        assert_eq!(find_division_loop(&program, 17), None);
+        assert_eq!(find_division_loop(&program, 19), None);
+    }
+
+    #[test]
+    fn test_fast_path() {
+        // the program with a small number runs just as well instruction by instruction
+        let input_string = r###"
+#ip 1
+seti 0 0 3
+addi 3 1 2
+muli 2 7 2
+gtrr 2 4 2
+addr 2 1 1
+addi 1 1 1
+seti 8 0 1
+addi 3 1 3
+seti 0 0 1
+setr 3 0 5
+        "###;
+
+        for number in &[0, 1, 6, 7, 8, 48, 49, 50, 1000] {
+            let program = Program::parse(input_string).unwrap();
+
+            let mut machine = Machine::new(program.clone());
+            machine.registers[4] = *number;
+            machine.run().unwrap();
+
+            let mut fast_machine = Machine::new(program);
+            fast_machine.registers[4] = *number;
+            run_with_fast_path(&mut fast_machine, |_machine| Flow::Continue).unwrap();
+
+            assert_eq!(machine.registers[5], number / 7);
+            assert_eq!(fast_machine.registers, machine.registers);
+            assert_eq!(
+                fast_machine.instruction_pointer,
+                machine.instruction_pointer
+            );
+            assert!(fast_machine.num_of_steps <= machine.num_of_steps);
+        }
+    }
+
+    #[test]
+    fn test_halting_values() {
+        let input_string = include_str!("input.txt");
+
+        // the first value halts the program, whether or not the division loop is skipped
+        let program = Program::parse(input_string).unwrap();
+        let mut machine = Machine::new(program);
+        machine.registers[0] = 3941014;
+        assert_eq!(machine.run(), Ok(Status::Halted));
+
+        let values = halting_values(input_string, |values| values.len() >= 3).unwrap();
+        assert_eq!(values.len(), 3);
+        assert_eq!(values[0], 3941014);
+    }
+
+    #[test]
+    fn test_decompile() {
+        let program = Program::parse(include_str!("input.txt")).unwrap();
+        let lines = decompile(&program);
+
+        assert_eq!(lines.len(), program.instructions.len());
+        assert_eq!(lines[0], "  0: r5 = 123");
+        assert_eq!(lines[2], "  2: r5 = r5 == 72");
+        assert_eq!(lines[3], "  3: goto r5 + 4");
+        assert_eq!(lines[4], "  4: goto 1");
+        assert_eq!(lines[13], " 13: r3 = 256 > r4");
+        assert_eq!(lines[15], " 15: goto 17");
+        assert_eq!(lines[28], " 28: r3 = r5 == r0");
+        assert_eq!(lines[30], " 30: goto 6");
+
+        let program = Program::parse("#ip 2\nmulr 2 2 2\nmulr 1 3 2\nsetr 3 0 2").unwrap();
+        assert_eq!(
+            decompile(&program),
+            vec!["  0: goto 1", "  1: goto (r1 * r3) + 1", "  2: goto r3 + 1"]
+        );
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(part_1(include_str!("input.txt")), Ok(3941014));
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(part_2(include_str!("input.txt")), Ok(13775890));
+    }
+}
@@ -0,0 +1,261 @@
+// benchmark the erosion level computation, the expansion of search states, and the fastest route search
+// on the real input.
+//
+// run with: cargo bench -p day-22-2018
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day_22_2018::{
+    parse_input, Bounds, Cave, CaveRules, Coordinate, Depth, RegionType, RiskLevel, SearchStrategy,
+    State, Time, Tool,
+};
+use std::collections::{HashMap, HashSet};
+
+// erosion levels memoized in a HashMap, filled row by row
+fn get_risk_level_memoized(depth: Depth, target: Coordinate, bounds: &Bounds) -> RiskLevel {
+    let mut erosion_levels: HashMap<Coordinate, i32> = HashMap::new();
+    let (max_x, max_y) = bounds.max;
+
+    let mut total_risk = 0;
+
+    for y in 0..=max_y {
+        for x in 0..=max_x {
+            let geologic_index = if (x, y) == (0, 0) || (x, y) == target {
+                0
+            } else if y == 0 {
+                x * 16807
+            } else if x == 0 {
+                y * 48271
+            } else {
+                erosion_levels[&(x - 1, y)] * erosion_levels[&(x, y - 1)]
+            };
+
+            let erosion_level = (geologic_index + depth) % 20183;
+            erosion_levels.insert((x, y), erosion_level);
+
+            total_risk += erosion_level % 3;
+        }
+    }
+
+    return total_risk;
+}
+
+fn get_risk_level_chunked(depth: Depth, target: Coordinate, bounds: &Bounds) -> RiskLevel {
+    let mut cave = Cave::new(depth, target, CaveRules::default());
+    return cave.risk_level_rect(bounds);
+}
+
+fn bench_erosion_levels(c: &mut Criterion) {
+    let (depth, target) = parse_input(include_str!("../src/input.txt")).unwrap();
+
+    // the regions looked at by the part 2 search
+    let mut cave = Cave::new(depth, target, CaveRules::default());
+    let padding = cave.get_safe_padding();
+    let bounds = cave.get_search_bounds(padding);
+
+    assert_eq!(
+        get_risk_level_memoized(depth, target, &bounds),
+        get_risk_level_chunked(depth, target, &bounds)
+    );
+
+    let mut group = c.benchmark_group("erosion levels");
+
+    group.bench_function("hashmap memoized", |b| {
+        b.iter(|| get_risk_level_memoized(black_box(depth), black_box(target), &bounds))
+    });
+
+    group.bench_function("chunked table", |b| {
+        b.iter(|| get_risk_level_chunked(black_box(depth), black_box(target), &bounds))
+    });
+
+    group.finish();
+}
+
+const TOOLS: [Tool; 3] = [Tool::None, Tool::Torch, Tool::ClimbingGear];
+
+// the region types of the search region, indexed by [y * width + x]
+fn get_region_types(cave: &mut Cave, bounds: &Bounds) -> Vec<RegionType> {
+    let (max_x, max_y) = bounds.max;
+    let mut region_types = Vec::with_capacity(((max_x + 1) * (max_y + 1)) as usize);
+
+    for y in 0..=max_y {
+        for x in 0..=max_x {
+            region_types.push(cave.region_type((x, y)));
+        }
+    }
+
+    return region_types;
+}
+
+fn get_adjacent(position: Coordinate) -> [Coordinate; 4] {
+    let (x, y) = position;
+    return [(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)];
+}
+
+// the bookkeeping of the search before the search states were Copy:
+// a HashSet of allowed tools is built for every check, and the edges of every state are collected
+// into a new Vec. cloning the states themselves compiles to the same copy, so the allocations are
+// what this measures.
+fn expand_states_allocating(region_types: &[RegionType], bounds: &Bounds) -> usize {
+    let (max_x, max_y) = bounds.max;
+    let width = max_x + 1;
+    let region_type = |(x, y): Coordinate| &region_types[(y * width + x) as usize];
+
+    let allowed_tools = |region_type: &RegionType| -> HashSet<Tool> {
+        return TOOLS
+            .iter()
+            .filter(|tool| region_type.is_tool_allowed(tool))
+            .copied()
+            .collect();
+    };
+
+    let mut best_costs: HashMap<State, Time> = HashMap::new();
+    let mut best_edges: HashMap<State, State> = HashMap::new();
+
+    for y in 0..=max_y {
+        for x in 0..=max_x {
+            for tool in TOOLS.iter() {
+                let current_state = State {
+                    position: (x, y),
+                    tool: *tool,
+                };
+
+                let mut next_states: Vec<State> = get_adjacent(current_state.position)
+                    .iter()
+                    .filter(|adjacent| bounds.contains(adjacent))
+                    .filter(|adjacent| allowed_tools(region_type(**adjacent)).contains(tool))
+                    .map(|adjacent| State {
+                        position: *adjacent,
+                        tool: *tool,
+                    })
+                    .collect();
+
+                for next_tool in allowed_tools(region_type((x, y))) {
+                    if next_tool != *tool {
+                        next_states.push(State {
+                            position: (x, y),
+                            tool: next_tool,
+                        });
+                    }
+                }
+
+                for next_state in next_states {
+                    best_costs.insert(next_state, 1);
+                    best_edges.insert(next_state, current_state);
+                }
+            }
+        }
+    }
+
+    return best_costs.len() + best_edges.len();
+}
+
+// the same bookkeeping with Copy search states, fixed arrays of allowed tools, and an edge buffer
+// that is reused across states
+fn expand_states_reusing(region_types: &[RegionType], bounds: &Bounds) -> usize {
+    let (max_x, max_y) = bounds.max;
+    let width = max_x + 1;
+    let region_type = |(x, y): Coordinate| &region_types[(y * width + x) as usize];
+
+    let mut best_costs: HashMap<State, Time> = HashMap::new();
+    let mut best_edges: HashMap<State, State> = HashMap::new();
+
+    let mut next_states: Vec<State> = Vec::with_capacity(5);
+
+    for y in 0..=max_y {
+        for x in 0..=max_x {
+            for tool in TOOLS.iter() {
+                let current_state = State {
+                    position: (x, y),
+                    tool: *tool,
+                };
+
+                next_states.clear();
+
+                for adjacent in get_adjacent(current_state.position).iter() {
+                    if bounds.contains(adjacent) && region_type(*adjacent).is_tool_allowed(tool) {
+                        next_states.push(State {
+                            position: *adjacent,
+                            tool: *tool,
+                        });
+                    }
+                }
+
+                for next_tool in TOOLS.iter() {
+                    if next_tool != tool && region_type((x, y)).is_tool_allowed(next_tool) {
+                        next_states.push(State {
+                            position: (x, y),
+                            tool: *next_tool,
+                        });
+                    }
+                }
+
+                for next_state in next_states.iter() {
+                    best_costs.insert(*next_state, 1);
+                    best_edges.insert(*next_state, current_state);
+                }
+            }
+        }
+    }
+
+    return best_costs.len() + best_edges.len();
+}
+
+fn bench_state_expansion(c: &mut Criterion) {
+    let (depth, target) = parse_input(include_str!("../src/input.txt")).unwrap();
+
+    let mut cave = Cave::new(depth, target, CaveRules::default());
+    let padding = cave.get_safe_padding();
+    let bounds = cave.get_search_bounds(padding);
+    let region_types = get_region_types(&mut cave, &bounds);
+
+    assert_eq!(
+        expand_states_allocating(&region_types, &bounds),
+        expand_states_reusing(&region_types, &bounds)
+    );
+
+    let mut group = c.benchmark_group("state expansion");
+    // every state of the search region is expanded in one iteration
+    group.sample_size(10);
+
+    group.bench_function("allocating", |b| {
+        b.iter(|| expand_states_allocating(black_box(&region_types), &bounds))
+    });
+
+    group.bench_function("reusing", |b| {
+        b.iter(|| expand_states_reusing(black_box(&region_types), &bounds))
+    });
+
+    group.finish();
+}
+
+fn bench_fastest_route(c: &mut Criterion) {
+    let (depth, target) = parse_input(include_str!("../src/input.txt")).unwrap();
+
+    let mut cave = Cave::new(depth, target, CaveRules::default());
+    let padding = cave.get_safe_padding();
+    let bounds = cave.get_search_bounds(padding);
+
+    // erosion levels are computed once, so that only the search is measured
+    cave.precompute(&bounds);
+
+    let strategies = vec![
+        ("dijkstra", SearchStrategy::Dijkstra),
+        ("a*", SearchStrategy::AStar),
+    ];
+
+    let mut group = c.benchmark_group("fastest route");
+
+    for (name, strategy) in strategies {
+        group.bench_function(name, |b| b.iter(|| cave.find_route(&bounds, strategy)));
+    }
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_erosion_levels,
+    bench_state_expansion,
+    bench_fastest_route
+);
+criterion_main!(benches);
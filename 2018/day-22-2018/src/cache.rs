@@ -0,0 +1,209 @@
+// on-disk cache of the answers, so that repeated runs with the same puzzle input skip the search.
+//
+// every entry is a file named after its key, e.g. 4002-5-746-1-7.txt for:
+//
+// depth: 4002
+// target: 5,746
+// move time: 1
+// switch tool time: 7
+// part 1: 4479
+// part 2: 1032
+
+use day_22_2018::{parse_coordinate, CaveRules, Coordinate, Depth, RiskLevel, Time};
+use std::path::{Path, PathBuf};
+
+// the puzzle parameters the answers depend on
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CacheKey {
+    pub depth: Depth,
+    pub target: Coordinate,
+    pub rules: CaveRules,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Answers {
+    pub part_1: RiskLevel,
+    pub part_2: Option<Time>,
+}
+
+// $XDG_CACHE_HOME/advent-of-code/day-22-2018, falling back to ~/.cache
+pub fn get_cache_dir() -> Option<PathBuf> {
+    let cache_home = std::env::var_os("XDG_CACHE_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))?;
+
+    return Some(cache_home.join("advent-of-code").join("day-22-2018"));
+}
+
+fn get_cache_path(cache_dir: &Path, key: &CacheKey) -> PathBuf {
+    let (x, y) = key.target;
+    let file_name = format!(
+        "{}-{}-{}-{}-{}.txt",
+        key.depth, x, y, key.rules.move_time, key.rules.switch_tool_time
+    );
+    return cache_dir.join(file_name);
+}
+
+fn to_string(key: &CacheKey, answers: &Answers) -> String {
+    let (x, y) = key.target;
+    let part_2 = match answers.part_2 {
+        Some(time) => time.to_string(),
+        None => "none".to_string(),
+    };
+
+    return format!(
+        "depth: {}\ntarget: {},{}\nmove time: {}\nswitch tool time: {}\npart 1: {}\npart 2: {}\n",
+        key.depth, x, y, key.rules.move_time, key.rules.switch_tool_time, answers.part_1, part_2
+    );
+}
+
+// parse a cache entry; None if it's malformed or if it's for another key
+fn parse_entry(key: &CacheKey, entry: &str) -> Option<Answers> {
+    let mut lines = entry.trim().lines().map(|line| line.trim());
+
+    let mut next_value = |name: &str| -> Option<String> {
+        let line = lines.next()?;
+        let value = line.strip_prefix(name)?.strip_prefix(':')?;
+        return Some(value.trim().to_string());
+    };
+
+    let depth: Depth = next_value("depth")?.parse().ok()?;
+    let target = parse_coordinate(&next_value("target")?)?;
+    let move_time: Time = next_value("move time")?.parse().ok()?;
+    let switch_tool_time: Time = next_value("switch tool time")?.parse().ok()?;
+
+    let entry_key = CacheKey {
+        depth,
+        target,
+        rules: CaveRules {
+            move_time,
+            switch_tool_time,
+        },
+    };
+
+    if entry_key != *key {
+        return None;
+    }
+
+    let part_1: RiskLevel = next_value("part 1")?.parse().ok()?;
+    let part_2 = match next_value("part 2")?.as_str() {
+        "none" => None,
+        time => Some(time.parse().ok()?),
+    };
+
+    return Some(Answers { part_1, part_2 });
+}
+
+// the cached answers for the key, if any
+pub fn load(cache_dir: &Path, key: &CacheKey) -> Option<Answers> {
+    let entry = std::fs::read_to_string(get_cache_path(cache_dir, key)).ok()?;
+    return parse_entry(key, &entry);
+}
+
+pub fn store(cache_dir: &Path, key: &CacheKey, answers: &Answers) -> Result<(), String> {
+    std::fs::create_dir_all(cache_dir)
+        .map_err(|err| format!("Unable to create {}: {}", cache_dir.display(), err))?;
+
+    let path = get_cache_path(cache_dir, key);
+
+    // write to a temporary file first, so that an interrupted run doesn't leave a partial entry behind
+    let temporary_path = path.with_extension("tmp");
+    std::fs::write(&temporary_path, to_string(key, answers))
+        .map_err(|err| format!("Unable to write {}: {}", temporary_path.display(), err))?;
+    std::fs::rename(&temporary_path, &path)
+        .map_err(|err| format!("Unable to write {}: {}", path.display(), err))?;
+
+    return Ok(());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cache() {
+        let cache_dir = std::env::temp_dir().join(format!(
+            "advent-of-code-day-22-2018-cache-test-{}",
+            std::process::id()
+        ));
+
+        let key = CacheKey {
+            depth: 4002,
+            target: (5, 746),
+            rules: CaveRules::default(),
+        };
+        let answers = Answers {
+            part_1: 4479,
+            part_2: Some(1032),
+        };
+
+        assert_eq!(load(&cache_dir, &key), None);
+
+        store(&cache_dir, &key, &answers).unwrap();
+        assert_eq!(load(&cache_dir, &key), Some(answers));
+
+        assert_eq!(
+            std::fs::read_to_string(cache_dir.join("4002-5-746-1-7.txt"))
+                .unwrap()
+                .trim(),
+            r###"
+depth: 4002
+target: 5,746
+move time: 1
+switch tool time: 7
+part 1: 4479
+part 2: 1032
+            "###
+            .trim()
+        );
+
+        // the answers depend on the rules
+        let other_key = CacheKey {
+            rules: CaveRules {
+                move_time: 2,
+                switch_tool_time: 7,
+            },
+            ..key
+        };
+        assert_eq!(load(&cache_dir, &other_key), None);
+
+        let other_answers = Answers {
+            part_1: 4479,
+            part_2: None,
+        };
+        store(&cache_dir, &other_key, &other_answers).unwrap();
+        assert_eq!(load(&cache_dir, &other_key), Some(other_answers));
+        assert_eq!(load(&cache_dir, &key), Some(answers));
+
+        std::fs::remove_dir_all(&cache_dir).unwrap();
+    }
+
+    #[test]
+    fn test_parse_entry() {
+        let key = CacheKey {
+            depth: 510,
+            target: (10, 10),
+            rules: CaveRules::default(),
+        };
+        let entry =
+            "depth: 510\ntarget: 10,10\nmove time: 1\nswitch tool time: 7\npart 1: 114\npart 2: 45";
+
+        assert_eq!(
+            parse_entry(&key, entry),
+            Some(Answers {
+                part_1: 114,
+                part_2: Some(45)
+            })
+        );
+
+        // entries for other keys, or that are cut short, are ignored
+        assert_eq!(parse_entry(&CacheKey { depth: 511, ..key }, entry), None);
+        assert_eq!(parse_entry(&key, "depth: 510\ntarget: 10,10"), None);
+        assert_eq!(
+            parse_entry(&key, &entry.replace("part 2: 45", "part 2: ?")),
+            None
+        );
+        assert_eq!(parse_entry(&key, ""), None);
+    }
+}
@@ -0,0 +1,2036 @@
+// https://adventofcode.com/2018/day/22
+
+// imports
+
+use rayon::prelude::*;
+use solver::{Example, Solver};
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::time::Duration;
+use std::time::Instant;
+
+// code
+
+type Cost = i32;
+
+// adapted from day 6
+// https://math.stackexchange.com/a/139604/10247
+pub type Distance = i32;
+fn get_manhattan_distance(start: Coordinate, end: Coordinate) -> Distance {
+    let (a, b) = start;
+    let (c, d) = end;
+
+    return (a - c).abs() + (b - d).abs();
+}
+
+#[derive(PartialEq, Hash, Eq, Clone, Copy, Debug)]
+struct TimeCoordinate {
+    time: Time,
+    distance: Distance,
+    position: Coordinate,
+    tool: Tool,
+}
+
+impl TimeCoordinate {
+    fn new(time: Time, distance: Distance, position: Coordinate, tool: Tool) -> Self {
+        return TimeCoordinate {
+            time,
+            distance,
+            position,
+            tool,
+        };
+    }
+
+    fn get_state(&self) -> State {
+        return State {
+            position: self.position,
+            tool: self.tool,
+        };
+    }
+
+    fn get_cost(&self) -> Cost {
+        return self.distance + self.time;
+    }
+
+    fn move_to_square(
+        &self,
+        distance: Distance,
+        new_position: Coordinate,
+        rules: &CaveRules,
+    ) -> Self {
+        assert!(get_manhattan_distance(new_position, self.position) == 1);
+        assert!(distance >= 0);
+
+        let mut next = *self;
+
+        next.distance = distance;
+        next.position = new_position;
+        next.time += rules.move_time;
+
+        return next;
+    }
+
+    fn switch_tool(&self, next_tool: Tool, rules: &CaveRules) -> Self {
+        assert!(next_tool != self.tool);
+
+        let mut next = *self;
+
+        next.tool = next_tool;
+        next.time += rules.switch_tool_time;
+
+        assert!(next.time >= self.time);
+
+        return next;
+    }
+}
+
+impl Ord for TimeCoordinate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // reversed for the binary heap which is a max-heap
+        return (self.get_cost()).cmp(&(other.get_cost())).reverse();
+    }
+}
+
+impl PartialOrd for TimeCoordinate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        // reversed for the binary heap which is a max-heap
+        return Some(self.cmp(other));
+    }
+}
+
+// the time it takes to traverse the cave
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CaveRules {
+    // time to move to an adjacent region
+    pub move_time: Time,
+    // time to switch to another tool
+    pub switch_tool_time: Time,
+}
+
+impl Default for CaveRules {
+    fn default() -> Self {
+        // Moving to an adjacent region takes one minute.
+        // Switching to using the climbing gear, torch, or neither always takes seven minutes
+        return CaveRules {
+            move_time: 1,
+            switch_tool_time: 7,
+        };
+    }
+}
+
+impl CaveRules {
+    pub fn validate(&self) -> Result<(), String> {
+        // the search bounds rely on every move taking some time
+        if self.move_time < 1 {
+            return Err("Move time must be at least 1".to_string());
+        }
+
+        if self.switch_tool_time < 0 {
+            return Err("Switch tool time must not be negative".to_string());
+        }
+
+        return Ok(());
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+pub enum Tool {
+    None, // neither
+    Torch,
+    ClimbingGear,
+}
+
+impl Tool {
+    pub fn to_string(self) -> String {
+        let result = match self {
+            Tool::None => "neither",
+            Tool::Torch => "torch",
+            Tool::ClimbingGear => "climbing gear",
+        };
+        return result.to_string();
+    }
+
+    pub fn to_color(self) -> &'static str {
+        match self {
+            // magenta
+            Tool::None => "\x1b[35m",
+            // yellow
+            Tool::Torch => "\x1b[33m",
+            // red
+            Tool::ClimbingGear => "\x1b[31m",
+        }
+    }
+}
+
+// a node of the search graph: being at a region with a tool equipped
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+pub struct State {
+    pub position: Coordinate,
+    pub tool: Tool,
+}
+
+// a fastest route from the mouth of the cave to the target
+#[derive(Debug, Clone, PartialEq)]
+pub struct Route {
+    // the states of the route, and the time at which they're reached
+    steps: Vec<(Time, State)>,
+}
+
+impl Route {
+    fn reconstruct(
+        best_edges: &HashMap<State, State>,
+        best_costs: &HashMap<State, Time>,
+        end: State,
+    ) -> Route {
+        let mut steps = vec![];
+        let mut current = end;
+
+        loop {
+            let time = *best_costs.get(&current).unwrap();
+            let previous = best_edges.get(&current).cloned();
+
+            steps.push((time, current));
+
+            match previous {
+                None => break,
+                Some(previous) => {
+                    current = previous;
+                }
+            }
+        }
+
+        steps.reverse();
+
+        return Route { steps };
+    }
+
+    pub fn get_time(&self) -> Time {
+        let (time, _state) = self.steps.last().unwrap();
+        return *time;
+    }
+
+    // the states of the route, and the time at which they're reached
+    pub fn get_steps(&self) -> &[(Time, State)] {
+        return &self.steps;
+    }
+
+    // the regions where tools are switched, and the tools switched from and to
+    pub fn get_tool_switches(&self) -> Vec<(Coordinate, Tool, Tool)> {
+        return self
+            .steps
+            .windows(2)
+            .filter_map(|window| {
+                let (_, current) = &window[0];
+                let (_, next) = &window[1];
+
+                if current.tool == next.tool {
+                    return None;
+                }
+
+                return Some((current.position, current.tool, next.tool));
+            })
+            .collect();
+    }
+
+    // the smallest bounds containing the mouth of the cave, the target, and the route
+    pub fn get_bounds(&self, target: Coordinate) -> Bounds {
+        let (target_x, target_y) = target;
+
+        let max_x = self
+            .steps
+            .iter()
+            .map(|(_time, state)| state.position.0)
+            .max()
+            .unwrap_or(0);
+        let max_y = self
+            .steps
+            .iter()
+            .map(|(_time, state)| state.position.1)
+            .max()
+            .unwrap_or(0);
+
+        return Bounds {
+            min: MOUTH_OF_CAVE,
+            max: (
+                std::cmp::max(max_x, target_x),
+                std::cmp::max(max_y, target_y),
+            ),
+        };
+    }
+
+    pub fn to_string(&self) -> String {
+        let mut lines = vec![];
+
+        for (index, (time, state)) in self.steps.iter().enumerate() {
+            let (x, y) = state.position;
+
+            let is_switch = index > 0 && {
+                let (_, previous) = &self.steps[index - 1];
+                previous.tool != state.tool
+            };
+
+            if is_switch {
+                let (_, previous) = &self.steps[index - 1];
+                lines.push(format!(
+                    "{:>5}  {},{}  switch from {} to {}",
+                    time,
+                    x,
+                    y,
+                    previous.tool.to_string(),
+                    state.tool.to_string()
+                ));
+            } else {
+                lines.push(format!(
+                    "{:>5}  {},{}  {}",
+                    time,
+                    x,
+                    y,
+                    state.tool.to_string()
+                ));
+            }
+        }
+
+        return lines.join("\n");
+    }
+}
+
+// counters collected while searching for the fastest route
+#[derive(Debug, Clone, Default)]
+pub struct SearchStats {
+    // states taken from the frontier and expanded
+    pub nodes_expanded: usize,
+    // states added to the frontier
+    pub states_pushed: usize,
+    pub max_frontier_size: usize,
+    pub elapsed: Duration,
+}
+
+impl SearchStats {
+    pub fn to_string(&self) -> String {
+        return format!(
+            "nodes expanded: {}\nstates pushed: {}\nmax frontier size: {}\nelapsed: {:?}",
+            self.nodes_expanded, self.states_pushed, self.max_frontier_size, self.elapsed
+        );
+    }
+}
+
+// how the fastest route to the target is searched for
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SearchStrategy {
+    // expand states in order of time
+    Dijkstra,
+    // expand states in order of time plus a lower bound of the remaining time to the target
+    AStar,
+    // expand states in order of time, searching from the mouth of the cave and from the target
+    // at the same time until the searches meet
+    Bidirectional,
+}
+
+// fastest times from the mouth of the cave, computed with a Dijkstra search that is paused once a
+// queried state is settled, and resumed by later queries.
+#[derive(Debug, Clone, Default)]
+struct FastestTimes {
+    // the search starts from the mouth of the cave with the torch equipped once this is non-empty
+    frontier: BinaryHeap<TimeCoordinate>,
+    // tentative times of states that have been reached
+    best_times: HashMap<State, Time>,
+    // states whose fastest time is known
+    settled: HashMap<State, Time>,
+}
+
+// an edge of the search graph
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+enum Edge {
+    // move to an adjacent region while keeping the equipped tool; takes 1 minute
+    Move(Coordinate),
+    // switch to another tool while staying at the same region; takes 7 minutes
+    SwitchTool(Tool),
+}
+
+pub type Coordinate = (i32, i32);
+type GeologicIndex = i32;
+pub type RiskLevel = i32;
+pub type ErosionLevel = i32;
+pub type Depth = i32;
+pub type Time = i32;
+
+const MOUTH_OF_CAVE: Coordinate = (0, 0);
+
+// https://en.wikipedia.org/wiki/ANSI_escape_code#Colors
+const ANSI_RESET: &str = "\x1b[0m";
+const ANSI_BOLD: &str = "\x1b[1m";
+const ANSI_UNDERLINE: &str = "\x1b[4m";
+
+// an inclusive rectangle of regions
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bounds {
+    pub min: Coordinate,
+    pub max: Coordinate,
+}
+
+impl Bounds {
+    pub fn contains(&self, coord: &Coordinate) -> bool {
+        let (x, y) = coord;
+        let (min_x, min_y) = self.min;
+        let (max_x, max_y) = self.max;
+
+        return min_x <= *x && *x <= max_x && min_y <= *y && *y <= max_y;
+    }
+}
+
+trait Transitions {
+    fn up(&self) -> Coordinate;
+    fn down(&self) -> Coordinate;
+    fn left(&self) -> Coordinate;
+    fn right(&self) -> Coordinate;
+}
+
+impl Transitions for Coordinate {
+    fn up(&self) -> Coordinate {
+        let (x, y) = self;
+        return (*x, y - 1);
+    }
+
+    fn down(&self) -> Coordinate {
+        let (x, y) = self;
+        return (*x, y + 1);
+    }
+
+    fn left(&self) -> Coordinate {
+        let (x, y) = self;
+        return (x - 1, *y);
+    }
+
+    fn right(&self) -> Coordinate {
+        let (x, y) = self;
+        return (x + 1, *y);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RegionType {
+    Rocky,
+    Narrow,
+    Wet,
+}
+
+impl RegionType {
+    pub fn risk_level(&self) -> RiskLevel {
+        match self {
+            RegionType::Rocky => 0,
+            RegionType::Wet => 1,
+            RegionType::Narrow => 2,
+        }
+    }
+
+    pub fn to_string(&self) -> String {
+        let result = match self {
+            RegionType::Rocky => ".",
+            RegionType::Wet => "=",
+            RegionType::Narrow => "|",
+        };
+        return result.to_string();
+    }
+
+    pub fn to_color(&self) -> &'static str {
+        match self {
+            // dark grey
+            RegionType::Rocky => "\x1b[90m",
+            // blue
+            RegionType::Wet => "\x1b[34m",
+            // green
+            RegionType::Narrow => "\x1b[32m",
+        }
+    }
+
+    pub fn is_tool_allowed(&self, tool: &Tool) -> bool {
+        return self.required_tools().contains(tool);
+    }
+
+    fn required_tools(&self) -> [Tool; 2] {
+        match self {
+            RegionType::Rocky => [Tool::ClimbingGear, Tool::Torch],
+            RegionType::Wet => [Tool::ClimbingGear, Tool::None],
+            RegionType::Narrow => [Tool::None, Tool::Torch],
+        }
+    }
+}
+
+// width and height of the square chunks the erosion levels are stored in
+const CHUNK_SIZE: usize = 64;
+
+// precomputing at least this many regions at once is done in parallel
+const PARALLEL_PRECOMPUTE_THRESHOLD: usize = 1 << 20;
+
+// erosion levels of a chunk, indexed by [y * CHUNK_SIZE + x] relative to the top-left corner of the chunk
+type Chunk = Vec<ErosionLevel>;
+
+pub struct Cave {
+    depth: Depth,
+    target: Coordinate,
+    rules: CaveRules,
+    // chunks of erosion levels indexed by [chunk_y][chunk_x]; they're allocated on demand and never freed.
+    //
+    // the erosion level of a region depends on the regions to its left and above it,
+    // so a chunk is only computed after the chunks to its left and above it.
+    // each row of chunks is therefore at most as long as the row above it.
+    erosion_levels: Vec<Vec<Chunk>>,
+    // fastest times computed so far by Cave::shortest_time_to
+    fastest_times: FastestTimes,
+}
+
+impl Cave {
+    pub fn new(depth: Depth, target: Coordinate, rules: CaveRules) -> Self {
+        assert!(rules.validate().is_ok());
+
+        let mut cave = Cave {
+            depth,
+            target,
+            rules,
+            erosion_levels: vec![],
+            fastest_times: FastestTimes::default(),
+        };
+
+        cave.precompute(&Bounds {
+            min: MOUTH_OF_CAVE,
+            max: target,
+        });
+
+        return cave;
+    }
+
+    // compute the erosion levels of all regions from the mouth of the cave up to bounds.max, chunk by chunk.
+    // previously computed chunks are kept.
+    pub fn precompute(&mut self, bounds: &Bounds) {
+        let (max_x, max_y) = bounds.max;
+        assert!(max_x >= 0 && max_y >= 0);
+
+        let max_chunk_x = max_x as usize / CHUNK_SIZE;
+        let max_chunk_y = max_y as usize / CHUNK_SIZE;
+
+        // number of regions that are yet to be computed
+        let missing_regions: usize = (0..=max_chunk_y)
+            .map(|chunk_y| {
+                let computed = self.erosion_levels.get(chunk_y).map_or(0, |row| row.len());
+                return (max_chunk_x + 1).saturating_sub(computed);
+            })
+            .sum::<usize>()
+            * CHUNK_SIZE
+            * CHUNK_SIZE;
+
+        if missing_regions >= PARALLEL_PRECOMPUTE_THRESHOLD {
+            self.precompute_parallel(max_chunk_x, max_chunk_y);
+        } else {
+            self.precompute_sequential(max_chunk_x, max_chunk_y);
+        }
+    }
+
+    fn precompute_sequential(&mut self, max_chunk_x: usize, max_chunk_y: usize) {
+        for chunk_y in 0..=max_chunk_y {
+            if chunk_y >= self.erosion_levels.len() {
+                self.erosion_levels.push(vec![]);
+            }
+
+            // invariant: the row of chunks above is at least as long as this row will be
+            while self.erosion_levels[chunk_y].len() <= max_chunk_x {
+                let chunk_x = self.erosion_levels[chunk_y].len();
+                let chunk = self.compute_chunk(chunk_x, chunk_y);
+                self.erosion_levels[chunk_y].push(chunk);
+            }
+        }
+    }
+
+    // chunks along an anti-diagonal (where chunk_x + chunk_y is the same) only depend on chunks of
+    // the previous anti-diagonals, so they're computed in parallel, one anti-diagonal at a time.
+    fn precompute_parallel(&mut self, max_chunk_x: usize, max_chunk_y: usize) {
+        while self.erosion_levels.len() <= max_chunk_y {
+            self.erosion_levels.push(vec![]);
+        }
+
+        for diagonal in 0..=(max_chunk_x + max_chunk_y) {
+            let missing_chunks: Vec<(usize, usize)> = (0..=std::cmp::min(diagonal, max_chunk_y))
+                .map(|chunk_y| (diagonal - chunk_y, chunk_y))
+                .filter(|(chunk_x, chunk_y)| {
+                    *chunk_x <= max_chunk_x && *chunk_x >= self.erosion_levels[*chunk_y].len()
+                })
+                .collect();
+
+            let chunks: Vec<Chunk> = missing_chunks
+                .par_iter()
+                .map(|(chunk_x, chunk_y)| self.compute_chunk(*chunk_x, *chunk_y))
+                .collect();
+
+            for ((chunk_x, chunk_y), chunk) in missing_chunks.into_iter().zip(chunks) {
+                // the chunk to the left was computed with a previous anti-diagonal
+                assert!(chunk_x == self.erosion_levels[chunk_y].len());
+                self.erosion_levels[chunk_y].push(chunk);
+            }
+        }
+    }
+
+    // invariant: the chunks to the left of and above the chunk are computed
+    fn compute_chunk(&self, chunk_x: usize, chunk_y: usize) -> Chunk {
+        let mut chunk: Chunk = vec![0; CHUNK_SIZE * CHUNK_SIZE];
+
+        // regions are computed row by row, so that the regions they depend on are computed first
+        for y in 0..CHUNK_SIZE {
+            for x in 0..CHUNK_SIZE {
+                let coord = (
+                    (chunk_x * CHUNK_SIZE + x) as i32,
+                    (chunk_y * CHUNK_SIZE + y) as i32,
+                );
+
+                let geologic_index = self.compute_geologic_index(&coord, |other: &Coordinate| {
+                    let (other_x, other_y) = (other.0 as usize, other.1 as usize);
+
+                    if other_x / CHUNK_SIZE == chunk_x && other_y / CHUNK_SIZE == chunk_y {
+                        return chunk[(other_y % CHUNK_SIZE) * CHUNK_SIZE + other_x % CHUNK_SIZE];
+                    }
+
+                    return self.get_computed_erosion_level(other);
+                });
+                let erosion_level = (geologic_index + self.depth) % 20183;
+
+                chunk[y * CHUNK_SIZE + x] = erosion_level;
+            }
+        }
+
+        return chunk;
+    }
+
+    #[allow(dead_code)]
+    fn get_chunk_count(&self) -> usize {
+        return self.erosion_levels.iter().map(|row| row.len()).sum();
+    }
+
+    fn is_computed(&self, coord: &Coordinate) -> bool {
+        let (x, y) = coord;
+        if *x < 0 || *y < 0 {
+            return false;
+        }
+
+        let (chunk_x, chunk_y) = (*x as usize / CHUNK_SIZE, *y as usize / CHUNK_SIZE);
+
+        return chunk_y < self.erosion_levels.len() && chunk_x < self.erosion_levels[chunk_y].len();
+    }
+
+    // ensure the erosion level of the region at coord is computed
+    fn ensure_computed(&mut self, coord: &Coordinate) {
+        if self.is_computed(coord) {
+            return;
+        }
+
+        self.precompute(&Bounds {
+            min: MOUTH_OF_CAVE,
+            max: *coord,
+        });
+    }
+
+    // invariant: the erosion level of the region at coord is computed
+    fn get_computed_erosion_level(&self, coord: &Coordinate) -> ErosionLevel {
+        let (x, y) = (coord.0 as usize, coord.1 as usize);
+        let chunk = &self.erosion_levels[y / CHUNK_SIZE][x / CHUNK_SIZE];
+        return chunk[(y % CHUNK_SIZE) * CHUNK_SIZE + x % CHUNK_SIZE];
+    }
+
+    pub fn get_target(&self) -> Coordinate {
+        return self.target;
+    }
+
+    // the type of the region at coord; regions are computed on demand
+    pub fn region_type(&mut self, coord: Coordinate) -> RegionType {
+        assert!(coord.0 >= 0 && coord.1 >= 0);
+        return self.get_region_type(&coord);
+    }
+
+    // the total risk level of the regions within the bounds
+    pub fn risk_level_rect(&mut self, bounds: &Bounds) -> RiskLevel {
+        let (min_x, min_y) = bounds.min;
+        let (max_x, max_y) = bounds.max;
+        assert!(min_x >= 0 && min_y >= 0);
+
+        self.precompute(bounds);
+
+        let mut total_risk: RiskLevel = 0;
+
+        for y in min_y..=max_y {
+            for x in min_x..=max_x {
+                total_risk += self.get_risk_level(&(x, y));
+            }
+        }
+
+        return total_risk;
+    }
+
+    // a fastest route from the mouth of the cave to the target, arriving with the torch equipped
+    pub fn fastest_rescue(&mut self) -> Option<Route> {
+        let padding = self.get_safe_padding();
+        let (route, _stats) =
+            self.find_route(&self.get_search_bounds(padding), SearchStrategy::AStar);
+        return route;
+    }
+
+    fn get_risk_level(&mut self, coord: &Coordinate) -> RiskLevel {
+        return self.get_region_type(coord).risk_level();
+    }
+
+    fn get_region_type(&mut self, coord: &Coordinate) -> RegionType {
+        let result = self.get_erosion_level(coord) % 3;
+
+        let result = match result {
+            0 => RegionType::Rocky,
+            1 => RegionType::Wet,
+            2 => RegionType::Narrow,
+            _ => {
+                unreachable!();
+            }
+        };
+
+        return result;
+    }
+
+    // the search region is the rectangle from the mouth of the cave to the target,
+    // extended by padding beyond the target in both directions.
+    pub fn get_search_bounds(&self, padding: Distance) -> Bounds {
+        assert!(padding >= 0);
+
+        let (target_x, target_y) = self.target;
+
+        return Bounds {
+            min: MOUTH_OF_CAVE,
+            max: (target_x + padding, target_y + padding),
+        };
+    }
+
+    // find a padding such that the search region is guaranteed to contain a fastest route to the target.
+    //
+    // a route that reaches p regions beyond the target (in either direction) needs to travel at least
+    // p regions out and p regions back; so its time is at least (manhattan(mouth, target) + 2p) * move time.
+    // it can only be a fastest route if that doesn't exceed the time of some known route.
+    // a route within the rectangle from the mouth to the target always exists (tools can always be switched),
+    // so its time serves as the upper bound.
+    pub fn get_safe_padding(&mut self) -> Distance {
+        let upper_bound = self
+            .find_target(&self.get_search_bounds(0))
+            .expect("There is always a route within the rectangle from the mouth to the target");
+
+        let shortest_time = self.get_estimated_time(&MOUTH_OF_CAVE);
+
+        return std::cmp::max(upper_bound - shortest_time, 0) / (2 * self.rules.move_time);
+    }
+
+    // a lower bound of the time to move from coord to the target
+    fn get_estimated_time(&self, coord: &Coordinate) -> Time {
+        return get_manhattan_distance(*coord, self.target) * self.rules.move_time;
+    }
+
+    fn get_heuristic(&self, coord: &Coordinate, strategy: SearchStrategy) -> Time {
+        match strategy {
+            SearchStrategy::Dijkstra | SearchStrategy::Bidirectional => 0,
+            SearchStrategy::AStar => self.get_estimated_time(coord),
+        }
+    }
+
+    pub fn get_erosion_level(&mut self, coord: &Coordinate) -> ErosionLevel {
+        self.ensure_computed(coord);
+        return self.get_computed_erosion_level(coord);
+    }
+
+    pub fn find_target(&mut self, bounds: &Bounds) -> Option<Time> {
+        let (route, _stats) = self.find_route(bounds, SearchStrategy::AStar);
+        return route.map(|route| route.get_time());
+    }
+
+    // find a fastest route from the mouth of the cave to the target within the bounds
+    pub fn find_route(
+        &mut self,
+        bounds: &Bounds,
+        strategy: SearchStrategy,
+    ) -> (Option<Route>, SearchStats) {
+        if strategy == SearchStrategy::Bidirectional {
+            return self.find_route_bidirectional(bounds);
+        }
+
+        let start_time = Instant::now();
+        let mut stats = SearchStats::default();
+
+        self.precompute(bounds);
+
+        let mut available_squares: BinaryHeap<TimeCoordinate> = BinaryHeap::new();
+        // keep track of the best minimum time spent for a state
+        let mut best_costs: HashMap<State, Time> = HashMap::new();
+        // keep track of the previous state of the best route to a state
+        let mut best_edges: HashMap<State, State> = HashMap::new();
+
+        // You start at 0,0 (the mouth of the cave) with the torch equipped
+
+        let start = TimeCoordinate::new(
+            0,
+            self.get_heuristic(&MOUTH_OF_CAVE, strategy),
+            MOUTH_OF_CAVE,
+            Tool::Torch,
+        );
+        best_costs.insert(start.get_state(), 0);
+        available_squares.push(start);
+        stats.states_pushed += 1;
+        stats.max_frontier_size = 1;
+
+        // reused to avoid allocating for every expanded state
+        let mut edges: Vec<Edge> = Vec::with_capacity(5);
+
+        while let Some(current_square) = available_squares.pop() {
+            let current_state = current_square.get_state();
+
+            if best_costs.get(&current_state).unwrap_or(&i32::max_value()) < &current_square.time {
+                // stale entry
+                continue;
+            }
+
+            stats.nodes_expanded += 1;
+
+            if current_state.position == self.target && current_state.tool == Tool::Torch {
+                let route = Route::reconstruct(&best_edges, &best_costs, current_state);
+                stats.elapsed = start_time.elapsed();
+                return (Some(route), stats);
+            }
+
+            self.get_edges(&current_state, bounds, &mut edges);
+
+            for edge in edges.iter() {
+                let next = match *edge {
+                    Edge::Move(adjacent_square) => current_square.move_to_square(
+                        self.get_heuristic(&adjacent_square, strategy),
+                        adjacent_square,
+                        &self.rules,
+                    ),
+                    Edge::SwitchTool(next_tool) => {
+                        current_square.switch_tool(next_tool, &self.rules)
+                    }
+                };
+
+                let next_state = next.get_state();
+
+                if best_costs.get(&next_state).unwrap_or(&i32::max_value()) <= &next.time {
+                    continue;
+                }
+
+                best_costs.insert(next_state, next.time);
+                best_edges.insert(next_state, current_state);
+                available_squares.push(next);
+
+                stats.states_pushed += 1;
+                stats.max_frontier_size =
+                    std::cmp::max(stats.max_frontier_size, available_squares.len());
+            }
+        }
+
+        stats.elapsed = start_time.elapsed();
+        return (None, stats);
+    }
+
+    // find a fastest route with a Dijkstra search from the mouth of the cave and another one from the target
+    // (with the torch equipped); they take turns expanding the smaller frontier.
+    //
+    // the search from the target can follow the same edges as the one from the mouth, since the search graph
+    // is symmetric: a tool is only ever equipped in regions that allow it, so moving back to the previous
+    // region with the same tool is always possible, as is switching back to the previous tool.
+    //
+    // every state that is reached by both searches gives a route through it. the searches stop once
+    // the fastest of these routes can't be improved by the states left in the frontiers.
+    fn find_route_bidirectional(&mut self, bounds: &Bounds) -> (Option<Route>, SearchStats) {
+        let start_time = Instant::now();
+        let mut stats = SearchStats::default();
+
+        self.precompute(bounds);
+
+        let start = TimeCoordinate::new(0, 0, MOUTH_OF_CAVE, Tool::Torch);
+        let end = TimeCoordinate::new(0, 0, self.target, Tool::Torch);
+
+        // the search from the mouth of the cave is at index 0, and the search from the target is at index 1
+        let mut frontiers: [BinaryHeap<TimeCoordinate>; 2] = [BinaryHeap::new(), BinaryHeap::new()];
+        let mut best_costs: [HashMap<State, Time>; 2] = [HashMap::new(), HashMap::new()];
+        // for the search from the target, this is the next state towards the target
+        let mut best_edges: [HashMap<State, State>; 2] = [HashMap::new(), HashMap::new()];
+
+        for (side, square) in [start, end].iter().enumerate() {
+            best_costs[side].insert(square.get_state(), 0);
+            frontiers[side].push(*square);
+            stats.states_pushed += 1;
+        }
+        stats.max_frontier_size = 2;
+
+        // the state where the fastest known route passes through, and its time
+        let mut meeting_state: Option<State> = None;
+        let mut best_time = i32::max_value();
+
+        if start == end {
+            meeting_state = Some(start.get_state());
+            best_time = 0;
+        }
+
+        let mut edges: Vec<Edge> = Vec::with_capacity(5);
+
+        loop {
+            let (forward_time, backward_time) = match (frontiers[0].peek(), frontiers[1].peek()) {
+                (Some(forward), Some(backward)) => (forward.time, backward.time),
+                // one of the searches explored every state it can reach
+                _ => break,
+            };
+
+            if forward_time + backward_time >= best_time {
+                break;
+            }
+
+            let side = if frontiers[0].len() <= frontiers[1].len() {
+                0
+            } else {
+                1
+            };
+
+            let current_square = frontiers[side].pop().unwrap();
+            let current_state = current_square.get_state();
+
+            if best_costs[side]
+                .get(&current_state)
+                .unwrap_or(&i32::max_value())
+                < &current_square.time
+            {
+                // stale entry
+                continue;
+            }
+
+            stats.nodes_expanded += 1;
+
+            self.get_edges(&current_state, bounds, &mut edges);
+
+            for edge in edges.iter() {
+                let next = match *edge {
+                    Edge::Move(adjacent_square) => {
+                        current_square.move_to_square(0, adjacent_square, &self.rules)
+                    }
+                    Edge::SwitchTool(next_tool) => {
+                        current_square.switch_tool(next_tool, &self.rules)
+                    }
+                };
+
+                let next_state = next.get_state();
+
+                if best_costs[side]
+                    .get(&next_state)
+                    .unwrap_or(&i32::max_value())
+                    <= &next.time
+                {
+                    continue;
+                }
+
+                best_costs[side].insert(next_state, next.time);
+                best_edges[side].insert(next_state, current_state);
+                frontiers[side].push(next);
+
+                if let Some(other_time) = best_costs[1 - side].get(&next_state) {
+                    if next.time + other_time < best_time {
+                        best_time = next.time + other_time;
+                        meeting_state = Some(next_state);
+                    }
+                }
+
+                stats.states_pushed += 1;
+                stats.max_frontier_size = std::cmp::max(
+                    stats.max_frontier_size,
+                    frontiers[0].len() + frontiers[1].len(),
+                );
+            }
+        }
+
+        let route = meeting_state.map(|meeting_state| {
+            let mut route = Route::reconstruct(&best_edges[0], &best_costs[0], meeting_state);
+
+            // follow the search from the target back to the target
+            let mut current = meeting_state;
+            while let Some(next) = best_edges[1].get(&current) {
+                let time = best_time - best_costs[1].get(next).unwrap();
+                route.steps.push((time, *next));
+                current = *next;
+            }
+
+            assert!(route.get_time() == best_time);
+
+            return route;
+        });
+
+        stats.elapsed = start_time.elapsed();
+        return (route, stats);
+    }
+
+    // the fastest time to reach coord from the mouth of the cave, ending with required_tool equipped.
+    //
+    // unlike Cave::find_route, the search isn't limited to some bounds, and isn't guided towards the target.
+    // the fastest times found along the way are kept, so that later queries only resume the search
+    // as far as needed.
+    pub fn shortest_time_to(&mut self, coord: Coordinate, required_tool: Tool) -> Option<Time> {
+        let (x, y) = coord;
+        if x < 0 || y < 0 || !self.get_region_type(&coord).is_tool_allowed(&required_tool) {
+            return None;
+        }
+
+        let destination = State {
+            position: coord,
+            tool: required_tool,
+        };
+
+        if let Some(time) = self.fastest_times.settled.get(&destination) {
+            return Some(*time);
+        }
+
+        // the whole cave, i.e. every region with non-negative coordinates
+        let bounds = Bounds {
+            min: MOUTH_OF_CAVE,
+            max: (i32::max_value() - 1, i32::max_value() - 1),
+        };
+
+        let mut fastest_times = std::mem::take(&mut self.fastest_times);
+
+        if fastest_times.best_times.is_empty() {
+            // You start at 0,0 (the mouth of the cave) with the torch equipped
+            let start = TimeCoordinate::new(0, 0, MOUTH_OF_CAVE, Tool::Torch);
+            fastest_times.best_times.insert(start.get_state(), 0);
+            fastest_times.frontier.push(start);
+        }
+
+        let mut edges: Vec<Edge> = Vec::with_capacity(5);
+        let mut result = None;
+
+        while let Some(current_square) = fastest_times.frontier.pop() {
+            let current_state = current_square.get_state();
+
+            if fastest_times.settled.contains_key(&current_state) {
+                // stale entry
+                continue;
+            }
+
+            fastest_times
+                .settled
+                .insert(current_state, current_square.time);
+
+            self.get_edges(&current_state, &bounds, &mut edges);
+
+            for edge in edges.iter() {
+                // without a distance estimate, states are expanded in order of time
+                let next = match *edge {
+                    Edge::Move(adjacent_square) => {
+                        current_square.move_to_square(0, adjacent_square, &self.rules)
+                    }
+                    Edge::SwitchTool(next_tool) => {
+                        current_square.switch_tool(next_tool, &self.rules)
+                    }
+                };
+
+                let next_state = next.get_state();
+
+                let best_time = fastest_times.best_times.get(&next_state);
+                if best_time.unwrap_or(&i32::max_value()) <= &next.time {
+                    continue;
+                }
+
+                fastest_times.best_times.insert(next_state, next.time);
+                fastest_times.frontier.push(next);
+            }
+
+            // the frontier is left as is, so that the search can be resumed
+            if current_state == destination {
+                result = Some(current_square.time);
+                break;
+            }
+        }
+
+        self.fastest_times = fastest_times;
+
+        return result;
+    }
+
+    // get the edges leaving a state of the search graph; edges is cleared beforehand
+    fn get_edges(&mut self, state: &State, bounds: &Bounds, edges: &mut Vec<Edge>) {
+        edges.clear();
+
+        let position = state.position;
+        let adjacent = [
+            position.left(),
+            position.right(),
+            position.up(),
+            position.down(),
+        ];
+
+        // moving is only possible if the equipped tool can be used in the destination region
+        for adjacent_square in adjacent.iter() {
+            if bounds.contains(adjacent_square)
+                && self
+                    .get_region_type(adjacent_square)
+                    .is_tool_allowed(&state.tool)
+            {
+                edges.push(Edge::Move(*adjacent_square));
+            }
+        }
+
+        // switching is only possible to a tool that can be used in the current region
+        let current_region = self.get_region_type(&position);
+
+        for next_tool in [Tool::None, Tool::Torch, Tool::ClimbingGear].iter() {
+            if *next_tool != state.tool && current_region.is_tool_allowed(next_tool) {
+                edges.push(Edge::SwitchTool(*next_tool));
+            }
+        }
+    }
+
+    #[allow(dead_code)]
+    fn get_geologic_index(&mut self, coord: &Coordinate) -> GeologicIndex {
+        self.ensure_computed(coord);
+        return self.compute_geologic_index(coord, |other| self.get_computed_erosion_level(other));
+    }
+
+    // invariant: erosion_level_at gives the erosion levels of the regions at X-1,Y and X,Y-1
+    fn compute_geologic_index(
+        &self,
+        coord: &Coordinate,
+        erosion_level_at: impl Fn(&Coordinate) -> ErosionLevel,
+    ) -> GeologicIndex {
+        // The region at 0,0 (the mouth of the cave) has a geologic index of 0.
+        if *coord == MOUTH_OF_CAVE {
+            return 0;
+        }
+
+        // The region at the coordinates of the target has a geologic index of 0.
+        if *coord == self.target {
+            return 0;
+        }
+
+        let (x, y) = coord;
+        if *y == 0 {
+            // If the region's Y coordinate is 0,
+            // the geologic index is its X coordinate times 16807.
+            return x * 16807;
+        }
+
+        if *x == 0 {
+            // If the region's X coordinate is 0,
+            // the geologic index is its Y coordinate times 48271.
+            return y * 48271;
+        }
+
+        // Otherwise, the region's geologic index is
+        // the result of multiplying the erosion levels of the regions at X-1,Y and X,Y-1.
+        return erosion_level_at(&coord.left()) * erosion_level_at(&coord.up());
+    }
+
+    pub fn to_string(&mut self) -> String {
+        let bounds = Bounds {
+            min: MOUTH_OF_CAVE,
+            max: self.target,
+        };
+
+        return self.render(&bounds, None, false);
+    }
+
+    // render the regions within the bounds, with the route overlaid.
+    //
+    // without colors, regions of the route are rendered as X.
+    // with colors, regions of the route are highlighted using the color of the equipped tool,
+    // and regions where tools are switched are underlined.
+    pub fn render(&mut self, bounds: &Bounds, route: Option<&Route>, use_colors: bool) -> String {
+        let mut route_tools: HashMap<Coordinate, Tool> = HashMap::new();
+        let mut tool_switches: HashSet<Coordinate> = HashSet::new();
+
+        if let Some(route) = route {
+            for (_time, state) in route.steps.iter() {
+                route_tools.insert(state.position, state.tool);
+            }
+
+            for (position, _from_tool, _to_tool) in route.get_tool_switches() {
+                tool_switches.insert(position);
+            }
+        }
+
+        let (min_x, min_y) = bounds.min;
+        let (max_x, max_y) = bounds.max;
+
+        let mut map_string: Vec<String> = vec![];
+
+        for y in min_y..=max_y {
+            let mut row_string = String::from("");
+
+            for x in min_x..=max_x {
+                let coord = (x, y);
+
+                let glyph = if coord == MOUTH_OF_CAVE {
+                    "M".to_string()
+                } else if coord == self.target {
+                    "T".to_string()
+                } else if route_tools.contains_key(&coord) && !use_colors {
+                    "X".to_string()
+                } else {
+                    self.get_region_type(&coord).to_string()
+                };
+
+                if !use_colors {
+                    row_string.push_str(&glyph);
+                    continue;
+                }
+
+                let color = match route_tools.get(&coord) {
+                    Some(tool) => tool.to_color(),
+                    None => self.get_region_type(&coord).to_color(),
+                };
+
+                let is_emphasized = coord == MOUTH_OF_CAVE
+                    || coord == self.target
+                    || route_tools.contains_key(&coord);
+
+                row_string.push_str(color);
+                if is_emphasized {
+                    row_string.push_str(ANSI_BOLD);
+                }
+                if tool_switches.contains(&coord) {
+                    row_string.push_str(ANSI_UNDERLINE);
+                }
+                row_string.push_str(&glyph);
+                row_string.push_str(ANSI_RESET);
+            }
+
+            map_string.push(row_string);
+        }
+
+        return map_string.join("\n");
+    }
+}
+
+pub fn part_1(depth: Depth, target: Coordinate) -> RiskLevel {
+    let mut cave = Cave::new(depth, target, CaveRules::default());
+
+    return cave.risk_level_rect(&Bounds {
+        min: MOUTH_OF_CAVE,
+        max: target,
+    });
+}
+
+// if padding isn't given, a safe padding is used; see Cave::get_safe_padding
+pub fn get_fastest_route(
+    depth: Depth,
+    target: Coordinate,
+    rules: CaveRules,
+    strategy: SearchStrategy,
+    padding: Option<Distance>,
+) -> (Option<Route>, SearchStats) {
+    let mut cave = Cave::new(depth, target, rules);
+
+    assert!(cave.get_region_type(&target) == RegionType::Rocky);
+
+    let padding = match padding {
+        Some(padding) => padding,
+        None => cave.get_safe_padding(),
+    };
+
+    return cave.find_route(&cave.get_search_bounds(padding), strategy);
+}
+
+pub fn part_2(depth: Depth, target: Coordinate, padding: Option<Distance>) -> Option<Time> {
+    let (route, _stats) = get_fastest_route(
+        depth,
+        target,
+        CaveRules::default(),
+        SearchStrategy::AStar,
+        padding,
+    );
+    return route.map(|route| route.get_time());
+}
+
+// parse the puzzle input:
+//
+// depth: 4002
+// target: 5,746
+pub fn parse_input(input_string: &str) -> Result<(Depth, Coordinate), String> {
+    let mut lines = input_string.trim().lines().map(|line| line.trim());
+
+    let depth: Depth = lines
+        .next()
+        .and_then(|line| line.strip_prefix("depth:"))
+        .ok_or_else(|| "Expected a line: depth: <depth>".to_string())?
+        .trim()
+        .parse()
+        .map_err(|_| "Invalid depth".to_string())?;
+
+    let target = lines
+        .next()
+        .and_then(|line| line.strip_prefix("target:"))
+        .ok_or_else(|| "Expected a line: target: <x>,<y>".to_string())?;
+    let target = parse_coordinate(target).ok_or_else(|| "Invalid target".to_string())?;
+
+    if let Some(line) = lines.next() {
+        return Err(format!("Unexpected line: {}", line));
+    }
+
+    if depth < 0 || target.0 < 0 || target.1 < 0 {
+        return Err("Depth and target must not be negative".to_string());
+    }
+
+    return Ok((depth, target));
+}
+
+// parse a coordinate of the form: <x>,<y>
+pub fn parse_coordinate(input: &str) -> Option<Coordinate> {
+    let coord: Vec<i32> = input
+        .split(',')
+        .map(|coord| coord.trim().parse())
+        .collect::<Result<Vec<i32>, _>>()
+        .ok()?;
+
+    if coord.len() != 2 {
+        return None;
+    }
+
+    return Some((coord[0], coord[1]));
+}
+
+pub const SOLVER: Solver = Solver {
+    year: 2018,
+    day: 22,
+    input: include_str!("input.txt"),
+    part_1: |input_string| {
+        let (depth, target) = parse_input(input_string)?;
+        return Ok(part_1(depth, target).to_string());
+    },
+    part_2: Some(|input_string| {
+        let (depth, target) = parse_input(input_string)?;
+        return part_2(depth, target, None)
+            .map(|time| time.to_string())
+            .ok_or_else(|| "The target can't be reached".to_string());
+    }),
+    examples: &[Example {
+        input: include_str!("../examples/1.txt"),
+        part_1: Some("114"),
+        part_2: Some("45"),
+    }],
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_parse_input() {
+        assert_eq!(parse_input(include_str!("input.txt")), Ok((4002, (5, 746))));
+        assert_eq!(
+            parse_input("depth: 510\ntarget: 10,10\n"),
+            Ok((510, (10, 10)))
+        );
+        assert_eq!(
+            parse_input("  depth:11820\r\n  target: 7, 782  "),
+            Ok((11820, (7, 782)))
+        );
+
+        assert!(parse_input("").is_err());
+        assert!(parse_input("depth: 510").is_err());
+        assert!(parse_input("target: 10,10\ndepth: 510").is_err());
+        assert!(parse_input("depth: 510\ntarget: 10").is_err());
+        assert!(parse_input("depth: 510\ntarget: 10,10,10").is_err());
+        assert!(parse_input("depth: abc\ntarget: 10,10").is_err());
+        assert!(parse_input("depth: 510\ntarget: -1,10").is_err());
+        assert!(parse_input("depth: 510\ntarget: 10,10\nfoo").is_err());
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(part_1(510, (10, 10)), 114);
+        assert_eq!(part_1(4002, (5, 746)), 4479);
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(part_2(510, (10, 10), None), Some(45));
+        assert_eq!(part_2(4002, (5, 746), None), Some(1032));
+        assert_eq!(part_2(11820, (7, 782), None), Some(1075));
+    }
+
+    #[test]
+    fn test_cave_model() {
+        let mut cave = Cave::new(510, (10, 10), CaveRules::default());
+
+        assert_eq!(cave.get_target(), (10, 10));
+
+        // from the puzzle description
+        assert_eq!(cave.region_type((0, 0)), RegionType::Rocky);
+        assert_eq!(cave.region_type((1, 0)), RegionType::Wet);
+        assert_eq!(cave.region_type((0, 1)), RegionType::Rocky);
+        assert_eq!(cave.region_type((1, 1)), RegionType::Narrow);
+        assert_eq!(cave.region_type((10, 10)), RegionType::Rocky);
+
+        let target_rect = Bounds {
+            min: MOUTH_OF_CAVE,
+            max: (10, 10),
+        };
+        assert_eq!(cave.risk_level_rect(&target_rect), 114);
+
+        // M=
+        // .|
+        let bounds = Bounds {
+            min: MOUTH_OF_CAVE,
+            max: (1, 1),
+        };
+        assert_eq!(cave.risk_level_rect(&bounds), 3);
+
+        // the rectangles don't need to start at the mouth of the cave
+        let bounds = Bounds {
+            min: (1, 1),
+            max: (1, 1),
+        };
+        assert_eq!(cave.risk_level_rect(&bounds), 2);
+
+        let route = cave.fastest_rescue().unwrap();
+        assert_eq!(route.get_time(), 45);
+
+        let (_time, end) = route.get_steps().last().unwrap();
+        assert_eq!(end.position, (10, 10));
+        assert_eq!(end.tool, Tool::Torch);
+    }
+
+    #[test]
+    fn test_cave_rules() {
+        let fastest_time = |rules: CaveRules| -> Option<Time> {
+            let (route, _stats) =
+                get_fastest_route(510, (10, 10), rules, SearchStrategy::AStar, None);
+            return route.map(|route| route.get_time());
+        };
+
+        let rules = |move_time: Time, switch_tool_time: Time| CaveRules {
+            move_time,
+            switch_tool_time,
+        };
+
+        assert_eq!(CaveRules::default(), rules(1, 7));
+        assert_eq!(fastest_time(rules(1, 7)), Some(45));
+
+        assert!(rules(0, 7).validate().is_err());
+        assert!(rules(1, -1).validate().is_err());
+        assert!(rules(1, 0).validate().is_ok());
+
+        // any two adjacent regions allow a common tool, so free switches only leave the moves
+        assert_eq!(fastest_time(rules(1, 0)), Some(20));
+        assert_eq!(fastest_time(rules(3, 0)), Some(60));
+
+        // scaling every cost scales the fastest time
+        assert_eq!(fastest_time(rules(2, 14)), Some(90));
+
+        // making moves or switches slower never makes the rescue faster
+        let mut previous = 0;
+        for switch_tool_time in 0..=12 {
+            let time = fastest_time(rules(1, switch_tool_time)).unwrap();
+            assert!(time >= previous);
+            previous = time;
+        }
+
+        let mut previous = 0;
+        for move_time in 1..=6 {
+            let time = fastest_time(rules(move_time, 7)).unwrap();
+            assert!(time >= previous);
+            previous = time;
+
+            // the unbounded search agrees with the bounded one
+            let mut cave = Cave::new(510, (10, 10), rules(move_time, 7));
+            assert_eq!(cave.shortest_time_to((10, 10), Tool::Torch), Some(time));
+        }
+    }
+
+    #[test]
+    fn test_search_bounds() {
+        // the fastest route of the example goes beyond the target
+        assert_eq!(part_2(510, (10, 10), Some(0)), Some(55));
+        assert_eq!(part_2(510, (10, 10), Some(5)), Some(45));
+
+        // too small of a padding yields a slower route
+        assert_eq!(part_2(4002, (5, 746), Some(0)), Some(1200));
+
+        let mut cave = Cave::new(4002, (5, 746), CaveRules::default());
+        // (1200 - (5 + 746)) / 2
+        assert_eq!(cave.get_safe_padding(), 224);
+
+        let bounds = cave.get_search_bounds(10);
+        assert_eq!(bounds.max, (15, 756));
+        assert!(bounds.contains(&(0, 0)));
+        assert!(bounds.contains(&(15, 756)));
+        assert!(!bounds.contains(&(16, 756)));
+        assert!(!bounds.contains(&(-1, 0)));
+    }
+
+    #[test]
+    fn test_precompute() {
+        let mut cave = Cave::new(510, (10, 10), CaveRules::default());
+
+        assert_eq!(cave.get_chunk_count(), 1);
+
+        // from the puzzle description
+        assert_eq!(cave.get_geologic_index(&(0, 0)), 0);
+        assert_eq!(cave.get_erosion_level(&(0, 0)), 510);
+        assert_eq!(cave.get_geologic_index(&(1, 0)), 16807);
+        assert_eq!(cave.get_erosion_level(&(1, 0)), 17317);
+        assert_eq!(cave.get_geologic_index(&(0, 1)), 48271);
+        assert_eq!(cave.get_erosion_level(&(0, 1)), 8415);
+        assert_eq!(cave.get_geologic_index(&(1, 1)), 145722555);
+        assert_eq!(cave.get_erosion_level(&(1, 1)), 1805);
+        assert_eq!(cave.get_geologic_index(&(10, 10)), 0);
+        assert_eq!(cave.get_erosion_level(&(10, 10)), 510);
+
+        assert_eq!(
+            cave.to_string(),
+            r###"
+M=.|=.|.|=.
+.|=|=|||..|
+.==|....||=
+=.|....|.==
+=|..==...=.
+=||.=.=||=|
+|.=.===|||.
+|..==||=.|=
+.=..===..=|
+.======|||=
+.===|=|===T
+        "###
+            .trim()
+        );
+
+        // regions beyond the precomputed bounds are computed on demand
+        assert_eq!(cave.get_region_type(&(15, 15)), RegionType::Narrow);
+        assert_eq!(cave.get_chunk_count(), 1);
+
+        cave.precompute(&Bounds {
+            min: MOUTH_OF_CAVE,
+            max: (200, 5),
+        });
+        assert_eq!(cave.get_chunk_count(), 4);
+
+        // only the chunks from the mouth of the cave up to a region are computed
+        let mut cave = Cave::new(4002, (5, 746), CaveRules::default());
+        assert_eq!(cave.get_chunk_count(), 12);
+        assert!(cave.erosion_levels.iter().all(|row| row.len() == 1));
+
+        cave.get_region_type(&(1000, 0));
+        assert_eq!(cave.erosion_levels[0].len(), 16);
+        assert_eq!(cave.get_chunk_count(), 16 + 11);
+
+        cave.get_region_type(&(200, 200));
+        assert_eq!(cave.get_chunk_count(), 16 + 4 * 3 + 8);
+
+        // the parallel and the sequential precomputation agree, also when extending computed chunks
+        for max in [(300, 300), (1000, 40), (40, 1000)] {
+            let mut sequential = Cave::new(4002, (5, 746), CaveRules::default());
+            let mut parallel = Cave::new(4002, (5, 746), CaveRules::default());
+
+            let (max_chunk_x, max_chunk_y) = (max.0 / CHUNK_SIZE, max.1 / CHUNK_SIZE);
+            sequential.precompute_sequential(max_chunk_x, max_chunk_y);
+            parallel.precompute_parallel(max_chunk_x, max_chunk_y);
+
+            assert!(sequential.erosion_levels == parallel.erosion_levels);
+        }
+
+        // deep caves don't overflow the stack
+        let mut cave = Cave::new(4002, (5, 746), CaveRules::default());
+        assert_eq!(cave.get_region_type(&(2000, 2000)), {
+            let mut other_cave = Cave::new(4002, (5, 746), CaveRules::default());
+            other_cave.precompute(&Bounds {
+                min: MOUTH_OF_CAVE,
+                max: (2000, 2000),
+            });
+            other_cave.get_region_type(&(2000, 2000))
+        });
+    }
+
+    // the erosion levels of the rectangle from the mouth of the cave up to max,
+    // computed straight from the puzzle description
+    fn get_reference_erosion_levels(
+        depth: Depth,
+        target: Coordinate,
+        max: Coordinate,
+    ) -> Vec<Vec<ErosionLevel>> {
+        let (max_x, max_y) = (max.0 as usize, max.1 as usize);
+        let mut erosion_levels = vec![vec![0; max_x + 1]; max_y + 1];
+
+        for y in 0..=max_y {
+            for x in 0..=max_x {
+                let geologic_index = if (x, y) == (0, 0) || (x as i32, y as i32) == target {
+                    0
+                } else if y == 0 {
+                    x as i32 * 16807
+                } else if x == 0 {
+                    y as i32 * 48271
+                } else {
+                    erosion_levels[y][x - 1] * erosion_levels[y - 1][x]
+                };
+
+                erosion_levels[y][x] = (geologic_index + depth) % 20183;
+            }
+        }
+
+        return erosion_levels;
+    }
+
+    proptest! {
+        #[test]
+        fn test_erosion_levels_match_reference(
+            depth in 0..20183,
+            target in (0..40, 0..40),
+            padding in 0..100,
+        ) {
+            let max = (target.0 + padding, target.1 + padding);
+            let expected = get_reference_erosion_levels(depth, target, max);
+
+            let mut cave = Cave::new(depth, target, CaveRules::default());
+
+            for (y, row) in expected.iter().enumerate() {
+                for (x, erosion_level) in row.iter().enumerate() {
+                    let coord = (x as i32, y as i32);
+                    prop_assert_eq!(cave.get_erosion_level(&coord), *erosion_level);
+                    prop_assert_eq!(
+                        cave.get_region_type(&coord).risk_level(),
+                        erosion_level % 3
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn test_geologic_index_recurrence(
+            depth in 0..20183,
+            target in (0..40, 0..40),
+            coord in (0..200, 0..200),
+        ) {
+            let mut cave = Cave::new(depth, target, CaveRules::default());
+
+            let geologic_index = cave.get_geologic_index(&coord);
+            prop_assert_eq!(cave.get_erosion_level(&coord), (geologic_index + depth) % 20183);
+
+            let (x, y) = coord;
+            if coord == MOUTH_OF_CAVE || coord == target {
+                prop_assert_eq!(geologic_index, 0);
+            } else if y == 0 {
+                prop_assert_eq!(geologic_index, x * 16807);
+            } else if x == 0 {
+                prop_assert_eq!(geologic_index, y * 48271);
+            } else {
+                let left = cave.get_erosion_level(&coord.left());
+                let up = cave.get_erosion_level(&coord.up());
+                prop_assert_eq!(geologic_index, left * up);
+            }
+        }
+
+        // regions computed on demand agree with the reference regardless of the order they're looked up in
+        #[test]
+        fn test_erosion_levels_lookup_order(
+            depth in 0..20183,
+            target in (0..40, 0..40),
+            coords in prop::collection::vec((0..200, 0..200), 1..20),
+        ) {
+            let expected = get_reference_erosion_levels(depth, target, (199, 199));
+
+            let mut cave = Cave::new(depth, target, CaveRules::default());
+
+            for (x, y) in coords {
+                prop_assert_eq!(
+                    cave.get_erosion_level(&(x, y)),
+                    expected[y as usize][x as usize]
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_shortest_time_to() {
+        let mut cave = Cave::new(510, (10, 10), CaveRules::default());
+
+        // the target, arriving with the torch equipped, is the puzzle answer
+        assert_eq!(cave.shortest_time_to((10, 10), Tool::Torch), Some(45));
+        assert_eq!(cave.shortest_time_to(MOUTH_OF_CAVE, Tool::Torch), Some(0));
+        assert_eq!(
+            cave.shortest_time_to(MOUTH_OF_CAVE, Tool::ClimbingGear),
+            Some(CaveRules::default().switch_tool_time)
+        );
+
+        // the region at 1,0 is wet, so the torch can't be equipped there
+        assert_eq!(cave.shortest_time_to((1, 0), Tool::Torch), None);
+        assert_eq!(cave.shortest_time_to((1, 0), Tool::ClimbingGear), Some(8));
+        assert_eq!(cave.shortest_time_to((-1, 0), Tool::ClimbingGear), None);
+
+        // queries are answered the same regardless of what was computed before
+        let destinations = [
+            ((10, 10), Tool::Torch),
+            ((2, 15), Tool::ClimbingGear),
+            ((14, 3), Tool::None),
+            ((7, 4), Tool::Torch),
+            ((25, 20), Tool::ClimbingGear),
+        ];
+
+        let expected: Vec<Option<Time>> = destinations
+            .iter()
+            .map(|(coord, tool)| {
+                Cave::new(510, (10, 10), CaveRules::default()).shortest_time_to(*coord, *tool)
+            })
+            .collect();
+
+        let mut cave = Cave::new(510, (10, 10), CaveRules::default());
+        let actual: Vec<Option<Time>> = destinations
+            .iter()
+            .rev()
+            .map(|(coord, tool)| cave.shortest_time_to(*coord, *tool))
+            .collect();
+
+        assert_eq!(actual.into_iter().rev().collect::<Vec<_>>(), expected);
+
+        // it agrees with the bounded search when the target is the destination
+        for target in [(7, 4), (3, 12), (14, 9)] {
+            let mut cave = Cave::new(510, target, CaveRules::default());
+            let padding = cave.get_safe_padding();
+            let expected = cave.find_target(&cave.get_search_bounds(padding));
+
+            assert_eq!(
+                Cave::new(510, target, CaveRules::default()).shortest_time_to(target, Tool::Torch),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_edges() {
+        let mut cave = Cave::new(510, (10, 10), CaveRules::default());
+        let bounds = cave.get_search_bounds(5);
+
+        let mut edges_of = |position: Coordinate, tool: Tool| {
+            let mut edges = vec![];
+            cave.get_edges(&State { position, tool }, &bounds, &mut edges);
+            return edges;
+        };
+
+        // the mouth of the cave is rocky; the region to its right is wet
+        assert_eq!(
+            edges_of((0, 0), Tool::Torch),
+            vec![Edge::Move((0, 1)), Edge::SwitchTool(Tool::ClimbingGear)]
+        );
+        assert_eq!(
+            edges_of((0, 0), Tool::ClimbingGear),
+            vec![
+                Edge::Move((1, 0)),
+                Edge::Move((0, 1)),
+                Edge::SwitchTool(Tool::Torch)
+            ]
+        );
+
+        // narrow region, surrounded by wet regions except for the rocky region to its left
+        assert_eq!(
+            edges_of((1, 1), Tool::Torch),
+            vec![Edge::Move((0, 1)), Edge::SwitchTool(Tool::None)]
+        );
+    }
+
+    #[test]
+    fn test_example_route() {
+        let mut cave = Cave::new(510, (10, 10), CaveRules::default());
+        let bounds = cave.get_search_bounds(5);
+
+        // the route from the puzzle description
+        let route: Vec<(Coordinate, Tool)> = vec![
+            ((0, 0), Tool::Torch),
+            ((0, 1), Tool::Torch),
+            ((1, 1), Tool::Torch),
+            ((1, 1), Tool::None),
+            ((2, 1), Tool::None),
+            ((3, 1), Tool::None),
+            ((4, 1), Tool::None),
+            ((4, 1), Tool::ClimbingGear),
+            ((4, 2), Tool::ClimbingGear),
+            ((4, 3), Tool::ClimbingGear),
+            ((4, 4), Tool::ClimbingGear),
+            ((4, 5), Tool::ClimbingGear),
+            ((4, 6), Tool::ClimbingGear),
+            ((4, 7), Tool::ClimbingGear),
+            ((4, 8), Tool::ClimbingGear),
+            ((4, 9), Tool::ClimbingGear),
+            ((5, 9), Tool::ClimbingGear),
+            ((5, 10), Tool::ClimbingGear),
+            ((5, 11), Tool::ClimbingGear),
+            ((5, 12), Tool::ClimbingGear),
+            ((6, 12), Tool::ClimbingGear),
+            ((7, 12), Tool::ClimbingGear),
+            ((8, 12), Tool::ClimbingGear),
+            ((8, 11), Tool::ClimbingGear),
+            ((8, 10), Tool::ClimbingGear),
+            ((9, 10), Tool::ClimbingGear),
+            ((10, 10), Tool::ClimbingGear),
+            ((10, 10), Tool::Torch),
+        ];
+
+        let mut time = 0;
+        let mut num_of_switches = 0;
+
+        for window in route.windows(2) {
+            let (position, tool) = window[0];
+            let (next_position, next_tool) = window[1];
+
+            let mut edges = vec![];
+            cave.get_edges(&State { position, tool }, &bounds, &mut edges);
+
+            let edge = if position == next_position {
+                num_of_switches += 1;
+                time += CaveRules::default().switch_tool_time;
+                Edge::SwitchTool(next_tool)
+            } else {
+                time += 1;
+                Edge::Move(next_position)
+            };
+
+            assert!(edges.contains(&edge));
+        }
+
+        assert_eq!(num_of_switches, 3);
+        assert_eq!(time, 45);
+        assert_eq!(cave.find_target(&bounds), Some(time));
+    }
+
+    #[test]
+    fn test_route() {
+        let route = get_fastest_route(
+            510,
+            (10, 10),
+            CaveRules::default(),
+            SearchStrategy::AStar,
+            None,
+        )
+        .0
+        .unwrap();
+
+        assert_eq!(route.get_time(), 45);
+
+        let (time, start) = route.steps.first().unwrap();
+        assert_eq!(*time, 0);
+        assert_eq!(start.position, MOUTH_OF_CAVE);
+        assert_eq!(start.tool, Tool::Torch);
+
+        let (_time, end) = route.steps.last().unwrap();
+        assert_eq!(end.position, (10, 10));
+        assert_eq!(end.tool, Tool::Torch);
+
+        // the route is made of edges of the search graph
+        let mut cave = Cave::new(510, (10, 10), CaveRules::default());
+        let bounds = cave.get_search_bounds(10);
+
+        for window in route.steps.windows(2) {
+            let (time, state) = &window[0];
+            let (next_time, next_state) = &window[1];
+
+            let edge = if state.position == next_state.position {
+                assert_eq!(next_time - time, CaveRules::default().switch_tool_time);
+                Edge::SwitchTool(next_state.tool)
+            } else {
+                assert_eq!(next_time - time, 1);
+                Edge::Move(next_state.position)
+            };
+
+            let mut edges = vec![];
+            cave.get_edges(state, &bounds, &mut edges);
+            assert!(edges.contains(&edge));
+        }
+
+        // 21 minutes are spent switching tools, and 24 minutes are spent moving
+        assert_eq!(route.get_tool_switches().len(), 3);
+        assert_eq!(route.steps.len(), 1 + 3 + 24);
+
+        let route_string = route.to_string();
+        assert!(route_string.starts_with("    0  0,0  torch"));
+        assert!(route_string.ends_with("   45  10,10  switch from climbing gear to torch"));
+
+        let route = get_fastest_route(
+            4002,
+            (5, 746),
+            CaveRules::default(),
+            SearchStrategy::AStar,
+            None,
+        )
+        .0
+        .unwrap();
+        assert_eq!(route.get_time(), 1032);
+    }
+
+    #[test]
+    fn test_search_stats() {
+        let mut cave = Cave::new(510, (10, 10), CaveRules::default());
+
+        let (route, stats) = cave.find_route(&cave.get_search_bounds(5), SearchStrategy::AStar);
+        assert_eq!(route.unwrap().get_time(), 45);
+
+        assert!(stats.nodes_expanded > 0);
+        assert!(stats.states_pushed >= stats.nodes_expanded);
+        assert!(stats.max_frontier_size > 0);
+        assert!(stats.max_frontier_size <= stats.states_pushed);
+
+        // there's no route to the target if the target is out of bounds
+        let bounds = Bounds {
+            min: MOUTH_OF_CAVE,
+            max: (5, 5),
+        };
+        let (route, stats) = cave.find_route(&bounds, SearchStrategy::AStar);
+        assert_eq!(route, None);
+        // 36 regions with 2 tools each
+        assert_eq!(stats.nodes_expanded, 36 * 2);
+
+        // the strategies find equally fast routes, A* by expanding fewer states than Dijkstra
+        for target in [(10, 10), (7, 4), (3, 12), (14, 9), (0, 0), (1, 0)] {
+            let mut cave = Cave::new(510, target, CaveRules::default());
+            let padding = cave.get_safe_padding();
+            let bounds = cave.get_search_bounds(padding);
+
+            let (dijkstra_route, dijkstra_stats) =
+                cave.find_route(&bounds, SearchStrategy::Dijkstra);
+            let (a_star_route, a_star_stats) = cave.find_route(&bounds, SearchStrategy::AStar);
+
+            assert_eq!(
+                dijkstra_route.map(|route| route.get_time()),
+                a_star_route.map(|route| route.get_time())
+            );
+            assert!(a_star_stats.nodes_expanded <= dijkstra_stats.nodes_expanded);
+        }
+    }
+
+    #[test]
+    fn test_bidirectional_search() {
+        let targets = [(10, 10), (7, 4), (3, 12), (14, 9), (0, 0), (1, 0), (0, 20)];
+
+        for target in targets.iter() {
+            let mut cave = Cave::new(510, *target, CaveRules::default());
+            let padding = cave.get_safe_padding();
+            let bounds = cave.get_search_bounds(padding);
+
+            let (route, _stats) = cave.find_route(&bounds, SearchStrategy::Bidirectional);
+            let route = route.unwrap();
+
+            assert_eq!(Some(route.get_time()), cave.find_target(&bounds));
+
+            // the route goes from the mouth of the cave to the target along edges of the search graph
+            let steps = route.get_steps();
+            assert_eq!(
+                steps.first().unwrap(),
+                &(
+                    0,
+                    State {
+                        position: MOUTH_OF_CAVE,
+                        tool: Tool::Torch
+                    }
+                )
+            );
+            assert_eq!(
+                steps.last().unwrap().1,
+                State {
+                    position: *target,
+                    tool: Tool::Torch
+                }
+            );
+
+            for window in steps.windows(2) {
+                let (time, state) = &window[0];
+                let (next_time, next_state) = &window[1];
+
+                let (edge, edge_time) = if state.position == next_state.position {
+                    (Edge::SwitchTool(next_state.tool), 7)
+                } else {
+                    (Edge::Move(next_state.position), 1)
+                };
+
+                let mut edges = vec![];
+                cave.get_edges(state, &bounds, &mut edges);
+                assert!(edges.contains(&edge));
+                assert_eq!(next_time - time, edge_time);
+            }
+        }
+
+        // other rules
+        let rules = CaveRules {
+            move_time: 3,
+            switch_tool_time: 2,
+        };
+        let mut cave = Cave::new(510, (10, 10), rules);
+        let padding = cave.get_safe_padding();
+        let bounds = cave.get_search_bounds(padding);
+        let (route, _stats) = cave.find_route(&bounds, SearchStrategy::Bidirectional);
+        assert_eq!(
+            route.map(|route| route.get_time()),
+            cave.find_target(&bounds)
+        );
+
+        // there's no route to the target if the target is out of bounds
+        let bounds = Bounds {
+            min: MOUTH_OF_CAVE,
+            max: (5, 5),
+        };
+        let mut cave = Cave::new(510, (10, 10), CaveRules::default());
+        let (route, _stats) = cave.find_route(&bounds, SearchStrategy::Bidirectional);
+        assert_eq!(route, None);
+    }
+
+    #[test]
+    fn test_render() {
+        let route = get_fastest_route(
+            510,
+            (10, 10),
+            CaveRules::default(),
+            SearchStrategy::AStar,
+            None,
+        )
+        .0
+        .unwrap();
+
+        let mut cave = Cave::new(510, (10, 10), CaveRules::default());
+        let bounds = route.get_bounds((10, 10));
+
+        let rendered = cave.render(&bounds, Some(&route), false);
+        let rows: Vec<&str> = rendered.lines().collect();
+
+        assert!(route.steps.iter().all(|(_time, state)| {
+            let (x, y) = state.position;
+            let glyph = rows[y as usize].chars().nth(x as usize).unwrap();
+            return glyph == 'X' || glyph == 'M' || glyph == 'T';
+        }));
+
+        // 24 moves; the mouth and the target are not rendered as X
+        assert_eq!(rendered.matches('X').count(), 24 + 1 - 2);
+        assert_eq!(rows.len() as i32, bounds.max.1 + 1);
+        assert!(rows.iter().all(|row| row.len() as i32 == bounds.max.0 + 1));
+
+        let colored = cave.render(&bounds, Some(&route), true);
+        assert!(colored.contains(ANSI_UNDERLINE));
+        assert!(colored.contains(Tool::ClimbingGear.to_color()));
+        assert_eq!(colored.lines().count(), rows.len());
+    }
+
+    #[test]
+    fn test_time_cost_min_heap() {
+        let mut available_squares: BinaryHeap<TimeCoordinate> = BinaryHeap::new();
+
+        let items = vec![
+            TimeCoordinate::new(5, 1, (1, 26), Tool::Torch),
+            TimeCoordinate::new(1, 2, (1, 26), Tool::Torch),
+            TimeCoordinate::new(4, 3, (1, 26), Tool::Torch),
+            TimeCoordinate::new(1, 4, (1, 26), Tool::Torch),
+        ];
+        available_squares.extend(items);
+
+        let mut actual: Vec<TimeCoordinate> = vec![];
+        while let Some(item) = available_squares.pop() {
+            actual.push(item);
+        }
+
+        let expected = vec![
+            TimeCoordinate::new(1, 2, (1, 26), Tool::Torch),
+            TimeCoordinate::new(1, 4, (1, 26), Tool::Torch),
+            TimeCoordinate::new(5, 1, (1, 26), Tool::Torch),
+            TimeCoordinate::new(4, 3, (1, 26), Tool::Torch),
+        ];
+
+        assert_eq!(actual, expected);
+    }
+}
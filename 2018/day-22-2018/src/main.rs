@@ -2,442 +2,289 @@
 
 // imports
 
-use std::cmp::Ordering;
-use std::collections::BinaryHeap;
-use std::collections::HashMap;
-use std::collections::HashSet;
+use day_22_2018::{
+    get_fastest_route, parse_coordinate, parse_input, part_1, Cave, CaveRules, Coordinate, Depth,
+    Distance, SearchStrategy, Time, Tool,
+};
+use solver::load_input;
 
-// code
-
-type Cost = i32;
-
-// adapted from day 6
-// https://math.stackexchange.com/a/139604/10247
-type Distance = i32;
-fn get_manhattan_distance(start: Coordinate, end: Coordinate) -> Distance {
-    let (a, b) = start;
-    let (c, d) = end;
-
-    return (a - c).abs() + (b - d).abs();
-}
-
-#[derive(PartialEq, Hash, Eq, Clone, Debug)]
-struct TimeCoordinate {
-    time: Time,
-    distance: Distance,
-    position: Coordinate,
-    tool: Tool,
-}
-
-impl TimeCoordinate {
-    fn new(time: Time, distance: Distance, position: Coordinate, tool: Tool) -> Self {
-        return TimeCoordinate {
-            time,
-            distance,
-            position,
-            tool,
-        };
-    }
-
-    fn get_cost(&self) -> Cost {
-        return self.distance + self.time;
-    }
-
-    fn move_to_square(&self, distance: Distance, new_position: Coordinate) -> Self {
-        assert!(get_manhattan_distance(new_position, self.position) == 1);
-        assert!(distance >= 0);
-
-        let mut next = self.clone();
-
-        next.distance = distance;
-        next.position = new_position;
-        next.time += 1;
-
-        return next;
-    }
-
-    fn switch_tool(&self, next_tool: Tool) -> Self {
-        assert!(next_tool != self.tool);
-
-        let mut next = self.clone();
-
-        next.tool = next_tool;
-        next.time += TIME_TO_SWITCH_TOOL;
-
-        assert!(next.time > self.time);
-
-        return next;
-    }
-}
-
-impl Ord for TimeCoordinate {
-    fn cmp(&self, other: &Self) -> Ordering {
-        // reversed for the binary heap which is a max-heap
-        return (self.get_cost()).cmp(&(other.get_cost())).reverse();
-    }
-}
-
-impl PartialOrd for TimeCoordinate {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        // reversed for the binary heap which is a max-heap
-        return Some(self.cmp(other));
-    }
-}
-
-// takes 7 minutes to switch tools
-const TIME_TO_SWITCH_TOOL: Time = 7;
-
-#[derive(Debug, Eq, PartialEq, Hash, Clone)]
-enum Tool {
-    None, // neither
-    Torch,
-    ClimbingGear,
-}
-
-type Coordinate = (i32, i32);
-type GeologicIndex = i32;
-type RiskLevel = i32;
-type ErosionLevel = i32;
-type Depth = i32;
-type Time = i32;
-
-const MOUTH_OF_CAVE: Coordinate = (0, 0);
-
-trait Transitions {
-    fn up(&self) -> Coordinate;
-    fn down(&self) -> Coordinate;
-    fn left(&self) -> Coordinate;
-    fn right(&self) -> Coordinate;
-}
+mod cache;
+use cache::{Answers, CacheKey};
 
-impl Transitions for Coordinate {
-    fn up(&self) -> Coordinate {
-        let (x, y) = self;
-        return (*x, y - 1);
-    }
-
-    fn down(&self) -> Coordinate {
-        let (x, y) = self;
-        return (*x, y + 1);
-    }
-
-    fn left(&self) -> Coordinate {
-        let (x, y) = self;
-        return (x - 1, *y);
-    }
+// code
 
-    fn right(&self) -> Coordinate {
-        let (x, y) = self;
-        return (x + 1, *y);
+fn parse_strategy(strategy: &str) -> Result<SearchStrategy, String> {
+    match strategy {
+        "dijkstra" => Ok(SearchStrategy::Dijkstra),
+        "astar" => Ok(SearchStrategy::AStar),
+        "bidirectional" => Ok(SearchStrategy::Bidirectional),
+        _ => Err(format!("Unknown strategy: {}", strategy)),
     }
 }
 
-#[derive(Debug, Clone, PartialEq)]
-enum RegionType {
-    Rocky,
-    Narrow,
-    Wet,
+struct Options {
+    // path to the input file; - reads from stdin
+    path: Option<String>,
+    // print the fastest route of part 2
+    show_route: bool,
+    // render the cave with the fastest route of part 2 overlaid
+    render: bool,
+    // print statistics of the part 2 search
+    show_stats: bool,
+    // print the fastest time to each of these regions, arriving with the torch equipped
+    time_to: Vec<Coordinate>,
+    rules: CaveRules,
+    // how the part 2 search is done
+    strategy: SearchStrategy,
+    // how far beyond the target the part 2 search may go; a safe padding is computed if not given
+    padding: Option<Distance>,
+    // print statistics of the part 2 search for every strategy
+    compare_strategies: bool,
+    // reuse the answers of previous runs with the same puzzle input and rules
+    use_cache: bool,
 }
 
-impl RegionType {
-    fn risk_level(&self) -> RiskLevel {
-        match self {
-            RegionType::Rocky => 0,
-            RegionType::Wet => 1,
-            RegionType::Narrow => 2,
-        }
-    }
-
-    fn to_string(&self) -> String {
-        let result = match self {
-            RegionType::Rocky => ".",
-            RegionType::Wet => "=",
-            RegionType::Narrow => "|",
-        };
-        return result.to_string();
-    }
-
-    fn required_tools(&self) -> HashSet<Tool> {
-        let mut set = HashSet::new();
-
-        match self {
-            RegionType::Rocky => {
-                set.insert(Tool::ClimbingGear);
-                set.insert(Tool::Torch);
-                return set;
+// parse command line arguments:
+// [--show-route] [--render] [--stats] [--time-to <x>,<y>]... [--move-time <n>] [--switch-tool-time <n>]
+// [--strategy <dijkstra|astar|bidirectional>] [--padding <n>] [--compare-strategies] [--cache] [path]
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let mut options = Options {
+        path: None,
+        show_route: false,
+        render: false,
+        show_stats: false,
+        time_to: vec![],
+        rules: CaveRules::default(),
+        strategy: SearchStrategy::AStar,
+        padding: None,
+        compare_strategies: false,
+        use_cache: false,
+    };
+
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--show-route" => {
+                options.show_route = true;
             }
-            RegionType::Wet => {
-                set.insert(Tool::ClimbingGear);
-                set.insert(Tool::None);
-                return set;
+            "--render" => {
+                options.render = true;
             }
-            RegionType::Narrow => {
-                set.insert(Tool::None);
-                set.insert(Tool::Torch);
-                return set;
+            "--stats" => {
+                options.show_stats = true;
             }
-        }
-    }
-}
-
-struct Cave {
-    depth: Depth,
-    target: Coordinate,
-    geologic_indices: HashMap<Coordinate, GeologicIndex>,
-    region_types: HashMap<Coordinate, RegionType>,
-}
-
-impl Cave {
-    fn new(depth: Depth, target: Coordinate) -> Self {
-        let mut geologic_indices = HashMap::new();
-        let region_types = HashMap::new();
-
-        // The region at 0,0 (the mouth of the cave) has a geologic index of 0.
-        geologic_indices.insert(MOUTH_OF_CAVE, 0);
-
-        // The region at the coordinates of the target has a geologic index of 0.
-        geologic_indices.insert(target, 0);
-
-        Cave {
-            depth,
-            target,
-            geologic_indices,
-            region_types,
-        }
-    }
-
-    fn get_risk_level(&mut self, coord: &Coordinate) -> RiskLevel {
-        return self.get_region_type(coord).risk_level();
-    }
-
-    fn get_region_type(&mut self, coord: &Coordinate) -> RegionType {
-        match self.region_types.get(coord) {
-            Some(region_type) => {
-                return region_type.clone();
+            "--compare-strategies" => {
+                options.compare_strategies = true;
             }
-            None => {}
-        }
-
-        let result = self.get_erosion_level(coord) % 3;
-
-        let result = match result {
-            0 => RegionType::Rocky,
-            1 => RegionType::Wet,
-            2 => RegionType::Narrow,
-            _ => {
-                unreachable!();
+            "--cache" => {
+                options.use_cache = true;
             }
-        };
-
-        self.region_types.insert(*coord, result.clone());
-
-        return result;
-    }
-
-    fn get_adjacent_squares(&self, coord: &Coordinate) -> Vec<Coordinate> {
-        let adjacent = vec![coord.left(), coord.right(), coord.up(), coord.down()];
-
-        return adjacent
-            .into_iter()
-            .filter(|coord| {
-                let (x, y) = coord;
-                return x >= &0 && y >= &0;
-            })
-            .collect();
-    }
-
-    fn get_erosion_level(&mut self, coord: &Coordinate) -> ErosionLevel {
-        return (self.get_geologic_index(coord) + self.depth) % 20183;
-    }
-
-    fn find_target(&mut self) -> Option<Time> {
-        let mut available_squares: BinaryHeap<TimeCoordinate> = BinaryHeap::new();
-        // keep track of the best minimum time spent for a coordinate
-        let mut best_costs: HashMap<(Tool, Coordinate), Time> = HashMap::new();
-
-        // You start at 0,0 (the mouth of the cave) with the torch equipped
-
-        available_squares.push(TimeCoordinate::new(
-            0,
-            get_manhattan_distance(MOUTH_OF_CAVE, self.target),
-            MOUTH_OF_CAVE,
-            Tool::Torch,
-        ));
-
-        while let Some(current_square) = available_squares.pop() {
-            let current_position = current_square.position;
-            let current_tool = current_square.tool.clone();
-
-            let current_region = self.get_region_type(&current_position);
-
-            if (current_region == RegionType::Rocky && current_tool == Tool::None)
-                || (current_region == RegionType::Wet && current_tool == Tool::Torch)
-                || (current_region == RegionType::Narrow && current_tool == Tool::ClimbingGear)
-            {
-                continue;
+            "--time-to" => {
+                let coord = args
+                    .next()
+                    .ok_or_else(|| "Expected a coordinate after --time-to".to_string())?;
+                let coord = parse_coordinate(coord)
+                    .filter(|(x, y)| *x >= 0 && *y >= 0)
+                    .ok_or_else(|| format!("Invalid coordinate: {}", coord))?;
+                options.time_to.push(coord);
             }
-
-            if best_costs
-                .get(&(current_tool.clone(), current_position))
-                .unwrap_or(&i32::max_value())
-                <= &current_square.time
-            {
-                continue;
+            "--strategy" => {
+                let strategy = args
+                    .next()
+                    .ok_or_else(|| "Expected a strategy after --strategy".to_string())?;
+                options.strategy = parse_strategy(strategy)?;
             }
-
-            best_costs.insert(
-                (current_tool.clone(), current_position),
-                current_square.time,
-            );
-
-            if current_position == self.target && current_tool == Tool::Torch {
-                return Some(current_square.time);
-            }
-
-            // add all possible movements
-            for adjacent_square in self.get_adjacent_squares(&current_position) {
-                let next = current_square.move_to_square(
-                    get_manhattan_distance(adjacent_square, self.target),
-                    adjacent_square,
-                );
-
-                available_squares.push(next);
+            "--padding" => {
+                let padding: Distance = args
+                    .next()
+                    .ok_or_else(|| "Expected a padding after --padding".to_string())?
+                    .parse()
+                    .ok()
+                    .filter(|padding| *padding >= 0)
+                    .ok_or_else(|| "Invalid padding".to_string())?;
+                options.padding = Some(padding);
             }
-
-            // switch tools
-
-            let required_tools = self
-                .get_region_type(&current_square.position)
-                .required_tools();
-
-            for next_tool in required_tools {
-                if next_tool != current_square.tool {
-                    let next = current_square.switch_tool(next_tool);
-
-                    available_squares.push(next);
+            "--move-time" | "--switch-tool-time" => {
+                let time: Time = args
+                    .next()
+                    .ok_or_else(|| format!("Expected a time after {}", arg))?
+                    .parse()
+                    .map_err(|_| format!("Invalid time for {}", arg))?;
+
+                if arg == "--move-time" {
+                    options.rules.move_time = time;
+                } else {
+                    options.rules.switch_tool_time = time;
                 }
             }
-        }
+            _ => {
+                if arg.starts_with("--") {
+                    return Err(format!("Unknown flag: {}", arg));
+                }
 
-        return None;
-    }
+                if options.path.is_some() {
+                    return Err(format!("Unexpected argument: {}", arg));
+                }
 
-    fn get_geologic_index(&mut self, coord: &Coordinate) -> GeologicIndex {
-        match self.geologic_indices.get(coord) {
-            Some(index) => {
-                return *index;
+                options.path = Some(arg.clone());
             }
-            None => {
-                // generate one
-            }
-        }
-
-        if *coord == MOUTH_OF_CAVE {
-            return 0;
-        }
-
-        if *coord == self.target {
-            return 0;
         }
+    }
 
-        let (x, y) = coord;
-        let geologic_index = if *y == 0 {
-            // If the region's Y coordinate is 0,
-            // the geologic index is its X coordinate times 16807.
-            x * 16807
-        } else if *x == 0 {
-            // If the region's X coordinate is 0,
-            // the geologic index is its Y coordinate times 48271.
-            y * 48271
-        } else {
-            // Otherwise, the region's geologic index is
-            // the result of multiplying the erosion levels of the regions at X-1,Y and X,Y-1.
-            self.get_erosion_level(&coord.left()) * self.get_erosion_level(&coord.up())
-        };
+    options.rules.validate()?;
 
-        self.geologic_indices.insert(*coord, geologic_index);
+    return Ok(options);
+}
 
-        return geologic_index;
+// search for the fastest route with every strategy, and print a table of their statistics
+fn print_strategy_comparison(depth: Depth, target: Coordinate, options: &Options) {
+    let strategies = vec![
+        ("dijkstra", SearchStrategy::Dijkstra),
+        ("astar", SearchStrategy::AStar),
+        ("bidirectional", SearchStrategy::Bidirectional),
+    ];
+
+    println!(
+        "{:<15}{:>8}{:>16}{:>16}{:>20}{:>16}",
+        "strategy", "time", "nodes expanded", "states pushed", "max frontier size", "elapsed"
+    );
+
+    for (name, strategy) in strategies {
+        let (route, stats) =
+            get_fastest_route(depth, target, options.rules, strategy, options.padding);
+        let time = route
+            .map(|route| route.get_time().to_string())
+            .unwrap_or_else(|| "-".to_string());
+
+        println!(
+            "{:<15}{:>8}{:>16}{:>16}{:>20}{:>16}",
+            name,
+            time,
+            stats.nodes_expanded,
+            stats.states_pushed,
+            stats.max_frontier_size,
+            format!("{:.2?}", stats.elapsed)
+        );
     }
+}
 
-    #[allow(dead_code)]
-    fn to_string(&mut self) -> String {
-        let (target_x, target_y) = self.target;
-
-        let mut map_string: Vec<String> = vec![];
-
-        for y in 0..=target_y {
-            let mut row_string = String::from("");
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let options = parse_args(&args).unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        std::process::exit(1);
+    });
 
-            for x in 0..=target_x {
-                let coord = (x, y);
+    // input
 
-                if coord == MOUTH_OF_CAVE {
-                    row_string.push_str("M");
-                    continue;
-                }
+    let input_string = load_input(options.path.as_deref()).unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        std::process::exit(1);
+    });
+
+    let (depth, target) = parse_input(&input_string).unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        std::process::exit(1);
+    });
+
+    let key = CacheKey {
+        depth,
+        target,
+        rules: options.rules,
+    };
+    let cache_dir = if options.use_cache {
+        cache::get_cache_dir()
+    } else {
+        None
+    };
+
+    // cached answers are only used if nothing else about the search is asked for.
+    // a given padding may be too small to contain a fastest route, so its answers aren't cached either.
+    let needs_search =
+        options.show_stats || options.render || options.show_route || options.padding.is_some();
+    let cached_answers = match &cache_dir {
+        Some(cache_dir) if !needs_search => cache::load(cache_dir, &key),
+        _ => None,
+    };
+
+    match cached_answers {
+        Some(answers) => {
+            println!("Part 1: {}", answers.part_1);
+            println!("Part 2: {}", format_part_2(answers.part_2));
+        }
+        None => {
+            let answers = solve(depth, target, &options);
 
-                if coord == self.target {
-                    row_string.push_str("T");
-                    continue;
+            if let (Some(cache_dir), None) = (&cache_dir, options.padding) {
+                if let Err(err) = cache::store(cache_dir, &key, &answers) {
+                    eprintln!("{}", err);
                 }
-
-                let result = self.get_region_type(&coord).to_string();
-
-                row_string.push_str(&result);
             }
-
-            map_string.push(row_string);
         }
-
-        return map_string.join("\n");
     }
-}
-
-fn part_1(depth: Depth, target: Coordinate) -> RiskLevel {
-    let (target_x, target_y) = target;
-
-    let mut cave = Cave::new(depth, target);
-
-    let mut total_risk: RiskLevel = 0;
 
-    for x in 0..=target_x {
-        for y in 0..=target_y {
-            let coord = (x, y);
+    if options.compare_strategies {
+        print_strategy_comparison(depth, target, &options);
+    }
 
-            total_risk += cave.get_risk_level(&coord);
+    if !options.time_to.is_empty() {
+        let mut cave = Cave::new(depth, target, options.rules);
+        for coord in options.time_to.iter() {
+            let (x, y) = coord;
+            match cave.shortest_time_to(*coord, Tool::Torch) {
+                Some(time) => println!("Fastest time to {},{}: {}", x, y, time),
+                None => println!("Fastest time to {},{}: unreachable with the torch", x, y),
+            }
         }
     }
-
-    // println!("{}", cave.to_string());
-
-    return total_risk;
 }
 
-fn part_2(depth: Depth, target: Coordinate) -> Option<Time> {
-    let mut cave = Cave::new(depth, target);
+fn format_part_2(part_2: Option<Time>) -> String {
+    match part_2 {
+        Some(time) => time.to_string(),
+        None => "no route".to_string(),
+    }
+}
 
-    assert!(cave.get_region_type(&target) == RegionType::Rocky);
+// compute and print the answers, along with whatever else about the part 2 search is asked for
+fn solve(depth: Depth, target: Coordinate, options: &Options) -> Answers {
+    let part_1 = part_1(depth, target);
+    println!("Part 1: {}", part_1);
 
-    let part_2 = cave.find_target();
+    let (route, stats) = get_fastest_route(
+        depth,
+        target,
+        options.rules,
+        options.strategy,
+        options.padding,
+    );
 
-    return part_2;
-}
-
-fn main() {
-    // input
+    if options.show_stats {
+        println!("{}", stats.to_string());
+    }
 
-    let depth = 4002;
-    let target: Coordinate = (5, 746);
+    if options.render {
+        if let Some(route) = &route {
+            let mut cave = Cave::new(depth, target, options.rules);
+            println!(
+                "{}",
+                cave.render(&route.get_bounds(target), Some(route), true)
+            );
+        }
+    }
 
-    // let depth = 11820;
-    // let target: Coordinate = (7, 782);
+    if options.show_route {
+        if let Some(route) = &route {
+            println!("{}", route.to_string());
+            println!("Tool switches: {}", route.get_tool_switches().len());
+        }
+    }
 
-    let part_1 = part_1(depth, target);
-    println!("Part 1: {}", part_1);
+    let part_2 = route.map(|route| route.get_time());
+    println!("Part 2: {}", format_part_2(part_2));
 
-    let part_2 = part_2(depth, target);
-    println!("Part 2: {:?}", part_2);
+    return Answers { part_1, part_2 };
 }
 
 #[cfg(test)]
@@ -445,41 +292,79 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_part_1() {
-        assert_eq!(part_1(510, (10, 10)), 114);
-        assert_eq!(part_1(4002, (5, 746)), 4479);
-    }
+    fn test_parse_args() {
+        let to_args = |args: Vec<&str>| -> Vec<String> {
+            return args.into_iter().map(|x| x.to_string()).collect();
+        };
 
-    #[test]
-    fn test_part_2() {
-        assert_eq!(part_2(510, (10, 10)), Some(45));
-        // assert_eq!(part_2(11820, (7, 782)), Some(1075));
+        let options = parse_args(&to_args(vec![])).unwrap();
+        assert_eq!(options.path, None);
+        assert!(!options.show_route);
+
+        let options = parse_args(&to_args(vec!["--show-route", "input.txt"])).unwrap();
+        assert_eq!(options.path, Some("input.txt".to_string()));
+        assert!(options.show_route);
+        assert!(!options.render);
+
+        let options = parse_args(&to_args(vec!["--render", "--stats"])).unwrap();
+        assert!(options.render);
+        assert!(options.show_stats);
+
+        let options = parse_args(&to_args(vec!["--time-to", "3,4", "--time-to", "10, 0"])).unwrap();
+        assert_eq!(options.time_to, vec![(3, 4), (10, 0)]);
+
+        assert!(parse_args(&to_args(vec!["--foo"])).is_err());
+        assert!(parse_args(&to_args(vec!["a.txt", "b.txt"])).is_err());
+        assert!(parse_args(&to_args(vec!["--time-to"])).is_err());
+        assert!(parse_args(&to_args(vec!["--time-to", "3"])).is_err());
+        assert!(parse_args(&to_args(vec!["--time-to", "-1,3"])).is_err());
+
+        let options = parse_args(&to_args(vec![
+            "--move-time",
+            "2",
+            "--switch-tool-time",
+            "3",
+        ]))
+        .unwrap();
+        assert_eq!(
+            options.rules,
+            CaveRules {
+                move_time: 2,
+                switch_tool_time: 3,
+            }
+        );
+        assert!(parse_args(&to_args(vec!["--move-time", "0"])).is_err());
+        assert!(parse_args(&to_args(vec!["--switch-tool-time"])).is_err());
+        assert!(parse_args(&to_args(vec!["--switch-tool-time", "x"])).is_err());
+
+        let options = parse_args(&to_args(vec![])).unwrap();
+        assert_eq!(options.strategy, SearchStrategy::AStar);
+        let options = parse_args(&to_args(vec!["--strategy", "dijkstra"])).unwrap();
+        assert_eq!(options.strategy, SearchStrategy::Dijkstra);
+        let options = parse_args(&to_args(vec!["--strategy", "bidirectional"])).unwrap();
+        assert_eq!(options.strategy, SearchStrategy::Bidirectional);
+        assert!(!options.compare_strategies);
+        let options = parse_args(&to_args(vec!["--compare-strategies"])).unwrap();
+        assert!(options.compare_strategies);
+        assert!(parse_args(&to_args(vec!["--strategy", "bfs"])).is_err());
+
+        assert!(!options.use_cache);
+        let options = parse_args(&to_args(vec!["--cache", "input.txt"])).unwrap();
+        assert!(options.use_cache);
+        assert_eq!(options.path, Some("input.txt".to_string()));
+        assert!(parse_args(&to_args(vec!["--strategy"])).is_err());
+
+        assert_eq!(options.padding, None);
+        let options = parse_args(&to_args(vec!["--padding", "20"])).unwrap();
+        assert_eq!(options.padding, Some(20));
+        assert!(parse_args(&to_args(vec!["--padding"])).is_err());
+        assert!(parse_args(&to_args(vec!["--padding", "-1"])).is_err());
+        assert!(parse_args(&to_args(vec!["--padding", "x"])).is_err());
     }
 
     #[test]
-    fn test_time_cost_min_heap() {
-        let mut available_squares: BinaryHeap<TimeCoordinate> = BinaryHeap::new();
-
-        let items = vec![
-            TimeCoordinate::new(5, 1, (1, 26), Tool::Torch),
-            TimeCoordinate::new(1, 2, (1, 26), Tool::Torch),
-            TimeCoordinate::new(4, 3, (1, 26), Tool::Torch),
-            TimeCoordinate::new(1, 4, (1, 26), Tool::Torch),
-        ];
-        available_squares.extend(items);
-
-        let mut actual: Vec<TimeCoordinate> = vec![];
-        while let Some(item) = available_squares.pop() {
-            actual.push(item);
-        }
-
-        let expected = vec![
-            TimeCoordinate::new(1, 2, (1, 26), Tool::Torch),
-            TimeCoordinate::new(1, 4, (1, 26), Tool::Torch),
-            TimeCoordinate::new(5, 1, (1, 26), Tool::Torch),
-            TimeCoordinate::new(4, 3, (1, 26), Tool::Torch),
-        ];
-
-        assert_eq!(actual, expected);
+    fn test_format_part_2() {
+        assert_eq!(format_part_2(Some(1032)), "1032");
+        assert_eq!(format_part_2(None), "no route");
     }
 }
@@ -0,0 +1,402 @@
+// https://adventofcode.com/2018/day/23
+
+// imports
+
+use solver::{Example, Solver};
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+// code
+
+pub type Distance = i64;
+type Radius = i64;
+type Coordinate = (i64, i64, i64);
+
+const ORIGIN: Coordinate = (0, 0, 0);
+
+// adapted from day 6
+// https://math.stackexchange.com/a/139604/10247
+fn get_manhattan_distance(start: Coordinate, end: Coordinate) -> Distance {
+    let (a, b, e) = start;
+    let (c, d, f) = end;
+
+    return (a - c).abs() + (b - d).abs() + (e - f).abs();
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct NanoBot {
+    position: Coordinate,
+    radius: Radius,
+}
+
+impl NanoBot {
+    fn new(position: Coordinate, radius: Radius) -> Self {
+        NanoBot { position, radius }
+    }
+
+    fn in_range(&self, position: Coordinate) -> bool {
+        return get_manhattan_distance(self.position, position) <= self.radius;
+    }
+}
+
+// pos=<0,0,0>, r=4
+fn parse_nanobot(input: &str) -> Result<NanoBot, String> {
+    let invalid = || format!("Invalid nanobot: {}", input);
+
+    let (position, radius) = input
+        .trim()
+        .strip_prefix("pos=<")
+        .and_then(|rest| {
+            let mut parts = rest.splitn(2, ">,");
+            return Some((parts.next()?, parts.next()?));
+        })
+        .ok_or_else(invalid)?;
+
+    let position: Vec<i64> = position
+        .split(',')
+        .map(|x| x.trim().parse().map_err(|_| invalid()))
+        .collect::<Result<Vec<i64>, String>>()?;
+
+    let radius: Radius = radius
+        .trim()
+        .strip_prefix("r=")
+        .and_then(|radius| radius.parse().ok())
+        .filter(|radius| *radius >= 0)
+        .ok_or_else(invalid)?;
+
+    match position.as_slice() {
+        [x, y, z] => {
+            return Ok(NanoBot::new((*x, *y, *z), radius));
+        }
+        _ => {
+            return Err(invalid());
+        }
+    }
+}
+
+fn parse_input(input_string: &str) -> Result<Vec<NanoBot>, String> {
+    let nanobots = input_string
+        .trim()
+        .lines()
+        .map(parse_nanobot)
+        .collect::<Result<Vec<NanoBot>, String>>()?;
+
+    if nanobots.is_empty() {
+        return Err("No nanobots".to_string());
+    }
+
+    return Ok(nanobots);
+}
+
+// the number of nanobots in range of the nanobot with the largest signal radius
+pub fn part_1(input_string: &str) -> Result<usize, String> {
+    let nanobots = parse_input(input_string)?;
+
+    let strongest_nanobot = nanobots.iter().max_by_key(|bot| bot.radius).unwrap();
+
+    let num_in_range = nanobots
+        .iter()
+        .filter(|bot| strongest_nanobot.in_range(bot.position))
+        .count();
+
+    return Ok(num_in_range);
+}
+
+// the distance between a value and the closest value within min..=max
+fn distance_to_range(value: i64, min: i64, max: i64) -> Distance {
+    if value < min {
+        return min - value;
+    }
+
+    if value > max {
+        return value - max;
+    }
+
+    return 0;
+}
+
+// a cube of coordinates, whose sides are a power of 2 long
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Cube {
+    // the corner of the cube with the smallest coordinates
+    corner: Coordinate,
+    size: i64,
+}
+
+impl Cube {
+    // the smallest cube that covers every coordinate
+    fn bounding(coordinates: &[Coordinate]) -> Cube {
+        let min_x = coordinates.iter().map(|(x, _y, _z)| *x).min().unwrap();
+        let min_y = coordinates.iter().map(|(_x, y, _z)| *y).min().unwrap();
+        let min_z = coordinates.iter().map(|(_x, _y, z)| *z).min().unwrap();
+
+        let max_x = coordinates.iter().map(|(x, _y, _z)| *x).max().unwrap();
+        let max_y = coordinates.iter().map(|(_x, y, _z)| *y).max().unwrap();
+        let max_z = coordinates.iter().map(|(_x, _y, z)| *z).max().unwrap();
+
+        let extent = (max_x - min_x).max(max_y - min_y).max(max_z - min_z) + 1;
+
+        let mut size = 1;
+        while size < extent {
+            size *= 2;
+        }
+
+        return Cube {
+            corner: (min_x, min_y, min_z),
+            size,
+        };
+    }
+
+    // the fewest steps from the position to any coordinate within the cube
+    fn distance_to(&self, position: Coordinate) -> Distance {
+        let (x, y, z) = self.corner;
+        let (a, b, c) = position;
+        let last = self.size - 1;
+
+        return distance_to_range(a, x, x + last)
+            + distance_to_range(b, y, y + last)
+            + distance_to_range(c, z, z + last);
+    }
+
+    // the eight cubes with sides half as long that make up the cube
+    fn split(&self) -> Vec<Cube> {
+        let (x, y, z) = self.corner;
+        let size = self.size / 2;
+
+        let mut cubes = vec![];
+
+        for dx in &[0, size] {
+            for dy in &[0, size] {
+                for dz in &[0, size] {
+                    cubes.push(Cube {
+                        corner: (x + dx, y + dy, z + dz),
+                        size,
+                    });
+                }
+            }
+        }
+
+        return cubes;
+    }
+}
+
+// a cube that is yet to be searched
+#[derive(Debug, PartialEq, Eq)]
+struct SearchCube {
+    cube: Cube,
+    // the number of nanobots in range of at least one coordinate within the cube.
+    // no coordinate within the cube is in range of more nanobots than this.
+    num_in_range: usize,
+    // the distance from the origin to the closest coordinate within the cube
+    distance: Distance,
+}
+
+impl SearchCube {
+    fn new(cube: Cube, nanobots: &[NanoBot]) -> Self {
+        let num_in_range = nanobots
+            .iter()
+            .filter(|bot| cube.distance_to(bot.position) <= bot.radius)
+            .count();
+
+        SearchCube {
+            cube,
+            num_in_range,
+            distance: cube.distance_to(ORIGIN),
+        }
+    }
+}
+
+impl Ord for SearchCube {
+    // the binary heap is a max-heap: the cube in range of the most nanobots comes first,
+    // then the cube closest to the origin, then the smallest cube
+    fn cmp(&self, other: &Self) -> Ordering {
+        return self
+            .num_in_range
+            .cmp(&other.num_in_range)
+            .then_with(|| self.distance.cmp(&other.distance).reverse())
+            .then_with(|| self.cube.size.cmp(&other.cube.size).reverse());
+    }
+}
+
+impl PartialOrd for SearchCube {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+// the coordinate in range of the most nanobots; of those, the one closest to the origin.
+//
+// a cube that covers every nanobot and the origin is split into ever smaller cubes, searching the most promising
+// cube first. the number of nanobots in range of a cube is never less than that of any coordinate within it,
+// and its distance to the origin is never more, so the first cube of a single coordinate is the best coordinate.
+fn find_best_coordinate(nanobots: &[NanoBot]) -> Coordinate {
+    let mut coordinates: Vec<Coordinate> = nanobots.iter().map(|bot| bot.position).collect();
+
+    // moving a coordinate towards this cube brings it closer to every nanobot, and to the origin
+    coordinates.push(ORIGIN);
+
+    let mut queue: BinaryHeap<SearchCube> = BinaryHeap::new();
+    queue.push(SearchCube::new(Cube::bounding(&coordinates), nanobots));
+
+    while let Some(search_cube) = queue.pop() {
+        if search_cube.cube.size == 1 {
+            return search_cube.cube.corner;
+        }
+
+        for cube in search_cube.cube.split() {
+            queue.push(SearchCube::new(cube, nanobots));
+        }
+    }
+
+    unreachable!();
+}
+
+// the distance from the origin to the coordinate in range of the most nanobots, and closest to the origin
+pub fn part_2(input_string: &str) -> Result<Distance, String> {
+    let nanobots = parse_input(input_string)?;
+
+    let best_coordinate = find_best_coordinate(&nanobots);
+
+    return Ok(get_manhattan_distance(best_coordinate, ORIGIN));
+}
+
+pub const SOLVER: Solver = Solver {
+    year: 2018,
+    day: 23,
+    input: include_str!("input.txt"),
+    part_1: |input_string| Ok(part_1(input_string)?.to_string()),
+    part_2: Some(|input_string| Ok(part_2(input_string)?.to_string())),
+    examples: &[
+        Example {
+            input: include_str!("../examples/1.txt"),
+            part_1: Some("7"),
+            part_2: None,
+        },
+        Example {
+            input: include_str!("../examples/2.txt"),
+            part_1: None,
+            part_2: Some("36"),
+        },
+    ],
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_nanobot() {
+        assert_eq!(
+            parse_nanobot("pos=<1,-20,3>, r=4"),
+            Ok(NanoBot::new((1, -20, 3), 4))
+        );
+
+        assert!(parse_nanobot("pos=<1,2>, r=4").is_err());
+        assert!(parse_nanobot("pos=<1,2,3>, r=-4").is_err());
+        assert!(parse_nanobot("pos=<1,2,3> r=4").is_err());
+        assert!(parse_input("").is_err());
+    }
+
+    #[test]
+    fn test_part_1() {
+        let input = include_str!("../examples/1.txt");
+
+        assert_eq!(part_1(input), Ok(7));
+
+        let input_string = include_str!("input.txt");
+        assert_eq!(part_1(input_string), Ok(737));
+    }
+
+    #[test]
+    fn test_cube() {
+        let cube = Cube::bounding(&[(0, 0, 0), (2, -3, 1)]);
+        assert_eq!(
+            cube,
+            Cube {
+                corner: (0, -3, 0),
+                size: 4
+            }
+        );
+
+        assert_eq!(cube.distance_to((1, -1, 2)), 0);
+        assert_eq!(cube.distance_to((5, 1, -2)), 2 + 1 + 2);
+
+        let cubes = cube.split();
+        assert_eq!(cubes.len(), 8);
+        assert!(cubes.iter().all(|cube| cube.size == 2));
+        assert!(cubes.contains(&Cube {
+            corner: (2, -1, 2),
+            size: 2
+        }));
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input = include_str!("../examples/2.txt");
+
+        let nanobots = parse_input(input).unwrap();
+        assert_eq!(find_best_coordinate(&nanobots), (12, 12, 12));
+        assert_eq!(part_2(input), Ok(36));
+
+        let input_string = include_str!("input.txt");
+        assert_eq!(part_2(input_string), Ok(123356173));
+    }
+
+    #[test]
+    fn test_part_2_brute_force() {
+        // small swarms of nanobots, compared with checking every coordinate
+        let mut seed: i64 = 7;
+        let mut random = |range: i64| -> i64 {
+            seed = (seed * 1103515245 + 12345) % 2147483648;
+            return (seed / 65536) % range;
+        };
+
+        for _ in 0..20 {
+            let nanobots: Vec<NanoBot> = (0..8)
+                .map(|_| {
+                    let position = (random(21) - 10, random(21) - 10, random(21) - 10);
+                    return NanoBot::new(position, random(8));
+                })
+                .collect();
+
+            let mut best: Option<(usize, Distance)> = None;
+
+            for x in -20..=20 {
+                for y in -20..=20 {
+                    for z in -20..=20 {
+                        let num_in_range = nanobots
+                            .iter()
+                            .filter(|bot| bot.in_range((x, y, z)))
+                            .count();
+                        let candidate = (num_in_range, get_manhattan_distance((x, y, z), ORIGIN));
+
+                        best = match best {
+                            Some((best_num, best_distance))
+                                if best_num > candidate.0
+                                    || (best_num == candidate.0
+                                        && best_distance <= candidate.1) =>
+                            {
+                                Some((best_num, best_distance))
+                            }
+                            _ => Some(candidate),
+                        };
+                    }
+                }
+            }
+
+            let best_coordinate = find_best_coordinate(&nanobots);
+            let num_in_range = nanobots
+                .iter()
+                .filter(|bot| bot.in_range(best_coordinate))
+                .count();
+
+            assert_eq!(
+                Some((
+                    num_in_range,
+                    get_manhattan_distance(best_coordinate, ORIGIN)
+                )),
+                best
+            );
+        }
+    }
+}
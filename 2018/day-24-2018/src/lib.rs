@@ -0,0 +1,437 @@
+// https://adventofcode.com/2018/day/24
+
+// imports
+
+use solver::{Example, Solver};
+use std::cmp::Reverse;
+use std::collections::HashSet;
+
+// code
+
+pub type Units = i64;
+type Damage = i64;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Army {
+    ImmuneSystem,
+    Infection,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Group {
+    army: Army,
+
+    num_of_units: Units,
+    hit_points: Units,
+
+    attack_damage: Damage,
+    attack_type: String,
+    initiative: i64,
+
+    immunities: HashSet<String>,
+    weaknesses: HashSet<String>,
+}
+
+impl Group {
+    fn effective_power(&self) -> Damage {
+        return self.attack_damage * self.num_of_units;
+    }
+
+    // the damage this group would deal to the defending group, accounting for its weaknesses and immunities,
+    // but not accounting for whether it has enough units to actually receive all of that damage
+    fn damage_to(&self, defending_group: &Group) -> Damage {
+        if defending_group.immunities.contains(&self.attack_type) {
+            return 0;
+        }
+
+        if defending_group.weaknesses.contains(&self.attack_type) {
+            return 2 * self.effective_power();
+        }
+
+        return self.effective_power();
+    }
+
+    // only whole units die; returns the number of units that died
+    fn take_damage(&mut self, damage: Damage) -> Units {
+        let num_of_units_dead = (damage / self.hit_points).min(self.num_of_units);
+        self.num_of_units -= num_of_units_dead;
+
+        return num_of_units_dead;
+    }
+
+    fn is_alive(&self) -> bool {
+        return self.num_of_units > 0;
+    }
+}
+
+// (immune to fire; weak to bludgeoning, slashing)
+fn parse_traits(
+    input: &str,
+    immunities: &mut HashSet<String>,
+    weaknesses: &mut HashSet<String>,
+) -> Result<(), String> {
+    for traits in input.split(';').map(|traits| traits.trim()) {
+        let (set, list) = if let Some(list) = traits.strip_prefix("immune to ") {
+            (&mut *immunities, list)
+        } else if let Some(list) = traits.strip_prefix("weak to ") {
+            (&mut *weaknesses, list)
+        } else {
+            return Err(format!("Invalid weaknesses or immunities: {}", traits));
+        };
+
+        set.extend(
+            list.split(',')
+                .map(|attack_type| attack_type.trim().to_string()),
+        );
+    }
+
+    return Ok(());
+}
+
+// 17 units each with 5390 hit points (weak to radiation, bludgeoning) with an attack that does 4507 fire damage at initiative 2
+fn parse_group(input: &str, army: Army) -> Result<Group, String> {
+    let invalid = || format!("Invalid group: {}", input);
+
+    let mut immunities = HashSet::new();
+    let mut weaknesses = HashSet::new();
+
+    // the weaknesses and immunities are optional
+    let description = match (input.find('('), input.find(')')) {
+        (Some(start), Some(end)) if start < end => {
+            parse_traits(&input[(start + 1)..end], &mut immunities, &mut weaknesses)?;
+            format!("{} {}", &input[..start], &input[(end + 1)..])
+        }
+        (None, None) => input.to_string(),
+        _ => {
+            return Err(invalid());
+        }
+    };
+
+    let words: Vec<&str> = description.split_whitespace().collect();
+
+    match words.as_slice() {
+        [num_of_units, "units", "each", "with", hit_points, "hit", "points", "with", "an", "attack", "that", "does", attack_damage, attack_type, "damage", "at", "initiative", initiative] =>
+        {
+            let parse_number = |x: &str| -> Result<i64, String> {
+                return x.parse().map_err(|_| invalid());
+            };
+
+            let group = Group {
+                army,
+                num_of_units: parse_number(num_of_units)?,
+                hit_points: parse_number(hit_points)?,
+                attack_damage: parse_number(attack_damage)?,
+                attack_type: attack_type.to_string(),
+                initiative: parse_number(initiative)?,
+                immunities,
+                weaknesses,
+            };
+
+            if group.hit_points <= 0 {
+                return Err(invalid());
+            }
+
+            return Ok(group);
+        }
+        _ => {
+            return Err(invalid());
+        }
+    }
+}
+
+fn parse_input(input_string: &str) -> Result<Vec<Group>, String> {
+    let mut groups = vec![];
+    let mut army = None;
+
+    for line in input_string.trim().lines().map(|line| line.trim()) {
+        match line {
+            "" => {}
+            "Immune System:" => {
+                army = Some(Army::ImmuneSystem);
+            }
+            "Infection:" => {
+                army = Some(Army::Infection);
+            }
+            _ => {
+                let army = army.ok_or_else(|| format!("Group without an army: {}", line))?;
+                groups.push(parse_group(line, army)?);
+            }
+        }
+    }
+
+    return Ok(groups);
+}
+
+// the attacking group and the defending group it chose, by their index
+type Target = (usize, usize);
+
+fn select_targets(groups: &[Group]) -> Vec<Target> {
+    // groups choose their targets in decreasing order of effective power; in a tie, the group with the
+    // higher initiative chooses first
+    let mut order: Vec<usize> = (0..groups.len()).collect();
+    order.sort_by_key(|index| {
+        let group = &groups[*index];
+        return Reverse((group.effective_power(), group.initiative));
+    });
+
+    let mut chosen: HashSet<usize> = HashSet::new();
+    let mut targets: Vec<Target> = vec![];
+
+    for attacker in order {
+        let attacking_group = &groups[attacker];
+
+        // the group it would deal the most damage to; in a tie, the defending group with the largest effective power,
+        // and then the defending group with the highest initiative
+        let target = groups
+            .iter()
+            .enumerate()
+            .filter(|(index, group)| group.army != attacking_group.army && !chosen.contains(index))
+            .map(|(index, group)| (index, group, attacking_group.damage_to(group)))
+            .filter(|(_index, _group, damage)| *damage > 0)
+            .max_by_key(|(_index, group, damage)| {
+                return (*damage, group.effective_power(), group.initiative);
+            });
+
+        if let Some((defender, _group, _damage)) = target {
+            chosen.insert(defender);
+            targets.push((attacker, defender));
+        }
+    }
+
+    return targets;
+}
+
+// fight one round; returns the number of units that died
+fn fight_round(groups: &mut Vec<Group>) -> Units {
+    let mut targets = select_targets(groups);
+
+    // groups attack in decreasing order of initiative
+    targets.sort_by_key(|(attacker, _defender)| Reverse(groups[*attacker].initiative));
+
+    let mut num_of_units_dead = 0;
+
+    for (attacker, defender) in targets {
+        // a group that lost all of its units earlier in the round doesn't attack
+        if !groups[attacker].is_alive() {
+            continue;
+        }
+
+        let damage = groups[attacker].damage_to(&groups[defender]);
+        num_of_units_dead += groups[defender].take_damage(damage);
+    }
+
+    groups.retain(|group| group.is_alive());
+
+    return num_of_units_dead;
+}
+
+#[derive(Debug, PartialEq)]
+enum Outcome {
+    // the army that won, and the number of units it has left
+    Won(Army, Units),
+    // neither army can kill any more units of the other
+    Stalemate,
+}
+
+fn fight(mut groups: Vec<Group>) -> Outcome {
+    loop {
+        let has_army = |army: Army| groups.iter().any(|group| group.army == army);
+
+        match (has_army(Army::ImmuneSystem), has_army(Army::Infection)) {
+            (true, true) => {}
+            (true, false) => {
+                return Outcome::Won(Army::ImmuneSystem, num_of_units(&groups));
+            }
+            (false, true) => {
+                return Outcome::Won(Army::Infection, num_of_units(&groups));
+            }
+            (false, false) => {
+                return Outcome::Stalemate;
+            }
+        }
+
+        if fight_round(&mut groups) == 0 {
+            return Outcome::Stalemate;
+        }
+    }
+}
+
+fn num_of_units(groups: &[Group]) -> Units {
+    return groups.iter().map(|group| group.num_of_units).sum();
+}
+
+fn boost(groups: &[Group], boost: Damage) -> Vec<Group> {
+    return groups
+        .iter()
+        .cloned()
+        .map(|mut group| {
+            if group.army == Army::ImmuneSystem {
+                group.attack_damage += boost;
+            }
+            return group;
+        })
+        .collect();
+}
+
+// the number of units the winning army has left
+pub fn part_1(input_string: &str) -> Result<Units, String> {
+    let groups = parse_input(input_string)?;
+
+    match fight(groups) {
+        Outcome::Won(_army, num_of_units) => {
+            return Ok(num_of_units);
+        }
+        Outcome::Stalemate => {
+            return Err("Neither army wins".to_string());
+        }
+    }
+}
+
+// the number of units the immune system has left, with the smallest boost that lets it win.
+//
+// a larger boost doesn't always fare better, since a stalemate may come about at some boosts but not others,
+// so every boost is tried in turn.
+pub fn part_2(input_string: &str) -> Result<Units, String> {
+    let groups = parse_input(input_string)?;
+
+    // past this boost, a single unit of the immune system wipes out any group it deals damage to,
+    // so the search gives up there
+    let max_hit_points = groups
+        .iter()
+        .map(|group| group.hit_points)
+        .max()
+        .unwrap_or(0);
+    let max_boost = max_hit_points * num_of_units(&groups);
+
+    for boost_amount in 0..=max_boost {
+        if let Outcome::Won(Army::ImmuneSystem, num_of_units) = fight(boost(&groups, boost_amount))
+        {
+            return Ok(num_of_units);
+        }
+    }
+
+    return Err(format!(
+        "No boost up to {} lets the immune system win",
+        max_boost
+    ));
+}
+
+pub const SOLVER: Solver = Solver {
+    year: 2018,
+    day: 24,
+    input: include_str!("input.txt"),
+    part_1: |input_string| Ok(part_1(input_string)?.to_string()),
+    part_2: Some(|input_string| Ok(part_2(input_string)?.to_string())),
+    examples: &[Example {
+        input: include_str!("../examples/1.txt"),
+        part_1: Some("5216"),
+        part_2: Some("51"),
+    }],
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../examples/1.txt");
+
+    #[test]
+    fn test_parse_input() {
+        let groups = parse_input(EXAMPLE).unwrap();
+
+        assert_eq!(groups.len(), 4);
+        assert_eq!(groups[1].army, Army::ImmuneSystem);
+        assert_eq!(groups[1].num_of_units, 989);
+        assert_eq!(groups[1].hit_points, 1274);
+        assert_eq!(groups[1].attack_damage, 25);
+        assert_eq!(groups[1].attack_type, "slashing");
+        assert_eq!(groups[1].initiative, 3);
+        assert!(groups[1].immunities.contains("fire"));
+        assert!(groups[1].weaknesses.contains("bludgeoning"));
+        assert!(groups[1].weaknesses.contains("slashing"));
+        assert_eq!(groups[2].army, Army::Infection);
+
+        let group = parse_group(
+            "10 units each with 20 hit points with an attack that does 3 cold damage at initiative 5",
+            Army::Infection,
+        )
+        .unwrap();
+        assert!(group.immunities.is_empty() && group.weaknesses.is_empty());
+
+        assert!(parse_group("10 units each with 20 hit points (weak to fire with an attack that does 3 cold damage at initiative 5", Army::Infection).is_err());
+        assert!(parse_group("10 units each with 20 hit points (strong to fire) with an attack that does 3 cold damage at initiative 5", Army::Infection).is_err());
+        assert!(parse_group("10 units each with 20 hit points", Army::Infection).is_err());
+        assert!(parse_input("10 units each with 20 hit points with an attack that does 3 cold damage at initiative 5").is_err());
+    }
+
+    #[test]
+    fn test_fight_round() {
+        let mut groups = parse_input(EXAMPLE).unwrap();
+
+        // infection group 1 would deal defending group 1 185832 damage, and defending group 2 185832 damage.
+        // it chooses defending group 1, which has the larger effective power.
+        assert_eq!(groups[2].damage_to(&groups[0]), 185832);
+        assert_eq!(groups[2].damage_to(&groups[1]), 185832);
+
+        let mut targets = select_targets(&groups);
+        targets.sort_unstable();
+        assert_eq!(targets, vec![(0, 3), (1, 2), (2, 0), (3, 1)]);
+
+        assert_eq!(fight_round(&mut groups), 17 + 4 + 84 + 51);
+
+        // immune system group 1 is gone
+        let num_of_units: Vec<Units> = groups.iter().map(|group| group.num_of_units).collect();
+        assert_eq!(num_of_units, vec![905, 797, 4434]);
+    }
+
+    #[test]
+    fn test_stalemate() {
+        // neither group can hurt the other
+        let input_string = r###"
+Immune System:
+10 units each with 20 hit points (immune to cold) with an attack that does 3 fire damage at initiative 2
+
+Infection:
+10 units each with 20 hit points (immune to fire) with an attack that does 3 cold damage at initiative 1
+        "###;
+        assert_eq!(
+            fight(parse_input(input_string).unwrap()),
+            Outcome::Stalemate
+        );
+
+        // damage is dealt, but never enough to kill a whole unit
+        let input_string = r###"
+Immune System:
+1 units each with 20 hit points with an attack that does 3 fire damage at initiative 2
+
+Infection:
+1 units each with 20 hit points with an attack that does 3 cold damage at initiative 1
+        "###;
+        assert_eq!(
+            fight(parse_input(input_string).unwrap()),
+            Outcome::Stalemate
+        );
+        assert!(part_1(input_string).is_err());
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(part_1(EXAMPLE), Ok(5216));
+
+        let input_string = include_str!("input.txt");
+        assert_eq!(part_1(input_string), Ok(14799));
+    }
+
+    #[test]
+    fn test_part_2() {
+        let groups = parse_input(EXAMPLE).unwrap();
+        assert_eq!(
+            fight(boost(&groups, 1570)),
+            Outcome::Won(Army::ImmuneSystem, 51)
+        );
+        assert_eq!(part_2(EXAMPLE), Ok(51));
+
+        let input_string = include_str!("input.txt");
+        assert_eq!(part_2(input_string), Ok(4428));
+    }
+}
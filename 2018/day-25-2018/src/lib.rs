@@ -0,0 +1,149 @@
+// https://adventofcode.com/2018/day/25
+
+// imports
+
+use solver::{Example, Solver};
+use union_find::UnionFind;
+
+// code
+
+type Distance = i32;
+type Coordinate = (i32, i32, i32, i32);
+
+// two points are in the same constellation if they're no further apart than this,
+// or if both are in the same constellation as a third point
+const MAX_DISTANCE: Distance = 3;
+
+// adapted from day 6
+// https://math.stackexchange.com/a/139604/10247
+fn get_manhattan_distance(start: Coordinate, end: Coordinate) -> Distance {
+    let (a, b, e, g) = start;
+    let (c, d, f, h) = end;
+
+    return (a - c).abs() + (b - d).abs() + (e - f).abs() + (g - h).abs();
+}
+
+// -1,2,2,0
+fn parse_point(input: &str) -> Result<Coordinate, String> {
+    let invalid = || format!("Invalid point: {}", input);
+
+    let coords: Vec<i32> = input
+        .split(',')
+        .map(|x| x.trim().parse().map_err(|_| invalid()))
+        .collect::<Result<Vec<i32>, String>>()?;
+
+    match coords.as_slice() {
+        [x, y, z, t] => {
+            return Ok((*x, *y, *z, *t));
+        }
+        _ => {
+            return Err(invalid());
+        }
+    }
+}
+
+fn parse_input(input_string: &str) -> Result<Vec<Coordinate>, String> {
+    return input_string
+        .trim()
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .map(parse_point)
+        .collect();
+}
+
+fn count_constellations(points: &[Coordinate]) -> usize {
+    let mut constellations = UnionFind::new(points.len());
+
+    for (index, point) in points.iter().enumerate() {
+        for (other_index, other_point) in points.iter().enumerate().skip(index + 1) {
+            if get_manhattan_distance(*point, *other_point) <= MAX_DISTANCE {
+                constellations.union(index, other_index);
+            }
+        }
+    }
+
+    return constellations.num_of_sets();
+}
+
+// the number of constellations formed by the fixed points in spacetime
+pub fn part_1(input_string: &str) -> Result<usize, String> {
+    let points = parse_input(input_string)?;
+
+    return Ok(count_constellations(&points));
+}
+
+pub const SOLVER: Solver = Solver {
+    year: 2018,
+    day: 25,
+    input: include_str!("input.txt"),
+    part_1: |input_string| Ok(part_1(input_string)?.to_string()),
+    part_2: None,
+    examples: &[
+        Example {
+            input: include_str!("../examples/1.txt"),
+            part_1: Some("2"),
+            part_2: None,
+        },
+        Example {
+            input: include_str!("../examples/2.txt"),
+            part_1: Some("4"),
+            part_2: None,
+        },
+        Example {
+            input: include_str!("../examples/3.txt"),
+            part_1: Some("3"),
+            part_2: None,
+        },
+        Example {
+            input: include_str!("../examples/4.txt"),
+            part_1: Some("8"),
+            part_2: None,
+        },
+    ],
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_input() {
+        assert_eq!(
+            parse_input(" 0,0,0,0\n-1,2,2,0\n"),
+            Ok(vec![(0, 0, 0, 0), (-1, 2, 2, 0)])
+        );
+
+        assert!(parse_input("0,0,0").is_err());
+        assert!(parse_input("0,0,0,x").is_err());
+    }
+
+    #[test]
+    fn test_examples() {
+        let input_string = include_str!("../examples/1.txt");
+
+        assert_eq!(part_1(input_string), Ok(2));
+
+        // a point that is close enough to both constellations joins them together
+        let mut points = parse_input(input_string).unwrap();
+        points.push((6, 0, 0, 0));
+        assert_eq!(count_constellations(&points), 1);
+
+        let input_string = include_str!("../examples/2.txt");
+
+        assert_eq!(part_1(input_string), Ok(4));
+
+        let input_string = include_str!("../examples/3.txt");
+
+        assert_eq!(part_1(input_string), Ok(3));
+
+        let input_string = include_str!("../examples/4.txt");
+
+        assert_eq!(part_1(input_string), Ok(8));
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(part_1(include_str!("input.txt")), Ok(352));
+    }
+}
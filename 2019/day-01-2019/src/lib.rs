@@ -0,0 +1,113 @@
+// https://adventofcode.com/2019/day/1
+
+use solver::{Example, Solver};
+
+pub type Mass = i64;
+
+// Fuel required to launch a given module is based on its mass.
+// Specifically, to find the fuel required for a module, take its mass, divide by three, round down, and subtract 2.
+fn fuel_required(mass: Mass) -> Mass {
+    return mass / 3 - 2;
+}
+
+// Fuel itself requires fuel just like a module. Any mass that would require negative fuel
+// should instead be treated as if it requires zero fuel.
+fn fuel_required_part_2(mass: Mass) -> Mass {
+    let mut sum = 0;
+    let mut last_fuel_required = fuel_required(mass);
+
+    while last_fuel_required > 0 {
+        sum += last_fuel_required;
+        last_fuel_required = fuel_required(last_fuel_required);
+    }
+
+    return sum;
+}
+
+// the mass of every module, one per line
+fn parse_input(input_string: &str) -> Result<Vec<Mass>, String> {
+    return input_string
+        .split_whitespace()
+        .map(|module| {
+            return module
+                .parse()
+                .map_err(|_| format!("Invalid module mass: {}", module));
+        })
+        .collect();
+}
+
+pub fn part_1(input_string: &str) -> Result<Mass, String> {
+    let modules = parse_input(input_string)?;
+
+    return Ok(modules.into_iter().map(fuel_required).sum());
+}
+
+pub fn part_2(input_string: &str) -> Result<Mass, String> {
+    let modules = parse_input(input_string)?;
+
+    return Ok(modules.into_iter().map(fuel_required_part_2).sum());
+}
+
+pub const SOLVER: Solver = Solver {
+    year: 2019,
+    day: 1,
+    input: include_str!("input.txt"),
+    part_1: |input_string| Ok(part_1(input_string)?.to_string()),
+    part_2: Some(|input_string| Ok(part_2(input_string)?.to_string())),
+    examples: &[
+        Example {
+            input: include_str!("../examples/1.txt"),
+            part_1: Some("2"),
+            part_2: None,
+        },
+        Example {
+            input: include_str!("../examples/2.txt"),
+            part_1: Some("2"),
+            part_2: Some("2"),
+        },
+        Example {
+            input: include_str!("../examples/3.txt"),
+            part_1: Some("654"),
+            part_2: Some("966"),
+        },
+        Example {
+            input: include_str!("../examples/4.txt"),
+            part_1: Some("33583"),
+            part_2: Some("50346"),
+        },
+    ],
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fuel_required() {
+        assert_eq!(fuel_required(12), 2);
+        assert_eq!(fuel_required(14), 2);
+        assert_eq!(fuel_required(1969), 654);
+        assert_eq!(fuel_required(100756), 33583);
+
+        assert_eq!(part_1(include_str!("../examples/1.txt")), Ok(2));
+        assert_eq!(part_1(include_str!("../examples/4.txt")), Ok(33583));
+        assert!(part_1("12\nfourteen\n").is_err());
+
+        let input_string = include_str!("input.txt");
+        assert_eq!(part_1(input_string), Ok(3249140));
+    }
+
+    #[test]
+    fn test_fuel_required_part_2() {
+        assert_eq!(fuel_required_part_2(14), 2);
+        assert_eq!(fuel_required_part_2(1969), 966);
+        assert_eq!(fuel_required_part_2(100756), 50346);
+        assert_eq!(part_2(include_str!("../examples/3.txt")), Ok(966));
+
+        // too light to need any fuel at all
+        assert_eq!(fuel_required_part_2(5), 0);
+
+        let input_string = include_str!("input.txt");
+        assert_eq!(part_2(input_string), Ok(4870838));
+    }
+}
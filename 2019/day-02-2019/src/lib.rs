@@ -0,0 +1,106 @@
+// https://adventofcode.com/2019/day/2
+
+use intcode::{parse_program, Computer, Value};
+use solver::Solver;
+
+// the output of the program, once halted, is the value at position 0
+fn run_computer(program: &[Value], noun: Value, verb: Value) -> Result<Value, String> {
+    let mut computer = Computer::new(program.to_vec());
+
+    // the noun is placed at position 1, and the verb at position 2
+    computer.write(1, noun)?;
+    computer.write(2, verb)?;
+
+    computer.run()?;
+
+    return computer.read(0);
+}
+
+pub fn part_1(input_string: &str) -> Result<Value, String> {
+    let program = parse_program(input_string)?;
+
+    // restore the gravity assist program (your puzzle input) to the "1202 program alarm" state
+    // it had just before the last computer caught fire. To do this, before running the program,
+    // replace position 1 with the value 12 and replace position 2 with the value 2.
+    return run_computer(&program, 12, 2);
+}
+
+// the noun and verb that produce the output
+fn find_noun_and_verb(program: &[Value], output: Value) -> Result<(Value, Value), String> {
+    // each of the two input values will be between 0 and 99, inclusive
+    for noun in 0..=99 {
+        for verb in 0..=99 {
+            if run_computer(program, noun, verb)? == output {
+                return Ok((noun, verb));
+            }
+        }
+    }
+
+    return Err(format!(
+        "Unable to find the noun and verb combination that produces {}",
+        output
+    ));
+}
+
+pub fn part_2(input_string: &str) -> Result<Value, String> {
+    let program = parse_program(input_string)?;
+
+    let (noun, verb) = find_noun_and_verb(&program, 19690720)?;
+
+    return Ok(100 * noun + verb);
+}
+
+pub const SOLVER: Solver = Solver {
+    year: 2019,
+    day: 2,
+    input: include_str!("input.txt"),
+    part_1: |input_string| Ok(part_1(input_string)?.to_string()),
+    part_2: Some(|input_string| Ok(part_2(input_string)?.to_string())),
+    // the examples are whole programs, which part 1 would break by writing its noun and verb into them
+    examples: &[],
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_computer() {
+        let run = |input_string: &str, noun: Value, verb: Value| -> Result<Value, String> {
+            return run_computer(&parse_program(input_string)?, noun, verb);
+        };
+
+        assert_eq!(run(include_str!("../examples/1.txt"), 9, 10), Ok(3500));
+        assert_eq!(run(include_str!("../examples/2.txt"), 0, 0), Ok(2));
+        assert_eq!(run(include_str!("../examples/3.txt"), 3, 0), Ok(2));
+        assert_eq!(run(include_str!("../examples/4.txt"), 4, 4), Ok(2));
+        assert_eq!(run(include_str!("../examples/5.txt"), 1, 1), Ok(30));
+    }
+
+    #[test]
+    fn test_find_noun_and_verb() {
+        // position 0 ends up as the sum of the values at the noun and the verb
+        let mut program = parse_program("1,0,0,0,99,7").unwrap();
+        program.resize(100, 0);
+
+        assert_eq!(find_noun_and_verb(&program, 2), Ok((0, 0)));
+        assert_eq!(find_noun_and_verb(&program, 8), Ok((0, 5)));
+        // the noun and verb are written into the program before it runs
+        assert_eq!(find_noun_and_verb(&program, 14), Ok((2, 14)));
+        assert!(find_noun_and_verb(&program, 1000).is_err());
+
+        // a noun or verb that points outside of memory
+        let program = parse_program(include_str!("../examples/2.txt")).unwrap();
+        assert!(find_noun_and_verb(&program, -1).is_err());
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(part_1(include_str!("input.txt")), Ok(3166704));
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(part_2(include_str!("input.txt")), Ok(8018));
+    }
+}
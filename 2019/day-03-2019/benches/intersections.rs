@@ -0,0 +1,52 @@
+// benchmark finding the intersections of two wires, trying every pair of segments, only the pairs with overlapping
+// bounding boxes, or sweeping across them.
+//
+// run with: cargo bench -p day-03-2019
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use day_03_2019::{generate_wires, get_wire_intersections, process_wires, SearchStrategy};
+
+fn bench_input(c: &mut Criterion) {
+    let wires = process_wires(include_str!("../src/input.txt").to_string()).unwrap();
+
+    let mut group = c.benchmark_group("intersections");
+
+    group.bench_function("all pairs", |b| {
+        b.iter(|| get_wire_intersections(&wires[0], &wires[1], SearchStrategy::AllPairs))
+    });
+
+    group.bench_function("bounding boxes", |b| {
+        b.iter(|| get_wire_intersections(&wires[0], &wires[1], SearchStrategy::BoundingBoxes))
+    });
+
+    group.bench_function("sweep line", |b| {
+        b.iter(|| get_wire_intersections(&wires[0], &wires[1], SearchStrategy::SweepLine))
+    });
+
+    group.finish();
+}
+
+// 100k segments in all; trying every pair is 2.5 billion pairs, which takes several seconds per run
+fn bench_generated(c: &mut Criterion) {
+    let wires = process_wires(generate_wires(50_000, 100, 2019)).unwrap();
+
+    let mut group = c.benchmark_group("intersections 100k segments");
+    group.sample_size(10);
+
+    group.bench_function("all pairs", |b| {
+        b.iter(|| get_wire_intersections(&wires[0], &wires[1], SearchStrategy::AllPairs))
+    });
+
+    group.bench_function("bounding boxes", |b| {
+        b.iter(|| get_wire_intersections(&wires[0], &wires[1], SearchStrategy::BoundingBoxes))
+    });
+
+    group.bench_function("sweep line", |b| {
+        b.iter(|| get_wire_intersections(&wires[0], &wires[1], SearchStrategy::SweepLine))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_input, bench_generated);
+criterion_main!(benches);
@@ -0,0 +1,1181 @@
+// https://adventofcode.com/2019/day/3
+
+// imports
+
+use geometry::{for_each_overlapping_pair, Coordinate, LineSegment};
+use solver::{Example, Solver};
+use std::collections::HashMap;
+use vector_image::Drawing;
+
+pub use geometry::{get_manhattan_distance, Distance};
+
+mod sweep;
+
+// code
+
+// the point where a horizontal and a vertical segment cross, in either order.
+// wire segments are always axis-aligned, so this is exact; collinear segments are left to line_segments_overlap.
+fn axis_aligned_intersection(
+    first_segment: LineSegment,
+    second_segment: LineSegment,
+) -> Option<Coordinate> {
+    let is_horizontal = |((_x_1, y_1), (_x_2, y_2)): LineSegment| y_1 == y_2;
+    let is_vertical = |((x_1, _y_1), (x_2, _y_2)): LineSegment| x_1 == x_2;
+
+    let (horizontal, vertical) = if is_horizontal(first_segment) && is_vertical(second_segment) {
+        (first_segment, second_segment)
+    } else if is_vertical(first_segment) && is_horizontal(second_segment) {
+        (second_segment, first_segment)
+    } else {
+        return None;
+    };
+
+    let ((x_1, y), (x_2, _)) = horizontal;
+    let ((x, y_1), (_, y_2)) = vertical;
+
+    if x_1.min(x_2) <= x && x <= x_1.max(x_2) && y_1.min(y_2) <= y && y <= y_1.max(y_2) {
+        return Some((x, y));
+    }
+
+    return None;
+}
+
+// the part that two collinear axis-aligned segments have in common, from its lowest to its highest coordinate
+fn line_segments_overlap(
+    first_segment: LineSegment,
+    second_segment: LineSegment,
+) -> Option<LineSegment> {
+    let (point_1, point_2) = first_segment;
+    let (point_3, point_4) = second_segment;
+
+    let (x_1, y_1) = point_1;
+    let (x_2, y_2) = point_2;
+    let (x_3, y_3) = point_3;
+    let (x_4, y_4) = point_4;
+
+    let is_vertical = x_1 == x_2 && x_3 == x_4 && x_1 == x_3;
+    let is_horizontal = y_1 == y_2 && y_3 == y_4 && y_1 == y_3;
+
+    if !is_vertical && !is_horizontal {
+        return None;
+    }
+
+    // the ranges of both segments along the axis they share
+    let (start_1, end_1, start_2, end_2) = if is_horizontal {
+        (x_1.min(x_2), x_1.max(x_2), x_3.min(x_4), x_3.max(x_4))
+    } else {
+        (y_1.min(y_2), y_1.max(y_2), y_3.min(y_4), y_3.max(y_4))
+    };
+
+    let start = start_1.max(start_2);
+    let end = end_1.min(end_2);
+
+    if start > end {
+        return None;
+    }
+
+    if is_horizontal {
+        return Some(((start, y_1), (end, y_1)));
+    }
+
+    return Some(((x_1, start), (x_1, end)));
+}
+
+// the points two segments have in common that can be closest to the central port,
+// either by manhattan distance or by steps.
+// for overlapping segments, that's either end of the overlap, or the points of the overlap nearest to the port.
+fn get_intersections(first_segment: LineSegment, second_segment: LineSegment) -> Vec<Coordinate> {
+    if let Some(coord) = axis_aligned_intersection(first_segment, second_segment) {
+        return vec![coord];
+    }
+
+    let ((start_x, start_y), (end_x, end_y)) =
+        match line_segments_overlap(first_segment, second_segment) {
+            None => {
+                return vec![];
+            }
+            Some(overlap) => overlap,
+        };
+
+    let closest_to_port = (0.max(start_x).min(end_x), 0.max(start_y).min(end_y));
+
+    let mut intersections = vec![(start_x, start_y), (end_x, end_y), closest_to_port];
+
+    // the port itself doesn't count, so its neighbours on the overlap might be the closest
+    if closest_to_port == (0, 0) {
+        let neighbours = vec![(-1, 0), (1, 0), (0, -1), (0, 1)];
+        for (x, y) in neighbours {
+            if start_x <= x && x <= end_x && start_y <= y && y <= end_y {
+                intersections.push((x, y));
+            }
+        }
+    }
+
+    intersections.sort();
+    intersections.dedup();
+
+    return intersections;
+}
+
+// a segment of a wire, along with the steps the wire takes to get to its start
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WireSegment {
+    pub start: Coordinate,
+    pub end: Coordinate,
+    pub steps_at_start: Distance,
+}
+
+impl WireSegment {
+    pub fn to_line_segment(&self) -> LineSegment {
+        return (self.start, self.end);
+    }
+
+    // the steps the wire takes to get to a point on this segment
+    pub fn get_steps_to(&self, coord: Coordinate) -> Distance {
+        return self.steps_at_start + get_manhattan_distance(self.start, coord);
+    }
+}
+
+// the segments of a wire, in the order the wire runs through them from the central port
+#[derive(Debug, Clone, PartialEq)]
+pub struct Wire(Vec<WireSegment>);
+
+impl Wire {
+    pub fn new(line_segments: &[LineSegment]) -> Wire {
+        let mut steps = 0;
+
+        let segments = line_segments
+            .iter()
+            .map(|(start, end)| {
+                let segment = WireSegment {
+                    start: *start,
+                    end: *end,
+                    steps_at_start: steps,
+                };
+                steps += get_manhattan_distance(*start, *end);
+                return segment;
+            })
+            .collect();
+
+        return Wire(segments);
+    }
+
+    pub fn get_segments(&self) -> &[WireSegment] {
+        return &self.0;
+    }
+
+    pub fn to_line_segments(&self) -> Vec<LineSegment> {
+        return self
+            .0
+            .iter()
+            .map(|segment| segment.to_line_segment())
+            .collect();
+    }
+}
+
+// an instruction of a wire that can't be followed
+#[derive(Debug, Clone, PartialEq)]
+pub struct WireParseError {
+    // which wire, and which instruction of that wire, counting from 1
+    pub wire: usize,
+    pub position: usize,
+    pub token: String,
+    pub reason: String,
+}
+
+impl WireParseError {
+    pub fn to_string(&self) -> String {
+        return format!(
+            "Invalid instruction {:?} of wire {} at position {}: {}",
+            self.token, self.wire, self.position, self.reason
+        );
+    }
+}
+
+// the steps of every wire, and of all of them together, fit in a Distance.
+// so do the combined steps to any intersection, and the distance of any point of a wire from the port.
+pub fn process_wires(input_string: String) -> Result<Vec<Wire>, WireParseError> {
+    let inputs: Vec<&str> = input_string.split_whitespace().collect();
+
+    let mut wires: Vec<Wire> = vec![];
+    let mut total_steps: Distance = 0;
+
+    for (wire_index, wire) in inputs.into_iter().enumerate() {
+        let mut current_coord: Coordinate = (0, 0);
+        let mut wire_steps: Distance = 0;
+        let mut line_segments: Vec<LineSegment> = vec![];
+
+        for (position, instructions) in wire.trim().split(',').enumerate() {
+            let instructions = instructions.trim();
+
+            let to_error = |reason: &str| -> WireParseError {
+                return WireParseError {
+                    wire: wire_index + 1,
+                    position: position + 1,
+                    token: instructions.to_string(),
+                    reason: reason.to_string(),
+                };
+            };
+
+            let direction: char = instructions
+                .chars()
+                .next()
+                .ok_or_else(|| to_error("missing direction"))?;
+            let steps: String = instructions.chars().skip(1).collect();
+            let steps: i32 = steps
+                .parse::<u32>()
+                .ok()
+                .filter(|steps| *steps <= i32::MAX as u32)
+                .ok_or_else(|| to_error("steps must be a non-negative number"))?
+                as i32;
+
+            wire_steps = wire_steps
+                .checked_add(steps)
+                .ok_or_else(|| to_error("wire is too long"))?;
+            total_steps = total_steps
+                .checked_add(steps)
+                .ok_or_else(|| to_error("wires are too long together"))?;
+
+            let previous_coord = current_coord;
+            let (x, y) = current_coord;
+
+            current_coord = match direction {
+                'U' => (
+                    x,
+                    y.checked_add(steps)
+                        .ok_or_else(|| to_error("wire is too long"))?,
+                ),
+                'D' => (
+                    x,
+                    y.checked_sub(steps)
+                        .ok_or_else(|| to_error("wire is too long"))?,
+                ),
+                'L' => (
+                    x.checked_sub(steps)
+                        .ok_or_else(|| to_error("wire is too long"))?,
+                    y,
+                ),
+                'R' => (
+                    x.checked_add(steps)
+                        .ok_or_else(|| to_error("wire is too long"))?,
+                    y,
+                ),
+                _ => {
+                    return Err(to_error(&format!(
+                        "unknown direction {:?}, expected one of U, D, L or R",
+                        direction
+                    )));
+                }
+            };
+
+            line_segments.push((previous_coord, current_coord));
+        }
+
+        wires.push(Wire::new(&line_segments));
+    }
+
+    return Ok(wires);
+}
+
+// how the pairs of segments that meet are found
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SearchStrategy {
+    // try every segment of one wire against every segment of the other
+    AllPairs,
+    // only try the pairs of segments whose bounding boxes overlap
+    BoundingBoxes,
+    // sweep across the segments in order, only trying the segments that are on the same line
+    SweepLine,
+}
+
+// the points where the wires meet, with the fewest combined steps to reach them, sorted by coordinate.
+// where the wires run along each other, only the points of the overlap that can be closest to the port are listed.
+// the central port doesn't count, even if the wires cross it again later on.
+// a point on the ends of segments is found for every segment it's on, but it's only listed once.
+pub fn get_wire_intersections(
+    wire_1: &Wire,
+    wire_2: &Wire,
+    strategy: SearchStrategy,
+) -> Vec<(Coordinate, Distance)> {
+    let segments_1 = wire_1.get_segments();
+    let segments_2 = wire_2.get_segments();
+
+    let mut intersections: HashMap<Coordinate, Distance> = HashMap::new();
+
+    let mut visit = |index_1: usize, index_2: usize| {
+        let segment_1 = segments_1[index_1];
+        let segment_2 = segments_2[index_2];
+
+        for intersection_coord in
+            get_intersections(segment_1.to_line_segment(), segment_2.to_line_segment())
+        {
+            if intersection_coord == (0, 0) {
+                continue;
+            }
+
+            let steps = segment_1.get_steps_to(intersection_coord)
+                + segment_2.get_steps_to(intersection_coord);
+
+            // a wire that passes the same point several times reaches it first with the fewest steps
+            let fewest_steps = intersections.entry(intersection_coord).or_insert(steps);
+            *fewest_steps = (*fewest_steps).min(steps);
+        }
+    };
+
+    match strategy {
+        SearchStrategy::AllPairs => {
+            for index_1 in 0..segments_1.len() {
+                for index_2 in 0..segments_2.len() {
+                    visit(index_1, index_2);
+                }
+            }
+        }
+        SearchStrategy::BoundingBoxes => {
+            for_each_overlapping_pair(
+                &wire_1.to_line_segments(),
+                &wire_2.to_line_segments(),
+                &mut visit,
+            );
+        }
+        SearchStrategy::SweepLine => {
+            sweep::for_each_meeting_pair(
+                &wire_1.to_line_segments(),
+                &wire_2.to_line_segments(),
+                &mut visit,
+            );
+        }
+    }
+
+    let mut intersections: Vec<(Coordinate, Distance)> = intersections.into_iter().collect();
+    intersections.sort();
+
+    return intersections;
+}
+
+// splitmix64; a small deterministic pseudo-random number generator for generating wires
+struct Random(u64);
+
+impl Random {
+    fn new(seed: u64) -> Random {
+        Random(seed)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        return z ^ (z >> 31);
+    }
+
+    // uniformly distributed within [0, n)
+    fn next_below(&mut self, n: u64) -> u64 {
+        return self.next_u64() % n;
+    }
+}
+
+// generate two random wires of the given number of segments each, in the format of the puzzle input.
+// every segment turns left or right from the last one, and is up to max_steps long.
+pub fn generate_wires(num_of_segments: usize, max_steps: u32, seed: u64) -> String {
+    let mut random = Random::new(seed);
+
+    let wires: Vec<String> = (0..2)
+        .map(|_| {
+            let mut is_horizontal = random.next_below(2) == 0;
+
+            let instructions: Vec<String> = (0..num_of_segments)
+                .map(|_| {
+                    let direction = match (is_horizontal, random.next_below(2)) {
+                        (true, 0) => 'L',
+                        (true, _) => 'R',
+                        (false, 0) => 'D',
+                        (false, _) => 'U',
+                    };
+                    let steps = 1 + random.next_below(max_steps as u64);
+                    is_horizontal = !is_horizontal;
+
+                    return format!("{}{}", direction, steps);
+                })
+                .collect();
+
+            return instructions.join(",");
+        })
+        .collect();
+
+    return wires.join("\n");
+}
+
+// the closest intersections of a pair of wires
+#[derive(Debug, Clone, PartialEq)]
+pub struct WirePair {
+    // the indices of the wires, in the order they're given
+    pub wires: (usize, usize),
+    // the intersection closest to the central port by manhattan distance, and its distance
+    pub closest_by_distance: Option<(Coordinate, Distance)>,
+    // the intersection reached with the fewest combined steps, and the steps
+    pub closest_by_steps: Option<(Coordinate, Distance)>,
+}
+
+// the closest intersections of every pair of wires; None for pairs that never meet.
+// ties are broken by the lowest coordinate.
+pub fn analyze_wires(wires: &[Wire], strategy: SearchStrategy) -> Vec<WirePair> {
+    let mut pairs = vec![];
+
+    for first in 0..wires.len() {
+        for second in (first + 1)..wires.len() {
+            let intersections = get_wire_intersections(&wires[first], &wires[second], strategy);
+
+            let closest_by_distance = intersections
+                .iter()
+                .map(|(coord, _steps)| (*coord, get_manhattan_distance((0, 0), *coord)))
+                .min_by_key(|(_coord, distance)| *distance);
+
+            let closest_by_steps = intersections
+                .iter()
+                .cloned()
+                .min_by_key(|(_coord, steps)| *steps);
+
+            pairs.push(WirePair {
+                wires: (first, second),
+                closest_by_distance,
+                closest_by_steps,
+            });
+        }
+    }
+
+    return pairs;
+}
+
+const PORT_COLOR: vector_image::Color = [0, 0, 0];
+const INTERSECTION_COLOR: vector_image::Color = [120, 120, 120];
+const CLOSEST_BY_DISTANCE_COLOR: vector_image::Color = [255, 0, 0];
+const CLOSEST_BY_STEPS_COLOR: vector_image::Color = [0, 0, 255];
+
+// every wire in a color of its own, with the intersections of every pair of wires marked.
+// the intersections closest to the central port by manhattan distance and by steps are marked larger,
+// in red and in blue.
+// y points up in the puzzle, so it's flipped.
+pub fn draw(wires: &[Wire]) -> Drawing {
+    let to_point = |(x, y): Coordinate| -> vector_image::Point {
+        return (x as f64, -y as f64);
+    };
+
+    // lines and markers are sized to the extent of the wires, so that they're visible at any scale
+    let extent = wires
+        .iter()
+        .flat_map(|wire| wire.get_segments().iter())
+        .map(|segment| {
+            let (x, y) = segment.end;
+            return x.abs().max(y.abs());
+        })
+        .max()
+        .unwrap_or(0)
+        .max(10) as f64;
+    let width = extent / 500.0;
+
+    let mut drawing = Drawing::new();
+
+    for (index, wire) in wires.iter().enumerate() {
+        let mut points = vec![to_point((0, 0))];
+        points.extend(
+            wire.get_segments()
+                .iter()
+                .map(|segment| to_point(segment.end)),
+        );
+
+        drawing.add_polyline(&points, vector_image::get_color(index), width);
+    }
+
+    let pairs = analyze_wires(wires, SearchStrategy::SweepLine);
+
+    for pair in pairs.iter() {
+        let (first, second) = pair.wires;
+        for (coord, _steps) in
+            get_wire_intersections(&wires[first], &wires[second], SearchStrategy::SweepLine)
+        {
+            drawing.add_circle(to_point(coord), width * 2.0, INTERSECTION_COLOR);
+        }
+    }
+
+    let closest_by_distance = pairs
+        .iter()
+        .filter_map(|pair| pair.closest_by_distance)
+        .min_by_key(|(_coord, distance)| *distance);
+    let closest_by_steps = pairs
+        .iter()
+        .filter_map(|pair| pair.closest_by_steps)
+        .min_by_key(|(_coord, steps)| *steps);
+
+    if let Some((coord, _distance)) = closest_by_distance {
+        drawing.add_circle(to_point(coord), width * 5.0, CLOSEST_BY_DISTANCE_COLOR);
+    }
+    if let Some((coord, _steps)) = closest_by_steps {
+        drawing.add_circle(to_point(coord), width * 5.0, CLOSEST_BY_STEPS_COLOR);
+    }
+
+    drawing.add_circle(to_point((0, 0)), width * 4.0, PORT_COLOR);
+
+    return drawing;
+}
+
+// the closest intersections of every pair of wires of the input
+fn analyze_input(input_string: String) -> Result<Vec<WirePair>, String> {
+    let wires: Vec<Wire> = process_wires(input_string).map_err(|err| err.to_string())?;
+
+    if wires.len() < 2 {
+        return Err(format!("Expected at least two wires, got {}", wires.len()));
+    }
+
+    return Ok(analyze_wires(&wires, SearchStrategy::SweepLine));
+}
+
+// the intersection of any pair of wires closest to the central port
+pub fn part_1(input_string: String) -> Result<Distance, String> {
+    let closest_intersection_to_port = analyze_input(input_string)?
+        .into_iter()
+        .filter_map(|pair| pair.closest_by_distance)
+        .map(|(_coord, distance)| distance)
+        .min()
+        .ok_or_else(|| "The wires have no intersections".to_string())?;
+
+    return Ok(closest_intersection_to_port);
+}
+
+// the intersection of any pair of wires with the fewest combined steps
+pub fn part_2(input_string: String) -> Result<Distance, String> {
+    let fewest_combined_steps = analyze_input(input_string)?
+        .into_iter()
+        .filter_map(|pair| pair.closest_by_steps)
+        .map(|(_coord, steps)| steps)
+        .min()
+        .ok_or_else(|| "The wires have no intersections".to_string())?;
+
+    return Ok(fewest_combined_steps);
+}
+
+pub const SOLVER: Solver = Solver {
+    year: 2019,
+    day: 3,
+    input: include_str!("input.txt"),
+    part_1: |input_string| Ok(part_1(input_string.to_string())?.to_string()),
+    part_2: Some(|input_string| Ok(part_2(input_string.to_string())?.to_string())),
+    examples: &[
+        Example {
+            input: include_str!("../examples/1.txt"),
+            part_1: Some("6"),
+            part_2: Some("30"),
+        },
+        Example {
+            input: include_str!("../examples/2.txt"),
+            part_1: Some("159"),
+            part_2: Some("610"),
+        },
+        Example {
+            input: include_str!("../examples/3.txt"),
+            part_1: Some("135"),
+            part_2: Some("410"),
+        },
+    ],
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    // walk every wire one grid cell at a time, and intersect the cells they visit.
+    // slow, but it doesn't need any geometry, so collinear segments and segments that touch are nothing special.
+    fn get_reference_intersections(input_string: &str) -> Vec<(Coordinate, Distance)> {
+        let visited: Vec<HashMap<Coordinate, Distance>> = input_string
+            .trim()
+            .lines()
+            .map(|wire| {
+                let mut visited = HashMap::new();
+                let mut current_coord: Coordinate = (0, 0);
+                let mut steps = 0;
+
+                for instruction in wire.trim().split(',') {
+                    let (dx, dy) = match &instruction[..1] {
+                        "U" => (0, 1),
+                        "D" => (0, -1),
+                        "L" => (-1, 0),
+                        "R" => (1, 0),
+                        direction => panic!("Unknown direction: {}", direction),
+                    };
+
+                    for _ in 0..instruction[1..].parse::<i32>().unwrap() {
+                        let (x, y) = current_coord;
+                        current_coord = (x + dx, y + dy);
+                        steps += 1;
+                        // only the first visit counts
+                        visited.entry(current_coord).or_insert(steps);
+                    }
+                }
+
+                return visited;
+            })
+            .collect();
+
+        let mut intersections: Vec<(Coordinate, Distance)> = visited[0]
+            .iter()
+            .filter(|(coord, _steps)| **coord != (0, 0))
+            .filter_map(|(coord, steps)| {
+                return visited[1]
+                    .get(coord)
+                    .map(|other_steps| (*coord, steps + other_steps));
+            })
+            .collect();
+        intersections.sort();
+
+        return intersections;
+    }
+
+    fn to_instructions(wire: &[(usize, i32)]) -> String {
+        let instructions: Vec<String> = wire
+            .iter()
+            .map(|(direction, steps)| format!("{}{}", ["U", "D", "L", "R"][*direction], steps))
+            .collect();
+        return instructions.join(",");
+    }
+
+    type Closest = Option<(Coordinate, Distance)>;
+
+    // the intersection closest to the port by manhattan distance, and the one with the fewest combined steps
+    fn get_closest(intersections: &[(Coordinate, Distance)]) -> (Closest, Closest) {
+        let closest_by_distance = intersections
+            .iter()
+            .map(|(coord, _steps)| (*coord, get_manhattan_distance((0, 0), *coord)))
+            .min_by_key(|(_coord, distance)| *distance);
+        let closest_by_steps = intersections
+            .iter()
+            .cloned()
+            .min_by_key(|(_coord, steps)| *steps);
+
+        return (closest_by_distance, closest_by_steps);
+    }
+
+    proptest! {
+        // short instructions on a small grid, so that the wires run along each other and touch often
+        #[test]
+        fn test_intersections_match_reference(
+            wire_1 in prop::collection::vec((0..4usize, 0..6i32), 1..25),
+            wire_2 in prop::collection::vec((0..4usize, 0..6i32), 1..25),
+        ) {
+            let input_string = format!("{}\n{}", to_instructions(&wire_1), to_instructions(&wire_2));
+            let expected = get_reference_intersections(&input_string);
+
+            let wires = process_wires(input_string).unwrap();
+            for strategy in [
+                SearchStrategy::AllPairs,
+                SearchStrategy::BoundingBoxes,
+                SearchStrategy::SweepLine,
+            ]
+            .iter()
+            {
+                let intersections = get_wire_intersections(&wires[0], &wires[1], *strategy);
+
+                // overlaps only yield some of their points, and those may be reached with fewer steps elsewhere
+                for (coord, steps) in intersections.iter() {
+                    let reference = expected.iter().find(|(other_coord, _steps)| other_coord == coord);
+                    prop_assert!(reference.is_some());
+                    prop_assert!(reference.unwrap().1 <= *steps);
+                }
+
+                prop_assert_eq!(get_closest(&intersections), get_closest(&expected));
+            }
+        }
+    }
+
+    #[test]
+    fn test_part_1() {
+        let input_string = include_str!("../examples/1.txt");
+
+        assert_eq!(part_1(input_string.to_string()), Ok(6));
+
+        let input_string = include_str!("../examples/2.txt");
+
+        assert_eq!(part_1(input_string.to_string()), Ok(159));
+
+        let input_string = include_str!("../examples/3.txt");
+
+        assert_eq!(part_1(input_string.to_string()), Ok(135));
+
+        // the wires run along each other from -3,5 to 4,5
+        let input_string = r###"
+L3,U5,R7
+R4,U5,L7
+        "###;
+
+        assert_eq!(part_1(input_string.to_string()), Ok(5));
+
+        // the wires overlap from the port onwards
+        let input_string = r###"
+R10
+L2,R20
+        "###;
+
+        assert_eq!(part_1(input_string.to_string()), Ok(1));
+
+        let input_string = include_str!("input.txt");
+        assert_eq!(part_1(input_string.to_string()), Ok(1519));
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input_string = include_str!("../examples/1.txt");
+
+        assert_eq!(part_2(input_string.to_string()), Ok(30));
+
+        let input_string = include_str!("../examples/2.txt");
+
+        assert_eq!(part_2(input_string.to_string()), Ok(610));
+
+        let input_string = include_str!("../examples/3.txt");
+
+        assert_eq!(part_2(input_string.to_string()), Ok(410));
+
+        // the wires run along each other from -3,5 to 4,5
+        let input_string = r###"
+L3,U5,R7
+R4,U5,L7
+        "###;
+
+        assert_eq!(part_2(input_string.to_string()), Ok(24));
+
+        // the wires overlap from the port onwards
+        let input_string = r###"
+R10
+L2,R20
+        "###;
+
+        assert_eq!(part_2(input_string.to_string()), Ok(6));
+
+        let input_string = include_str!("input.txt");
+        assert_eq!(part_2(input_string.to_string()), Ok(14358));
+    }
+
+    #[test]
+    fn test_parts_without_intersections() {
+        // the wires leave the port in opposite directions, and never meet again
+        let input_string = "R1\nL1";
+        let err = "The wires have no intersections".to_string();
+        assert_eq!(part_1(input_string.to_string()), Err(err.clone()));
+        assert_eq!(part_2(input_string.to_string()), Err(err));
+
+        let err = "Expected at least two wires, got 1".to_string();
+        assert_eq!(part_1("R8,U5,L5,D3".to_string()), Err(err.clone()));
+        assert_eq!(part_2("R8,U5,L5,D3".to_string()), Err(err));
+
+        assert!(part_1("R8,X5".to_string()).is_err());
+        assert!((SOLVER.part_1)("R1\nL1").is_err());
+    }
+
+    #[test]
+    fn test_get_wire_intersections() {
+        let get_intersections = |input_string: &str| {
+            let wires = process_wires(input_string.to_string()).unwrap();
+            let intersections =
+                get_wire_intersections(&wires[0], &wires[1], SearchStrategy::AllPairs);
+            assert_eq!(
+                get_wire_intersections(&wires[0], &wires[1], SearchStrategy::SweepLine),
+                intersections
+            );
+            return intersections;
+        };
+
+        assert_eq!(
+            get_intersections("R8,U5,L5,D3\nU7,R6,D4,L4"),
+            vec![((3, 3), 40), ((6, 5), 30)]
+        );
+
+        // the first wire crosses the port again, which doesn't count
+        assert_eq!(
+            get_intersections("R5,U5,L5,D10\nL3,U2,R10"),
+            vec![((0, 2), 26), ((5, 2), 20)]
+        );
+        assert_eq!(part_1("R5,U5,L5,D10\nL3,U2,R10".to_string()), Ok(2));
+        assert_eq!(part_2("R5,U5,L5,D10\nL3,U2,R10".to_string()), Ok(20));
+
+        // the second wire ends on the first wire, in a T-junction
+        assert_eq!(get_intersections("R10\nU2,R5,D2"), vec![((5, 0), 14)]);
+
+        // the second wire ends on the corner of the first wire
+        assert_eq!(
+            get_intersections("R5,U5\nU2,R7,D2,L2"),
+            vec![((5, 0), 18), ((5, 2), 14)]
+        );
+
+        // the second wire runs along the first one from 3,0 to 3,1, and turns away at both ends
+        assert_eq!(
+            get_intersections("U3,R3,D6\nR3,U1,L2,U1,R4"),
+            vec![((3, 0), 12), ((3, 1), 12), ((3, 2), 16)]
+        );
+    }
+
+    #[test]
+    fn test_analyze_wires() {
+        let wires = process_wires("R8,U5,L5,D3\nU7,R6,D4,L4".to_string()).unwrap();
+        assert_eq!(
+            analyze_wires(&wires, SearchStrategy::SweepLine),
+            vec![WirePair {
+                wires: (0, 1),
+                closest_by_distance: Some(((3, 3), 6)),
+                closest_by_steps: Some(((6, 5), 30)),
+            }]
+        );
+
+        // the third wire only meets the first one
+        let input_string = r###"
+R8,U5,L5,D3
+U7,R6,D4,L4
+D2,R4,U4
+        "###;
+
+        let wires = process_wires(input_string.to_string()).unwrap();
+        assert_eq!(
+            analyze_wires(&wires, SearchStrategy::SweepLine),
+            vec![
+                WirePair {
+                    wires: (0, 1),
+                    closest_by_distance: Some(((3, 3), 6)),
+                    closest_by_steps: Some(((6, 5), 30)),
+                },
+                WirePair {
+                    wires: (0, 2),
+                    closest_by_distance: Some(((4, 0), 4)),
+                    closest_by_steps: Some(((4, 0), 12)),
+                },
+                WirePair {
+                    wires: (1, 2),
+                    closest_by_distance: None,
+                    closest_by_steps: None,
+                },
+            ]
+        );
+
+        assert_eq!(part_1(input_string.to_string()), Ok(4));
+        assert_eq!(part_2(input_string.to_string()), Ok(12));
+
+        assert_eq!(analyze_wires(&wires[..1], SearchStrategy::AllPairs), vec![]);
+    }
+
+    #[test]
+    fn test_draw() {
+        let wires = process_wires("R8,U5,L5,D3\nU7,R6,D4,L4".to_string()).unwrap();
+        let svg = draw(&wires).to_svg();
+
+        // a path for every wire, and markers for both intersections, both closest intersections and the port
+        assert_eq!(svg.matches("<path").count(), 2);
+        assert_eq!(svg.matches("<circle").count(), 5);
+
+        assert!(svg.contains("<path d=\"M0 0 L8 0 L8 -5 L3 -5 L3 -2\""));
+        assert!(svg.contains("<path d=\"M0 0 L0 -7 L6 -7 L6 -3 L2 -3\""));
+        assert!(svg.contains("<circle cx=\"3\" cy=\"-3\" r=\"0.1\" fill=\"#ff0000\"/>"));
+        assert!(svg.contains("<circle cx=\"6\" cy=\"-5\" r=\"0.1\" fill=\"#0000ff\"/>"));
+        assert!(svg.contains("<circle cx=\"0\" cy=\"0\" r=\"0.08\" fill=\"#000000\"/>"));
+    }
+
+    #[test]
+    fn test_reference_intersections() {
+        let input_string = "R8,U5,L5,D3\nU7,R6,D4,L4";
+        assert_eq!(
+            get_reference_intersections(input_string),
+            vec![((3, 3), 40), ((6, 5), 30)]
+        );
+
+        let wires = process_wires(include_str!("input.txt").to_string()).unwrap();
+        assert_eq!(
+            get_closest(&get_reference_intersections(include_str!("input.txt"))),
+            get_closest(&get_wire_intersections(
+                &wires[0],
+                &wires[1],
+                SearchStrategy::SweepLine
+            ))
+        );
+    }
+
+    #[test]
+    fn test_search_strategies() {
+        let input_strings = vec![
+            include_str!("input.txt").to_string(),
+            generate_wires(1000, 20, 1),
+            generate_wires(1000, 20, 2),
+            // short segments that run along each other often
+            generate_wires(500, 3, 3),
+        ];
+
+        for input_string in input_strings {
+            let wires = process_wires(input_string).unwrap();
+            let intersections =
+                get_wire_intersections(&wires[0], &wires[1], SearchStrategy::AllPairs);
+
+            assert!(!intersections.is_empty());
+            assert_eq!(
+                get_wire_intersections(&wires[0], &wires[1], SearchStrategy::BoundingBoxes),
+                intersections
+            );
+            assert_eq!(
+                get_wire_intersections(&wires[0], &wires[1], SearchStrategy::SweepLine),
+                intersections
+            );
+        }
+    }
+
+    #[test]
+    fn test_generate_wires() {
+        let input_string = generate_wires(10, 5, 2019);
+        assert_eq!(input_string, generate_wires(10, 5, 2019));
+        assert_ne!(input_string, generate_wires(10, 5, 2020));
+
+        let wires = process_wires(input_string).unwrap();
+        assert_eq!(wires.len(), 2);
+        for wire in wires {
+            assert_eq!(wire.get_segments().len(), 10);
+            for segment in wire.get_segments() {
+                let steps = get_manhattan_distance(segment.start, segment.end);
+                assert!((1..=5).contains(&steps));
+            }
+        }
+    }
+
+    #[test]
+    fn test_process_wires() {
+        let wires = process_wires(" R8,U5,L5,D3\n\nU7,R6,D4,L4\n".to_string()).unwrap();
+        assert_eq!(wires.len(), 2);
+        assert_eq!(wires[0].get_segments().len(), 4);
+
+        let err = process_wires("R8,U5,L5,D3\nU7,X6,D4".to_string()).unwrap_err();
+        assert_eq!(
+            err,
+            WireParseError {
+                wire: 2,
+                position: 2,
+                token: "X6".to_string(),
+                reason: "unknown direction 'X', expected one of U, D, L or R".to_string(),
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "Invalid instruction \"X6\" of wire 2 at position 2: unknown direction 'X', expected one of U, D, L or R"
+        );
+
+        let err = process_wires("R8,U-5".to_string()).unwrap_err();
+        assert_eq!((err.wire, err.position, err.token.as_str()), (1, 2, "U-5"));
+
+        let err = process_wires("R8,U".to_string()).unwrap_err();
+        assert_eq!((err.position, err.token.as_str()), (2, "U"));
+
+        let err = process_wires("R8,,U5".to_string()).unwrap_err();
+        assert_eq!(
+            (err.position, err.reason.as_str()),
+            (2, "missing direction")
+        );
+
+        let err = process_wires("R2147483647,R1".to_string()).unwrap_err();
+        assert_eq!((err.position, err.reason.as_str()), (2, "wire is too long"));
+
+        // the wire turns back before it leaves the grid, but it takes too many steps
+        let err = process_wires("R2000000000,L2000000000\nU1".to_string()).unwrap_err();
+        assert_eq!(
+            (err.wire, err.position, err.reason.as_str()),
+            (1, 2, "wire is too long")
+        );
+
+        // either wire fits, but not the steps of both of them to where they meet
+        let err = process_wires("R2000000000\nR2000000000".to_string()).unwrap_err();
+        assert_eq!(
+            (err.wire, err.position, err.reason.as_str()),
+            (2, 1, "wires are too long together")
+        );
+
+        let wires = process_wires("R2147483646\nU1".to_string()).unwrap();
+        assert_eq!(wires.len(), 2);
+    }
+
+    #[test]
+    fn test_wire() {
+        let wires = process_wires("R8,U5,L5,D3\nU7".to_string()).unwrap();
+
+        assert_eq!(
+            wires[0].get_segments(),
+            &[
+                WireSegment {
+                    start: (0, 0),
+                    end: (8, 0),
+                    steps_at_start: 0,
+                },
+                WireSegment {
+                    start: (8, 0),
+                    end: (8, 5),
+                    steps_at_start: 8,
+                },
+                WireSegment {
+                    start: (8, 5),
+                    end: (3, 5),
+                    steps_at_start: 13,
+                },
+                WireSegment {
+                    start: (3, 5),
+                    end: (3, 2),
+                    steps_at_start: 18,
+                },
+            ]
+        );
+        assert_eq!(wires[1], Wire::new(&[((0, 0), (0, 7))]));
+
+        assert_eq!(wires[0].get_segments()[2].get_steps_to((3, 5)), 18);
+        assert_eq!(wires[0].get_segments()[3].get_steps_to((3, 3)), 20);
+        assert_eq!(
+            wires[0].to_line_segments(),
+            vec![
+                ((0, 0), (8, 0)),
+                ((8, 0), (8, 5)),
+                ((8, 5), (3, 5)),
+                ((3, 5), (3, 2))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_axis_aligned_intersection() {
+        // intersection
+
+        let line_segment_1 = ((3, 2), (3, 10));
+        let line_segment_2 = ((0, 3), (10, 3));
+
+        assert_eq!(
+            axis_aligned_intersection(line_segment_1, line_segment_2),
+            Some((3, 3))
+        );
+
+        // no intersection
+
+        let line_segment_1 = ((3, 2), (3, 10));
+        let line_segment_2 = ((0, 30), (10, 30));
+
+        assert_eq!(
+            axis_aligned_intersection(line_segment_1, line_segment_2),
+            None
+        );
+
+        // the segments touch at the end of one of them
+
+        let line_segment_1 = ((3, 2), (3, 10));
+        let line_segment_2 = ((3, 10), (-5, 10));
+
+        assert_eq!(
+            axis_aligned_intersection(line_segment_1, line_segment_2),
+            Some((3, 10))
+        );
+
+        // crossings far from the port are exact
+
+        let line_segment_1 = ((-1_000_000_001, 7), (1_000_000_003, 7));
+        let line_segment_2 = ((999_999_999, -1_000_000_000), (999_999_999, 1_000_000_000));
+
+        assert_eq!(
+            axis_aligned_intersection(line_segment_1, line_segment_2),
+            Some((999_999_999, 7))
+        );
+
+        // collinear intersection y-axis
+
+        let line_segment_1 = ((3, 2), (3, 10));
+        let line_segment_2 = ((3, -10), (3, 20));
+
+        assert_eq!(
+            axis_aligned_intersection(line_segment_1, line_segment_2),
+            None
+        );
+
+        // collinear intersection x-axis
+
+        let line_segment_1 = ((-10, 3), (20, 3));
+        let line_segment_2 = ((0, 3), (10, 3));
+
+        assert_eq!(
+            axis_aligned_intersection(line_segment_1, line_segment_2),
+            None
+        );
+    }
+
+    #[test]
+    fn test_line_segments_overlap() {
+        // overlap y-axis
+
+        let line_segment_1 = ((3, 2), (3, 10));
+        let line_segment_2 = ((3, 20), (3, -10));
+
+        assert_eq!(
+            line_segments_overlap(line_segment_1, line_segment_2),
+            Some(((3, 2), (3, 10)))
+        );
+
+        // partial overlap x-axis
+
+        let line_segment_1 = ((-10, 3), (5, 3));
+        let line_segment_2 = ((10, 3), (0, 3));
+
+        assert_eq!(
+            line_segments_overlap(line_segment_1, line_segment_2),
+            Some(((0, 3), (5, 3)))
+        );
+
+        // collinear, but apart
+
+        let line_segment_1 = ((0, 3), (4, 3));
+        let line_segment_2 = ((5, 3), (10, 3));
+
+        assert_eq!(line_segments_overlap(line_segment_1, line_segment_2), None);
+
+        // parallel
+
+        let line_segment_1 = ((0, 3), (10, 3));
+        let line_segment_2 = ((0, 4), (10, 4));
+
+        assert_eq!(line_segments_overlap(line_segment_1, line_segment_2), None);
+
+        // crossing
+
+        let line_segment_1 = ((3, 2), (3, 10));
+        let line_segment_2 = ((0, 3), (10, 3));
+
+        assert_eq!(line_segments_overlap(line_segment_1, line_segment_2), None);
+    }
+
+    #[test]
+    fn test_get_intersections() {
+        assert_eq!(
+            get_intersections(((3, 2), (3, 10)), ((0, 3), (10, 3))),
+            vec![(3, 3)]
+        );
+        assert_eq!(
+            get_intersections(((0, 3), (4, 3)), ((5, 3), (10, 3))),
+            vec![]
+        );
+
+        // the ends of the overlap, and the point closest to the port
+        assert_eq!(
+            get_intersections(((-10, 3), (5, 3)), ((10, 3), (-4, 3))),
+            vec![(-4, 3), (0, 3), (5, 3)]
+        );
+
+        // the overlap runs through the port
+        assert_eq!(
+            get_intersections(((-10, 0), (5, 0)), ((10, 0), (-4, 0))),
+            vec![(-4, 0), (-1, 0), (0, 0), (1, 0), (5, 0)]
+        );
+
+        // a long overlap still only yields a few points
+        assert_eq!(
+            get_intersections(((0, 7), (2_000_000_000, 7)), ((-5, 7), (i32::MAX, 7))),
+            vec![(0, 7), (2_000_000_000, 7)]
+        );
+
+        // segments that only touch at their ends
+        assert_eq!(
+            get_intersections(((0, 3), (4, 3)), ((4, 3), (10, 3))),
+            vec![(4, 3)]
+        );
+    }
+}
@@ -0,0 +1,203 @@
+// finding the pairs of segments of two wires that meet, without trying every pair.
+//
+// wire segments are either horizontal or vertical, so two segments meet in one of two ways:
+//
+// - a horizontal segment crosses a vertical segment of the other wire. a vertical line is swept from left to right,
+//   keeping the horizontal segments it's on by their y. every vertical segment the line reaches only needs to look
+//   at the horizontal segments within its range of y.
+// - segments on the same line overlap. the segments of every line are sorted by where they start, and every segment
+//   only needs to look at the segments of the other wire that haven't ended yet.
+//
+// zero-length segments count as horizontal.
+
+// imports
+
+use geometry::LineSegment;
+use std::collections::BTreeMap;
+
+// code
+
+fn is_horizontal(segment: &LineSegment) -> bool {
+    let ((_x_1, y_1), (_x_2, y_2)) = segment;
+    return y_1 == y_2;
+}
+
+fn is_vertical(segment: &LineSegment) -> bool {
+    let ((x_1, y_1), (x_2, y_2)) = segment;
+    return x_1 == x_2 && y_1 != y_2;
+}
+
+// events at the same x are handled in this order, so that segments that only touch are still found
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Event {
+    Start,
+    Vertical,
+    End,
+}
+
+// visit every horizontal segment of one wire that crosses a vertical segment of the other,
+// as (index of the horizontal segment, index of the vertical segment)
+fn sweep_crossings(
+    horizontal_wire: &[LineSegment],
+    vertical_wire: &[LineSegment],
+    visit: &mut impl FnMut(usize, usize),
+) {
+    let mut events: Vec<(i32, Event, usize)> = vec![];
+
+    for (index, segment) in horizontal_wire.iter().enumerate() {
+        if is_horizontal(segment) {
+            let ((x_1, _y_1), (x_2, _y_2)) = *segment;
+            events.push((x_1.min(x_2), Event::Start, index));
+            events.push((x_1.max(x_2), Event::End, index));
+        }
+    }
+
+    for (index, segment) in vertical_wire.iter().enumerate() {
+        if is_vertical(segment) {
+            let ((x, _y_1), (_x, _y_2)) = *segment;
+            events.push((x, Event::Vertical, index));
+        }
+    }
+
+    events.sort();
+
+    // the horizontal segments the sweep line is on, by their y
+    let mut active: BTreeMap<i32, Vec<usize>> = BTreeMap::new();
+
+    for (_x, event, index) in events {
+        match event {
+            Event::Start => {
+                let ((_x, y), _end) = horizontal_wire[index];
+                active.entry(y).or_default().push(index);
+            }
+            Event::End => {
+                let ((_x, y), _end) = horizontal_wire[index];
+                let indices = active.get_mut(&y).unwrap();
+                indices.retain(|other_index| *other_index != index);
+                if indices.is_empty() {
+                    active.remove(&y);
+                }
+            }
+            Event::Vertical => {
+                let ((_x_1, y_1), (_x_2, y_2)) = vertical_wire[index];
+                for (_y, indices) in active.range(y_1.min(y_2)..=y_1.max(y_2)) {
+                    for horizontal_index in indices {
+                        visit(*horizontal_index, index);
+                    }
+                }
+            }
+        }
+    }
+}
+
+// visit every pair of segments of the two wires that are on the same line and overlap
+fn sweep_overlaps(
+    wire_1: &[LineSegment],
+    wire_2: &[LineSegment],
+    visit: &mut impl FnMut(usize, usize),
+) {
+    // (is the line vertical, the line, start, end, wire, index of the segment)
+    let mut ranges: Vec<(bool, i32, i32, i32, usize, usize)> = vec![];
+
+    for (wire, segments) in [wire_1, wire_2].iter().enumerate() {
+        for (index, segment) in segments.iter().enumerate() {
+            let ((x_1, y_1), (x_2, y_2)) = *segment;
+            if is_horizontal(segment) {
+                ranges.push((false, y_1, x_1.min(x_2), x_1.max(x_2), wire, index));
+            } else {
+                ranges.push((true, x_1, y_1.min(y_2), y_1.max(y_2), wire, index));
+            }
+        }
+    }
+
+    ranges.sort();
+
+    // the segments of each wire on the current line that haven't ended yet, as (end, index)
+    let mut active: [Vec<(i32, usize)>; 2] = [vec![], vec![]];
+    let mut current_line = None;
+
+    for (is_vertical, line, start, end, wire, index) in ranges {
+        if current_line != Some((is_vertical, line)) {
+            current_line = Some((is_vertical, line));
+            active = [vec![], vec![]];
+        }
+
+        for segments in active.iter_mut() {
+            segments.retain(|(other_end, _other_index)| *other_end >= start);
+        }
+
+        for (_other_end, other_index) in active[1 - wire].iter() {
+            if wire == 0 {
+                visit(index, *other_index);
+            } else {
+                visit(*other_index, index);
+            }
+        }
+
+        active[wire].push((end, index));
+    }
+}
+
+// visit every pair of segments of the two wires that meet, as (index in wire 1, index in wire 2).
+// a pair may be visited more than once.
+pub fn for_each_meeting_pair(
+    wire_1: &[LineSegment],
+    wire_2: &[LineSegment],
+    visit: &mut impl FnMut(usize, usize),
+) {
+    sweep_crossings(wire_1, wire_2, visit);
+    sweep_crossings(wire_2, wire_1, &mut |index_2, index_1| {
+        visit(index_1, index_2)
+    });
+    sweep_overlaps(wire_1, wire_2, visit);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_for_each_meeting_pair() {
+        let get_pairs = |wire_1: &[LineSegment], wire_2: &[LineSegment]| {
+            let mut pairs = vec![];
+            for_each_meeting_pair(wire_1, wire_2, &mut |index_1, index_2| {
+                pairs.push((index_1, index_2));
+            });
+            pairs.sort();
+            pairs.dedup();
+            return pairs;
+        };
+
+        // R8,U5,L5,D3 and U7,R6,D4,L4
+        let wire_1 = vec![
+            ((0, 0), (8, 0)),
+            ((8, 0), (8, 5)),
+            ((8, 5), (3, 5)),
+            ((3, 5), (3, 2)),
+        ];
+        let wire_2 = vec![
+            ((0, 0), (0, 7)),
+            ((0, 7), (6, 7)),
+            ((6, 7), (6, 3)),
+            ((6, 3), (2, 3)),
+        ];
+
+        // the wires start at the same point, and cross at 6,5 and 3,3
+        assert_eq!(get_pairs(&wire_1, &wire_2), vec![(0, 0), (2, 2), (3, 3)]);
+
+        // segments on the same line that overlap, or only touch
+        let wire_1 = vec![((0, 0), (5, 0)), ((5, 0), (5, 5))];
+        let wire_2 = vec![
+            ((9, 0), (3, 0)),
+            ((3, 0), (3, -2)),
+            ((3, -2), (5, -2)),
+            ((5, -2), (5, -1)),
+        ];
+        assert_eq!(get_pairs(&wire_1, &wire_2), vec![(0, 0), (0, 1), (1, 0)]);
+
+        // segments on the same line that are apart
+        let wire_1 = vec![((0, 0), (0, 5))];
+        let wire_2 = vec![((0, 6), (0, 9)), ((0, 9), (1, 9))];
+        assert_eq!(get_pairs(&wire_1, &wire_2), vec![]);
+    }
+}
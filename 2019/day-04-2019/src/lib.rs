@@ -0,0 +1,266 @@
+// https://adventofcode.com/2019/day/4
+
+use solver::{Example, Solver};
+
+// the range given as the puzzle input
+pub const INPUT: &str = "193651-649729";
+
+// It is a six-digit number.
+const NUM_OF_DIGITS: usize = 6;
+
+// the digits of a password, from the most significant digit
+type Digits = [u8; NUM_OF_DIGITS];
+
+fn to_digits(number: u32) -> Option<Digits> {
+    if !(100_000..=999_999).contains(&number) {
+        return None;
+    }
+
+    let mut digits = [0; NUM_OF_DIGITS];
+    let mut rest = number;
+
+    for digit in digits.iter_mut().rev() {
+        *digit = (rest % 10) as u8;
+        rest /= 10;
+    }
+
+    return Some(digits);
+}
+
+// move on to the digits of the next number. returns false once the digits would roll over to 000000.
+fn increment(digits: &mut Digits) -> bool {
+    for digit in digits.iter_mut().rev() {
+        if *digit < 9 {
+            *digit += 1;
+            return true;
+        }
+
+        *digit = 0;
+    }
+
+    return false;
+}
+
+// Going from left to right, the digits never decrease;
+// they only ever increase or stay the same (like 111123 or 135679).
+fn is_non_decreasing(digits: &Digits) -> bool {
+    return digits.windows(2).all(|pair| pair[0] <= pair[1]);
+}
+
+// the lengths of the runs of the same digit, from left to right
+fn run_lengths(digits: &Digits) -> Vec<usize> {
+    let mut lengths = vec![];
+    let mut length = 1;
+
+    for pair in digits.windows(2) {
+        if pair[0] == pair[1] {
+            length += 1;
+        } else {
+            lengths.push(length);
+            length = 1;
+        }
+    }
+
+    lengths.push(length);
+
+    return lengths;
+}
+
+// Two adjacent digits are the same (like 22 in 122345).
+fn has_adjacent_pair(digits: &Digits) -> bool {
+    return digits.windows(2).any(|pair| pair[0] == pair[1]);
+}
+
+// the two adjacent matching digits are not part of a larger group of matching digits.
+fn has_exact_pair(digits: &Digits) -> bool {
+    return run_lengths(digits).contains(&2);
+}
+
+fn is_valid_password(digits: &Digits) -> bool {
+    return is_non_decreasing(digits) && has_adjacent_pair(digits);
+}
+
+fn is_valid_password_part_2(digits: &Digits) -> bool {
+    return is_non_decreasing(digits) && has_exact_pair(digits);
+}
+
+// 193651-649729
+fn parse_input(input_string: &str) -> Result<(u32, u32), String> {
+    let invalid = || format!("Invalid range: {}", input_string.trim());
+
+    let mut bounds = input_string.trim().splitn(2, '-');
+
+    let mut parse_bound = || -> Result<u32, String> {
+        return bounds
+            .next()
+            .and_then(|bound| bound.trim().parse().ok())
+            .ok_or_else(invalid);
+    };
+
+    let start = parse_bound()?;
+    let end = parse_bound()?;
+
+    if start > end {
+        return Err(invalid());
+    }
+
+    return Ok((start, end));
+}
+
+// the number of six-digit passwords within the range that meet the criteria.
+// the digits are counted up one number at a time, rather than worked out from every number.
+fn count_passwords(
+    input_string: &str,
+    is_valid: impl Fn(&Digits) -> bool,
+) -> Result<usize, String> {
+    let (start, end) = parse_input(input_string)?;
+
+    // The value is within the range given in your puzzle input.
+    let start = start.max(100_000);
+    let end = end.min(999_999);
+
+    if start > end {
+        return Ok(0);
+    }
+
+    let mut digits = to_digits(start).unwrap();
+    let mut count = 0;
+
+    for _number in start..=end {
+        if is_valid(&digits) {
+            count += 1;
+        }
+
+        increment(&mut digits);
+    }
+
+    return Ok(count);
+}
+
+pub fn part_1(input_string: &str) -> Result<usize, String> {
+    return count_passwords(input_string, is_valid_password);
+}
+
+pub fn part_2(input_string: &str) -> Result<usize, String> {
+    return count_passwords(input_string, is_valid_password_part_2);
+}
+
+pub const SOLVER: Solver = Solver {
+    year: 2019,
+    day: 4,
+    input: INPUT,
+    part_1: |input_string| Ok(part_1(input_string)?.to_string()),
+    part_2: Some(|input_string| Ok(part_2(input_string)?.to_string())),
+    examples: &[
+        Example {
+            input: include_str!("../examples/1.txt"),
+            part_1: Some("1"),
+            part_2: None,
+        },
+        Example {
+            input: include_str!("../examples/2.txt"),
+            part_1: Some("0"),
+            part_2: None,
+        },
+        Example {
+            input: include_str!("../examples/3.txt"),
+            part_1: Some("0"),
+            part_2: None,
+        },
+        Example {
+            input: include_str!("../examples/4.txt"),
+            part_1: None,
+            part_2: Some("1"),
+        },
+        Example {
+            input: include_str!("../examples/5.txt"),
+            part_1: None,
+            part_2: Some("0"),
+        },
+        Example {
+            input: include_str!("../examples/6.txt"),
+            part_1: None,
+            part_2: Some("1"),
+        },
+    ],
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(number: u32) -> Digits {
+        return to_digits(number).unwrap();
+    }
+
+    #[test]
+    fn test_digits() {
+        assert_eq!(to_digits(123450), Some([1, 2, 3, 4, 5, 0]));
+        assert_eq!(to_digits(99_999), None);
+        assert_eq!(to_digits(1_000_000), None);
+
+        let mut number = digits(129999);
+        assert!(increment(&mut number));
+        assert_eq!(number, digits(130000));
+
+        let mut number = digits(999999);
+        assert!(!increment(&mut number));
+
+        assert_eq!(run_lengths(&digits(111223)), vec![3, 2, 1]);
+        assert_eq!(run_lengths(&digits(123456)), vec![1, 1, 1, 1, 1, 1]);
+        assert_eq!(run_lengths(&digits(111111)), vec![6]);
+    }
+
+    #[test]
+    fn test_is_valid_password() {
+        assert!(is_valid_password(&digits(111111)));
+        assert!(is_valid_password(&digits(122345)));
+        assert!(is_valid_password(&digits(111123)));
+        assert!(!is_valid_password(&digits(135679)));
+
+        assert!(!is_valid_password(&digits(223450)));
+        assert!(!is_valid_password(&digits(123789)));
+    }
+
+    #[test]
+    fn test_is_valid_password_part_2() {
+        assert!(!is_valid_password_part_2(&digits(111111)));
+        assert!(is_valid_password_part_2(&digits(122345)));
+        assert!(!is_valid_password_part_2(&digits(111123)));
+        assert!(!is_valid_password_part_2(&digits(135679)));
+
+        assert!(!is_valid_password_part_2(&digits(223450)));
+        assert!(!is_valid_password_part_2(&digits(123789)));
+        assert!(is_valid_password_part_2(&digits(112233)));
+        assert!(!is_valid_password_part_2(&digits(123444)));
+        assert!(is_valid_password_part_2(&digits(111122)));
+        assert!(is_valid_password_part_2(&digits(112345)));
+    }
+
+    #[test]
+    fn test_count_passwords() {
+        assert_eq!(part_1(include_str!("../examples/1.txt")), Ok(1));
+        assert_eq!(part_1(include_str!("../examples/2.txt")), Ok(0));
+        assert_eq!(part_2(include_str!("../examples/4.txt")), Ok(1));
+        assert_eq!(part_2(include_str!("../examples/5.txt")), Ok(0));
+
+        // 111111, 111112, ..., 111119, 111122
+        assert_eq!(part_1("111111-111122"), Ok(10));
+        // 111122 only
+        assert_eq!(part_2("111111-111122"), Ok(1));
+
+        // only six-digit numbers count
+        assert_eq!(part_1("0-111111"), Ok(1));
+        assert_eq!(part_1("999999-1000000"), Ok(1));
+
+        assert!(part_1("2-1").is_err());
+        assert!(part_1("12").is_err());
+        assert!(part_1("a-b").is_err());
+    }
+
+    #[test]
+    fn test_parts() {
+        assert_eq!(part_1(INPUT), Ok(1605));
+        assert_eq!(part_2(INPUT), Ok(1102));
+    }
+}
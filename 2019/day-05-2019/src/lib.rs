@@ -0,0 +1,131 @@
+// https://adventofcode.com/2019/day/5
+
+use intcode::{Computer, Status, Value};
+use solver::{Example, Solver};
+
+// the ID of the ship's air conditioner unit
+const AIR_CONDITIONER: Value = 1;
+
+// the ID of the ship's thermal radiator controller
+const THERMAL_RADIATOR_CONTROLLER: Value = 5;
+
+// run the diagnostic program (your puzzle input) with the ID of the system to test as its only input.
+// returns every value the program outputs.
+fn run_diagnostic(input_string: &str, system_id: Value) -> Result<Vec<Value>, String> {
+    let mut computer = Computer::parse(input_string)?;
+    computer.input.send(system_id);
+
+    match computer.run()? {
+        Status::Halted => {}
+        status => {
+            return Err(format!(
+                "The diagnostic program stopped without halting: {:?}",
+                status
+            ));
+        }
+    }
+
+    return Ok(computer.output.drain());
+}
+
+// For each test, it will run an output instruction indicating how far the result of the test
+// was from the expected value, where 0 means the test was successful.
+// Finally, the program will output a diagnostic code and immediately halt.
+fn diagnostic_code(outputs: &[Value]) -> Result<Value, String> {
+    let (diagnostic_code, test_results) = outputs
+        .split_last()
+        .ok_or_else(|| "The diagnostic program did not output anything".to_string())?;
+
+    if let Some(position) = test_results.iter().position(|&result| result != 0) {
+        return Err(format!(
+            "Diagnostic test {} failed: {}",
+            position + 1,
+            test_results[position]
+        ));
+    }
+
+    return Ok(*diagnostic_code);
+}
+
+pub fn part_1(input_string: &str) -> Result<Value, String> {
+    let outputs = run_diagnostic(input_string, AIR_CONDITIONER)?;
+    return diagnostic_code(&outputs);
+}
+
+pub fn part_2(input_string: &str) -> Result<Value, String> {
+    let outputs = run_diagnostic(input_string, THERMAL_RADIATOR_CONTROLLER)?;
+    return diagnostic_code(&outputs);
+}
+
+pub const SOLVER: Solver = Solver {
+    year: 2019,
+    day: 5,
+    input: include_str!("input.txt"),
+    part_1: |input_string| Ok(part_1(input_string)?.to_string()),
+    part_2: Some(|input_string| Ok(part_2(input_string)?.to_string())),
+    examples: &[
+        Example {
+            input: include_str!("../examples/1.txt"),
+            part_1: Some("1"),
+            part_2: Some("5"),
+        },
+        Example {
+            input: include_str!("../examples/2.txt"),
+            part_1: Some("0"),
+            part_2: Some("0"),
+        },
+        Example {
+            input: include_str!("../examples/3.txt"),
+            part_1: Some("999"),
+            part_2: Some("999"),
+        },
+    ],
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diagnostic_code() {
+        assert_eq!(diagnostic_code(&[0, 0, 0, 42]), Ok(42));
+        assert_eq!(diagnostic_code(&[42]), Ok(42));
+        assert!(diagnostic_code(&[0, 3, 0, 42]).is_err());
+        assert!(diagnostic_code(&[]).is_err());
+    }
+
+    #[test]
+    fn test_run_diagnostic() {
+        // outputs whatever it gets as input
+        assert_eq!(
+            run_diagnostic(include_str!("../examples/1.txt"), 7),
+            Ok(vec![7])
+        );
+        // outputs whether its input is equal to 8
+        assert_eq!(
+            run_diagnostic(include_str!("../examples/2.txt"), 8),
+            Ok(vec![1])
+        );
+        assert_eq!(
+            run_diagnostic(include_str!("../examples/2.txt"), 7),
+            Ok(vec![0])
+        );
+        // outputs 999, 1000 or 1001 for an input below, equal to or above 8
+        let program = include_str!("../examples/3.txt");
+        assert_eq!(run_diagnostic(program, 7), Ok(vec![999]));
+        assert_eq!(run_diagnostic(program, 8), Ok(vec![1000]));
+        assert_eq!(run_diagnostic(program, 9), Ok(vec![1001]));
+        // asks for more input than it is given
+        assert!(run_diagnostic("3,0,3,0,99", 7).is_err());
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(part_1(include_str!("input.txt")), Ok(5182797));
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(part_2(include_str!("input.txt")), Ok(12077198));
+    }
+}
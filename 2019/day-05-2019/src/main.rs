@@ -1,38 +1,22 @@
 // https://adventofcode.com/2019/day/5
 
-enum ParameterMode {
-    // position mode, which causes the parameter to be interpreted as a position
-    Position,
-    // In immediate mode, a parameter is interpreted as a value
-    Immediate,
-}
-
-struct Computer {
-    current_instruction_pointer: usize,
-    tape: Vec<i32>,
-    mode: ParameterMode,
-}
+use day_05_2019::{part_1, part_2};
+use solver::load_input_from_args;
 
-impl Computer {
-    fn new(input_string: String) -> Self {
-        let inputs: Vec<i32> = input_string
-            .trim()
-            .split(',')
-            .map(|value| -> i32 {
-                return value.parse().unwrap();
-            })
-            .collect();
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let input_string = load_input_from_args(&args).unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        std::process::exit(1);
+    });
 
-        Computer {
-            current_instruction_pointer: 0,
-            tape: inputs,
-            mode: ParameterMode::Position,
-        }
+    match part_1(&input_string) {
+        Ok(diagnostic_code) => println!("Part 1: {}", diagnostic_code),
+        Err(err) => println!("Part 1: {}", err),
     }
-}
-
-fn main() {
-    let input_string = include_str!("input.txt");
 
-    println!("{}", input_string);
+    match part_2(&input_string) {
+        Ok(diagnostic_code) => println!("Part 2: {}", diagnostic_code),
+        Err(err) => println!("Part 2: {}", err),
+    }
 }
@@ -0,0 +1,313 @@
+// the expected answers of every puzzle, kept in aoc/answers.toml, and checking the answers of a run against them.
+
+// imports
+
+use crate::runner::{Outcome, PartReport};
+use solver::{Day, Year};
+use std::collections::HashMap;
+use terminal::{colorize, GREEN, RED, YELLOW};
+
+// code
+
+pub const ANSWERS: &str = include_str!("../answers.toml");
+
+// the expected answer of every part, by year, day, and part
+pub type Answers = HashMap<(Year, Day, usize), String>;
+
+// a TOML basic string, such as "513"
+fn parse_string(value: &str) -> Result<String, String> {
+    let invalid = || format!("Invalid string: {}", value);
+
+    if value.len() < 2 || !value.starts_with('"') || !value.ends_with('"') {
+        return Err(invalid());
+    }
+
+    let mut string = String::new();
+    let mut chars = value[1..value.len() - 1].chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                let escaped = match chars.next() {
+                    Some('"') => '"',
+                    Some('\\') => '\\',
+                    Some('n') => '\n',
+                    Some('t') => '\t',
+                    _ => {
+                        return Err(invalid());
+                    }
+                };
+                string.push(escaped);
+            }
+            '"' => {
+                return Err(invalid());
+            }
+            _ => {
+                string.push(c);
+            }
+        }
+    }
+
+    return Ok(string);
+}
+
+// only the part of TOML that answers.toml needs is understood:
+// comments, [<year>.<day>] tables, and part_<n> = "<answer>" keys
+pub fn parse_answers(input_string: &str) -> Result<Answers, String> {
+    let mut answers = Answers::new();
+    let mut table: Option<(Year, Day)> = None;
+
+    for (index, line) in input_string.lines().enumerate() {
+        let line_number = index + 1;
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let invalid = || format!("Invalid line {}: {}", line_number, line);
+
+        if line.starts_with('[') {
+            let (year, day) = line
+                .trim_start_matches('[')
+                .trim_end_matches(']')
+                .split_once('.')
+                .ok_or_else(invalid)?;
+
+            let year = year.trim().parse().map_err(|_| invalid())?;
+            let day = day.trim().parse().map_err(|_| invalid())?;
+
+            table = Some((year, day));
+            continue;
+        }
+
+        let (year, day) = table.ok_or_else(|| {
+            format!(
+                "Line {} is not in a [<year>.<day>] table: {}",
+                line_number, line
+            )
+        })?;
+
+        let (key, value) = line.split_once('=').ok_or_else(invalid)?;
+
+        let part: usize = key
+            .trim()
+            .strip_prefix("part_")
+            .and_then(|part| part.parse().ok())
+            .ok_or_else(invalid)?;
+
+        let answer =
+            parse_string(value.trim()).map_err(|err| format!("{} on line {}", err, line_number))?;
+
+        if answers.insert((year, day, part), answer).is_some() {
+            return Err(format!(
+                "Line {} repeats the answer of {} day {} part {}",
+                line_number, year, day, part
+            ));
+        }
+    }
+
+    return Ok(answers);
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Verdict {
+    Correct,
+    Wrong { expected: String },
+    // there's no expected answer to compare with, such as for a puzzle that was just added
+    Unknown,
+}
+
+pub fn verify(report: &PartReport, answers: &Answers) -> Verdict {
+    let expected = answers.get(&(report.year, report.day, report.part));
+
+    return check(report, expected.map(|expected| expected.as_str()));
+}
+
+// compares the answer of a part with the expected one, if there is one
+pub fn check(report: &PartReport, expected: Option<&str>) -> Verdict {
+    let expected = match expected {
+        None => {
+            return Verdict::Unknown;
+        }
+        Some(expected) => expected,
+    };
+
+    match &report.outcome {
+        Outcome::Answer(answer) if answer == expected => {
+            return Verdict::Correct;
+        }
+        _ => {
+            return Verdict::Wrong {
+                expected: expected.to_string(),
+            };
+        }
+    }
+}
+
+// the verdict of a part under the given name, followed by the expected and the actual answers when they differ.
+// the wrong answer is the one in red; the expected one is left uncolored.
+pub fn get_verdict_lines(
+    name: &str,
+    report: &PartReport,
+    verdict: &Verdict,
+    use_color: bool,
+) -> Vec<String> {
+    let paint = |text: &str, style: &str| -> String {
+        if use_color {
+            return colorize(text, style);
+        }
+        return text.to_string();
+    };
+
+    let answer = report.outcome.to_string();
+
+    match verdict {
+        Verdict::Correct => {
+            return vec![format!("{}: {}", name, paint("ok", GREEN))];
+        }
+        Verdict::Wrong { expected } => {
+            return vec![
+                format!("{}: {}", name, paint("wrong answer", RED)),
+                format!("    - {}", expected),
+                paint(&format!("    + {}", answer), RED),
+            ];
+        }
+        Verdict::Unknown => {
+            return vec![format!(
+                "{}: {}, got {}",
+                name,
+                paint("no expected answer", YELLOW),
+                answer
+            )];
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+    use std::time::Duration;
+
+    fn report(year: Year, day: Day, part: usize, outcome: Outcome) -> PartReport {
+        return PartReport {
+            year,
+            day,
+            part,
+            outcome,
+            elapsed: Duration::from_millis(1),
+            memory: Default::default(),
+        };
+    }
+
+    #[test]
+    fn test_parse_answers() {
+        let input_string = r#"
+# 2018
+
+[2018.1]
+part_1 = "513"
+part_2 = "287"
+
+[2018.10]
+part_1 = "line \"one\"\nline two"
+"#;
+
+        let answers = parse_answers(input_string).unwrap();
+        assert_eq!(answers.len(), 3);
+        assert_eq!(answers[&(2018, 1, 1)], "513");
+        assert_eq!(answers[&(2018, 1, 2)], "287");
+        assert_eq!(answers[&(2018, 10, 1)], "line \"one\"\nline two");
+
+        assert!(parse_answers("part_1 = \"513\"").is_err());
+        assert!(parse_answers("[2018]").is_err());
+        assert!(parse_answers("[2018.1]\npart_1 = 513").is_err());
+        assert!(parse_answers("[2018.1]\npart_1 = \"5\"13\"").is_err());
+        assert!(parse_answers("[2018.1]\nanswer = \"513\"").is_err());
+        assert!(parse_answers("[2018.1]\npart_1 = \"513\"\npart_1 = \"514\"").is_err());
+    }
+
+    #[test]
+    fn test_stored_answers() {
+        let answers = parse_answers(ANSWERS).unwrap();
+
+        // every part of every puzzle has an expected answer, and nothing else does
+        let mut num_of_parts = 0;
+        for solver in registry::find_all(None) {
+            for (part, _solve) in solver.parts() {
+                num_of_parts += 1;
+                assert!(
+                    answers.contains_key(&(solver.year, solver.day, part)),
+                    "{} part {} has no expected answer",
+                    solver.name(),
+                    part
+                );
+            }
+        }
+        assert_eq!(answers.len(), num_of_parts);
+
+        assert_eq!(answers[&(2018, 22, 1)], "4479");
+    }
+
+    #[test]
+    fn test_verify() {
+        let answers = parse_answers("[2018.1]\npart_1 = \"513\"\npart_2 = \"287\"").unwrap();
+
+        let correct = report(2018, 1, 1, Outcome::Answer("513".to_string()));
+        assert_eq!(verify(&correct, &answers), Verdict::Correct);
+        assert_eq!(
+            get_verdict_lines("2018 day  1 part 1", &correct, &Verdict::Correct, false),
+            vec!["2018 day  1 part 1: ok"]
+        );
+        assert_eq!(
+            get_verdict_lines("2018 day  1 part 1", &correct, &Verdict::Correct, true),
+            vec!["2018 day  1 part 1: \x1b[32mok\x1b[0m"]
+        );
+
+        let wrong = report(2018, 1, 2, Outcome::Answer("288".to_string()));
+        let verdict = verify(&wrong, &answers);
+        assert_eq!(
+            verdict,
+            Verdict::Wrong {
+                expected: "287".to_string()
+            }
+        );
+        assert_eq!(
+            get_verdict_lines("2018 day  1 part 2", &wrong, &verdict, false),
+            vec!["2018 day  1 part 2: wrong answer", "    - 287", "    + 288"]
+        );
+        assert_eq!(
+            get_verdict_lines("2018 day  1 part 2", &wrong, &verdict, true),
+            vec![
+                "2018 day  1 part 2: \x1b[31mwrong answer\x1b[0m",
+                "    - 287",
+                "\x1b[31m    + 288\x1b[0m",
+            ]
+        );
+
+        let panicked = report(2018, 1, 2, Outcome::Panicked("oops".to_string()));
+        assert_eq!(verify(&panicked, &answers), verdict);
+
+        let unknown = report(2018, 2, 1, Outcome::Answer("6200".to_string()));
+        assert_eq!(verify(&unknown, &answers), Verdict::Unknown);
+        assert_eq!(
+            get_verdict_lines("2018 day  2 part 1", &unknown, &Verdict::Unknown, false),
+            vec!["2018 day  2 part 1: no expected answer, got 6200"]
+        );
+    }
+
+    #[test]
+    fn test_check() {
+        let answer = report(2018, 6, 1, Outcome::Answer("17".to_string()));
+
+        assert_eq!(check(&answer, Some("17")), Verdict::Correct);
+        assert_eq!(
+            check(&answer, Some("16")),
+            Verdict::Wrong {
+                expected: "16".to_string()
+            }
+        );
+        assert_eq!(check(&answer, None), Verdict::Unknown);
+    }
+}
@@ -0,0 +1,223 @@
+// downloading puzzle inputs with the session cookie of an Advent of Code account,
+// and caching them under inputs/<year>/<day>.txt
+//
+// the download is done by curl, so no http client is needed to build the runner.
+
+// imports
+
+use solver::{Day, Solver, Year};
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+// code
+
+pub const INPUTS_DIR: &str = "inputs";
+
+const SESSION_VAR: &str = "AOC_SESSION";
+
+// the time of the last download is kept here, so separate runs of the runner also wait
+const LAST_FETCH_FILE: &str = ".last-fetch";
+
+// puzzle inputs never change, so a cached input is never downloaded again;
+// downloads of different inputs are still spaced out to go easy on the servers.
+const TIME_BETWEEN_FETCHES: Duration = Duration::from_secs(5);
+
+// identifies the runner to the maintainers of Advent of Code
+const USER_AGENT: &str = "github.com/dashed/advent-of-code-2018 by mail4alberto@gmail.com";
+
+pub fn get_session() -> Option<String> {
+    let session = std::env::var(SESSION_VAR).ok()?;
+    let session = session.trim();
+
+    if session.is_empty() {
+        return None;
+    }
+
+    return Some(session.to_string());
+}
+
+pub fn get_input_path(dir: &Path, year: Year, day: Day) -> PathBuf {
+    return dir.join(year.to_string()).join(format!("{}.txt", day));
+}
+
+pub fn read_cached_input(dir: &Path, year: Year, day: Day) -> Option<String> {
+    return fs::read_to_string(get_input_path(dir, year, day)).ok();
+}
+
+fn get_seconds_since_epoch() -> u64 {
+    return SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0);
+}
+
+fn wait_for_rate_limit(dir: &Path) {
+    let last_fetch: Option<u64> = fs::read_to_string(dir.join(LAST_FETCH_FILE))
+        .ok()
+        .and_then(|contents| contents.trim().parse().ok());
+
+    if let Some(last_fetch) = last_fetch {
+        let elapsed = Duration::from_secs(get_seconds_since_epoch().saturating_sub(last_fetch));
+
+        if elapsed < TIME_BETWEEN_FETCHES {
+            thread::sleep(TIME_BETWEEN_FETCHES - elapsed);
+        }
+    }
+}
+
+fn download_input(year: Year, day: Day, session: &str) -> Result<String, String> {
+    let url = format!("https://adventofcode.com/{}/day/{}/input", year, day);
+
+    // the cookie is passed through stdin, so that it doesn't show up in the list of processes
+    let mut child = Command::new("curl")
+        .args(["--silent", "--show-error", "--fail", "--location"])
+        .args(["--user-agent", USER_AGENT, "--header", "@-", &url])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|err| format!("Unable to run curl: {}", err))?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        writeln!(stdin, "Cookie: session={}", session)
+            .map_err(|err| format!("Unable to run curl: {}", err))?;
+    }
+
+    let output = child
+        .wait_with_output()
+        .map_err(|err| format!("Unable to run curl: {}", err))?;
+
+    if !output.status.success() {
+        // the puzzle is not unlocked yet, or the session expired
+        return Err(format!(
+            "Unable to fetch {}: {}",
+            url,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    let input = String::from_utf8(output.stdout)
+        .map_err(|_| format!("Unable to fetch {}: the input is not valid UTF-8", url))?;
+
+    if input.trim().is_empty() {
+        return Err(format!("Unable to fetch {}: the input is empty", url));
+    }
+
+    return Ok(input);
+}
+
+// download the input of the puzzle, unless it's cached; the path of the cached input is returned
+pub fn fetch_input(dir: &Path, year: Year, day: Day, session: &str) -> Result<PathBuf, String> {
+    let path = get_input_path(dir, year, day);
+
+    if path.exists() {
+        return Ok(path);
+    }
+
+    let year_dir = path.parent().unwrap();
+    fs::create_dir_all(year_dir)
+        .map_err(|err| format!("Unable to create {}: {}", year_dir.display(), err))?;
+
+    wait_for_rate_limit(dir);
+
+    let input = download_input(year, day, session);
+
+    // failed downloads count towards the rate limit too
+    fs::write(
+        dir.join(LAST_FETCH_FILE),
+        get_seconds_since_epoch().to_string(),
+    )
+    .map_err(|err| format!("Unable to write {}: {}", LAST_FETCH_FILE, err))?;
+
+    fs::write(&path, input?)
+        .map_err(|err| format!("Unable to write {}: {}", path.display(), err))?;
+
+    return Ok(path);
+}
+
+// the input of the puzzle is the cached input, or else the downloaded input when there's a session.
+// the input bundled with the puzzle is never used in their place, so that a missing input isn't mistaken
+// for one's own.
+pub fn load_input(dir: &Path, solver: &Solver, session: Option<&str>) -> Result<String, String> {
+    if let Some(input) = read_cached_input(dir, solver.year, solver.day) {
+        return Ok(input);
+    }
+
+    match session {
+        None => {
+            return Err(format!(
+                "No input for {} at {}; fetch it with AOC_SESSION set: aoc fetch {} {}, \
+                 or run with the input bundled with the puzzle: aoc run --bundled {} {}",
+                solver.name(),
+                get_input_path(dir, solver.year, solver.day).display(),
+                solver.year,
+                solver.day,
+                solver.year,
+                solver.day
+            ));
+        }
+        Some(session) => {
+            let path = fetch_input(dir, solver.year, solver.day, session)?;
+
+            return fs::read_to_string(&path)
+                .map_err(|err| format!("Unable to read {}: {}", path.display(), err));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOLVER: Solver = Solver {
+        year: 2018,
+        day: 9,
+        input: "bundled",
+        part_1: |input_string| Ok(input_string.to_string()),
+        part_2: None,
+        examples: &[],
+    };
+
+    fn get_test_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-inputs-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        return dir;
+    }
+
+    #[test]
+    fn test_get_input_path() {
+        assert_eq!(
+            get_input_path(Path::new(INPUTS_DIR), 2018, 9),
+            Path::new("inputs/2018/9.txt")
+        );
+    }
+
+    #[test]
+    fn test_load_input() {
+        let dir = get_test_dir("load");
+
+        // nothing is cached and there's no session
+        assert_eq!(read_cached_input(&dir, 2018, 9), None);
+        let err = load_input(&dir, &SOLVER, None).unwrap_err();
+        assert!(err.contains("aoc fetch 2018 9"));
+        assert!(err.contains("aoc run --bundled 2018 9"));
+
+        // a cached input is preferred, and never downloaded again
+        let path = get_input_path(&dir, 2018, 9);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "cached").unwrap();
+
+        assert_eq!(load_input(&dir, &SOLVER, None), Ok("cached".to_string()));
+        assert_eq!(
+            load_input(&dir, &SOLVER, Some("session")),
+            Ok("cached".to_string())
+        );
+        assert_eq!(fetch_input(&dir, 2018, 9, "session"), Ok(path));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
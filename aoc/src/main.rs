@@ -0,0 +1,951 @@
+// runs the puzzles of every year from one place.
+//
+// aoc [-v|-vv] <command>
+// aoc list [<year>]
+// aoc run [--time-budget <milliseconds>] [--example] <year> <day> [<part>]
+// aoc run [--time-budget <milliseconds>] [--jobs <n>] --all [<year>]
+// aoc verify [<year>]
+// aoc watch <year> <day> [<part>]
+// aoc fetch <year> <day>
+
+// imports
+
+use solver::{Day, Solver, Year};
+use std::io::IsTerminal;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+use tracing_subscriber::EnvFilter;
+
+mod answers;
+mod inputs;
+mod memory;
+mod registry;
+mod runner;
+mod watch;
+
+// code
+
+#[global_allocator]
+static ALLOCATOR: memory::CountingAllocator = memory::CountingAllocator;
+
+const USAGE: &str = "Usage:
+    aoc [-v|-vv] <command>
+    aoc list [<year>]
+    aoc run [--time-budget <milliseconds>] [--example] [--bundled] <year> <day> [<part>]
+    aoc run [--time-budget <milliseconds>] [--jobs <n>] [--bundled] --all [<year>]
+    aoc verify [<year>]
+    aoc watch [--bundled] <year> <day> [<part>]
+    aoc fetch <year> <day>
+
+Every part is reported with its answer, wall-clock time, and peak heap memory.
+With --time-budget, the run fails when a part takes longer than the budget.
+With --example, a puzzle is run with the examples of its description instead,
+and fails when an answer differs from the one the description gives.
+
+--all runs the parts of the puzzles alongside each other, on as many threads
+as there are CPUs, or on <n> threads with --jobs. Memory is only reported
+with --jobs 1, when the parts run one after another.
+
+verify runs the puzzles with their bundled inputs, and fails when an answer
+differs from the one in aoc/answers.toml.
+
+watch runs a puzzle with cargo run --release, and runs it again whenever
+its source or its input changes, until it's stopped with Ctrl-C.
+
+-v logs what the puzzles do along the way to stderr, and -vv logs every step.
+RUST_LOG picks the logs instead when it's set, such as RUST_LOG=day_15_2018=trace.
+
+Inputs are read from inputs/<year>/<day>.txt when they were fetched before.
+Otherwise they are fetched when AOC_SESSION is set to the session cookie of
+an Advent of Code account, or else the run fails. With --bundled, the inputs
+bundled with the puzzles are used instead.";
+
+#[derive(Debug, PartialEq, Default)]
+struct RunOptions {
+    // a part that takes longer than this fails the run
+    time_budget: Option<Duration>,
+    // run the examples of the puzzle instead of its input
+    example: bool,
+    // the number of parts of --all to run at once; one for every CPU if not given
+    jobs: Option<usize>,
+    // run with the inputs bundled with the puzzles instead of the fetched ones
+    bundled: bool,
+}
+
+#[derive(Debug, PartialEq)]
+enum Command {
+    // list the puzzles of a year, or of every year
+    List {
+        year: Option<Year>,
+    },
+    // run every part of a puzzle with its input, or only the given part
+    Run {
+        year: Year,
+        day: Day,
+        part: Option<usize>,
+        options: RunOptions,
+    },
+    // run every puzzle of a year, or of every year, one after another
+    RunAll {
+        year: Option<Year>,
+        options: RunOptions,
+    },
+    // run every puzzle of a year, or of every year, and compare the answers with the stored ones
+    Verify {
+        year: Option<Year>,
+    },
+    // run a puzzle, or only one of its parts, whenever its source or input changes
+    Watch {
+        year: Year,
+        day: Day,
+        part: Option<usize>,
+        // run with the input bundled with the puzzle
+        bundled: bool,
+    },
+    // download the input of a puzzle into inputs/<year>/<day>.txt
+    Fetch {
+        year: Year,
+        day: Day,
+    },
+}
+
+// the -v and -vv flags can go anywhere; the number of v's is returned, along with the other arguments
+fn parse_verbosity(args: &[String]) -> (usize, Vec<String>) {
+    let mut verbosity = 0;
+    let mut other_args = vec![];
+
+    for arg in args {
+        let is_verbosity_flag =
+            arg.len() > 1 && arg.starts_with('-') && arg[1..].chars().all(|c| c == 'v');
+
+        if is_verbosity_flag {
+            verbosity += arg.len() - 1;
+            continue;
+        }
+
+        other_args.push(arg.clone());
+    }
+
+    return (verbosity, other_args);
+}
+
+fn get_log_filter(verbosity: usize) -> &'static str {
+    match verbosity {
+        0 => "warn",
+        1 => "debug",
+        _ => "trace",
+    }
+}
+
+// logs go to stderr, so that they don't get mixed up with the answers
+fn init_logging(verbosity: usize) {
+    let filter = EnvFilter::try_from_default_env()
+        .unwrap_or_else(|_| EnvFilter::new(get_log_filter(verbosity)));
+
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(std::io::stderr)
+        .init();
+}
+
+fn parse_value<T: FromStr>(name: &str, value: Option<&String>) -> Result<T, String> {
+    let value = value.ok_or_else(|| format!("Missing {}\n{}", name, USAGE))?;
+
+    return value
+        .parse()
+        .map_err(|_| format!("Invalid {}: {}", name, value));
+}
+
+fn parse_args(args: &[String]) -> Result<Command, String> {
+    let mut args = args.iter();
+
+    let command = match args.next().map(|command| command.as_str()) {
+        Some("list") => {
+            let year = match args.next() {
+                None => None,
+                Some(year) => Some(parse_value("year", Some(year))?),
+            };
+
+            Command::List { year }
+        }
+        Some("run") => {
+            let mut options = RunOptions::default();
+            let mut run_all = false;
+            let mut values = vec![];
+
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--all" => {
+                        run_all = true;
+                    }
+                    "--example" => {
+                        options.example = true;
+                    }
+                    "--bundled" => {
+                        options.bundled = true;
+                    }
+                    "--time-budget" => {
+                        let milliseconds = parse_value("time budget", args.next())?;
+                        options.time_budget = Some(Duration::from_millis(milliseconds));
+                    }
+                    "--jobs" => {
+                        let jobs = parse_value("number of jobs", args.next())?;
+                        if jobs == 0 {
+                            return Err(format!("Invalid number of jobs: {}", jobs));
+                        }
+                        options.jobs = Some(jobs);
+                    }
+                    _ => {
+                        if arg.starts_with("--") {
+                            return Err(format!("Unknown flag: {}\n{}", arg, USAGE));
+                        }
+
+                        values.push(arg);
+                    }
+                }
+            }
+
+            let mut values = values.into_iter();
+
+            if run_all && options.example {
+                return Err(format!(
+                    "--example runs a single puzzle, not --all\n{}",
+                    USAGE
+                ));
+            }
+
+            if !run_all && options.jobs.is_some() {
+                return Err(format!("--jobs only applies to --all\n{}", USAGE));
+            }
+
+            let command = if run_all {
+                let year = match values.next() {
+                    None => None,
+                    Some(year) => Some(parse_value("year", Some(year))?),
+                };
+
+                Command::RunAll { year, options }
+            } else {
+                let year = parse_value("year", values.next())?;
+                let day = parse_value("day", values.next())?;
+                let part = match values.next() {
+                    None => None,
+                    Some(part) => Some(parse_value("part", Some(part))?),
+                };
+
+                Command::Run {
+                    year,
+                    day,
+                    part,
+                    options,
+                }
+            };
+
+            if let Some(arg) = values.next() {
+                return Err(format!("Unknown argument: {}\n{}", arg, USAGE));
+            }
+
+            command
+        }
+        Some("verify") => {
+            let year = match args.next() {
+                None => None,
+                Some(year) => Some(parse_value("year", Some(year))?),
+            };
+
+            Command::Verify { year }
+        }
+        Some("watch") => {
+            let mut args = args.by_ref().peekable();
+            let bundled = args.next_if(|arg| *arg == "--bundled").is_some();
+
+            let year = parse_value("year", args.next())?;
+            let day = parse_value("day", args.next())?;
+            let part = match args.next() {
+                None => None,
+                Some(part) => Some(parse_value("part", Some(part))?),
+            };
+
+            Command::Watch {
+                year,
+                day,
+                part,
+                bundled,
+            }
+        }
+        Some("fetch") => {
+            let year = parse_value("year", args.next())?;
+            let day = parse_value("day", args.next())?;
+
+            Command::Fetch { year, day }
+        }
+        Some(command) => {
+            return Err(format!("Unknown command: {}\n{}", command, USAGE));
+        }
+        None => {
+            return Err(USAGE.to_string());
+        }
+    };
+
+    if let Some(arg) = args.next() {
+        return Err(format!("Unknown argument: {}\n{}", arg, USAGE));
+    }
+
+    return Ok(command);
+}
+
+fn list(year: Option<Year>) -> Result<Vec<String>, String> {
+    let solvers = registry::find_all(year);
+
+    if solvers.is_empty() {
+        return Err(format!("No puzzles found for {}", year.unwrap()));
+    }
+
+    let lines = solvers
+        .into_iter()
+        .map(|solver| {
+            let line = format!("{} day {:>2}: {}", solver.year, solver.day, solver.name());
+
+            if solver.part_2.is_none() {
+                return format!("{} (part 1 only)", line);
+            }
+
+            return line;
+        })
+        .collect();
+
+    return Ok(lines);
+}
+
+fn load_input(solver: &Solver, options: &RunOptions) -> Result<String, String> {
+    if options.bundled {
+        return Ok(solver.input.to_string());
+    }
+
+    let session = inputs::get_session();
+
+    return inputs::load_input(Path::new(inputs::INPUTS_DIR), solver, session.as_deref());
+}
+
+// the parts that took longer than the time budget, which fail the run
+fn check_time_budget(reports: &[runner::PartReport], options: &RunOptions) -> Result<(), String> {
+    let time_budget = match options.time_budget {
+        None => {
+            return Ok(());
+        }
+        Some(time_budget) => time_budget,
+    };
+
+    let over_budget = runner::get_over_budget(reports, time_budget);
+
+    if over_budget.is_empty() {
+        return Ok(());
+    }
+
+    let lines: Vec<String> = over_budget
+        .into_iter()
+        .map(|report| {
+            return format!(
+                "{} day {} part {} took {:.2?}, over the time budget of {:.2?}",
+                report.year, report.day, report.part, report.elapsed, time_budget
+            );
+        })
+        .collect();
+
+    return Err(lines.join("\n"));
+}
+
+// the verdict of every part of every puzzle, followed by a summary.
+// puzzles are run with their bundled inputs, since those are the ones the stored answers are for.
+fn verify(year: Option<Year>, use_color: bool) -> Result<(Vec<String>, bool), String> {
+    let stored_answers = answers::parse_answers(answers::ANSWERS)?;
+
+    let solvers = registry::find_all(year);
+
+    if solvers.is_empty() {
+        return Err(format!("No puzzles found for {}", year.unwrap()));
+    }
+
+    let puzzles: Vec<(&Solver, String)> = solvers
+        .into_iter()
+        .map(|solver| (solver, solver.input.to_string()))
+        .collect();
+
+    let mut lines = vec![];
+    let mut num_of_correct = 0;
+    let mut num_of_wrong = 0;
+    let mut num_of_unknown = 0;
+
+    for report in runner::run_all(&puzzles, 1)? {
+        let verdict = answers::verify(&report, &stored_answers);
+
+        match verdict {
+            answers::Verdict::Correct => num_of_correct += 1,
+            answers::Verdict::Wrong { .. } => num_of_wrong += 1,
+            answers::Verdict::Unknown => num_of_unknown += 1,
+        }
+
+        let name = format!("{} day {:>2} part {}", report.year, report.day, report.part);
+        lines.extend(answers::get_verdict_lines(
+            &name, &report, &verdict, use_color,
+        ));
+    }
+
+    lines.push(format!(
+        "{} correct, {} wrong, {} without an expected answer",
+        num_of_correct, num_of_wrong, num_of_unknown
+    ));
+
+    return Ok((lines, num_of_wrong == 0));
+}
+
+// the verdict of every part that an example of a puzzle gives an answer for, followed by a summary.
+// the other parts aren't run, since an example is often only given for one of the parts.
+fn run_examples(
+    solver: &Solver,
+    part: Option<usize>,
+    use_color: bool,
+) -> Result<(Vec<String>, bool), String> {
+    let parts: Vec<usize> = match part {
+        None => solver
+            .parts()
+            .into_iter()
+            .map(|(part, _solve)| part)
+            .collect(),
+        Some(part) => {
+            solver
+                .part(part)
+                .ok_or_else(|| format!("{} has no part {}", solver.name(), part))?;
+            vec![part]
+        }
+    };
+
+    let mut lines = vec![];
+    let mut num_of_correct = 0;
+    let mut num_of_wrong = 0;
+
+    for (index, example) in solver.examples.iter().enumerate() {
+        for part in parts.iter() {
+            let expected = match example.answer(*part) {
+                None => {
+                    continue;
+                }
+                Some(expected) => expected,
+            };
+
+            for report in runner::run_solver(solver, example.input, Some(*part))? {
+                let verdict = answers::check(&report, Some(expected));
+
+                match verdict {
+                    answers::Verdict::Correct => num_of_correct += 1,
+                    _ => num_of_wrong += 1,
+                }
+
+                let name = format!(
+                    "Example {} part {} ({})",
+                    index + 1,
+                    report.part,
+                    report.get_usage()
+                );
+                lines.extend(answers::get_verdict_lines(
+                    &name, &report, &verdict, use_color,
+                ));
+            }
+        }
+    }
+
+    if num_of_correct + num_of_wrong == 0 {
+        return match part {
+            None => Err(format!("{} has no examples", solver.name())),
+            Some(part) => Err(format!(
+                "{} has no examples of part {}",
+                solver.name(),
+                part
+            )),
+        };
+    }
+
+    lines.push(format!(
+        "{} correct, {} wrong",
+        num_of_correct, num_of_wrong
+    ));
+
+    return Ok((lines, num_of_wrong == 0));
+}
+
+fn use_color() -> bool {
+    return std::io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none();
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let (verbosity, args) = parse_verbosity(&args);
+    let command = parse_args(&args).unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        std::process::exit(1);
+    });
+
+    init_logging(verbosity);
+
+    match command {
+        Command::List { year } => {
+            let lines = list(year).unwrap_or_else(|err| {
+                eprintln!("{}", err);
+                std::process::exit(1);
+            });
+
+            for line in lines {
+                println!("{}", line);
+            }
+        }
+        Command::Run {
+            year,
+            day,
+            part,
+            options,
+        } => {
+            let solver = registry::find(year, day).unwrap_or_else(|| {
+                eprintln!("No puzzle found for {} day {}", year, day);
+                std::process::exit(1);
+            });
+
+            if options.example {
+                let (lines, is_correct) =
+                    run_examples(solver, part, use_color()).unwrap_or_else(|err| {
+                        eprintln!("{}", err);
+                        std::process::exit(1);
+                    });
+
+                for line in lines {
+                    println!("{}", line);
+                }
+
+                if !is_correct {
+                    std::process::exit(1);
+                }
+                return;
+            }
+
+            let reports = load_input(solver, &options)
+                .and_then(|input| runner::run_solver(solver, &input, part))
+                .unwrap_or_else(|err| {
+                    eprintln!("{}", err);
+                    std::process::exit(1);
+                });
+
+            for report in reports.iter() {
+                println!(
+                    "Part {}: {} ({})",
+                    report.part,
+                    report.outcome.to_string(),
+                    report.get_usage()
+                );
+            }
+
+            if let Err(err) = check_time_budget(&reports, &options) {
+                eprintln!("{}", err);
+                std::process::exit(1);
+            }
+
+            if runner::has_failures(&reports) {
+                std::process::exit(1);
+            }
+        }
+        Command::RunAll { year, options } => {
+            let solvers = registry::find_all(year);
+
+            if solvers.is_empty() {
+                eprintln!("No puzzles found for {}", year.unwrap());
+                std::process::exit(1);
+            }
+
+            let puzzles: Vec<(&Solver, String)> = solvers
+                .into_iter()
+                .map(|solver| {
+                    let input = load_input(solver, &options).unwrap_or_else(|err| {
+                        eprintln!("{}", err);
+                        std::process::exit(1);
+                    });
+
+                    return (solver, input);
+                })
+                .collect();
+
+            let jobs = options.jobs.unwrap_or_else(|| {
+                return std::thread::available_parallelism()
+                    .map(|num_of_cpus| num_of_cpus.get())
+                    .unwrap_or(1);
+            });
+
+            let start = Instant::now();
+            let reports = runner::run_all(&puzzles, jobs).unwrap_or_else(|err| {
+                eprintln!("{}", err);
+                std::process::exit(1);
+            });
+            let elapsed = start.elapsed();
+
+            println!("{}", runner::get_summary_table(&reports));
+            println!(
+                "Ran {} parts in {:.2?} with {} {}",
+                reports.len(),
+                elapsed,
+                jobs,
+                if jobs == 1 { "job" } else { "jobs" }
+            );
+
+            if let Err(err) = check_time_budget(&reports, &options) {
+                eprintln!("{}", err);
+                std::process::exit(1);
+            }
+
+            if runner::has_failures(&reports) {
+                std::process::exit(1);
+            }
+        }
+        Command::Verify { year } => {
+            let (lines, is_correct) = verify(year, use_color()).unwrap_or_else(|err| {
+                eprintln!("{}", err);
+                std::process::exit(1);
+            });
+
+            for line in lines {
+                println!("{}", line);
+            }
+
+            if !is_correct {
+                std::process::exit(1);
+            }
+        }
+        Command::Watch {
+            year,
+            day,
+            part,
+            bundled,
+        } => {
+            let solver = registry::find(year, day).unwrap_or_else(|| {
+                eprintln!("No puzzle found for {} day {}", year, day);
+                std::process::exit(1);
+            });
+
+            if let Some(part) = part {
+                if solver.part(part).is_none() {
+                    eprintln!("{} has no part {}", solver.name(), part);
+                    std::process::exit(1);
+                }
+            }
+
+            watch::watch(solver, part, bundled, verbosity).unwrap_or_else(|err| {
+                eprintln!("{}", err);
+                std::process::exit(1);
+            });
+        }
+        Command::Fetch { year, day } => {
+            let session = inputs::get_session().unwrap_or_else(|| {
+                eprintln!("AOC_SESSION is not set\n{}", USAGE);
+                std::process::exit(1);
+            });
+
+            let path = inputs::fetch_input(Path::new(inputs::INPUTS_DIR), year, day, &session)
+                .unwrap_or_else(|err| {
+                    eprintln!("{}", err);
+                    std::process::exit(1);
+                });
+
+            println!("{}", path.display());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        return args.iter().map(|arg| arg.to_string()).collect();
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(
+            parse_args(&args(&["list"])),
+            Ok(Command::List { year: None })
+        );
+        assert_eq!(
+            parse_args(&args(&["list", "2018"])),
+            Ok(Command::List { year: Some(2018) })
+        );
+        assert_eq!(
+            parse_args(&args(&["run", "2018", "9"])),
+            Ok(Command::Run {
+                year: 2018,
+                day: 9,
+                part: None,
+                options: RunOptions::default()
+            })
+        );
+        assert_eq!(
+            parse_args(&args(&["run", "2018", "9", "2"])),
+            Ok(Command::Run {
+                year: 2018,
+                day: 9,
+                part: Some(2),
+                options: RunOptions::default()
+            })
+        );
+        assert_eq!(
+            parse_args(&args(&["run", "--time-budget", "500", "2018", "9"])),
+            Ok(Command::Run {
+                year: 2018,
+                day: 9,
+                part: None,
+                options: RunOptions {
+                    time_budget: Some(Duration::from_millis(500)),
+                    ..RunOptions::default()
+                }
+            })
+        );
+        assert_eq!(
+            parse_args(&args(&["run", "--example", "2018", "6", "1"])),
+            Ok(Command::Run {
+                year: 2018,
+                day: 6,
+                part: Some(1),
+                options: RunOptions {
+                    example: true,
+                    ..RunOptions::default()
+                }
+            })
+        );
+
+        assert_eq!(
+            parse_args(&args(&["run", "--bundled", "2018", "9"])),
+            Ok(Command::Run {
+                year: 2018,
+                day: 9,
+                part: None,
+                options: RunOptions {
+                    bundled: true,
+                    ..RunOptions::default()
+                }
+            })
+        );
+
+        assert_eq!(
+            parse_args(&args(&["run", "--all"])),
+            Ok(Command::RunAll {
+                year: None,
+                options: RunOptions::default()
+            })
+        );
+        assert_eq!(
+            parse_args(&args(&["run", "--all", "2019"])),
+            Ok(Command::RunAll {
+                year: Some(2019),
+                options: RunOptions::default()
+            })
+        );
+        assert_eq!(
+            parse_args(&args(&["run", "--jobs", "4", "--all", "2018"])),
+            Ok(Command::RunAll {
+                year: Some(2018),
+                options: RunOptions {
+                    jobs: Some(4),
+                    ..RunOptions::default()
+                }
+            })
+        );
+        assert_eq!(
+            parse_args(&args(&["run", "--all", "--time-budget", "1000"])),
+            Ok(Command::RunAll {
+                year: None,
+                options: RunOptions {
+                    time_budget: Some(Duration::from_secs(1)),
+                    ..RunOptions::default()
+                }
+            })
+        );
+
+        assert_eq!(
+            parse_args(&args(&["verify"])),
+            Ok(Command::Verify { year: None })
+        );
+        assert_eq!(
+            parse_args(&args(&["verify", "2019"])),
+            Ok(Command::Verify { year: Some(2019) })
+        );
+
+        assert_eq!(
+            parse_args(&args(&["watch", "2018", "15"])),
+            Ok(Command::Watch {
+                year: 2018,
+                day: 15,
+                part: None,
+                bundled: false
+            })
+        );
+        assert_eq!(
+            parse_args(&args(&["watch", "2018", "15", "2"])),
+            Ok(Command::Watch {
+                year: 2018,
+                day: 15,
+                part: Some(2),
+                bundled: false
+            })
+        );
+        assert_eq!(
+            parse_args(&args(&["watch", "--bundled", "2018", "15"])),
+            Ok(Command::Watch {
+                year: 2018,
+                day: 15,
+                part: None,
+                bundled: true
+            })
+        );
+
+        assert_eq!(
+            parse_args(&args(&["fetch", "2018", "9"])),
+            Ok(Command::Fetch { year: 2018, day: 9 })
+        );
+
+        assert!(parse_args(&args(&[])).is_err());
+        assert!(parse_args(&args(&["solve"])).is_err());
+        assert!(parse_args(&args(&["fetch", "2018"])).is_err());
+        assert!(parse_args(&args(&["watch", "2018"])).is_err());
+        assert!(parse_args(&args(&["watch", "2018", "15", "2", "1"])).is_err());
+        assert!(parse_args(&args(&["verify", "2019", "1"])).is_err());
+        assert!(parse_args(&args(&["run", "2018"])).is_err());
+        assert!(parse_args(&args(&["run", "2018", "nine"])).is_err());
+        assert!(parse_args(&args(&["run", "2018", "9", "2", "3"])).is_err());
+        assert!(parse_args(&args(&["list", "2018", "2019"])).is_err());
+        assert!(parse_args(&args(&["run", "--all", "2018", "2019"])).is_err());
+        assert!(parse_args(&args(&["run", "--time-budget"])).is_err());
+        assert!(parse_args(&args(&["run", "--time-budget", "1s", "--all"])).is_err());
+        assert!(parse_args(&args(&["run", "--jobs", "4", "2018", "9"])).is_err());
+        assert!(parse_args(&args(&["run", "--jobs", "0", "--all"])).is_err());
+        assert!(parse_args(&args(&["run", "--jobs", "--all"])).is_err());
+        assert!(parse_args(&args(&["run", "--example", "--all"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_verbosity() {
+        assert_eq!(
+            parse_verbosity(&args(&["run", "2018", "15"])),
+            (0, args(&["run", "2018", "15"]))
+        );
+        assert_eq!(
+            parse_verbosity(&args(&["-v", "run", "2018", "15"])),
+            (1, args(&["run", "2018", "15"]))
+        );
+        assert_eq!(
+            parse_verbosity(&args(&["run", "-vv", "--all"])),
+            (2, args(&["run", "--all"]))
+        );
+        assert_eq!(
+            parse_verbosity(&args(&["-v", "verify", "-v"])),
+            (2, args(&["verify"]))
+        );
+        // other flags that start with a dash are left for the command
+        assert_eq!(
+            parse_verbosity(&args(&["-", "-x", "--verify"])),
+            (0, args(&["-", "-x", "--verify"]))
+        );
+
+        assert_eq!(get_log_filter(0), "warn");
+        assert_eq!(get_log_filter(1), "debug");
+        assert_eq!(get_log_filter(2), "trace");
+        assert_eq!(get_log_filter(3), "trace");
+    }
+
+    #[test]
+    fn test_list() {
+        let lines = list(Some(2019)).unwrap();
+        assert_eq!(lines[0], "2019 day  1: day-01-2019");
+
+        let lines = list(Some(2018)).unwrap();
+        assert_eq!(lines[24], "2018 day 25: day-25-2018 (part 1 only)");
+
+        assert!(list(Some(2017)).is_err());
+    }
+
+    #[test]
+    fn test_run() {
+        let solver = registry::find(2019, 1).unwrap();
+
+        let answers: Vec<(usize, String)> = runner::run_solver(solver, solver.input, None)
+            .unwrap()
+            .into_iter()
+            .map(|report| (report.part, report.outcome.to_string()))
+            .collect();
+        assert_eq!(
+            answers,
+            vec![(1, "3249140".to_string()), (2, "4870838".to_string())]
+        );
+        assert!(runner::run_solver(solver, solver.input, Some(3)).is_err());
+    }
+
+    #[test]
+    fn test_verify() {
+        let (lines, is_correct) = verify(Some(2019), false).unwrap();
+
+        assert!(is_correct);
+        assert_eq!(lines[0], "2019 day  1 part 1: ok");
+        assert_eq!(
+            lines.last().unwrap(),
+            "10 correct, 0 wrong, 0 without an expected answer"
+        );
+
+        assert!(verify(Some(2017), false).is_err());
+    }
+
+    #[test]
+    fn test_run_examples() {
+        let solver = registry::find(2018, 6).unwrap();
+
+        // part 2 of the example is for a smaller safe distance, so there's nothing to run
+        let (lines, is_correct) = run_examples(solver, None, false).unwrap();
+        assert!(is_correct);
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("Example 1 part 1 ("));
+        assert!(lines[0].ends_with("): ok"));
+        assert_eq!(lines[1], "1 correct, 0 wrong");
+
+        assert!(run_examples(solver, Some(2), false).is_err());
+        assert!(run_examples(solver, Some(3), false).is_err());
+
+        let solver = registry::find(2018, 21).unwrap();
+        assert!(run_examples(solver, None, false).is_err());
+
+        // every stored example gives the answers of the puzzle description
+        for solver in registry::find_all(None) {
+            if solver.examples.is_empty() {
+                continue;
+            }
+
+            let (lines, is_correct) = run_examples(solver, None, false).unwrap();
+            assert!(is_correct, "{}:\n{}", solver.name(), lines.join("\n"));
+        }
+    }
+
+    #[test]
+    fn test_check_time_budget() {
+        let solver = registry::find(2019, 1).unwrap();
+        let reports = runner::run_solver(solver, solver.input, None).unwrap();
+
+        assert!(check_time_budget(&reports, &RunOptions::default()).is_ok());
+
+        let options = RunOptions {
+            time_budget: Some(Duration::from_secs(60)),
+            ..RunOptions::default()
+        };
+        assert!(check_time_budget(&reports, &options).is_ok());
+
+        let options = RunOptions {
+            time_budget: Some(Duration::from_secs(0)),
+            ..RunOptions::default()
+        };
+        let err = check_time_budget(&reports, &options).unwrap_err();
+        assert_eq!(err.lines().count(), 2);
+        assert!(err.starts_with("2019 day 1 part 1 took "));
+    }
+}
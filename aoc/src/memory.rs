@@ -0,0 +1,153 @@
+// counts the heap memory used by the runner, to report the peak memory of every part.
+//
+// the peak resident set size of the process only ever grows, so it can't tell the parts apart;
+// counting what the parts allocate can.
+
+// imports
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// code
+
+// bytes of heap memory in use
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+// the most bytes of heap memory in use at once, since the last reset
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+static NUM_OF_ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+// the system allocator, keeping count of what goes through it
+pub struct CountingAllocator;
+
+fn record_allocation(size: usize) {
+    let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(current, Ordering::Relaxed);
+    NUM_OF_ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+}
+
+fn record_deallocation(size: usize) {
+    CURRENT.fetch_sub(size, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+
+        if !ptr.is_null() {
+            record_allocation(layout.size());
+        }
+
+        return ptr;
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+
+        if !ptr.is_null() {
+            record_allocation(layout.size());
+        }
+
+        return ptr;
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+
+        // a reallocation is counted as a new allocation of the new size
+        if !new_ptr.is_null() {
+            record_deallocation(layout.size());
+            record_allocation(new_size);
+        }
+
+        return new_ptr;
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record_deallocation(layout.size());
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct MemoryUsage {
+    // the most heap memory in use at once, on top of what was in use when the tracking started
+    pub peak_bytes: usize,
+    pub num_of_allocations: usize,
+}
+
+pub struct MemoryTracker {
+    start_bytes: usize,
+    start_num_of_allocations: usize,
+}
+
+impl MemoryTracker {
+    // everything allocated from now on is tracked.
+    // the counts are shared by every thread, so whatever runs alongside is counted too.
+    pub fn start() -> MemoryTracker {
+        let start_bytes = CURRENT.load(Ordering::Relaxed);
+        PEAK.store(start_bytes, Ordering::Relaxed);
+
+        return MemoryTracker {
+            start_bytes,
+            start_num_of_allocations: NUM_OF_ALLOCATIONS.load(Ordering::Relaxed),
+        };
+    }
+
+    pub fn stop(&self) -> MemoryUsage {
+        return MemoryUsage {
+            peak_bytes: PEAK
+                .load(Ordering::Relaxed)
+                .saturating_sub(self.start_bytes),
+            num_of_allocations: NUM_OF_ALLOCATIONS
+                .load(Ordering::Relaxed)
+                .saturating_sub(self.start_num_of_allocations),
+        };
+    }
+}
+
+pub fn format_bytes(bytes: usize) -> String {
+    let units = ["B", "KiB", "MiB", "GiB"];
+
+    let mut size = bytes as f64;
+    let mut unit = 0;
+
+    while size >= 1024.0 && unit < units.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        return format!("{} {}", bytes, units[unit]);
+    }
+
+    return format!("{:.2} {}", size, units[unit]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1024), "1.00 KiB");
+        assert_eq!(format_bytes(1536), "1.50 KiB");
+        assert_eq!(format_bytes(5 * 1024 * 1024), "5.00 MiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024 * 1024), "3.00 GiB");
+    }
+
+    #[test]
+    fn test_memory_tracker() {
+        let tracker = MemoryTracker::start();
+
+        let buffer: Vec<u8> = Vec::with_capacity(1024 * 1024);
+
+        // other tests allocate and free at the same time, so only a lower bound can be checked
+        let usage = tracker.stop();
+        assert!(usage.peak_bytes >= 512 * 1024);
+        assert!(usage.num_of_allocations >= 1);
+
+        drop(buffer);
+    }
+}
@@ -0,0 +1,101 @@
+// every puzzle the runner knows about, in order of year and day.
+// a puzzle is added by depending on its package in Cargo.toml, and listing the SOLVER it exports here.
+
+// imports
+
+use solver::{Day, Solver, Year};
+
+// code
+
+pub const SOLVERS: &[Solver] = &[
+    // 2015
+    day_01_2015::SOLVER,
+    day_02_2015::SOLVER,
+    day_03_2015::SOLVER,
+    day_04_2015::SOLVER,
+    day_05_2015::SOLVER,
+    // 2018
+    day_01_2018::SOLVER,
+    day_02_2018::SOLVER,
+    day_03_2018::SOLVER,
+    day_04_2018::SOLVER,
+    day_05_2018::SOLVER,
+    day_06_2018::SOLVER,
+    day_07_2018::SOLVER,
+    day_08_2018::SOLVER,
+    day_09_2018::SOLVER,
+    day_10_2018::SOLVER,
+    day_11_2018::SOLVER,
+    day_12_2018::SOLVER,
+    day_13_2018::SOLVER,
+    day_14_2018::SOLVER,
+    day_15_2018::SOLVER,
+    day_16_2018::SOLVER,
+    day_17_2018::SOLVER,
+    day_18_2018::SOLVER,
+    day_19_2018::SOLVER,
+    day_20_2018::SOLVER,
+    day_21_2018::SOLVER,
+    day_22_2018::SOLVER,
+    day_23_2018::SOLVER,
+    day_24_2018::SOLVER,
+    day_25_2018::SOLVER,
+    // 2019
+    day_01_2019::SOLVER,
+    day_02_2019::SOLVER,
+    day_03_2019::SOLVER,
+    day_04_2019::SOLVER,
+    day_05_2019::SOLVER,
+    // 2020
+    day_01_2020::SOLVER,
+    day_02_2020::SOLVER,
+];
+
+pub fn find(year: Year, day: Day) -> Option<&'static Solver> {
+    return SOLVERS
+        .iter()
+        .find(|solver| solver.year == year && solver.day == day);
+}
+
+// the puzzles of a year, or of every year
+pub fn find_all(year: Option<Year>) -> Vec<&'static Solver> {
+    return SOLVERS
+        .iter()
+        .filter(|solver| match year {
+            None => true,
+            Some(year) => solver.year == year,
+        })
+        .collect();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solvers() {
+        // in order of year and day, with no puzzle listed twice
+        for pair in SOLVERS.windows(2) {
+            assert!((pair[0].year, pair[0].day) < (pair[1].year, pair[1].day));
+        }
+
+        for solver in SOLVERS {
+            assert!(1 <= solver.day && solver.day <= 25);
+            assert!(!solver.input.trim().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_find() {
+        let solver = find(2018, 25).unwrap();
+        assert_eq!(solver.name(), "day-25-2018");
+        assert!(solver.part_2.is_none());
+
+        assert!(find(2018, 26).is_none());
+        assert!(find(2017, 1).is_none());
+
+        assert_eq!(find_all(Some(2018)).len(), 25);
+        assert!(find_all(Some(2017)).is_empty());
+        assert_eq!(find_all(None).len(), SOLVERS.len());
+    }
+}
@@ -0,0 +1,421 @@
+// running the parts of puzzles, and reporting how each of them went.
+
+// imports
+
+use crate::memory::{format_bytes, MemoryTracker, MemoryUsage};
+use rayon::prelude::*;
+use solver::{Day, Part, Solver, Year};
+use std::any::Any;
+use std::panic;
+use std::time::{Duration, Instant};
+use tracing::debug;
+
+// code
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome {
+    Answer(String),
+    // the part gave up on the puzzle input
+    Error(String),
+    // the part panicked, with the message it panicked with
+    Panicked(String),
+}
+
+impl Outcome {
+    pub fn is_failure(&self) -> bool {
+        match self {
+            Outcome::Answer(_) => false,
+            Outcome::Error(_) | Outcome::Panicked(_) => true,
+        }
+    }
+
+    pub fn to_string(&self) -> String {
+        match self {
+            Outcome::Answer(answer) => answer.clone(),
+            Outcome::Error(err) => format!("error: {}", err),
+            Outcome::Panicked(message) => format!("panicked: {}", message),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PartReport {
+    pub year: Year,
+    pub day: Day,
+    pub part: usize,
+    pub outcome: Outcome,
+    // wall-clock time of the part, including parsing the puzzle input
+    pub elapsed: Duration,
+    // parts that run alongside each other share the heap, so their memory can't be told apart
+    pub memory: Option<MemoryUsage>,
+}
+
+impl PartReport {
+    // the time and memory the part took, such as: 1.25ms, 2.00 KiB peak in 8 allocations
+    pub fn get_usage(&self) -> String {
+        let memory = match self.memory {
+            None => {
+                return format!("{:.2?}", self.elapsed);
+            }
+            Some(memory) => memory,
+        };
+
+        return format!(
+            "{:.2?}, {} peak in {} allocations",
+            self.elapsed,
+            format_bytes(memory.peak_bytes),
+            memory.num_of_allocations
+        );
+    }
+}
+
+fn get_panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        return message.to_string();
+    }
+
+    if let Some(message) = payload.downcast_ref::<String>() {
+        return message.clone();
+    }
+
+    return "unknown cause".to_string();
+}
+
+// a panicking part is caught, so the parts after it still run
+pub fn run_part(solve: Part, input: &str) -> (Outcome, Duration, MemoryUsage) {
+    let tracker = MemoryTracker::start();
+    let start = Instant::now();
+    let result = panic::catch_unwind(|| solve(input));
+    let elapsed = start.elapsed();
+    let memory = tracker.stop();
+
+    let outcome = match result {
+        Ok(Ok(answer)) => Outcome::Answer(answer),
+        Ok(Err(err)) => Outcome::Error(err),
+        Err(payload) => Outcome::Panicked(get_panic_message(payload.as_ref())),
+    };
+
+    return (outcome, elapsed, memory);
+}
+
+// run every part of the puzzle with the given input, or only the given part
+pub fn run_solver(
+    solver: &Solver,
+    input: &str,
+    part: Option<usize>,
+) -> Result<Vec<PartReport>, String> {
+    let parts = match part {
+        None => solver.parts(),
+        Some(part) => {
+            let solve = solver
+                .part(part)
+                .ok_or_else(|| format!("{} has no part {}", solver.name(), part))?;
+            vec![(part, solve)]
+        }
+    };
+
+    let reports = parts
+        .into_iter()
+        .map(|(part, solve)| {
+            debug!(year = solver.year, day = solver.day, part, "running");
+            let (outcome, elapsed, memory) = run_part(solve, input);
+
+            return PartReport {
+                year: solver.year,
+                day: solver.day,
+                part,
+                outcome,
+                elapsed,
+                memory: Some(memory),
+            };
+        })
+        .collect();
+
+    return Ok(reports);
+}
+
+// every part of every puzzle, each with the input of its puzzle, in the order of the puzzles.
+// with more than one job, the parts run alongside each other on a thread pool of that many threads,
+// and their memory isn't reported.
+pub fn run_all(puzzles: &[(&Solver, String)], jobs: usize) -> Result<Vec<PartReport>, String> {
+    if jobs == 1 {
+        let reports = puzzles
+            .iter()
+            .flat_map(|(solver, input)| run_solver(solver, input, None).unwrap())
+            .collect();
+
+        return Ok(reports);
+    }
+
+    let parts: Vec<(&Solver, &str, usize, Part)> = puzzles
+        .iter()
+        .flat_map(|(solver, input)| {
+            return solver
+                .parts()
+                .into_iter()
+                .map(move |(part, solve)| (*solver, input.as_str(), part, solve));
+        })
+        .collect();
+
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(jobs)
+        .build()
+        .map_err(|err| format!("Unable to start {} jobs: {}", jobs, err))?;
+
+    // collecting a parallel iterator keeps the order of the parts, whichever finishes first
+    let reports = pool.install(|| {
+        return parts
+            .par_iter()
+            .map(|(solver, input, part, solve)| {
+                debug!(year = solver.year, day = solver.day, part, "running");
+                let (outcome, elapsed, _memory) = run_part(*solve, input);
+
+                return PartReport {
+                    year: solver.year,
+                    day: solver.day,
+                    part: *part,
+                    outcome,
+                    elapsed,
+                    memory: None,
+                };
+            })
+            .collect();
+    });
+
+    return Ok(reports);
+}
+
+pub fn has_failures(reports: &[PartReport]) -> bool {
+    return reports.iter().any(|report| report.outcome.is_failure());
+}
+
+// the parts that took longer than the time budget
+pub fn get_over_budget(reports: &[PartReport], time_budget: Duration) -> Vec<&PartReport> {
+    return reports
+        .iter()
+        .filter(|report| report.elapsed > time_budget)
+        .collect();
+}
+
+pub fn get_summary_table(reports: &[PartReport]) -> String {
+    let answers: Vec<String> = reports
+        .iter()
+        .map(|report| report.outcome.to_string())
+        .collect();
+
+    let width = answers
+        .iter()
+        .map(|answer| answer.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max("answer".len());
+
+    let mut lines = vec![format!(
+        "{:<6}{:>4}{:>6}  {:<width$}{:>12}{:>14}{:>13}",
+        "year",
+        "day",
+        "part",
+        "answer",
+        "time",
+        "peak memory",
+        "allocations",
+        width = width
+    )];
+
+    // the memory columns of a part that ran alongside others are left blank
+    let get_memory_columns = |memory: Option<MemoryUsage>| -> (String, String) {
+        return match memory {
+            None => ("-".to_string(), "-".to_string()),
+            Some(memory) => (
+                format_bytes(memory.peak_bytes),
+                memory.num_of_allocations.to_string(),
+            ),
+        };
+    };
+
+    for (report, answer) in reports.iter().zip(answers.iter()) {
+        let (peak_memory, num_of_allocations) = get_memory_columns(report.memory);
+
+        lines.push(format!(
+            "{:<6}{:>4}{:>6}  {:<width$}{:>12}{:>14}{:>13}",
+            report.year,
+            report.day,
+            report.part,
+            answer,
+            format!("{:.2?}", report.elapsed),
+            peak_memory,
+            num_of_allocations,
+            width = width
+        ));
+    }
+
+    let total: Duration = reports.iter().map(|report| report.elapsed).sum();
+    let num_of_failures = reports
+        .iter()
+        .filter(|report| report.outcome.is_failure())
+        .count();
+    // the parts ran one after another, so the peak of the run is the highest peak of a part
+    let memory: Option<Vec<MemoryUsage>> = reports.iter().map(|report| report.memory).collect();
+    let total_memory = memory.map(|memory| MemoryUsage {
+        peak_bytes: memory
+            .iter()
+            .map(|memory| memory.peak_bytes)
+            .max()
+            .unwrap_or(0),
+        num_of_allocations: memory.iter().map(|memory| memory.num_of_allocations).sum(),
+    });
+    let (peak_memory, num_of_allocations) = get_memory_columns(total_memory);
+
+    lines.push(format!(
+        "{:<6}{:>4}{:>6}  {:<width$}{:>12}{:>14}{:>13}",
+        "total",
+        "",
+        reports.len(),
+        format!("{} failed", num_of_failures),
+        format!("{:.2?}", total),
+        peak_memory,
+        num_of_allocations,
+        width = width
+    ));
+
+    return lines.join("\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOLVER: Solver = Solver {
+        year: 2018,
+        day: 1,
+        input: "1 2 3",
+        part_1: |input_string| Ok(input_string.split_whitespace().count().to_string()),
+        part_2: Some(|_input_string| panic!("not solved yet")),
+        examples: &[],
+    };
+
+    #[test]
+    fn test_run_part() {
+        let (outcome, _elapsed, _memory) = run_part(SOLVER.part_1, SOLVER.input);
+        assert_eq!(outcome, Outcome::Answer("3".to_string()));
+
+        let (outcome, _elapsed, _memory) = run_part(|_| Err("No answer".to_string()), "");
+        assert_eq!(outcome, Outcome::Error("No answer".to_string()));
+        assert!(outcome.is_failure());
+
+        let (outcome, _elapsed, _memory) = run_part(SOLVER.part_2.unwrap(), SOLVER.input);
+        assert_eq!(outcome, Outcome::Panicked("not solved yet".to_string()));
+        assert!(outcome.is_failure());
+
+        let (outcome, _elapsed, _memory) =
+            run_part(|input_string| panic!("{} failed", input_string), "1");
+        assert_eq!(outcome, Outcome::Panicked("1 failed".to_string()));
+    }
+
+    #[test]
+    fn test_run_solver() {
+        let reports = run_solver(&SOLVER, SOLVER.input, None).unwrap();
+        assert_eq!(reports.len(), 2);
+        assert_eq!(reports[0].part, 1);
+        assert_eq!(reports[1].part, 2);
+        assert!(has_failures(&reports));
+
+        let reports = run_solver(&SOLVER, SOLVER.input, Some(1)).unwrap();
+        assert_eq!(reports.len(), 1);
+        assert!(!has_failures(&reports));
+
+        assert!(run_solver(&SOLVER, SOLVER.input, Some(3)).is_err());
+
+        // the parts after a panicking part still run
+        let puzzles = vec![
+            (&SOLVER, "1 2".to_string()),
+            (&SOLVER, SOLVER.input.to_string()),
+        ];
+        let reports = run_all(&puzzles, 1).unwrap();
+        assert_eq!(reports.len(), 4);
+        assert_eq!(reports[0].outcome, Outcome::Answer("2".to_string()));
+        assert_eq!(reports[2].outcome, Outcome::Answer("3".to_string()));
+        assert!(reports[0].memory.is_some());
+
+        // parts that run alongside each other are reported in the same order, without their memory
+        let parallel_reports = run_all(&puzzles, 4).unwrap();
+        assert_eq!(parallel_reports.len(), 4);
+        for (report, parallel_report) in reports.iter().zip(parallel_reports.iter()) {
+            assert_eq!(
+                (report.day, report.part, &report.outcome),
+                (
+                    parallel_report.day,
+                    parallel_report.part,
+                    &parallel_report.outcome
+                )
+            );
+            assert_eq!(parallel_report.memory, None);
+        }
+    }
+
+    #[test]
+    fn test_summary_table() {
+        let report = |part: usize, outcome: Outcome, millis: u64, peak_bytes: usize| PartReport {
+            year: 2018,
+            day: 1,
+            part,
+            outcome,
+            elapsed: Duration::from_millis(millis),
+            memory: Some(MemoryUsage {
+                peak_bytes,
+                num_of_allocations: 10,
+            }),
+        };
+        let reports = vec![
+            report(1, Outcome::Answer("513".to_string()), 1, 2048),
+            report(2, Outcome::Panicked("oops".to_string()), 20, 512),
+        ];
+
+        assert_eq!(
+            reports[0].get_usage(),
+            "1.00ms, 2.00 KiB peak in 10 allocations"
+        );
+
+        let over_budget = get_over_budget(&reports, Duration::from_millis(10));
+        assert_eq!(over_budget.len(), 1);
+        assert_eq!(over_budget[0].part, 2);
+        assert!(get_over_budget(&reports, Duration::from_millis(20)).is_empty());
+
+        let table = get_summary_table(&reports);
+        let lines: Vec<&str> = table.lines().collect();
+
+        assert_eq!(
+            lines,
+            vec![
+                "year   day  part  answer                time   peak memory  allocations",
+                "2018     1     1  513                 1.00ms      2.00 KiB           10",
+                "2018     1     2  panicked: oops     20.00ms         512 B           10",
+                "total          2  1 failed           21.00ms      2.00 KiB           20",
+            ]
+        );
+
+        // parts that ran alongside each other have no memory to report
+        let reports: Vec<PartReport> = reports
+            .into_iter()
+            .map(|report| PartReport {
+                memory: None,
+                ..report
+            })
+            .collect();
+
+        assert_eq!(reports[0].get_usage(), "1.00ms");
+
+        let table = get_summary_table(&reports);
+        let lines: Vec<&str> = table.lines().collect();
+
+        assert_eq!(
+            lines,
+            vec![
+                "year   day  part  answer                time   peak memory  allocations",
+                "2018     1     1  513                 1.00ms             -            -",
+                "2018     1     2  panicked: oops     20.00ms             -            -",
+                "total          2  1 failed           21.00ms             -            -",
+            ]
+        );
+    }
+}
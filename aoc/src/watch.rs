@@ -0,0 +1,392 @@
+// re-running a puzzle whenever its source, the source of the shared crates it uses, or its input changes.
+//
+// the puzzles are compiled into the runner, so a change to the source needs a new build;
+// every run is a cargo run of the runner, which rebuilds whatever changed first.
+// there's no file watcher among the dependencies, so the files are polled for changes.
+
+// imports
+
+use crate::inputs;
+use solver::Solver;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use std::process::Command;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+// code
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+// the last time every file was modified, in order of path
+type Snapshot = Vec<(PathBuf, SystemTime)>;
+
+fn get_workspace_dir() -> PathBuf {
+    return Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .to_path_buf();
+}
+
+// the package of a puzzle is at <year>/<name>, such as 2018/day-15-2018
+pub fn get_package_dir(workspace_dir: &Path, solver: &Solver) -> PathBuf {
+    return workspace_dir
+        .join(solver.year.to_string())
+        .join(solver.name());
+}
+
+// the paths of the dependencies of a package that are in the workspace, such as ../../shared/solver.
+// only the [dependencies] tables of the manifest are read, since those are what a run builds.
+pub fn get_path_dependencies(manifest: &str) -> Vec<String> {
+    let mut paths = vec![];
+    let mut is_dependencies = false;
+
+    for line in manifest.lines() {
+        let line = line.trim();
+
+        if line.starts_with('[') {
+            is_dependencies = line == "[dependencies]";
+            continue;
+        }
+
+        if !is_dependencies {
+            continue;
+        }
+
+        // such as: solver = { path = "../../shared/solver" }
+        let path = line
+            .split(['{', ',', '}'])
+            .filter_map(|field| {
+                let (key, value) = field.split_once('=')?;
+                if key.trim() != "path" {
+                    return None;
+                }
+                return Some(value.trim().trim_matches('"').to_string());
+            })
+            .next();
+
+        if let Some(path) = path {
+            paths.push(path);
+        }
+    }
+
+    return paths;
+}
+
+// a path with its . and .. components resolved, without touching the file system
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            _ => {
+                normalized.push(component);
+            }
+        }
+    }
+
+    return normalized;
+}
+
+// the manifest and source of a package, followed by those of every package in the workspace it depends on,
+// such as the shared crates. a change to any of them is rebuilt by the next run.
+fn add_package_paths(package_dir: &Path, paths: &mut Vec<PathBuf>) {
+    let manifest_path = package_dir.join("Cargo.toml");
+
+    if paths.contains(&manifest_path) {
+        return;
+    }
+
+    paths.push(manifest_path.clone());
+    paths.push(package_dir.join("src"));
+
+    let manifest = fs::read_to_string(&manifest_path).unwrap_or_default();
+
+    for dependency in get_path_dependencies(&manifest) {
+        add_package_paths(&normalize(&package_dir.join(dependency)), paths);
+    }
+}
+
+// the source of the puzzle and of the shared crates it depends on,
+// and the cached input the runner reads, which may not be fetched yet
+pub fn get_watched_paths(package_dir: &Path, inputs_dir: &Path, solver: &Solver) -> Vec<PathBuf> {
+    let mut paths = vec![];
+
+    add_package_paths(package_dir, &mut paths);
+    paths.push(inputs::get_input_path(inputs_dir, solver.year, solver.day));
+
+    return paths;
+}
+
+fn add_to_snapshot(path: &Path, snapshot: &mut Snapshot) {
+    let metadata = match fs::metadata(path) {
+        // a path that doesn't exist yet is left out, so it's a change once it does
+        Err(_) => {
+            return;
+        }
+        Ok(metadata) => metadata,
+    };
+
+    if metadata.is_dir() {
+        if let Ok(entries) = fs::read_dir(path) {
+            for entry in entries.flatten() {
+                add_to_snapshot(&entry.path(), snapshot);
+            }
+        }
+        return;
+    }
+
+    if let Ok(modified) = metadata.modified() {
+        snapshot.push((path.to_path_buf(), modified));
+    }
+}
+
+// every file under the given paths; a file that is added, removed, or modified changes the snapshot
+pub fn take_snapshot(paths: &[PathBuf]) -> Snapshot {
+    let mut snapshot = vec![];
+
+    for path in paths {
+        add_to_snapshot(path, &mut snapshot);
+    }
+
+    snapshot.sort();
+
+    return snapshot;
+}
+
+// the time of day in UTC, such as 09:41:07
+pub fn format_timestamp(time: SystemTime) -> String {
+    let seconds = time
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0);
+
+    return format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600 % 24,
+        seconds / 60 % 60,
+        seconds % 60
+    );
+}
+
+// build the runner and run the puzzle with it, as: aoc [-v|-vv] run [--bundled] <year> <day> [<part>]
+fn run(
+    workspace_dir: &Path,
+    solver: &Solver,
+    part: Option<usize>,
+    bundled: bool,
+    verbosity: usize,
+) -> Result<(), String> {
+    let mut command = Command::new(std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()));
+
+    command
+        .args(["run", "--release", "--quiet", "--package", "aoc"])
+        .arg("--manifest-path")
+        .arg(workspace_dir.join("Cargo.toml"))
+        .arg("--");
+
+    if verbosity > 0 {
+        command.arg(format!("-{}", "v".repeat(verbosity)));
+    }
+
+    command.arg("run");
+
+    if bundled {
+        command.arg("--bundled");
+    }
+
+    command
+        .arg(solver.year.to_string())
+        .arg(solver.day.to_string());
+
+    if let Some(part) = part {
+        command.arg(part.to_string());
+    }
+
+    let status = command
+        .status()
+        .map_err(|err| format!("Unable to run cargo: {}", err))?;
+
+    if !status.success() {
+        return Err(format!("The run failed with {}", status));
+    }
+
+    return Ok(());
+}
+
+// run the puzzle, then run it again after every change, until the runner is stopped
+pub fn watch(
+    solver: &Solver,
+    part: Option<usize>,
+    bundled: bool,
+    verbosity: usize,
+) -> Result<(), String> {
+    let workspace_dir = get_workspace_dir();
+    let package_dir = get_package_dir(&workspace_dir, solver);
+
+    if !package_dir.is_dir() {
+        return Err(format!(
+            "No package found for {} at {}",
+            solver.name(),
+            package_dir.display()
+        ));
+    }
+
+    let paths = get_watched_paths(&package_dir, Path::new(inputs::INPUTS_DIR), solver);
+
+    println!(
+        "Watching {} for changes; stop with Ctrl-C",
+        paths
+            .iter()
+            .map(|path| path.display().to_string())
+            .collect::<Vec<String>>()
+            .join(", ")
+    );
+
+    let mut snapshot = take_snapshot(&paths);
+
+    loop {
+        println!(
+            "[{}] {} day {}",
+            format_timestamp(SystemTime::now()),
+            solver.year,
+            solver.day
+        );
+
+        let start = Instant::now();
+        let result = run(&workspace_dir, solver, part, bundled, verbosity);
+        let elapsed = start.elapsed();
+
+        match result {
+            Ok(()) => println!(
+                "[{}] done in {:.2?}",
+                format_timestamp(SystemTime::now()),
+                elapsed
+            ),
+            Err(err) => println!("[{}] {}", format_timestamp(SystemTime::now()), err),
+        }
+
+        loop {
+            thread::sleep(POLL_INTERVAL);
+
+            let next_snapshot = take_snapshot(&paths);
+
+            if next_snapshot != snapshot {
+                snapshot = next_snapshot;
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOLVER: Solver = Solver {
+        year: 2018,
+        day: 15,
+        input: "",
+        part_1: |input_string| Ok(input_string.to_string()),
+        part_2: None,
+        examples: &[],
+    };
+
+    #[test]
+    fn test_get_watched_paths() {
+        let package_dir = get_package_dir(Path::new("/aoc"), &SOLVER);
+        assert_eq!(package_dir, Path::new("/aoc/2018/day-15-2018"));
+
+        // without a manifest, there are no dependencies to follow
+        assert_eq!(
+            get_watched_paths(&package_dir, Path::new("inputs"), &SOLVER),
+            vec![
+                Path::new("/aoc/2018/day-15-2018/Cargo.toml"),
+                Path::new("/aoc/2018/day-15-2018/src"),
+                Path::new("inputs/2018/15.txt"),
+            ]
+        );
+
+        // the package of every puzzle is found
+        let workspace_dir = get_workspace_dir();
+        assert!(get_package_dir(&workspace_dir, &SOLVER).is_dir());
+
+        // along with the shared crates it depends on
+        let paths = get_watched_paths(
+            &get_package_dir(&workspace_dir, &SOLVER),
+            Path::new("inputs"),
+            &SOLVER,
+        );
+        assert!(paths.contains(&workspace_dir.join("shared/solver/src")));
+        assert!(paths.contains(&workspace_dir.join("shared/solver/Cargo.toml")));
+        assert_eq!(paths.last().unwrap(), Path::new("inputs/2018/15.txt"));
+    }
+
+    #[test]
+    fn test_get_path_dependencies() {
+        let manifest = r###"
+[package]
+name = "day-03-2019"
+
+[dependencies]
+geometry = { path = "../../shared/geometry" }
+rayon = "1.3.0"
+solver = { version = "0.1.0", path = "../../shared/solver" }
+
+[dev-dependencies]
+proptest = { path = "../../vendor/proptest" }
+        "###;
+
+        assert_eq!(
+            get_path_dependencies(manifest),
+            vec!["../../shared/geometry", "../../shared/solver"]
+        );
+        assert!(get_path_dependencies("").is_empty());
+
+        assert_eq!(
+            normalize(Path::new("/aoc/2019/day-03-2019/../../shared/./geometry")),
+            Path::new("/aoc/shared/geometry")
+        );
+    }
+
+    #[test]
+    fn test_take_snapshot() {
+        let dir = std::env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("src")).unwrap();
+
+        let input_path = dir.join("input.txt");
+        let paths = vec![dir.join("src"), input_path.clone()];
+
+        fs::write(dir.join("src").join("lib.rs"), "// day 15").unwrap();
+        let snapshot = take_snapshot(&paths);
+        assert_eq!(snapshot.len(), 1);
+        assert_eq!(take_snapshot(&paths), snapshot);
+
+        // a new file is a change
+        fs::write(dir.join("src").join("main.rs"), "fn main() {}").unwrap();
+        let next_snapshot = take_snapshot(&paths);
+        assert_eq!(next_snapshot.len(), 2);
+        assert_ne!(next_snapshot, snapshot);
+
+        // so is a file that is fetched
+        fs::write(&input_path, "#######").unwrap();
+        assert_eq!(take_snapshot(&paths).len(), 3);
+
+        fs::remove_dir_all(&dir).unwrap();
+        assert!(take_snapshot(&paths).is_empty());
+    }
+
+    #[test]
+    fn test_format_timestamp() {
+        assert_eq!(format_timestamp(UNIX_EPOCH), "00:00:00");
+        assert_eq!(
+            format_timestamp(UNIX_EPOCH + Duration::from_secs(2 * 86400 + 9 * 3600 + 41 * 60 + 7)),
+            "09:41:07"
+        );
+    }
+}
@@ -0,0 +1,131 @@
+// detecting when a simulation starts repeating itself.
+// shared by the puzzles that ask for the state after far more steps than can be simulated one by one.
+
+// imports
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// code
+
+// a sequence of states that repeats forever once reached
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cycle {
+    // the step at which the first state of the cycle is reached
+    pub start: usize,
+    // number of steps after which the states repeat
+    pub length: usize,
+}
+
+impl Cycle {
+    // the states stop changing at the start of the cycle
+    pub fn is_fixed_point(&self) -> bool {
+        return self.length == 1;
+    }
+
+    // the number of times the cycle is gone through by a step, and the step within the first pass through the cycle
+    // it lines up with. steps before the start of the cycle line up with themselves.
+    pub fn fast_forward(&self, step: usize) -> (usize, usize) {
+        if step < self.start {
+            return (0, step);
+        }
+
+        let passes = (step - self.start) / self.length;
+        let offset = (step - self.start) % self.length;
+
+        return (passes, self.start + offset);
+    }
+}
+
+// step through states until the key of a state is seen again, or for at most max_steps.
+// the key is what two states are compared by; it may leave out what's allowed to change between passes
+// through the cycle, such as where a pattern is.
+//
+// returns the cycle if found, and every state that was stepped through, starting with the initial state.
+// when there's a cycle, the last state is the repeat of the first state of the cycle.
+pub fn find_cycle<T, K: Hash + Eq>(
+    initial: T,
+    step: impl Fn(&T) -> T,
+    key: impl Fn(&T) -> K,
+    max_steps: usize,
+) -> (Option<Cycle>, Vec<T>) {
+    // the step at which the state with a key is seen
+    let mut seen: HashMap<K, usize> = HashMap::new();
+    seen.insert(key(&initial), 0);
+
+    let mut states: Vec<T> = vec![initial];
+
+    for current_step in 1..=max_steps {
+        let next = step(states.last().unwrap());
+        let next_key = key(&next);
+        states.push(next);
+
+        if let Some(start) = seen.get(&next_key) {
+            let cycle = Cycle {
+                start: *start,
+                length: current_step - start,
+            };
+            return (Some(cycle), states);
+        }
+
+        seen.insert(next_key, current_step);
+    }
+
+    return (None, states);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_cycle() {
+        // 1, 2, 4, 8, 16, 11, 1, ... modulo 21
+        let (cycle, states) = find_cycle(1, |x| x * 2 % 21, |x| *x, 100);
+        assert_eq!(
+            cycle,
+            Some(Cycle {
+                start: 0,
+                length: 6
+            })
+        );
+        assert_eq!(states, vec![1, 2, 4, 8, 16, 11, 1]);
+
+        // 7, 2, 4, 8, 4, ... modulo 12, after a lead-in
+        let (cycle, states) = find_cycle(7, |x| x * 2 % 12, |x| *x, 100);
+        assert_eq!(
+            cycle,
+            Some(Cycle {
+                start: 2,
+                length: 2
+            })
+        );
+        assert_eq!(states, vec![7, 2, 4, 8, 4]);
+
+        let (cycle, states) = find_cycle(0, |x| x + 1, |x| *x, 10);
+        assert_eq!(cycle, None);
+        assert_eq!(states.len(), 11);
+        assert_eq!(find_cycle(0, |x| x + 1, |x| *x, 0).1, vec![0]);
+
+        // the key leaves out how far the pattern moved
+        let (cycle, states) = find_cycle((0, 5), |(x, y)| (x + 3, *y), |(_x, y)| *y, 100);
+        let cycle = cycle.unwrap();
+        assert!(cycle.is_fixed_point());
+        assert_eq!(states, vec![(0, 5), (3, 5)]);
+    }
+
+    #[test]
+    fn test_fast_forward() {
+        let cycle = Cycle {
+            start: 2,
+            length: 3,
+        };
+
+        assert_eq!(cycle.fast_forward(0), (0, 0));
+        assert_eq!(cycle.fast_forward(1), (0, 1));
+        assert_eq!(cycle.fast_forward(2), (0, 2));
+        assert_eq!(cycle.fast_forward(4), (0, 4));
+        assert_eq!(cycle.fast_forward(5), (1, 2));
+        assert_eq!(cycle.fast_forward(12), (3, 3));
+    }
+}
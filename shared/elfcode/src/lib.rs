@@ -0,0 +1,484 @@
+// the wrist device's instruction set, as reverse engineered in 2018 day 16.
+// shared by the puzzles that run programs on the device's registers, such as 2018 days 16, 19 and 21.
+
+// code
+
+pub type Value = i64;
+
+// every instruction reads its inputs A and B either as the value of a register, or as the value itself,
+// and writes its output to register C
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum Opcode {
+    Addr,
+    Addi,
+    Mulr,
+    Muli,
+    Banr,
+    Bani,
+    Borr,
+    Bori,
+    Setr,
+    Seti,
+    Gtir,
+    Gtri,
+    Gtrr,
+    Eqir,
+    Eqri,
+    Eqrr,
+}
+
+pub const ALL_OPCODES: [Opcode; 16] = [
+    Opcode::Addr,
+    Opcode::Addi,
+    Opcode::Mulr,
+    Opcode::Muli,
+    Opcode::Banr,
+    Opcode::Bani,
+    Opcode::Borr,
+    Opcode::Bori,
+    Opcode::Setr,
+    Opcode::Seti,
+    Opcode::Gtir,
+    Opcode::Gtri,
+    Opcode::Gtrr,
+    Opcode::Eqir,
+    Opcode::Eqri,
+    Opcode::Eqrr,
+];
+
+impl Opcode {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Opcode::Addr => "addr",
+            Opcode::Addi => "addi",
+            Opcode::Mulr => "mulr",
+            Opcode::Muli => "muli",
+            Opcode::Banr => "banr",
+            Opcode::Bani => "bani",
+            Opcode::Borr => "borr",
+            Opcode::Bori => "bori",
+            Opcode::Setr => "setr",
+            Opcode::Seti => "seti",
+            Opcode::Gtir => "gtir",
+            Opcode::Gtri => "gtri",
+            Opcode::Gtrr => "gtrr",
+            Opcode::Eqir => "eqir",
+            Opcode::Eqri => "eqri",
+            Opcode::Eqrr => "eqrr",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Opcode> {
+        return ALL_OPCODES
+            .iter()
+            .find(|opcode| opcode.name() == name)
+            .cloned();
+    }
+
+    // whether input A is read from a register
+    pub fn reads_register_a(&self) -> bool {
+        return !matches!(self, Opcode::Seti | Opcode::Gtir | Opcode::Eqir);
+    }
+
+    // whether input B is read from a register
+    pub fn reads_register_b(&self) -> bool {
+        return matches!(
+            self,
+            Opcode::Addr
+                | Opcode::Mulr
+                | Opcode::Banr
+                | Opcode::Borr
+                | Opcode::Gtir
+                | Opcode::Gtrr
+                | Opcode::Eqir
+                | Opcode::Eqrr
+        );
+    }
+
+    // setr and seti ignore input B altogether
+    pub fn ignores_b(&self) -> bool {
+        return matches!(self, Opcode::Setr | Opcode::Seti);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Instruction {
+    pub opcode: Opcode,
+    pub a: Value,
+    pub b: Value,
+    pub c: Value,
+}
+
+fn get_register(registers: &[Value], register: Value) -> Result<Value, String> {
+    if register < 0 || register as usize >= registers.len() {
+        return Err(format!("Invalid register: {}", register));
+    }
+
+    return Ok(registers[register as usize]);
+}
+
+impl Instruction {
+    pub fn new(opcode: Opcode, a: Value, b: Value, c: Value) -> Instruction {
+        Instruction { opcode, a, b, c }
+    }
+
+    // opcode A B C
+    pub fn parse(input: &str) -> Result<Instruction, String> {
+        let invalid = || format!("Invalid instruction: {}", input);
+
+        let tokens: Vec<&str> = input.split_whitespace().collect();
+
+        match tokens.as_slice() {
+            [opcode, a, b, c] => {
+                let opcode = Opcode::from_name(opcode)
+                    .ok_or_else(|| format!("Unknown opcode: {}", opcode))?;
+
+                let parse_value = |x: &str| -> Result<Value, String> {
+                    return x.parse().map_err(|_| invalid());
+                };
+
+                return Ok(Instruction::new(
+                    opcode,
+                    parse_value(a)?,
+                    parse_value(b)?,
+                    parse_value(c)?,
+                ));
+            }
+            _ => {
+                return Err(invalid());
+            }
+        }
+    }
+
+    // run the instruction on the registers. the registers are left untouched
+    // if the instruction refers to a register that doesn't exist.
+    pub fn execute(&self, registers: &mut [Value]) -> Result<(), String> {
+        let value_a = if self.opcode.reads_register_a() {
+            get_register(registers, self.a)?
+        } else {
+            self.a
+        };
+
+        let value_b = if self.opcode.ignores_b() {
+            0
+        } else if self.opcode.reads_register_b() {
+            get_register(registers, self.b)?
+        } else {
+            self.b
+        };
+
+        // make sure the output register exists
+        get_register(registers, self.c)?;
+
+        let result = match self.opcode {
+            Opcode::Addr | Opcode::Addi => value_a + value_b,
+            Opcode::Mulr | Opcode::Muli => value_a * value_b,
+            Opcode::Banr | Opcode::Bani => value_a & value_b,
+            Opcode::Borr | Opcode::Bori => value_a | value_b,
+            Opcode::Setr | Opcode::Seti => value_a,
+            Opcode::Gtir | Opcode::Gtri | Opcode::Gtrr => (value_a > value_b) as Value,
+            Opcode::Eqir | Opcode::Eqri | Opcode::Eqrr => (value_a == value_b) as Value,
+        };
+
+        registers[self.c as usize] = result;
+
+        return Ok(());
+    }
+
+    pub fn to_string(&self) -> String {
+        return format!("{} {} {} {}", self.opcode.name(), self.a, self.b, self.c);
+    }
+}
+
+// the number of registers of the device once it runs programs with a bound instruction pointer
+pub const NUM_OF_REGISTERS: usize = 6;
+
+// a program, with the register that the instruction pointer is bound to, if any
+#[derive(Debug, Clone, PartialEq)]
+pub struct Program {
+    pub instruction_pointer_bound: Option<usize>,
+    pub instructions: Vec<Instruction>,
+}
+
+impl Program {
+    // #ip 4
+    // addi 4 16 4
+    // ...
+    pub fn parse(input_string: &str) -> Result<Program, String> {
+        let mut instruction_pointer_bound = None;
+        let mut instructions = vec![];
+
+        for line in input_string.trim().lines().map(|line| line.trim()) {
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(register) = line.strip_prefix("#ip") {
+                let register: usize = register
+                    .trim()
+                    .parse()
+                    .ok()
+                    .filter(|register| *register < NUM_OF_REGISTERS)
+                    .ok_or_else(|| format!("Invalid instruction pointer declaration: {}", line))?;
+
+                instruction_pointer_bound = Some(register);
+                continue;
+            }
+
+            instructions.push(Instruction::parse(line)?);
+        }
+
+        return Ok(Program {
+            instruction_pointer_bound,
+            instructions,
+        });
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Status {
+    Running,
+    // the instruction pointer points outside of the program
+    Halted,
+}
+
+// what to do once a hook has seen the machine, right before the next instruction
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Flow {
+    Continue,
+    Stop,
+}
+
+// a device running a program
+#[derive(Debug, Clone)]
+pub struct Machine {
+    pub program: Program,
+    pub instruction_pointer: Value,
+    pub registers: Vec<Value>,
+    // the number of instructions executed so far
+    pub num_of_steps: usize,
+}
+
+impl Machine {
+    // the instruction pointer and every register start at 0
+    pub fn new(program: Program) -> Machine {
+        Machine {
+            program,
+            instruction_pointer: 0,
+            registers: vec![0; NUM_OF_REGISTERS],
+            num_of_steps: 0,
+        }
+    }
+
+    // the instruction the instruction pointer points to
+    pub fn current_instruction(&self) -> Option<&Instruction> {
+        if self.instruction_pointer < 0 {
+            return None;
+        }
+
+        return self
+            .program
+            .instructions
+            .get(self.instruction_pointer as usize);
+    }
+
+    // execute the instruction the instruction pointer points to.
+    //
+    // the value of the instruction pointer is written to the bound register right before the instruction is executed,
+    // and the value of the bound register is written back to the instruction pointer right after.
+    // the instruction pointer then moves on to the next instruction.
+    pub fn step(&mut self) -> Result<Status, String> {
+        let instruction = match self.current_instruction() {
+            None => {
+                return Ok(Status::Halted);
+            }
+            Some(instruction) => *instruction,
+        };
+
+        if let Some(bound) = self.program.instruction_pointer_bound {
+            self.registers[bound] = self.instruction_pointer;
+        }
+
+        instruction.execute(&mut self.registers)?;
+
+        if let Some(bound) = self.program.instruction_pointer_bound {
+            self.instruction_pointer = self.registers[bound];
+        }
+
+        self.instruction_pointer += 1;
+        self.num_of_steps += 1;
+
+        return Ok(Status::Running);
+    }
+
+    // run the program until it halts
+    pub fn run(&mut self) -> Result<Status, String> {
+        return self.run_with_hook(|_machine| Flow::Continue);
+    }
+
+    // run the program until it halts, or until the hook stops it.
+    //
+    // the hook sees the machine right before every instruction. it may change the registers and the instruction pointer,
+    // for instance to skip over a loop whose outcome is known, and the instruction that's executed next is the one
+    // the instruction pointer then points to.
+    pub fn run_with_hook(
+        &mut self,
+        mut hook: impl FnMut(&mut Machine) -> Flow,
+    ) -> Result<Status, String> {
+        loop {
+            if hook(self) == Flow::Stop {
+                return Ok(Status::Running);
+            }
+
+            if self.step()? == Status::Halted {
+                return Ok(Status::Halted);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_opcode_names() {
+        for opcode in ALL_OPCODES.iter() {
+            assert_eq!(Opcode::from_name(opcode.name()), Some(*opcode));
+        }
+
+        assert_eq!(Opcode::from_name("jmp"), None);
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            Instruction::parse("addi 3 16 3"),
+            Ok(Instruction::new(Opcode::Addi, 3, 16, 3))
+        );
+        assert_eq!(
+            Instruction::parse("seti 5 0 1").unwrap().to_string(),
+            "seti 5 0 1"
+        );
+
+        assert!(Instruction::parse("nope 1 2 3").is_err());
+        assert!(Instruction::parse("addi 1 2").is_err());
+        assert!(Instruction::parse("addi 1 x 3").is_err());
+    }
+
+    #[test]
+    fn test_execute() {
+        let execute =
+            |opcode: Opcode, a: Value, b: Value, c: Value| -> Result<Vec<Value>, String> {
+                let mut registers = vec![3, 2, 1, 1];
+                Instruction::new(opcode, a, b, c).execute(&mut registers)?;
+                return Ok(registers);
+            };
+
+        // the example sample from the puzzle: before [3, 2, 1, 1], 9 2 1 2, after [3, 2, 2, 1]
+        assert_eq!(execute(Opcode::Mulr, 2, 1, 2), Ok(vec![3, 2, 2, 1]));
+        assert_eq!(execute(Opcode::Addi, 2, 1, 2), Ok(vec![3, 2, 2, 1]));
+        assert_eq!(execute(Opcode::Seti, 2, 1, 2), Ok(vec![3, 2, 2, 1]));
+
+        assert_eq!(execute(Opcode::Addr, 0, 1, 3), Ok(vec![3, 2, 1, 5]));
+        assert_eq!(execute(Opcode::Muli, 0, 7, 3), Ok(vec![3, 2, 1, 21]));
+        assert_eq!(execute(Opcode::Banr, 0, 1, 3), Ok(vec![3, 2, 1, 2]));
+        assert_eq!(execute(Opcode::Bani, 0, 6, 3), Ok(vec![3, 2, 1, 2]));
+        assert_eq!(execute(Opcode::Borr, 0, 1, 3), Ok(vec![3, 2, 1, 3]));
+        assert_eq!(execute(Opcode::Bori, 1, 4, 3), Ok(vec![3, 2, 1, 6]));
+        assert_eq!(execute(Opcode::Setr, 0, 99, 3), Ok(vec![3, 2, 1, 3]));
+        assert_eq!(execute(Opcode::Gtir, 3, 1, 3), Ok(vec![3, 2, 1, 1]));
+        assert_eq!(execute(Opcode::Gtri, 1, 2, 3), Ok(vec![3, 2, 1, 0]));
+        assert_eq!(execute(Opcode::Gtrr, 0, 1, 3), Ok(vec![3, 2, 1, 1]));
+        assert_eq!(execute(Opcode::Eqir, 2, 1, 3), Ok(vec![3, 2, 1, 1]));
+        assert_eq!(execute(Opcode::Eqri, 0, 2, 3), Ok(vec![3, 2, 1, 0]));
+        assert_eq!(execute(Opcode::Eqrr, 2, 3, 0), Ok(vec![1, 2, 1, 1]));
+
+        // registers that don't exist
+        assert!(execute(Opcode::Addr, 4, 0, 0).is_err());
+        assert!(execute(Opcode::Addi, 0, 0, 4).is_err());
+        assert!(execute(Opcode::Gtir, 4, 4, 0).is_err());
+        assert!(execute(Opcode::Seti, 4, 4, 0).is_ok());
+        assert!(execute(Opcode::Setr, -1, 0, 0).is_err());
+    }
+
+    #[test]
+    fn test_parse_program() {
+        let program = Program::parse("#ip 0\nseti 5 0 1\nseti 6 0 2\n").unwrap();
+
+        assert_eq!(program.instruction_pointer_bound, Some(0));
+        assert_eq!(
+            program.instructions,
+            vec![
+                Instruction::new(Opcode::Seti, 5, 0, 1),
+                Instruction::new(Opcode::Seti, 6, 0, 2),
+            ]
+        );
+
+        assert_eq!(
+            Program::parse("seti 5 0 1")
+                .unwrap()
+                .instruction_pointer_bound,
+            None
+        );
+        assert!(Program::parse("#ip 6\nseti 5 0 1").is_err());
+        assert!(Program::parse("#ip x").is_err());
+    }
+
+    #[test]
+    fn test_machine() {
+        let program = Program::parse(
+            r###"
+#ip 0
+seti 5 0 1
+seti 6 0 2
+addi 0 1 0
+addr 1 2 3
+setr 1 0 0
+seti 8 0 4
+seti 9 0 5
+        "###,
+        )
+        .unwrap();
+
+        let mut machine = Machine::new(program.clone());
+
+        assert_eq!(machine.step(), Ok(Status::Running));
+        assert_eq!(machine.instruction_pointer, 1);
+        assert_eq!(machine.registers, vec![0, 5, 0, 0, 0, 0]);
+
+        // the example from the puzzle: the instruction pointer ends up at 7, past the end of the program
+        assert_eq!(machine.run(), Ok(Status::Halted));
+        assert_eq!(machine.instruction_pointer, 7);
+        assert_eq!(machine.registers, vec![6, 5, 6, 0, 0, 9]);
+        assert_eq!(machine.num_of_steps, 5);
+        assert_eq!(machine.current_instruction(), None);
+
+        // the hook stops the machine right before the first addi
+        let mut machine = Machine::new(program.clone());
+        let status = machine.run_with_hook(|machine| {
+            if machine
+                .current_instruction()
+                .map(|instruction| instruction.opcode)
+                == Some(Opcode::Addi)
+            {
+                return Flow::Stop;
+            }
+            return Flow::Continue;
+        });
+        assert_eq!(status, Ok(Status::Running));
+        assert_eq!(machine.instruction_pointer, 2);
+
+        // the hook skips straight to the second to last instruction
+        let mut machine = Machine::new(program);
+        machine
+            .run_with_hook(|machine| {
+                if machine.instruction_pointer == 0 {
+                    machine.instruction_pointer = 5;
+                }
+                return Flow::Continue;
+            })
+            .unwrap();
+        assert_eq!(machine.registers, vec![6, 0, 0, 0, 8, 9]);
+    }
+}
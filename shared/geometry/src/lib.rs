@@ -0,0 +1,325 @@
+// points and line segments on an integer grid.
+// shared by the puzzles that trace paths through a plane, such as wires, or that measure distances on one.
+
+// code
+
+pub type Coordinate = (i32, i32);
+
+// a line segment is defined by two coordinates
+pub type LineSegment = (Coordinate, Coordinate);
+
+pub type Distance = i32;
+
+// https://math.stackexchange.com/a/139604/10247
+pub fn get_manhattan_distance(start: Coordinate, end: Coordinate) -> Distance {
+    let (a, b) = start;
+    let (c, d) = end;
+
+    return (a - c).abs() + (b - d).abs();
+}
+
+// the point where two line segments at any angle cross, rounded to the nearest coordinate.
+// collinear segments have no single point in common, so they never intersect.
+// based on http://www.cs.swan.ac.uk/~cssimon/line_intersection.html
+pub fn line_segments_intersection(
+    first_segment: LineSegment,
+    second_segment: LineSegment,
+) -> Option<Coordinate> {
+    let (point_1, point_2) = first_segment;
+    let (point_3, point_4) = second_segment;
+
+    let (x_1, y_1) = point_1;
+    let (x_2, y_2) = point_2;
+    let (x_3, y_3) = point_3;
+    let (x_4, y_4) = point_4;
+
+    let parameter_1_numerator = (y_3 - y_4) * (x_1 - x_3) + (x_4 - x_3) * (y_1 - y_3);
+    let parameter_1_denominator = (x_4 - x_3) * (y_1 - y_2) - (x_1 - x_2) * (y_4 - y_3);
+
+    let parameter_2_numerator = (y_1 - y_2) * (x_1 - x_3) + (x_2 - x_1) * (y_1 - y_3);
+    let parameter_2_denominator = (x_4 - x_3) * (y_1 - y_2) - (x_1 - x_2) * (y_4 - y_3);
+
+    if parameter_1_denominator == 0 || parameter_2_denominator == 0 {
+        return None;
+    }
+
+    let parameter_1: f64 = parameter_1_numerator as f64 / parameter_1_denominator as f64;
+    let parameter_2: f64 = parameter_2_numerator as f64 / parameter_2_denominator as f64;
+
+    if (0.0 <= parameter_1 && parameter_1 <= 1.0) && (0.0 <= parameter_2 && parameter_2 <= 1.0) {
+        let x = x_1 as f64 + parameter_1 * (x_2 as f64 - x_1 as f64);
+        let y = y_1 as f64 + parameter_1 * (y_2 as f64 - y_1 as f64);
+
+        return Some((x.round() as i32, y.round() as i32));
+    }
+
+    return None;
+}
+
+// the smallest axis-aligned rectangle that contains a line segment
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundingBox {
+    pub min: Coordinate,
+    pub max: Coordinate,
+}
+
+impl BoundingBox {
+    pub fn from_line_segment(line_segment: LineSegment) -> BoundingBox {
+        let ((x_1, y_1), (x_2, y_2)) = line_segment;
+
+        return BoundingBox {
+            min: (x_1.min(x_2), y_1.min(y_2)),
+            max: (x_1.max(x_2), y_1.max(y_2)),
+        };
+    }
+
+    // the smallest box that contains every coordinate; there's none without any coordinates
+    pub fn from_coordinates(coordinates: &[Coordinate]) -> Option<BoundingBox> {
+        let (first, rest) = coordinates.split_first()?;
+
+        let bounding_box = rest.iter().fold(
+            BoundingBox {
+                min: *first,
+                max: *first,
+            },
+            |bounding_box, (x, y)| {
+                let (min_x, min_y) = bounding_box.min;
+                let (max_x, max_y) = bounding_box.max;
+
+                return BoundingBox {
+                    min: (min_x.min(*x), min_y.min(*y)),
+                    max: (max_x.max(*x), max_y.max(*y)),
+                };
+            },
+        );
+
+        return Some(bounding_box);
+    }
+
+    // coordinates on the edges are within the box
+    pub fn contains(&self, coordinate: Coordinate) -> bool {
+        let (x, y) = coordinate;
+        let (min_x, min_y) = self.min;
+        let (max_x, max_y) = self.max;
+
+        return (min_x..=max_x).contains(&x) && (min_y..=max_y).contains(&y);
+    }
+
+    pub fn is_on_edge(&self, coordinate: Coordinate) -> bool {
+        let (x, y) = coordinate;
+        let (min_x, min_y) = self.min;
+        let (max_x, max_y) = self.max;
+
+        return self.contains(coordinate) && (x == min_x || x == max_x || y == min_y || y == max_y);
+    }
+
+    // the box grown by a margin on every side
+    pub fn expand(&self, margin: i32) -> BoundingBox {
+        let (min_x, min_y) = self.min;
+        let (max_x, max_y) = self.max;
+
+        return BoundingBox {
+            min: (min_x - margin, min_y - margin),
+            max: (max_x + margin, max_y + margin),
+        };
+    }
+
+    // every coordinate within the box, row by row
+    pub fn coordinates(&self) -> impl Iterator<Item = Coordinate> {
+        let (min_x, min_y) = self.min;
+        let (max_x, max_y) = self.max;
+
+        return (min_y..=max_y).flat_map(move |y| (min_x..=max_x).map(move |x| (x, y)));
+    }
+
+    // boxes that only touch at their edges or corners overlap as well
+    pub fn overlaps(&self, other: &BoundingBox) -> bool {
+        let (min_x, min_y) = self.min;
+        let (max_x, max_y) = self.max;
+        let (other_min_x, other_min_y) = other.min;
+        let (other_max_x, other_max_y) = other.max;
+
+        return min_x <= other_max_x
+            && other_min_x <= max_x
+            && min_y <= other_max_y
+            && other_min_y <= max_y;
+    }
+}
+
+// visit every pair of a segment of the first list and a segment of the second list whose bounding boxes overlap,
+// by their indices.
+// segments can only meet if their bounding boxes overlap, so this is a cheap filter before any intersection math.
+// the boxes of the second list are sorted by their left edge, so for every box of the first list,
+// only the boxes that start before its right edge are tried.
+pub fn for_each_overlapping_pair(
+    first_segments: &[LineSegment],
+    second_segments: &[LineSegment],
+    visit: &mut impl FnMut(usize, usize),
+) {
+    let mut second_boxes: Vec<(BoundingBox, usize)> = second_segments
+        .iter()
+        .enumerate()
+        .map(|(index, segment)| (BoundingBox::from_line_segment(*segment), index))
+        .collect();
+    second_boxes.sort_by_key(|(bounding_box, _index)| bounding_box.min);
+
+    for (first_index, first_segment) in first_segments.iter().enumerate() {
+        let first_box = BoundingBox::from_line_segment(*first_segment);
+        let (max_x, _max_y) = first_box.max;
+
+        let num_of_candidates =
+            second_boxes.partition_point(|(bounding_box, _index)| bounding_box.min.0 <= max_x);
+
+        for (second_box, second_index) in second_boxes[..num_of_candidates].iter() {
+            if first_box.overlaps(second_box) {
+                visit(first_index, *second_index);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_segments_intersection() {
+        // intersection
+
+        let line_segment_1 = ((3, 2), (3, 10));
+        let line_segment_2 = ((0, 3), (10, 3));
+
+        assert_eq!(
+            line_segments_intersection(line_segment_1, line_segment_2),
+            Some((3, 3))
+        );
+
+        // diagonal intersection
+
+        let line_segment_1 = ((0, 0), (10, 10));
+        let line_segment_2 = ((0, 10), (10, 0));
+
+        assert_eq!(
+            line_segments_intersection(line_segment_1, line_segment_2),
+            Some((5, 5))
+        );
+
+        // the intersection is rounded, rather than truncated
+
+        let line_segment_1 = ((0, 0), (3, 2));
+        let line_segment_2 = ((0, 2), (3, 0));
+
+        assert_eq!(
+            line_segments_intersection(line_segment_1, line_segment_2),
+            Some((2, 1))
+        );
+
+        // no intersection
+
+        let line_segment_1 = ((3, 2), (3, 10));
+        let line_segment_2 = ((0, 30), (10, 30));
+
+        assert_eq!(
+            line_segments_intersection(line_segment_1, line_segment_2),
+            None
+        );
+
+        // collinear intersection y-axis
+
+        let line_segment_1 = ((3, 2), (3, 10));
+        let line_segment_2 = ((3, -10), (3, 20));
+
+        assert_eq!(
+            line_segments_intersection(line_segment_1, line_segment_2),
+            None
+        );
+
+        // collinear intersection x-axis
+
+        let line_segment_1 = ((-10, 3), (20, 3));
+        let line_segment_2 = ((0, 3), (10, 3));
+
+        assert_eq!(
+            line_segments_intersection(line_segment_1, line_segment_2),
+            None
+        );
+    }
+    #[test]
+    fn test_get_manhattan_distance() {
+        assert_eq!(get_manhattan_distance((0, 0), (0, 0)), 0);
+        assert_eq!(get_manhattan_distance((0, 0), (3, 3)), 6);
+        assert_eq!(get_manhattan_distance((-2, 5), (3, -1)), 11);
+    }
+
+    #[test]
+    fn test_bounding_box_of_coordinates() {
+        assert_eq!(BoundingBox::from_coordinates(&[]), None);
+
+        let bounding_box = BoundingBox::from_coordinates(&[(1, 6), (8, 3), (3, 4)]).unwrap();
+        assert_eq!(bounding_box.min, (1, 3));
+        assert_eq!(bounding_box.max, (8, 6));
+
+        assert!(bounding_box.contains((1, 3)));
+        assert!(bounding_box.contains((5, 5)));
+        assert!(!bounding_box.contains((9, 5)));
+
+        assert!(bounding_box.is_on_edge((1, 4)));
+        assert!(bounding_box.is_on_edge((5, 6)));
+        assert!(!bounding_box.is_on_edge((5, 5)));
+        assert!(!bounding_box.is_on_edge((0, 4)));
+
+        let bounding_box = bounding_box.expand(1);
+        assert_eq!((bounding_box.min, bounding_box.max), ((0, 2), (9, 7)));
+
+        let bounding_box = BoundingBox::from_coordinates(&[(0, 0), (1, 1)]).unwrap();
+        assert_eq!(
+            bounding_box.coordinates().collect::<Vec<Coordinate>>(),
+            vec![(0, 0), (1, 0), (0, 1), (1, 1)]
+        );
+    }
+
+    #[test]
+    fn test_bounding_box() {
+        let bounding_box = BoundingBox::from_line_segment(((5, 3), (-2, 3)));
+        assert_eq!(bounding_box.min, (-2, 3));
+        assert_eq!(bounding_box.max, (5, 3));
+
+        assert!(bounding_box.overlaps(&BoundingBox::from_line_segment(((0, 0), (0, 10)))));
+        // touching at a corner
+        assert!(bounding_box.overlaps(&BoundingBox::from_line_segment(((5, 3), (9, 3)))));
+        assert!(!bounding_box.overlaps(&BoundingBox::from_line_segment(((6, 3), (9, 3)))));
+        assert!(!bounding_box.overlaps(&BoundingBox::from_line_segment(((0, 4), (0, 10)))));
+    }
+
+    #[test]
+    fn test_for_each_overlapping_pair() {
+        let first_segments = vec![((0, 0), (10, 0)), ((10, 0), (10, 10)), ((20, 20), (30, 20))];
+        let second_segments = vec![
+            ((5, -5), (5, 5)),
+            ((-5, 10), (15, 10)),
+            ((12, -5), (12, 5)),
+            ((0, 0), (0, -5)),
+        ];
+
+        let mut pairs = vec![];
+        for_each_overlapping_pair(&first_segments, &second_segments, &mut |first, second| {
+            pairs.push((first, second));
+        });
+        pairs.sort();
+
+        assert_eq!(pairs, vec![(0, 0), (0, 3), (1, 1)]);
+
+        // the pairs that pass are exactly the pairs of overlapping boxes
+        let mut expected = vec![];
+        for (first, first_segment) in first_segments.iter().enumerate() {
+            for (second, second_segment) in second_segments.iter().enumerate() {
+                if BoundingBox::from_line_segment(*first_segment)
+                    .overlaps(&BoundingBox::from_line_segment(*second_segment))
+                {
+                    expected.push((first, second));
+                }
+            }
+        }
+        assert_eq!(pairs, expected);
+    }
+}
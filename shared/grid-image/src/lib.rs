@@ -0,0 +1,92 @@
+// render grids of cells into images, and animate them as GIFs.
+// shared by the puzzles that play out on a grid.
+
+// imports
+
+use image::codecs::gif::GifEncoder;
+use image::{Delay, Frame, Rgba};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::time::Duration;
+
+pub use image::RgbaImage;
+
+// code
+
+pub type Color = [u8; 3];
+
+// render a grid of width x height cells, where every cell is a square of tile_size pixels
+pub fn render_grid(
+    width: usize,
+    height: usize,
+    tile_size: u32,
+    color_of: impl Fn(usize, usize) -> Color,
+) -> RgbaImage {
+    assert!(tile_size > 0, "Tile size must be at least 1 pixel");
+
+    return RgbaImage::from_fn(
+        width as u32 * tile_size,
+        height as u32 * tile_size,
+        |x, y| {
+            let [red, green, blue] = color_of((x / tile_size) as usize, (y / tile_size) as usize);
+            return Rgba([red, green, blue, 255]);
+        },
+    );
+}
+
+// encode the frames as an animated GIF, waiting delay between frames.
+// frames are encoded as they're produced, so they don't all need to be kept around.
+pub fn encode_gif<W: Write>(
+    writer: W,
+    frames: impl IntoIterator<Item = RgbaImage>,
+    delay: Duration,
+) -> Result<(), String> {
+    let mut encoder = GifEncoder::new(writer);
+    let delay = Delay::from_saturating_duration(delay);
+
+    return encoder
+        .encode_frames(
+            frames
+                .into_iter()
+                .map(|frame| Frame::from_parts(frame, 0, 0, delay)),
+        )
+        .map_err(|err| format!("Unable to encode GIF: {}", err));
+}
+
+pub fn save_gif(
+    path: &Path,
+    frames: impl IntoIterator<Item = RgbaImage>,
+    delay: Duration,
+) -> Result<(), String> {
+    let file = File::create(path)
+        .map_err(|err| format!("Unable to create {}: {}", path.display(), err))?;
+
+    return encode_gif(BufWriter::new(file), frames, delay);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_grid() {
+        let image = render_grid(3, 2, 4, |x, y| [x as u8, y as u8, 7]);
+
+        assert_eq!(image.dimensions(), (12, 8));
+        assert_eq!(*image.get_pixel(0, 0), Rgba([0, 0, 7, 255]));
+        assert_eq!(*image.get_pixel(3, 3), Rgba([0, 0, 7, 255]));
+        assert_eq!(*image.get_pixel(4, 3), Rgba([1, 0, 7, 255]));
+        assert_eq!(*image.get_pixel(11, 7), Rgba([2, 1, 7, 255]));
+    }
+
+    #[test]
+    fn test_encode_gif() {
+        let frames = (0..3).map(|frame| render_grid(2, 2, 1, |_x, _y| [frame * 100, 0, 0]));
+
+        let mut gif: Vec<u8> = vec![];
+        encode_gif(&mut gif, frames, Duration::from_millis(100)).unwrap();
+
+        assert!(gif.starts_with(b"GIF89a"));
+    }
+}
@@ -0,0 +1,500 @@
+// the Intcode computer of the ship.
+// shared by the 2019 puzzles that run Intcode programs, starting with 2019 day 2.
+
+// imports
+
+use std::collections::VecDeque;
+
+// code
+
+pub type Value = i64;
+
+// the position of a value in memory
+pub type Address = usize;
+
+// 1,9,10,3,2,3,11,0,99,30,40,50
+pub fn parse_program(input_string: &str) -> Result<Vec<Value>, String> {
+    return input_string
+        .trim()
+        .split(',')
+        .map(|value| {
+            let value = value.trim();
+            return value
+                .parse()
+                .map_err(|_| format!("Invalid Intcode value: {}", value));
+        })
+        .collect();
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Opcode {
+    // adds together the values of the first two parameters,
+    // and stores the sum at the position of the third parameter
+    Add,
+    // like Add, but multiplies the values instead
+    Multiply,
+    // takes a value from the input, and stores it at the position of the only parameter
+    Input,
+    // sends the value of the only parameter to the output
+    Output,
+    // jumps to the value of the second parameter if the value of the first parameter is non-zero
+    JumpIfTrue,
+    // jumps to the value of the second parameter if the value of the first parameter is zero
+    JumpIfFalse,
+    // stores 1 at the position of the third parameter if the value of the first parameter
+    // is less than the value of the second parameter, and 0 otherwise
+    LessThan,
+    // like LessThan, but compares whether the values are equal
+    Equals,
+    // the program is finished
+    Halt,
+}
+
+impl Opcode {
+    // the opcode is the rightmost two digits of the first value of an instruction
+    pub fn from_value(value: Value) -> Option<Opcode> {
+        match value % 100 {
+            1 => Some(Opcode::Add),
+            2 => Some(Opcode::Multiply),
+            3 => Some(Opcode::Input),
+            4 => Some(Opcode::Output),
+            5 => Some(Opcode::JumpIfTrue),
+            6 => Some(Opcode::JumpIfFalse),
+            7 => Some(Opcode::LessThan),
+            8 => Some(Opcode::Equals),
+            99 => Some(Opcode::Halt),
+            _ => None,
+        }
+    }
+
+    // the number of parameters that follow the opcode
+    pub fn num_of_parameters(&self) -> usize {
+        match self {
+            Opcode::Add | Opcode::Multiply | Opcode::LessThan | Opcode::Equals => 3,
+            Opcode::JumpIfTrue | Opcode::JumpIfFalse => 2,
+            Opcode::Input | Opcode::Output => 1,
+            Opcode::Halt => 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ParameterMode {
+    // the parameter is the position of its value
+    Position,
+    // the parameter is the value itself
+    Immediate,
+}
+
+impl ParameterMode {
+    // the mode of the nth parameter, starting from 1, is the nth digit of the first value of an instruction,
+    // right to left, after the two digits of the opcode. missing digits are 0.
+    pub fn from_instruction(value: Value, nth: usize) -> Result<ParameterMode, String> {
+        let mode = (value / 10_i64.pow(nth as u32 + 1)) % 10;
+
+        match mode {
+            0 => Ok(ParameterMode::Position),
+            1 => Ok(ParameterMode::Immediate),
+            _ => Err(format!(
+                "Unknown mode of parameter {} of instruction {}",
+                nth, value
+            )),
+        }
+    }
+}
+
+// a queue of values that flow into or out of a computer.
+// the output of one computer can be fed straight into the input of another.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Channel {
+    values: VecDeque<Value>,
+}
+
+impl Channel {
+    pub fn new() -> Channel {
+        Channel {
+            values: VecDeque::new(),
+        }
+    }
+
+    pub fn send(&mut self, value: Value) {
+        self.values.push_back(value);
+    }
+
+    // the value that was sent the earliest
+    pub fn receive(&mut self) -> Option<Value> {
+        return self.values.pop_front();
+    }
+
+    // every value that was sent, in the order they were sent
+    pub fn drain(&mut self) -> Vec<Value> {
+        return self.values.drain(..).collect();
+    }
+
+    pub fn len(&self) -> usize {
+        return self.values.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.values.is_empty();
+    }
+}
+
+impl Extend<Value> for Channel {
+    fn extend<T: IntoIterator<Item = Value>>(&mut self, values: T) {
+        self.values.extend(values);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Status {
+    Running,
+    // the program needs a value from the input, which is empty.
+    // the program picks up from the same instruction once a value is sent to the input.
+    WaitingForInput,
+    Halted,
+}
+
+#[derive(Debug, Clone)]
+pub struct Computer {
+    pub memory: Vec<Value>,
+    pub instruction_pointer: Address,
+    pub input: Channel,
+    pub output: Channel,
+}
+
+impl Computer {
+    // the instruction pointer starts at the first value of the program
+    pub fn new(program: Vec<Value>) -> Computer {
+        Computer {
+            memory: program,
+            instruction_pointer: 0,
+            input: Channel::new(),
+            output: Channel::new(),
+        }
+    }
+
+    pub fn parse(input_string: &str) -> Result<Computer, String> {
+        return Ok(Computer::new(parse_program(input_string)?));
+    }
+
+    fn to_address(&self, value: Value) -> Result<Address, String> {
+        if value < 0 || value as usize >= self.memory.len() {
+            return Err(format!("Invalid address: {}", value));
+        }
+
+        return Ok(value as Address);
+    }
+
+    pub fn read(&self, address: Value) -> Result<Value, String> {
+        let address = self.to_address(address)?;
+        return Ok(self.memory[address]);
+    }
+
+    pub fn write(&mut self, address: Value, value: Value) -> Result<(), String> {
+        let address = self.to_address(address)?;
+        self.memory[address] = value;
+        return Ok(());
+    }
+
+    // the nth parameter of the current instruction as written, starting from 1
+    fn parameter(&self, nth: usize) -> Result<Value, String> {
+        return self.read((self.instruction_pointer + nth) as Value);
+    }
+
+    fn parameter_mode(&self, nth: usize) -> Result<ParameterMode, String> {
+        let instruction = self.read(self.instruction_pointer as Value)?;
+        return ParameterMode::from_instruction(instruction, nth);
+    }
+
+    // the value of the nth parameter, according to its mode
+    fn read_parameter(&self, nth: usize) -> Result<Value, String> {
+        let parameter = self.parameter(nth)?;
+
+        match self.parameter_mode(nth)? {
+            ParameterMode::Position => {
+                return self.read(parameter);
+            }
+            ParameterMode::Immediate => {
+                return Ok(parameter);
+            }
+        }
+    }
+
+    // store the value at the position of the nth parameter.
+    // parameters that an instruction writes to are never in immediate mode.
+    fn write_parameter(&mut self, nth: usize, value: Value) -> Result<(), String> {
+        let parameter = self.parameter(nth)?;
+
+        match self.parameter_mode(nth)? {
+            ParameterMode::Position => {
+                return self.write(parameter, value);
+            }
+            ParameterMode::Immediate => {
+                return Err(format!(
+                    "Parameter {} of the instruction at position {} is written to in immediate mode",
+                    nth, self.instruction_pointer
+                ));
+            }
+        }
+    }
+
+    fn jump_to(&mut self, address: Value) -> Result<(), String> {
+        if address < 0 {
+            return Err(format!("Invalid jump to position: {}", address));
+        }
+
+        self.instruction_pointer = address as Address;
+
+        return Ok(());
+    }
+
+    // execute the instruction the instruction pointer points to.
+    // the instruction pointer then moves on to the next instruction, unless the instruction jumped elsewhere.
+    pub fn step(&mut self) -> Result<Status, String> {
+        let value = self.read(self.instruction_pointer as Value)?;
+        let opcode = Opcode::from_value(value).ok_or_else(|| {
+            format!(
+                "Unknown opcode at position {}: {}",
+                self.instruction_pointer, value
+            )
+        })?;
+
+        match opcode {
+            Opcode::Add => {
+                let result = self.read_parameter(1)? + self.read_parameter(2)?;
+                self.write_parameter(3, result)?;
+            }
+            Opcode::Multiply => {
+                let result = self.read_parameter(1)? * self.read_parameter(2)?;
+                self.write_parameter(3, result)?;
+            }
+            Opcode::Input => {
+                // make sure the parameter is valid before the input value is taken
+                self.parameter(1)?;
+
+                match self.input.receive() {
+                    None => {
+                        return Ok(Status::WaitingForInput);
+                    }
+                    Some(input) => {
+                        self.write_parameter(1, input)?;
+                    }
+                }
+            }
+            Opcode::Output => {
+                let output = self.read_parameter(1)?;
+                self.output.send(output);
+            }
+            Opcode::JumpIfTrue | Opcode::JumpIfFalse => {
+                let condition = self.read_parameter(1)? != 0;
+
+                if condition == (opcode == Opcode::JumpIfTrue) {
+                    let address = self.read_parameter(2)?;
+                    self.jump_to(address)?;
+                    return Ok(Status::Running);
+                }
+            }
+            Opcode::LessThan => {
+                let result = self.read_parameter(1)? < self.read_parameter(2)?;
+                self.write_parameter(3, result as Value)?;
+            }
+            Opcode::Equals => {
+                let result = self.read_parameter(1)? == self.read_parameter(2)?;
+                self.write_parameter(3, result as Value)?;
+            }
+            Opcode::Halt => {
+                return Ok(Status::Halted);
+            }
+        }
+
+        self.instruction_pointer += 1 + opcode.num_of_parameters();
+
+        return Ok(Status::Running);
+    }
+
+    // run the program until it halts, or until it needs a value from an empty input
+    pub fn run(&mut self) -> Result<Status, String> {
+        loop {
+            let status = self.step()?;
+
+            if status != Status::Running {
+                return Ok(status);
+            }
+        }
+    }
+
+    // send the values to the input, and run the program until it halts or runs out of input.
+    // returns the values the program sent to the output along the way.
+    pub fn run_with_input(&mut self, input: &[Value]) -> Result<Vec<Value>, String> {
+        self.input.extend(input.iter().cloned());
+        self.run()?;
+
+        return Ok(self.output.drain());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(input_string: &str) -> Result<Vec<Value>, String> {
+        let mut computer = Computer::parse(input_string)?;
+        computer.run()?;
+        return Ok(computer.memory);
+    }
+
+    fn run_with_input(input_string: &str, input: &[Value]) -> Result<Vec<Value>, String> {
+        let mut computer = Computer::parse(input_string)?;
+        return computer.run_with_input(input);
+    }
+
+    #[test]
+    fn test_parse_program() {
+        assert_eq!(parse_program("1,0,0,3,99\n"), Ok(vec![1, 0, 0, 3, 99]));
+        assert_eq!(parse_program("-1, 2"), Ok(vec![-1, 2]));
+        assert!(parse_program("1,,2").is_err());
+    }
+
+    #[test]
+    fn test_run() {
+        assert_eq!(
+            run("1,9,10,3,2,3,11,0,99,30,40,50"),
+            Ok(vec![3500, 9, 10, 70, 2, 3, 11, 0, 99, 30, 40, 50])
+        );
+        assert_eq!(run("1,0,0,0,99"), Ok(vec![2, 0, 0, 0, 99]));
+        assert_eq!(run("2,3,0,3,99"), Ok(vec![2, 3, 0, 6, 99]));
+        assert_eq!(run("2,4,4,5,99,0"), Ok(vec![2, 4, 4, 5, 99, 9801]));
+        assert_eq!(
+            run("1,1,1,4,99,5,6,0,99"),
+            Ok(vec![30, 1, 1, 4, 2, 5, 6, 0, 99])
+        );
+    }
+
+    #[test]
+    fn test_step() {
+        let mut computer = Computer::parse("1,9,10,3,2,3,11,0,99,30,40,50").unwrap();
+
+        assert_eq!(computer.step(), Ok(Status::Running));
+        assert_eq!(computer.instruction_pointer, 4);
+        assert_eq!(computer.memory[3], 70);
+
+        assert_eq!(computer.step(), Ok(Status::Running));
+        assert_eq!(computer.step(), Ok(Status::Halted));
+        assert_eq!(computer.instruction_pointer, 8);
+    }
+
+    #[test]
+    fn test_parameter_modes() {
+        assert_eq!(
+            ParameterMode::from_instruction(1002, 1),
+            Ok(ParameterMode::Position)
+        );
+        assert_eq!(
+            ParameterMode::from_instruction(1002, 2),
+            Ok(ParameterMode::Immediate)
+        );
+        assert_eq!(
+            ParameterMode::from_instruction(1002, 3),
+            Ok(ParameterMode::Position)
+        );
+        assert!(ParameterMode::from_instruction(3002, 2).is_err());
+
+        // multiply the value at position 4 by 3, and store it at position 4
+        assert_eq!(run("1002,4,3,4,33"), Ok(vec![1002, 4, 3, 4, 99]));
+        // negative values
+        assert_eq!(run("1101,100,-1,4,0"), Ok(vec![1101, 100, -1, 4, 99]));
+
+        // parameters that are written to are never in immediate mode
+        assert!(run("11101,1,1,4,99").is_err());
+    }
+
+    #[test]
+    fn test_input_and_output() {
+        // outputs whatever it gets as input
+        assert_eq!(run_with_input("3,0,4,0,99", &[42]), Ok(vec![42]));
+
+        // waits for input, and picks up from the same instruction once there is some
+        let mut computer = Computer::parse("3,0,4,0,99").unwrap();
+        assert_eq!(computer.run(), Ok(Status::WaitingForInput));
+        assert_eq!(computer.instruction_pointer, 0);
+        assert!(computer.output.is_empty());
+
+        computer.input.send(7);
+        assert_eq!(computer.run(), Ok(Status::Halted));
+        assert_eq!(computer.output.drain(), vec![7]);
+        assert!(computer.output.is_empty());
+    }
+
+    #[test]
+    fn test_channels() {
+        // the output of one computer feeds the input of another
+        let mut first = Computer::parse("3,0,102,2,0,0,4,0,99").unwrap();
+        let mut second = Computer::parse("3,0,1001,0,1,0,4,0,99").unwrap();
+
+        first.input.send(20);
+        first.run().unwrap();
+        second.input.extend(first.output.drain());
+        second.run().unwrap();
+
+        assert_eq!(second.output.receive(), Some(41));
+        assert_eq!(second.output.receive(), None);
+
+        let mut channel = Channel::new();
+        channel.extend(vec![1, 2]);
+        channel.send(3);
+        assert_eq!(channel.len(), 3);
+        assert_eq!(channel.receive(), Some(1));
+        assert_eq!(channel.drain(), vec![2, 3]);
+        assert!(channel.is_empty());
+    }
+
+    #[test]
+    fn test_comparisons() {
+        // is the input equal to 8, in position mode and in immediate mode
+        for program in &["3,9,8,9,10,9,4,9,99,-1,8", "3,3,1108,-1,8,3,4,3,99"] {
+            assert_eq!(run_with_input(program, &[8]), Ok(vec![1]));
+            assert_eq!(run_with_input(program, &[7]), Ok(vec![0]));
+        }
+
+        // is the input less than 8, in position mode and in immediate mode
+        for program in &["3,9,7,9,10,9,4,9,99,-1,8", "3,3,1107,-1,8,3,4,3,99"] {
+            assert_eq!(run_with_input(program, &[7]), Ok(vec![1]));
+            assert_eq!(run_with_input(program, &[8]), Ok(vec![0]));
+            assert_eq!(run_with_input(program, &[9]), Ok(vec![0]));
+        }
+    }
+
+    #[test]
+    fn test_jumps() {
+        // is the input non-zero, in position mode and in immediate mode
+        for program in &[
+            "3,12,6,12,15,1,13,14,13,4,13,99,-1,0,1,9",
+            "3,3,1105,-1,9,1101,0,0,12,4,12,99,1",
+        ] {
+            assert_eq!(run_with_input(program, &[0]), Ok(vec![0]));
+            assert_eq!(run_with_input(program, &[5]), Ok(vec![1]));
+        }
+
+        // 999 if the input is below 8, 1000 if it is equal to 8, and 1001 if it is greater than 8
+        let program = "3,21,1008,21,8,20,1005,20,22,107,8,21,20,1006,20,31,\
+                       1106,0,36,98,0,0,1002,21,125,20,4,20,1105,1,46,104,\
+                       999,1105,1,46,1101,1000,1,20,4,20,1105,1,46,98,99";
+        assert_eq!(run_with_input(program, &[7]), Ok(vec![999]));
+        assert_eq!(run_with_input(program, &[8]), Ok(vec![1000]));
+        assert_eq!(run_with_input(program, &[9]), Ok(vec![1001]));
+
+        // a jump to a negative position
+        assert!(run("1105,1,-1").is_err());
+    }
+
+    #[test]
+    fn test_errors() {
+        // unknown opcode
+        assert!(run("42,0,0,0,99").is_err());
+        // a position outside of memory
+        assert!(run("1,0,7,0,99").is_err());
+        // runs off the end of memory without halting
+        assert!(run("1,0,0,0").is_err());
+    }
+}
@@ -0,0 +1,193 @@
+// the solutions of a puzzle, in a shape that tools can run without knowing anything else about the puzzle.
+// shared by the puzzles of every year, which each export a SOLVER, and the aoc runner that collects them.
+
+// imports
+
+use std::io::Read;
+
+// code
+
+// the path of the puzzle input, when a puzzle isn't given one on the command line
+pub const INPUT_VAR: &str = "AOC_INPUT";
+
+pub type Year = u16;
+
+pub type Day = u8;
+
+// answers come in all sorts of types, so they're handed over as text
+pub type Answer = Result<String, String>;
+
+// solves a part of the puzzle for the given puzzle input
+pub type Part = fn(&str) -> Answer;
+
+// a sample input from the description of the puzzle, with the answers the description gives for it
+#[derive(Debug, Clone, Copy)]
+pub struct Example {
+    pub input: &'static str,
+    // the answer of every part the example is given for
+    pub part_1: Option<&'static str>,
+    pub part_2: Option<&'static str>,
+}
+
+impl Example {
+    pub fn answer(&self, part: usize) -> Option<&'static str> {
+        match part {
+            1 => self.part_1,
+            2 => self.part_2,
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Solver {
+    pub year: Year,
+    pub day: Day,
+    // the puzzle input that is bundled with the puzzle
+    pub input: &'static str,
+    pub part_1: Part,
+    // the last day of an event only has one part
+    pub part_2: Option<Part>,
+    // the examples of the puzzle that can be solved by its parts as they are;
+    // an example that needs other parameters than the puzzle input, such as fewer workers, is left out.
+    pub examples: &'static [Example],
+}
+
+impl Solver {
+    // the name of the package of the puzzle, such as day-01-2018
+    pub fn name(&self) -> String {
+        return format!("day-{:02}-{}", self.day, self.year);
+    }
+
+    // the parts of the puzzle, numbered from 1
+    pub fn parts(&self) -> Vec<(usize, Part)> {
+        let mut parts = vec![(1, self.part_1)];
+
+        if let Some(part_2) = self.part_2 {
+            parts.push((2, part_2));
+        }
+
+        return parts;
+    }
+
+    pub fn part(&self, part: usize) -> Option<Part> {
+        match part {
+            1 => Some(self.part_1),
+            2 => self.part_2,
+            _ => None,
+        }
+    }
+}
+
+// read the puzzle input from a file path, or from stdin if the path is -
+pub fn read_input(path: &str) -> Result<String, String> {
+    if path == "-" {
+        let mut input_string = String::new();
+        std::io::stdin()
+            .read_to_string(&mut input_string)
+            .map_err(|err| format!("Unable to read stdin: {}", err))?;
+        return Ok(input_string);
+    }
+
+    return std::fs::read_to_string(path)
+        .map_err(|err| format!("Unable to read {}: {}", path, err));
+}
+
+// the puzzle input is read from, in order of preference:
+// - the given path (or stdin)
+// - the path in AOC_INPUT (or stdin)
+// the input bundled with a puzzle is only for its tests, so there's no falling back to it.
+pub fn load_input(path: Option<&str>) -> Result<String, String> {
+    if let Some(path) = path {
+        return read_input(path);
+    }
+
+    match std::env::var(INPUT_VAR) {
+        Ok(path) if !path.is_empty() => {
+            return read_input(&path);
+        }
+        _ => {
+            return Err(format!(
+                "No puzzle input; pass a path to it, - to read it from stdin, or set {}",
+                INPUT_VAR
+            ));
+        }
+    }
+}
+
+// the puzzle input of a puzzle that has no options, only a path:
+// <path>
+pub fn load_input_from_args(args: &[String]) -> Result<String, String> {
+    match args {
+        [] => load_input(None),
+        [path] if !path.starts_with("--") => load_input(Some(path)),
+        [arg] => Err(format!("Unknown flag: {}", arg)),
+        [_, arg, ..] => Err(format!("Unexpected argument: {}", arg)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOLVER: Solver = Solver {
+        year: 2018,
+        day: 1,
+        input: "+1\n-2\n",
+        part_1: |input_string| Ok(input_string.lines().count().to_string()),
+        part_2: None,
+        examples: &[Example {
+            input: "+1\n+1\n-2\n",
+            part_1: Some("3"),
+            part_2: None,
+        }],
+    };
+
+    #[test]
+    fn test_solver() {
+        assert_eq!(SOLVER.name(), "day-01-2018");
+
+        let parts = SOLVER.parts();
+        assert_eq!(parts.len(), 1);
+        assert_eq!(parts[0].0, 1);
+        assert_eq!((parts[0].1)(SOLVER.input), Ok("2".to_string()));
+
+        assert!(SOLVER.part(1).is_some());
+        assert!(SOLVER.part(2).is_none());
+        assert!(SOLVER.part(3).is_none());
+
+        let example = SOLVER.examples[0];
+        assert_eq!((parts[0].1)(example.input), Ok("3".to_string()));
+        assert_eq!(example.answer(1), Some("3"));
+        assert_eq!(example.answer(2), None);
+
+        let solver = Solver {
+            part_2: Some(|_input_string| Err("No answer".to_string())),
+            ..SOLVER
+        };
+        assert_eq!(solver.parts().len(), 2);
+        assert_eq!(
+            solver.part(2).map(|part| part(solver.input)),
+            Some(Err("No answer".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_load_input() {
+        let path = std::env::temp_dir().join(format!("solver-input-{}.txt", std::process::id()));
+        std::fs::write(&path, "+3\n").unwrap();
+        let path = path.to_str().unwrap().to_string();
+
+        assert_eq!(load_input(Some(&path)), Ok("+3\n".to_string()));
+        assert!(load_input(Some("does-not-exist.txt")).is_err());
+
+        assert_eq!(
+            load_input_from_args(std::slice::from_ref(&path)),
+            Ok("+3\n".to_string())
+        );
+        assert!(load_input_from_args(&["--verbose".to_string()]).is_err());
+        assert!(load_input_from_args(&[path.clone(), path.clone()]).is_err());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
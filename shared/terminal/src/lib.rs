@@ -0,0 +1,78 @@
+// draw grids of cells on the terminal, and animate them by redrawing every frame in place.
+// shared by the puzzles that play out on a grid.
+
+// imports
+
+use std::io::Write;
+use std::time::Duration;
+
+// code
+
+// https://en.wikipedia.org/wiki/ANSI_escape_code#Terminal_output_sequences
+pub const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+// https://en.wikipedia.org/wiki/ANSI_escape_code#Colors
+pub const RESET: &str = "\x1b[0m";
+pub const BOLD: &str = "\x1b[1m";
+pub const RED: &str = "\x1b[31m";
+pub const GREEN: &str = "\x1b[32m";
+pub const YELLOW: &str = "\x1b[33m";
+
+// wrap text in an ANSI style, such as one of the colors above
+pub fn colorize(text: &str, style: &str) -> String {
+    return format!("{}{}{}", style, text, RESET);
+}
+
+// redraw every frame in place, waiting delay between frames.
+// frames are drawn as they're produced, so they don't all need to be kept around.
+pub fn animate_to<W: Write>(
+    writer: &mut W,
+    frames: impl IntoIterator<Item = String>,
+    delay: Duration,
+) -> Result<(), String> {
+    let mut frames = frames.into_iter().peekable();
+
+    while let Some(frame) = frames.next() {
+        write!(writer, "{}{}", CLEAR_SCREEN, frame)
+            .and_then(|_| writer.flush())
+            .map_err(|err| format!("Unable to draw frame: {}", err))?;
+
+        if frames.peek().is_some() {
+            std::thread::sleep(delay);
+        }
+    }
+
+    return Ok(());
+}
+
+pub fn animate(frames: impl IntoIterator<Item = String>, delay: Duration) -> Result<(), String> {
+    let stdout = std::io::stdout();
+    return animate_to(&mut stdout.lock(), frames, delay);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_colorize() {
+        assert_eq!(colorize("X", RED), "\x1b[31mX\x1b[0m");
+        assert_eq!(colorize("", BOLD), "\x1b[1m\x1b[0m");
+    }
+
+    #[test]
+    fn test_animate_to() {
+        let frames = (0..3).map(|frame| format!("frame {}\n", frame));
+
+        let mut output: Vec<u8> = vec![];
+        animate_to(&mut output, frames, Duration::from_millis(0)).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            format!(
+                "{}frame 0\n{}frame 1\n{}frame 2\n",
+                CLEAR_SCREEN, CLEAR_SCREEN, CLEAR_SCREEN
+            )
+        );
+    }
+}
@@ -0,0 +1,115 @@
+// disjoint sets of elements numbered 0 to n - 1, which can be merged together.
+// shared by the puzzles that group things together by some relation, such as constellations of points.
+
+// code
+
+#[derive(Debug, Clone)]
+pub struct UnionFind {
+    // the parent of every element; the root of a set is its own parent
+    parents: Vec<usize>,
+    // an upper bound on the height of the tree of every root, to keep the trees shallow when merging
+    ranks: Vec<usize>,
+    num_of_sets: usize,
+}
+
+impl UnionFind {
+    // every element starts out in a set of its own
+    pub fn new(num_of_elements: usize) -> UnionFind {
+        UnionFind {
+            parents: (0..num_of_elements).collect(),
+            ranks: vec![0; num_of_elements],
+            num_of_sets: num_of_elements,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        return self.parents.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.parents.is_empty();
+    }
+
+    pub fn num_of_sets(&self) -> usize {
+        return self.num_of_sets;
+    }
+
+    // the root of the set the element is in. the elements along the way are pointed straight at the root.
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+
+        let mut current = element;
+        while self.parents[current] != root {
+            let parent = self.parents[current];
+            self.parents[current] = root;
+            current = parent;
+        }
+
+        return root;
+    }
+
+    // merge the sets of both elements. returns false if they were already in the same set.
+    pub fn union(&mut self, first: usize, second: usize) -> bool {
+        let first_root = self.find(first);
+        let second_root = self.find(second);
+
+        if first_root == second_root {
+            return false;
+        }
+
+        // the shallower tree goes under the deeper one
+        if self.ranks[first_root] < self.ranks[second_root] {
+            self.parents[first_root] = second_root;
+        } else {
+            self.parents[second_root] = first_root;
+
+            if self.ranks[first_root] == self.ranks[second_root] {
+                self.ranks[first_root] += 1;
+            }
+        }
+
+        self.num_of_sets -= 1;
+
+        return true;
+    }
+
+    pub fn is_same_set(&mut self, first: usize, second: usize) -> bool {
+        return self.find(first) == self.find(second);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_union_find() {
+        let mut sets = UnionFind::new(6);
+
+        assert_eq!(sets.len(), 6);
+        assert_eq!(sets.num_of_sets(), 6);
+        assert!(!sets.is_same_set(0, 1));
+
+        assert!(sets.union(0, 1));
+        assert!(sets.union(2, 3));
+        assert!(sets.union(1, 3));
+        assert_eq!(sets.num_of_sets(), 3);
+
+        assert!(sets.is_same_set(0, 2));
+        assert!(!sets.is_same_set(0, 4));
+        assert_eq!(sets.find(3), sets.find(0));
+
+        // already in the same set
+        assert!(!sets.union(3, 0));
+        assert_eq!(sets.num_of_sets(), 3);
+
+        assert!(sets.union(4, 5));
+        assert!(sets.union(5, 0));
+        assert_eq!(sets.num_of_sets(), 1);
+
+        assert!(UnionFind::new(0).is_empty());
+    }
+}
@@ -0,0 +1,194 @@
+// draw paths and markers into SVG images.
+// shared by the puzzles that trace routes through a plane, such as tracks or wires.
+
+// imports
+
+use std::path::Path;
+
+// code
+
+pub type Color = [u8; 3];
+
+pub type Point = (f64, f64);
+
+// distinct colors for the paths of a drawing, e.g. one per cart or wire
+const PALETTE: [Color; 8] = [
+    [230, 25, 75],
+    [60, 180, 75],
+    [0, 130, 200],
+    [245, 130, 48],
+    [145, 30, 180],
+    [70, 240, 240],
+    [240, 50, 230],
+    [210, 245, 60],
+];
+
+// the nth color of the palette; colors repeat after the palette runs out
+pub fn get_color(index: usize) -> Color {
+    return PALETTE[index % PALETTE.len()];
+}
+
+fn to_hex(color: Color) -> String {
+    let [red, green, blue] = color;
+    return format!("#{:02x}{:02x}{:02x}", red, green, blue);
+}
+
+// numbers are rounded to 3 decimals, so that sums like 12.7 - 0.3 don't print as 12.399999999999999
+fn format_number(number: f64) -> String {
+    return format!("{}", (number * 1000.0).round() / 1000.0);
+}
+
+fn format_point(point: Point) -> String {
+    let (x, y) = point;
+    return format!("{} {}", format_number(x), format_number(y));
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Drawing {
+    // the elements of the image, in the order they're drawn
+    elements: Vec<String>,
+    // the smallest box containing every point drawn so far, as (min, max)
+    bounds: Option<(Point, Point)>,
+}
+
+impl Drawing {
+    pub fn new() -> Drawing {
+        return Drawing::default();
+    }
+
+    fn include(&mut self, point: Point, margin: f64) {
+        let (x, y) = point;
+        let ((min_x, min_y), (max_x, max_y)) = self.bounds.unwrap_or(((x, y), (x, y)));
+
+        self.bounds = Some((
+            (min_x.min(x - margin), min_y.min(y - margin)),
+            (max_x.max(x + margin), max_y.max(y + margin)),
+        ));
+    }
+
+    // a set of line segments that are drawn as one path
+    pub fn add_segments(&mut self, segments: &[(Point, Point)], color: Color, width: f64) {
+        if segments.is_empty() {
+            return;
+        }
+
+        let mut data = vec![];
+        for (start, end) in segments {
+            self.include(*start, width / 2.0);
+            self.include(*end, width / 2.0);
+            data.push(format!("M{} L{}", format_point(*start), format_point(*end)));
+        }
+
+        self.elements.push(format!(
+            r#"<path d="{}" fill="none" stroke="{}" stroke-width="{}" stroke-linecap="round"/>"#,
+            data.join(" "),
+            to_hex(color),
+            format_number(width)
+        ));
+    }
+
+    // a path through every point, in order
+    pub fn add_polyline(&mut self, points: &[Point], color: Color, width: f64) {
+        if points.is_empty() {
+            return;
+        }
+
+        let mut data = vec![];
+        for (index, point) in points.iter().enumerate() {
+            self.include(*point, width / 2.0);
+            let command = if index == 0 { "M" } else { "L" };
+            data.push(format!("{}{}", command, format_point(*point)));
+        }
+
+        self.elements.push(format!(
+            r#"<path d="{}" fill="none" stroke="{}" stroke-width="{}" stroke-linecap="round" stroke-linejoin="round"/>"#,
+            data.join(" "),
+            to_hex(color),
+            format_number(width)
+        ));
+    }
+
+    pub fn add_circle(&mut self, center: Point, radius: f64, color: Color) {
+        self.include(center, radius);
+
+        self.elements.push(format!(
+            r#"<circle cx="{}" cy="{}" r="{}" fill="{}"/>"#,
+            format_number(center.0),
+            format_number(center.1),
+            format_number(radius),
+            to_hex(color)
+        ));
+    }
+
+    // the drawing as an SVG document; the view box fits everything drawn, and y points down
+    pub fn to_svg(&self) -> String {
+        let ((min_x, min_y), (max_x, max_y)) = self.bounds.unwrap_or(((0.0, 0.0), (0.0, 0.0)));
+
+        let mut lines = vec![format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{} {} {} {}">"#,
+            format_number(min_x),
+            format_number(min_y),
+            format_number(max_x - min_x),
+            format_number(max_y - min_y)
+        )];
+
+        for element in self.elements.iter() {
+            lines.push(format!("  {}", element));
+        }
+
+        lines.push("</svg>".to_string());
+
+        return lines.join("\n") + "\n";
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        return std::fs::write(path, self.to_svg())
+            .map_err(|err| format!("Unable to write {}: {}", path.display(), err));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_svg() {
+        assert_eq!(
+            Drawing::new().to_svg(),
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 0 0\">\n</svg>\n"
+        );
+
+        let mut drawing = Drawing::new();
+        drawing.add_segments(
+            &[((0.0, 0.0), (4.0, 0.0)), ((4.0, 0.0), (4.0, 3.0))],
+            [0, 0, 0],
+            1.0,
+        );
+        drawing.add_polyline(&[(1.0, 1.0), (2.0, 1.0), (2.0, 2.5)], get_color(0), 0.5);
+        drawing.add_circle((4.0, 3.0), 1.0, get_color(1));
+
+        assert_eq!(
+            drawing.to_svg(),
+            r###"<svg xmlns="http://www.w3.org/2000/svg" viewBox="-0.5 -0.5 5.5 4.5">
+  <path d="M0 0 L4 0 M4 0 L4 3" fill="none" stroke="#000000" stroke-width="1" stroke-linecap="round"/>
+  <path d="M1 1 L2 1 L2 2.5" fill="none" stroke="#e6194b" stroke-width="0.5" stroke-linecap="round" stroke-linejoin="round"/>
+  <circle cx="4" cy="3" r="1" fill="#3cb44b"/>
+</svg>
+"###
+        );
+    }
+
+    #[test]
+    fn test_format_number() {
+        assert_eq!(format_number(3.0), "3");
+        assert_eq!(format_number(-0.25), "-0.25");
+        assert_eq!(format_number(12.7 - 0.3), "12.4");
+        assert_eq!(format_point((0.1 + 0.2, 1.0 / 3.0)), "0.3 0.333");
+    }
+
+    #[test]
+    fn test_get_color() {
+        assert_eq!(get_color(0), get_color(PALETTE.len()));
+        assert_ne!(get_color(0), get_color(1));
+    }
+}